@@ -5,6 +5,8 @@
 //! - [Black-Scholes Option Pricing Model](models/black_scholes/struct.BlackScholesModel.html)
 //! - [Binomial Option Pricing Model](models/binomial_tree/struct.BinomialTreeModel.html)
 //! - [Monte Carlo Option Pricing Model](models/monte_carlo/struct.MonteCarloModel.html)
+//! - [Finite Difference Option Pricing Model](models/finite_diff/struct.FiniteDiffModel.html)
+//! - [Heston Stochastic Volatility Model](models/heston/struct.HestonModel.html)
 //!
 //! ## Greek calculations
 //!
@@ -15,10 +17,15 @@ pub use self::types::*;
 pub use greeks::*;
 pub use instrument::*;
 pub use models::*;
+pub use term_structure::*;
 pub use traits::*;
+pub use vol_surface::*;
 
 mod greeks;
 mod instrument;
 mod models;
+mod numerics;
+mod term_structure;
 mod traits;
 mod types;
+mod vol_surface;
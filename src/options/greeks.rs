@@ -22,8 +22,7 @@
 //! println!("Rho: {}", greeks.rho);
 //! ```
 
-use super::{Option, OptionGreeks};
-use std::panic::{AssertUnwindSafe, catch_unwind};
+use super::{Option, OptionGreeks, PricingError};
 
 /// A struct representing the Greeks of an option.
 #[derive(Debug)]
@@ -37,9 +36,9 @@ pub struct Greeks {
     pub theta: f64,
     /// Rho measures the rate of change of the option price with respect to changes in the risk-free interest rate.
     pub rho: f64,
-    /// Lambda measures the rate of change of the option delta with respect to changes in the risk-free interest rate.
+    /// Lambda (elasticity) measures the percentage change of the option price per percentage change in the price of the underlying asset.
     pub lambda: f64,
-    /// Epsilon measures the rate of change of the option delta with respect to changes in the dividend yield.
+    /// Epsilon measures the rate of change of the option price with respect to changes in the dividend yield.
     pub epsilon: f64,
 
     // Second-order Greeks
@@ -53,7 +52,7 @@ pub struct Greeks {
     pub vomma: f64,
     /// Veta measures the rate of change of the option vega with respect to changes in time to maturity.
     pub veta: f64,
-    /// Speed measures the rate of change of the option gamma with respect to changes in the price of the underlying asset.
+    /// Vera measures the rate of change of the option rho with respect to changes in the volatility of the underlying asset.
     pub vera: f64,
 
     // Third-order Greeks
@@ -72,10 +71,12 @@ pub struct Greeks {
 impl Greeks {
     /// Calculate the Greeks for a given option.
     ///
+    /// Greeks that cannot be calculated (e.g., not implemented by the model) are set to zero.
+    ///
     /// Arguments
     ///
+    /// * `model` - The model used to calculate the Greeks.
     /// * `option` - The option for which to calculate the Greeks.
-    /// * `option_type` - The type of option (Call or Put).
     ///
     /// Returns
     ///
@@ -83,25 +84,70 @@ impl Greeks {
     #[rustfmt::skip]
     pub fn calculate<T: OptionGreeks, S: Option>(model: &T, option: &S) -> Self {
         Greeks {
-            delta: catch_unwind(AssertUnwindSafe(|| model.delta(option))).unwrap_or_default(),
-            vega: catch_unwind(AssertUnwindSafe(|| model.vega(option))).unwrap_or_default(),
-            theta: catch_unwind(AssertUnwindSafe(|| model.theta(option))).unwrap_or_default(),
-            rho: catch_unwind(AssertUnwindSafe(|| model.rho(option))).unwrap_or_default(),
-            lambda: catch_unwind(AssertUnwindSafe(|| model.lambda(option))).unwrap_or_default(),
-            epsilon: catch_unwind(AssertUnwindSafe(|| model.epsilon(option))).unwrap_or_default(),
+            delta: model.try_delta(option).unwrap_or_default(),
+            vega: model.try_vega(option).unwrap_or_default(),
+            theta: model.try_theta(option).unwrap_or_default(),
+            rho: model.try_rho(option).unwrap_or_default(),
+            lambda: model.try_lambda(option).unwrap_or_default(),
+            epsilon: model.try_epsilon(option).unwrap_or_default(),
 
-            gamma: catch_unwind(AssertUnwindSafe(|| model.gamma(option))).unwrap_or_default(),
-            vanna: catch_unwind(AssertUnwindSafe(|| model.vanna(option))).unwrap_or_default(),
-            charm: catch_unwind(AssertUnwindSafe(|| model.charm(option))).unwrap_or_default(),
-            vomma: catch_unwind(AssertUnwindSafe(|| model.vomma(option))).unwrap_or_default(),
-            veta: catch_unwind(AssertUnwindSafe(|| model.veta(option))).unwrap_or_default(),
-            vera: catch_unwind(AssertUnwindSafe(|| model.vera(option))).unwrap_or_default(),
+            gamma: model.try_gamma(option).unwrap_or_default(),
+            vanna: model.try_vanna(option).unwrap_or_default(),
+            charm: model.try_charm(option).unwrap_or_default(),
+            vomma: model.try_vomma(option).unwrap_or_default(),
+            veta: model.try_veta(option).unwrap_or_default(),
+            vera: model.try_vera(option).unwrap_or_default(),
 
-            speed: catch_unwind(AssertUnwindSafe(|| model.speed(option))).unwrap_or_default(),
-            zomma: catch_unwind(AssertUnwindSafe(|| model.zomma(option))).unwrap_or_default(),
-            color: catch_unwind(AssertUnwindSafe(|| model.color(option))).unwrap_or_default(),
-            ultima: catch_unwind(AssertUnwindSafe(|| model.ultima(option))).unwrap_or_default(),
-            parmicharma: catch_unwind(AssertUnwindSafe(|| model.parmicharma(option))).unwrap_or_default(),
+            speed: model.try_speed(option).unwrap_or_default(),
+            zomma: model.try_zomma(option).unwrap_or_default(),
+            color: model.try_color(option).unwrap_or_default(),
+            ultima: model.try_ultima(option).unwrap_or_default(),
+            parmicharma: model.try_parmicharma(option).unwrap_or_default(),
         }
     }
+
+    /// Calculate the Greeks for a given option without panicking.
+    ///
+    /// Greeks not implemented by the model are set to zero, but unsupported option styles and invalid inputs
+    /// are returned as errors.
+    ///
+    /// Arguments
+    ///
+    /// * `model` - The model used to calculate the Greeks.
+    /// * `option` - The option for which to calculate the Greeks.
+    ///
+    /// Returns
+    ///
+    /// The calculated Greeks or the reason why they cannot be calculated.
+    pub fn try_calculate<T: OptionGreeks, S: Option>(
+        model: &T,
+        option: &S,
+    ) -> Result<Self, PricingError> {
+        let available = |greek: Result<f64, PricingError>| match greek {
+            Err(PricingError::NotImplemented(_)) => Ok(0.0),
+            greek => greek,
+        };
+
+        Ok(Greeks {
+            delta: available(model.try_delta(option))?,
+            vega: available(model.try_vega(option))?,
+            theta: available(model.try_theta(option))?,
+            rho: available(model.try_rho(option))?,
+            lambda: available(model.try_lambda(option))?,
+            epsilon: available(model.try_epsilon(option))?,
+
+            gamma: available(model.try_gamma(option))?,
+            vanna: available(model.try_vanna(option))?,
+            charm: available(model.try_charm(option))?,
+            vomma: available(model.try_vomma(option))?,
+            veta: available(model.try_veta(option))?,
+            vera: available(model.try_vera(option))?,
+
+            speed: available(model.try_speed(option))?,
+            zomma: available(model.try_zomma(option))?,
+            color: available(model.try_color(option))?,
+            ultima: available(model.try_ultima(option))?,
+            parmicharma: available(model.try_parmicharma(option))?,
+        })
+    }
 }
@@ -10,21 +10,58 @@
 //! ```
 //! use quantrs::options::Instrument;
 //!
-//! let asset1 = Instrument::new().with_spot(100.0);
-//! let asset2 = Instrument::new().with_spot(110.0);
+//! let asset1 = Instrument::new().with_spot(100.0).with_volatility(0.2);
+//! let asset2 = Instrument::new().with_spot(110.0).with_volatility(0.3);
 //!
 //! let instrument = Instrument::new()
 //!     .with_spot(100.0)
 //!     .with_continuous_dividend_yield(0.2)
 //!     .with_discrete_dividend_yield(0.0)
 //!     .with_dividend_times(vec![])
-//!     .with_weighted_assets(vec![(asset1, 0.5), (asset2, 0.5)]);
+//!     .with_weighted_assets(vec![(asset1, 0.5), (asset2, 0.5)])
+//!     .with_correlation_matrix(vec![vec![1.0, 0.5], vec![0.5, 1.0]]);
+//! ```
+//!
+//! Cash dividends are given as a schedule of amounts paid on their ex-dividend dates:
+//!
+//! ```
+//! use quantrs::options::{CashDividend, Instrument};
+//!
+//! let instrument = Instrument::new()
+//!     .with_spot(100.0)
+//!     .with_cash_dividends(vec![CashDividend::new(0.25, 1.5), CashDividend::new(0.75, 1.5)]);
+//! assert!(instrument.escrowed_spot(0.05, 1.0) < 97.1);
 //! ```
 
 use core::f64;
-use rand::rngs::ThreadRng;
+use rand::Rng;
 use rand_distr::{Distribution, Normal};
 
+/// A cash dividend paid on its ex-dividend date.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CashDividend {
+    /// Time of the ex-dividend date in years, at which the spot price drops by the amount.
+    pub ex_date: f64,
+    /// Amount of the dividend in units of the spot price.
+    pub amount: f64,
+}
+
+impl CashDividend {
+    /// Create a new `CashDividend`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ex_date` - The time of the ex-dividend date in years.
+    /// * `amount` - The amount of the dividend.
+    ///
+    /// # Returns
+    ///
+    /// A new `CashDividend`.
+    pub fn new(ex_date: f64, amount: f64) -> Self {
+        Self { ex_date, amount }
+    }
+}
+
 /// A struct representing an instrument with dividend properties.
 #[derive(Debug, Default, Clone)]
 pub struct Instrument {
@@ -36,10 +73,19 @@ pub struct Instrument {
     pub discrete_dividend_yield: f64,
     /// Times at which discrete dividends are paid.
     pub dividend_times: Vec<f64>,
+    /// Cash dividends sorted by their ex-dividend dates.
+    pub cash_dividends: Vec<CashDividend>,
     /// Assets and their weights.
     pub assets: Vec<(Instrument, f64)>,
     /// Whether the assets are sorted by performance.
     pub sorted: bool,
+    /// Volatility of the instrument (used for the constituents of multi-asset options; models fall back to their own volatility if not set).
+    pub volatility: Option<f64>,
+    /// Correlation matrix of the assets' returns in the order in which the assets were passed (empty means
+    /// uncorrelated assets; see [`Instrument::correlation_matrix`] for the matrix indexed like `assets`).
+    pub correlation: Vec<Vec<f64>>,
+    /// Position of each of the `assets` in the order in which they were passed (empty if not reordered).
+    pub asset_order: Vec<usize>,
 }
 
 impl Instrument {
@@ -54,8 +100,12 @@ impl Instrument {
             continuous_dividend_yield: 0.0,
             discrete_dividend_yield: 0.0,
             dividend_times: Vec::new(),
+            cash_dividends: Vec::new(),
             assets: Vec::new(),
             sorted: false,
+            volatility: None,
+            correlation: Vec::new(),
+            asset_order: Vec::new(),
         }
     }
 
@@ -155,6 +205,21 @@ impl Instrument {
         self
     }
 
+    /// Set the cash dividends of the instrument.
+    ///
+    /// # Arguments
+    ///
+    /// * `dividends` - The cash dividends of the instrument (in any order).
+    ///
+    /// # Returns
+    ///
+    /// The instrument with the cash dividends set, sorted by their ex-dividend dates.
+    pub fn with_cash_dividends(mut self, mut dividends: Vec<CashDividend>) -> Self {
+        dividends.sort_by(|a, b| a.ex_date.total_cmp(&b.ex_date));
+        self.cash_dividends = dividends;
+        self
+    }
+
     /// Set the assets of the instrument.
     ///
     /// # Arguments
@@ -171,6 +236,7 @@ impl Instrument {
 
         let weight = 1.0 / assets.len() as f64;
         self.assets = assets.iter().map(|asset| (asset.clone(), weight)).collect();
+        self.asset_order.clear();
         let new_spot = self.assets.iter().map(|(a, w)| a.spot() * w).sum::<f64>();
         self.spot = vec![new_spot];
 
@@ -193,14 +259,83 @@ impl Instrument {
         }
 
         self.assets = assets;
+        self.asset_order.clear();
         self.sort_assets_by_performance();
         self
     }
 
+    /// Set the volatility of the instrument.
+    ///
+    /// # Arguments
+    ///
+    /// * `volatility` - The volatility of the instrument (e.g., 0.2 for 20%).
+    ///
+    /// # Returns
+    ///
+    /// The instrument with the volatility set.
+    pub fn with_volatility(mut self, volatility: f64) -> Self {
+        self.volatility = Some(volatility);
+        self
+    }
+
+    /// Set the correlation matrix of the assets.
+    ///
+    /// The rows and columns follow the order in which the assets are passed, regardless of whether the matrix is set
+    /// before or after the assets. The matrix is validated when pricing (see [`crate::options::PricingError`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `correlation` - The (symmetric) correlation matrix of the assets' returns.
+    ///
+    /// # Returns
+    ///
+    /// The instrument with the correlation matrix set.
+    pub fn with_correlation_matrix(mut self, correlation: Vec<Vec<f64>>) -> Self {
+        self.correlation = correlation;
+        self
+    }
+
+    /// Get the correlation matrix of the assets, indexed like `assets`.
+    ///
+    /// # Returns
+    ///
+    /// The correlation matrix, or the identity matrix if no correlation matrix is set.
+    pub fn correlation_matrix(&self) -> Vec<Vec<f64>> {
+        let n = self.assets.len().max(1);
+        if self.correlation.is_empty() {
+            return (0..n)
+                .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+                .collect();
+        }
+        let order = &self.asset_order;
+        let reordered = order.len() == n
+            && self.correlation.len() == n
+            && self.correlation.iter().all(|row| row.len() == n);
+        if !reordered {
+            return self.correlation.clone();
+        }
+        order
+            .iter()
+            .map(|&i| order.iter().map(|&j| self.correlation[i][j]).collect())
+            .collect()
+    }
+
     /// Sort the assets by their performance at the payment date.
     pub fn sort_assets_by_performance(&mut self) {
-        self.assets
-            .sort_by(|a, b| b.0.spot.partial_cmp(&a.0.spot).unwrap());
+        let mut order: Vec<usize> = (0..self.assets.len()).collect();
+        order.sort_by(|&a, &b| {
+            self.assets[b]
+                .0
+                .spot
+                .partial_cmp(&self.assets[a].0.spot)
+                .unwrap()
+        });
+        self.assets = order.iter().map(|&i| self.assets[i].clone()).collect();
+        self.asset_order = if self.asset_order.len() == order.len() {
+            order.iter().map(|&i| self.asset_order[i]).collect()
+        } else {
+            order
+        };
         self.spot = vec![(self.assets.iter().map(|(a, w)| a.spot() * w).sum::<f64>())];
         self.sorted = true;
     }
@@ -281,6 +416,39 @@ impl Instrument {
         self.spot() * (1.0 - self.discrete_dividend_yield).powf(n_dividends)
     }
 
+    /// Calculate the present value of the cash dividends going ex after a time and up to maturity.
+    ///
+    /// # Arguments
+    ///
+    /// * `risk_free_rate` - The risk-free rate used to discount the dividends.
+    /// * `time` - The time at which the dividends are valued.
+    /// * `ttm` - Time to maturity of the option.
+    ///
+    /// # Returns
+    ///
+    /// The value at `time` of the dividends with an ex-dividend date in `(time, ttm]`.
+    pub fn dividends_present_value(&self, risk_free_rate: f64, time: f64, ttm: f64) -> f64 {
+        self.cash_dividends
+            .iter()
+            .filter(|d| d.ex_date > time && d.ex_date <= ttm)
+            .map(|d| d.amount * (-risk_free_rate * (d.ex_date - time)).exp())
+            .sum()
+    }
+
+    /// Calculate the escrowed spot price, i.e. the adjusted spot price less the present value of the cash dividends.
+    ///
+    /// # Arguments
+    ///
+    /// * `risk_free_rate` - The risk-free rate used to discount the dividends.
+    /// * `ttm` - Time to maturity of the option.
+    ///
+    /// # Returns
+    ///
+    /// The escrowed spot price, which equals the adjusted spot price without cash dividends.
+    pub fn escrowed_spot(&self, risk_free_rate: f64, ttm: f64) -> f64 {
+        self.calculate_adjusted_spot(ttm) - self.dividends_present_value(risk_free_rate, 0.0, ttm)
+    }
+
     /// Return current spot price.
     ///
     /// # Returns
@@ -310,9 +478,9 @@ impl Instrument {
     /// # Returns
     ///
     /// A vector of simulated asset prices.
-    pub fn euler_simulation(
+    pub fn euler_simulation<R: Rng + ?Sized>(
         &self,
-        rng: &mut ThreadRng,
+        rng: &mut R,
         risk_free_rate: f64,
         volatility: f64,
         steps: usize,
@@ -343,9 +511,9 @@ impl Instrument {
     /// # Returns
     ///
     /// A vector of simulated asset prices' logarithms.
-    pub fn log_simulation(
+    pub fn log_simulation<R: Rng + ?Sized>(
         &self,
-        rng: &mut ThreadRng,
+        rng: &mut R,
         volatility: f64,
         time_to_maturity: f64,
         risk_free_rate: f64,
@@ -377,9 +545,9 @@ impl Instrument {
     /// # Returns
     ///
     /// The average asset price.
-    pub fn simulate_arithmetic_average(
+    pub fn simulate_arithmetic_average<R: Rng + ?Sized>(
         &self,
-        rng: &mut ThreadRng,
+        rng: &mut R,
         method: SimMethod,
         volatility: f64,
         time_to_maturity: f64,
@@ -411,9 +579,9 @@ impl Instrument {
     /// # Returns
     ///
     /// The geometric average asset price.
-    pub fn simulate_geometric_average(
+    pub fn simulate_geometric_average<R: Rng + ?Sized>(
         &self,
-        rng: &mut ThreadRng,
+        rng: &mut R,
         method: SimMethod,
         volatility: f64,
         time_to_maturity: f64,
@@ -428,7 +596,7 @@ impl Instrument {
             }
         };
 
-        (self.spot.iter().map(|price| price.ln()).sum::<f64>() / self.spot.len() as f64).exp()
+        (prices.iter().map(|price| price.ln()).sum::<f64>() / prices.len() as f64).exp()
     }
 
     /// Average asset prices (mutates the underlying instrument)
@@ -445,9 +613,9 @@ impl Instrument {
     /// # Returns
     ///
     /// The average asset price.
-    pub fn simulate_arithmetic_average_mut(
+    pub fn simulate_arithmetic_average_mut<R: Rng + ?Sized>(
         &mut self,
-        rng: &mut ThreadRng,
+        rng: &mut R,
         method: SimMethod,
         volatility: f64,
         time_to_maturity: f64,
@@ -479,9 +647,9 @@ impl Instrument {
     /// # Returns
     ///
     /// The geometric average asset price.
-    pub fn simulate_geometric_average_mut(
+    pub fn simulate_geometric_average_mut<R: Rng + ?Sized>(
         &mut self,
-        rng: &mut ThreadRng,
+        rng: &mut R,
         method: SimMethod,
         volatility: f64,
         time_to_maturity: f64,
@@ -512,9 +680,9 @@ impl Instrument {
     /// # Returns
     ///
     /// The simulated asset price.
-    pub fn simulate_geometric_brownian_motion(
+    pub fn simulate_geometric_brownian_motion<R: Rng + ?Sized>(
         &self,
-        rng: &mut ThreadRng,
+        rng: &mut R,
         volatility: f64,
         time_to_maturity: f64,
         risk_free_rate: f64,
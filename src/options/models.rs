@@ -3,24 +3,44 @@
 //! ## Supported models
 //!
 //! - [Black-Scholes Option Pricing Model](black_scholes/struct.BlackScholesModel.html)
+//! - [Bachelier (Normal) Option Pricing Model](bachelier/struct.BachelierModel.html)
 //! - [Binomial Option Pricing Model](binomial_tree/struct.BinomialTreeModel.html)
 //! - [Monte Carlo Option Pricing Model](monte_carlo/struct.MonteCarloModel.html)
+//! - [Finite Difference Option Pricing Model](finite_diff/struct.FiniteDiffModel.html)
+//! - [Heston Stochastic Volatility Model](heston/struct.HestonModel.html)
+//! - [Local Volatility Model](local_vol/struct.LocalVolModel.html)
+//! - [Merton Jump-Diffusion Model](jump_diffusion/struct.MertonJumpModel.html)
+//! - [Kou Jump-Diffusion Model](jump_diffusion/struct.KouJumpModel.html)
+//! - [SABR Stochastic Volatility Model](sabr/struct.SabrModel.html)
+//!
+//! Any model can be wrapped in [NumericalGreeks](numerical_greeks/struct.NumericalGreeks.html) to calculate its
+//! Greeks by bumping and repricing.
 //!
 //! ## Greek calculations
 //!
 //! This module also provides implementations of the Greeks for each option pricing model.
 //! See the [Greeks](options/trait.Greeks.html) trait for more information.
 
-pub use binomial_tree::BinomialTreeModel;
+pub use bachelier::BachelierModel;
+pub use binomial_tree::{BinomialTreeModel, LatticeScheme};
 pub use black_76::Black76Model;
-pub use black_scholes::BlackScholesModel;
-pub use finite_diff::FiniteDiffModel;
-pub use heston::HestonModel;
-pub use monte_carlo::MonteCarloModel;
+pub use black_scholes::{AsianApproximation, BlackScholesModel};
+pub use finite_diff::{FiniteDiffModel, FiniteDiffScheme};
+pub use heston::{CalibrationTarget, HestonCalibration, HestonCalibrator, HestonModel};
+pub use jump_diffusion::{JumpProcess, KouJumpModel, MertonJumpModel};
+pub use local_vol::{LocalVolMethod, LocalVolModel};
+pub use monte_carlo::{MonteCarloModel, MonteCarloResult, RegressionBasis, Sampling};
+pub use numerical_greeks::NumericalGreeks;
+pub use sabr::{SabrCalibration, SabrCalibrator, SabrExpansion, SabrModel};
 
+mod bachelier;
 mod binomial_tree;
 mod black_76;
 mod black_scholes;
 mod finite_diff;
 mod heston;
+mod jump_diffusion;
+mod local_vol;
 mod monte_carlo;
+mod numerical_greeks;
+mod sabr;
@@ -0,0 +1,465 @@
+//! Module for the Bachelier (normal) option pricing model.
+//!
+//! The Bachelier model assumes that the forward price F(t) of the underlying follows an arithmetic Brownian motion
+//! with constant (absolute) volatility σ:
+//!
+//! ```text
+//! dF = σ dW
+//! ```
+//!
+//! Unlike the lognormal [`Black76Model`], the forward and the strike may be zero or negative, which makes the model
+//! the market standard for options on interest rates and on spreads. The spot price of the [`Instrument`] is used
+//! as the forward price of the underlying, and the volatility is quoted in units of the underlying (e.g., 0.01 for
+//! 100 basis points per year on a rate).
+//!
+//! European options are priced in closed form:
+//!
+//! ```text
+//! C = e^(-rT) ((F - K) N(d) + σ√T n(d)),   P = e^(-rT) ((K - F) N(-d) + σ√T n(d)),   d = (F - K) / (σ√T)
+//! ```
+//!
+//! ## References
+//!
+//! - Bachelier, L. Théorie de la spéculation, Annales scientifiques de l'École Normale Supérieure, 1900
+//! - Schachermayer, W., Teichmann, J. How close are the option pricing formulas of Bachelier and Black-Merton-Scholes?, Mathematical Finance 18, 2008
+//!
+//! ## Example
+//!
+//! ```
+//! use quantrs::options::{BachelierModel, EuropeanOption, Instrument, OptionGreeks, OptionPricing, OptionType};
+//!
+//! // Floorlet on a negative forward rate
+//! let instrument = Instrument::new().with_spot(-0.002);
+//! let option = EuropeanOption::new(instrument, 0.0, 1.0, OptionType::Put);
+//! let model = BachelierModel::new(0.01, 0.006);
+//!
+//! let price = model.price(&option);
+//! println!("Price: {}", price);
+//! println!("Delta: {}", model.delta(&option));
+//! println!("Normal implied volatility: {}", model.implied_volatility(&option, price));
+//!
+//! // Convert a Black volatility of a positive forward to the normal volatility with the same price
+//! let option = EuropeanOption::new(Instrument::new().with_spot(0.03), 0.035, 2.0, OptionType::Call);
+//! let model = BachelierModel::from_black_volatility(0.01, 0.2, &option).unwrap();
+//! println!("Normal volatility: {}", model.volatility);
+//! println!("Black volatility: {}", model.black_volatility(&option).unwrap());
+//! ```
+
+use crate::options::{
+    Black76Model, Instrument, ModelParameters, Option, OptionGreeks, OptionPricing, OptionStrategy,
+    OptionStyle, OptionType, PricingError, numerics,
+};
+use statrs::distribution::{Continuous, ContinuousCDF, Normal};
+
+/// Bachelier (normal) option pricing model.
+#[derive(Debug, Default, Clone)]
+pub struct BachelierModel {
+    /// Risk-free interest rate (e.g., 0.05 for 5%).
+    pub risk_free_rate: f64,
+    /// Normal volatility of the forward price in units of the underlying (e.g., 0.01 for 100 basis points).
+    pub volatility: f64,
+}
+
+/// Terms of the Bachelier formula shared by the price and the Greeks of a European option.
+struct NormalTerms {
+    /// Discount factor to the expiry.
+    discount: f64,
+    /// Standard deviation of the forward at the expiry `σ√T`.
+    deviation: f64,
+    /// Standardised moneyness `d = (F - K) / (σ√T)`.
+    d: f64,
+    /// Standard normal density at `d`.
+    density: f64,
+    /// Standard normal distribution function at `d` for calls and at `-d` for puts.
+    probability: f64,
+    /// 1 for calls and -1 for puts.
+    sign: f64,
+}
+
+impl BachelierModel {
+    /// Create a new `BachelierModel`.
+    ///
+    /// # Arguments
+    ///
+    /// * `risk_free_rate` - Risk-free interest rate (e.g., 0.05 for 5%).
+    /// * `volatility` - Normal volatility of the forward price (e.g., 0.01 for 100 basis points).
+    ///
+    /// # Returns
+    ///
+    /// A new `BachelierModel`.
+    pub fn new(risk_free_rate: f64, volatility: f64) -> Self {
+        Self {
+            risk_free_rate,
+            volatility,
+        }
+    }
+
+    /// Create a new `BachelierModel` with the normal volatility that gives the price of a Black volatility.
+    ///
+    /// # Arguments
+    ///
+    /// * `risk_free_rate` - Risk-free interest rate (e.g., 0.05 for 5%).
+    /// * `black_volatility` - The lognormal (Black-76) volatility of the option.
+    /// * `option` - The European option at whose strike and expiry the volatilities are converted.
+    ///
+    /// # Returns
+    ///
+    /// A new `BachelierModel` or an error if the forward or the strike are not positive.
+    pub fn from_black_volatility<T: Option>(
+        risk_free_rate: f64,
+        black_volatility: f64,
+        option: &T,
+    ) -> Result<Self, PricingError> {
+        check_lognormal(option)?;
+        let price = Black76Model::new(risk_free_rate, black_volatility).try_price(option)?;
+        let volatility = Self::new(risk_free_rate, 0.0).try_implied_volatility(option, price)?;
+        Ok(Self::new(risk_free_rate, volatility))
+    }
+
+    /// Calculate the Black (lognormal) volatility that gives the price of the normal volatility of the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The European option at whose strike and expiry the volatilities are converted.
+    ///
+    /// # Returns
+    ///
+    /// The Black-76 implied volatility or an error if the forward or the strike are not positive.
+    pub fn black_volatility<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        check_lognormal(option)?;
+        let price = self.try_price(option)?;
+        Black76Model::new(self.risk_free_rate, self.volatility)
+            .try_implied_volatility(option, price)
+    }
+
+    /// Calculate the price of a European option with the Bachelier formula.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument` - The underlying instrument (its spot price is the forward price).
+    /// * `strike` - The strike price of the option.
+    /// * `ttm` - Time to maturity of the option.
+    /// * `option_type` - The type of the option.
+    ///
+    /// # Returns
+    ///
+    /// The price of the European option (the discounted intrinsic value if there is no volatility).
+    fn price_european(
+        &self,
+        instrument: &Instrument,
+        strike: f64,
+        ttm: f64,
+        option_type: OptionType,
+    ) -> f64 {
+        let discount = (-self.risk_free_rate * ttm).exp();
+        let sign = match option_type {
+            OptionType::Call => 1.0,
+            OptionType::Put => -1.0,
+        };
+        let moneyness = sign * (instrument.spot() - strike);
+        let deviation = self.volatility * ttm.sqrt();
+        if deviation <= 0.0 {
+            return discount * moneyness.max(0.0);
+        }
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let d = moneyness / deviation;
+        discount * (moneyness * normal.cdf(d) + deviation * normal.pdf(d))
+    }
+
+    /// Calculate the terms of the Bachelier formula of a European option.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to calculate the terms for.
+    ///
+    /// # Returns
+    ///
+    /// The terms of the option or an error if the inputs are invalid or the option is not European.
+    fn terms<T: Option>(&self, option: &T) -> Result<NormalTerms, PricingError> {
+        validate(option)?;
+        if *option.style() != OptionStyle::European {
+            return Err(PricingError::unsupported_style(
+                "BachelierModel",
+                *option.style(),
+            ));
+        }
+        let ttm = option.time_to_maturity();
+        let sign = match option.option_type() {
+            OptionType::Call => 1.0,
+            OptionType::Put => -1.0,
+        };
+        let deviation = self.volatility * ttm.sqrt();
+        let d = (option.instrument().spot() - option.strike()) / deviation;
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        Ok(NormalTerms {
+            discount: (-self.risk_free_rate * ttm).exp(),
+            deviation,
+            d,
+            density: normal.pdf(d),
+            probability: normal.cdf(sign * d),
+            sign,
+        })
+    }
+}
+
+/// Check that the forward and the strike are finite (they may be negative) and that the time to maturity is finite
+/// and non-negative.
+fn validate<T: Option>(option: &T) -> Result<(), PricingError> {
+    let inputs = [
+        ("Forward price", option.instrument().spot()),
+        ("Strike", option.strike()),
+    ];
+    for (name, value) in inputs {
+        if !value.is_finite() {
+            return Err(PricingError::InvalidInput(format!(
+                "{name} must be finite, got {value}"
+            )));
+        }
+    }
+    let ttm = option.time_to_maturity();
+    if !ttm.is_finite() || ttm < 0.0 {
+        return Err(PricingError::InvalidInput(format!(
+            "Time to maturity must be finite and non-negative, got {ttm}"
+        )));
+    }
+    Ok(())
+}
+
+/// Check that the forward and the strike are positive, as required by a Black volatility.
+fn check_lognormal<T: Option>(option: &T) -> Result<(), PricingError> {
+    if option.instrument().spot() > 0.0 && option.strike() > 0.0 {
+        Ok(())
+    } else {
+        Err(PricingError::invalid_input(
+            "Black volatilities require a positive forward and strike",
+        ))
+    }
+}
+
+impl OptionPricing for BachelierModel {
+    fn price<T: Option>(&self, option: &T) -> f64 {
+        self.try_price(option).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_price<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate(option)?;
+        match option.style() {
+            OptionStyle::European => Ok(self.price_european(
+                option.instrument(),
+                option.strike(),
+                option.time_to_maturity(),
+                option.option_type(),
+            )),
+            _ => Err(PricingError::unsupported_style(
+                "BachelierModel",
+                *option.style(),
+            )),
+        }
+    }
+
+    /// Calculate the normal implied volatility of a given market price.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to calculate the implied volatility for.
+    /// * `market_price` - The market price of the option.
+    ///
+    /// # Returns
+    ///
+    /// The normal implied volatility of the option.
+    fn implied_volatility<T: Option>(&self, option: &T, market_price: f64) -> f64 {
+        self.try_implied_volatility(option, market_price)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Calculate the normal implied volatility of a European option without panicking.
+    ///
+    /// In-the-money options are converted to their out-of-the-money counterpart by put-call parity. Newton's method
+    /// with analytic vega is safeguarded by bisection on a bracket that contains the root. Market prices below the
+    /// discounted intrinsic value are rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to calculate the implied volatility for.
+    /// * `market_price` - The market price of the option.
+    ///
+    /// # Returns
+    ///
+    /// The normal implied volatility of the option or the reason why it cannot be calculated.
+    fn try_implied_volatility<T: Option>(
+        &self,
+        option: &T,
+        market_price: f64,
+    ) -> Result<f64, PricingError> {
+        validate(option)?;
+        if !market_price.is_finite() || market_price < 0.0 {
+            return Err(PricingError::InvalidInput(format!(
+                "Market price must be finite and non-negative, got {market_price}"
+            )));
+        }
+        if *option.style() != OptionStyle::European {
+            return Err(PricingError::unsupported_style(
+                "BachelierModel",
+                *option.style(),
+            ));
+        }
+        let ttm = option.time_to_maturity();
+        if ttm <= 0.0 {
+            return Err(PricingError::invalid_input(
+                "Time to maturity must be positive to imply a volatility",
+            ));
+        }
+        let discount = (-self.risk_free_rate * ttm).exp();
+        let moneyness = option.instrument().spot() - option.strike();
+        let intrinsic = discount
+            * match option.option_type() {
+                OptionType::Call => moneyness,
+                OptionType::Put => -moneyness,
+            }
+            .max(0.0);
+        let target = if intrinsic > 0.0 {
+            option.flip()
+        } else {
+            option.clone()
+        };
+        let target_price = market_price - intrinsic;
+
+        // The out-of-the-money price is unbounded in the volatility and exceeds the target price at the upper end
+        let scale = (2.0 * std::f64::consts::PI / ttm).sqrt();
+        let upper = 2.0 * scale * (target_price / discount + moneyness.abs());
+        let with_volatility = |volatility| Self {
+            volatility,
+            ..self.clone()
+        };
+        numerics::implied_volatility(
+            |volatility| with_volatility(volatility).price(&target),
+            |volatility| with_volatility(volatility).vega(&target),
+            target_price,
+            (0.0, upper.max(f64::MIN_POSITIVE)),
+            scale * target_price / discount,
+        )
+        .map_err(|err| match err {
+            PricingError::PriceOutOfBounds {
+                price,
+                lower,
+                upper,
+            } => PricingError::PriceOutOfBounds {
+                price: price + intrinsic,
+                lower: lower + intrinsic,
+                upper: upper + intrinsic,
+            },
+            err => err,
+        })
+    }
+}
+
+/// Greeks with respect to the forward price (spot of the instrument), the normal volatility and the time to maturity.
+impl OptionGreeks for BachelierModel {
+    fn try_delta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let terms = self.terms(option)?;
+        Ok(terms.sign * terms.discount * terms.probability)
+    }
+
+    fn try_gamma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let terms = self.terms(option)?;
+        Ok(terms.discount * terms.density / terms.deviation)
+    }
+
+    fn try_theta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let terms = self.terms(option)?;
+        Ok(terms.discount * terms.density * self.volatility
+            / (2.0 * option.time_to_maturity().sqrt())
+            - self.risk_free_rate * self.try_price(option)?)
+    }
+
+    fn try_vega<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let terms = self.terms(option)?;
+        Ok(terms.discount * terms.density * option.time_to_maturity().sqrt())
+    }
+
+    fn try_rho<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.terms(option)?;
+        Ok(-option.time_to_maturity() * self.try_price(option)?)
+    }
+
+    fn try_lambda<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Ok(self.try_delta(option)? * option.instrument().spot() / self.try_price(option)?)
+    }
+
+    /// The forward price already contains the cost of carry, so the price does not depend on a dividend yield.
+    fn try_epsilon<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.terms(option)?;
+        Ok(0.0)
+    }
+
+    fn try_vanna<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let terms = self.terms(option)?;
+        Ok(-terms.discount * terms.density * terms.d / self.volatility)
+    }
+
+    fn try_charm<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let terms = self.terms(option)?;
+        Ok(-self.risk_free_rate * self.try_delta(option)?
+            - terms.discount * terms.density * terms.d / (2.0 * option.time_to_maturity()))
+    }
+
+    fn try_vomma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let terms = self.terms(option)?;
+        Ok(self.try_vega(option)? * terms.d.powi(2) / self.volatility)
+    }
+
+    fn try_veta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let terms = self.terms(option)?;
+        Ok(self.try_vega(option)?
+            * ((1.0 + terms.d.powi(2)) / (2.0 * option.time_to_maturity()) - self.risk_free_rate))
+    }
+
+    fn try_vera<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Ok(-option.time_to_maturity() * self.try_vega(option)?)
+    }
+
+    fn try_speed<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let terms = self.terms(option)?;
+        Ok(-self.try_gamma(option)? * terms.d / terms.deviation)
+    }
+
+    fn try_zomma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let terms = self.terms(option)?;
+        Ok(self.try_gamma(option)? * (terms.d.powi(2) - 1.0) / self.volatility)
+    }
+
+    fn try_color<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let terms = self.terms(option)?;
+        Ok(self.try_gamma(option)?
+            * ((terms.d.powi(2) - 1.0) / (2.0 * option.time_to_maturity()) - self.risk_free_rate))
+    }
+
+    fn try_ultima<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let terms = self.terms(option)?;
+        Ok(
+            self.try_vega(option)? * terms.d.powi(2) * (terms.d.powi(2) - 3.0)
+                / self.volatility.powi(2),
+        )
+    }
+}
+
+/// The volatility of the Bachelier model is its normal volatility.
+impl ModelParameters for BachelierModel {
+    fn risk_free_rate(&self) -> f64 {
+        self.risk_free_rate
+    }
+
+    fn set_risk_free_rate(&mut self, risk_free_rate: f64) {
+        self.risk_free_rate = risk_free_rate;
+    }
+
+    fn volatility(&self) -> f64 {
+        self.volatility
+    }
+
+    fn set_volatility(&mut self, volatility: f64) {
+        self.volatility = volatility;
+    }
+}
+
+impl OptionStrategy for BachelierModel {}
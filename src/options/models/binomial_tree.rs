@@ -54,15 +54,43 @@
 //! - `K` is the strike price of the option.
 //! - `max` is the maximum function.
 //!
+//! ## Lattice schemes
+//!
+//! The factors above are the Cox-Ross-Rubinstein (CRR) parametrisation, whose prices oscillate in the number of steps.
+//! Other parametrisations are selected with [`LatticeScheme`]:
+//!
+//! - **Jarrow-Rudd**: `u, d = e^((r - q - σ²/2)Δt ± σ√Δt)`, centred on the drift of the log-price.
+//! - **Tian**: `u` and `d` match the first three moments of the underlying over a step.
+//! - **Leisen-Reimer**: the tree is centred on the strike with the Peizer-Pratt inversion of the normal distribution,
+//!   which removes the oscillation and converges at second order for European options (an odd number of steps is
+//!   used).
+//! - **Trinomial**: the tree of Boyle with the Kamrad-Ritchken probabilities, where the price moves up, down or stays
+//!   unchanged by `u = e^(λσ√Δt)` with `λ = √(3/2)`.
+//!
+//! The convergence of American and Bermudan options can be accelerated further with:
+//!
+//! - **Richardson extrapolation**: the prices of a Leisen-Reimer tree and of a tree with half the steps are
+//!   extrapolated to an infinite number of steps (at first order, or at second order for European options). The
+//!   error of the other schemes oscillates with the number of steps, so they are not extrapolated.
+//! - **Black-Scholes control variate**: the error of the tree for the European option with the same strike and
+//!   expiry, which is known in closed form, is subtracted from the price of the tree.
+//!
+//! Cash dividends are handled by building the tree on the escrowed spot price `S* = S - PV(D)` and shifting every node
+//! at time `t` by the present value of the dividends going ex in `(t, T]`, so that the tree recombines and the spot
+//! drops by the dividend amount on the ex-dividend date.
+//!
 //! ## References
 //!
 //! - [Wikipedia - Binomial options pricing model](https://en.wikipedia.org/wiki/Binomial_options_pricing_model)
 //! - [Options, Futures, and Other Derivatives (9th Edition)](https://www.pearson.com/store/p/options-futures-and-other-derivatives/P1000000000000013194)
+//! - Leisen, D. P. J., Reimer, M. Binomial models for option valuation - examining and improving convergence, Applied Mathematical Finance 3, 1996
+//! - Kamrad, B., Ritchken, P. Multinomial approximating models for options with k state variables, Management Science 37, 1991
+//! - Broadie, M., Detemple, J. American option valuation: new bounds, approximations, and a comparison of existing methods, Review of Financial Studies 9, 1996
 //!
 //! ## Example
 //!
 //! ```
-//! use quantrs::options::{OptionPricing, BinomialTreeModel, EuropeanOption, Instrument, OptionType};
+//! use quantrs::options::{AmericanOption, OptionPricing, BinomialTreeModel, EuropeanOption, Instrument, LatticeScheme, OptionType};
 //!
 //! let instrument = Instrument::new().with_spot(100.0);
 //! let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
@@ -70,12 +98,40 @@
 //!
 //! let price = model.price(&option);
 //! println!("Option price: {price}");
+//!
+//! // Leisen-Reimer tree with Richardson extrapolation and a Black-Scholes control variate for an American put
+//! let option = AmericanOption::new(Instrument::new().with_spot(100.0), 100.0, 1.0, OptionType::Put);
+//! let model = BinomialTreeModel::new(0.05, 0.2, 200)
+//!     .with_scheme(LatticeScheme::LeisenReimer)
+//!     .with_richardson_extrapolation()
+//!     .with_control_variate();
+//! println!("American put price: {}", model.price(&option));
 //! ```
 
-use crate::options::{Option, OptionPricing, OptionStrategy, OptionStyle};
+use crate::options::{
+    BlackScholesModel, EuropeanOption, Instrument, ModelParameters, Monitoring, NumericalGreeks,
+    Option, OptionGreeks, OptionPricing, OptionStrategy, OptionStyle, OptionType, PricingError,
+    RainbowType::*, validate_option,
+};
+
+/// Enum for the parametrisation of the lattice of a [`BinomialTreeModel`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum LatticeScheme {
+    /// Cox-Ross-Rubinstein binomial tree with `u = e^(σ√Δt)` and `d = 1 / u`.
+    #[default]
+    CoxRossRubinstein,
+    /// Jarrow-Rudd binomial tree with factors centred on the drift of the log-price.
+    JarrowRudd,
+    /// Tian binomial tree matching the first three moments of the underlying.
+    Tian,
+    /// Leisen-Reimer binomial tree centred on the strike (an odd number of steps is used).
+    LeisenReimer,
+    /// Boyle trinomial tree with the Kamrad-Ritchken probabilities.
+    Trinomial,
+}
 
 /// Binomial tree option pricing model.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct BinomialTreeModel {
     /// Risk-free interest rate (e.g., 0.05 for 5%).
     pub risk_free_rate: f64,
@@ -83,6 +139,86 @@ pub struct BinomialTreeModel {
     pub volatility: f64,
     /// Number of steps in the binomial tree.
     pub steps: usize,
+    /// Parametrisation of the lattice.
+    pub scheme: LatticeScheme,
+    /// Whether to extrapolate the price of the tree and of a tree with half the steps (Leisen-Reimer trees only).
+    pub richardson_extrapolation: bool,
+    /// Whether to correct American and Bermudan prices by the error of the tree for the European option.
+    pub control_variate: bool,
+}
+
+/// Number of steps by which the tree is extended before the valuation date to read off delta, gamma and theta.
+const EXTENSION: usize = 2;
+
+/// Price and Greeks of an option read off a tree.
+struct TreeValues {
+    /// Price of the option.
+    price: f64,
+    /// Delta of the option.
+    delta: f64,
+    /// Gamma of the option.
+    gamma: f64,
+    /// Theta of the option.
+    theta: f64,
+}
+
+/// Recombining lattice of a [`BinomialTreeModel`] over the life of an option.
+struct Lattice {
+    /// Length of a step in years.
+    dt: f64,
+    /// Factor of an upward movement.
+    up: f64,
+    /// Factor of a downward movement.
+    down: f64,
+    /// Risk-neutral probabilities of the downward, (unchanged,) and upward movements.
+    probabilities: Vec<f64>,
+    /// Discount factor of a step.
+    discount_factor: f64,
+}
+
+impl Lattice {
+    /// Get the number of nodes after a number of steps.
+    fn nodes(&self, step: usize) -> usize {
+        (self.probabilities.len() - 1) * step + 1
+    }
+
+    /// Get the growth of the spot price at a node relative to the root.
+    ///
+    /// # Arguments
+    ///
+    /// * `step` - The number of steps from the root.
+    /// * `i` - The index of the node, counted from the lowest node.
+    ///
+    /// # Returns
+    ///
+    /// The product of the movement factors leading to the node.
+    fn growth(&self, step: usize, i: usize) -> f64 {
+        if self.probabilities.len() == 3 {
+            self.up.powi(i as i32 - step as i32)
+        } else {
+            self.up.powi(i as i32) * self.down.powi((step - i) as i32)
+        }
+    }
+
+    /// Get the discounted expected value of a node from the values at the next step.
+    ///
+    /// # Arguments
+    ///
+    /// * `next` - The values of the nodes at the next step.
+    /// * `i` - The index of the node.
+    ///
+    /// # Returns
+    ///
+    /// The discounted risk-neutral expectation of the values reachable from the node.
+    fn expected(&self, next: &[f64], i: usize) -> f64 {
+        self.discount_factor
+            * self
+                .probabilities
+                .iter()
+                .zip(&next[i..])
+                .map(|(p, value)| p * value)
+                .sum::<f64>()
+    }
 }
 
 impl BinomialTreeModel {
@@ -102,71 +238,575 @@ impl BinomialTreeModel {
             risk_free_rate,
             volatility,
             steps,
+            scheme: LatticeScheme::CoxRossRubinstein,
+            richardson_extrapolation: false,
+            control_variate: false,
         }
     }
-}
 
-impl OptionPricing for BinomialTreeModel {
-    fn price<T: Option>(&self, option: &T) -> f64 {
-        // Multiplicative up-/downward movements of an asset in a single step of the binomial tree
-        let dt = option.time_to_maturity() / self.steps as f64;
-        let u = (self.volatility * dt.sqrt()).exp();
-        let d = 1.0 / u;
+    /// Set the parametrisation of the lattice.
+    ///
+    /// # Arguments
+    ///
+    /// * `scheme` - The lattice scheme.
+    ///
+    /// # Returns
+    ///
+    /// The model with the lattice scheme set.
+    pub fn with_scheme(mut self, scheme: LatticeScheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Use Richardson extrapolation, i.e. extrapolate the price of the tree and of a tree with half the steps to an
+    /// infinite number of steps.
+    ///
+    /// Only Leisen-Reimer trees converge smoothly enough for a two-point extrapolation. The error of the other schemes
+    /// oscillates with the number of steps (e.g., with the position of the strike between the nodes), so that the
+    /// extrapolation could increase it; their prices are left unchanged.
+    ///
+    /// # Returns
+    ///
+    /// The model with Richardson extrapolation enabled.
+    pub fn with_richardson_extrapolation(mut self) -> Self {
+        self.richardson_extrapolation = true;
+        self
+    }
+
+    /// Use the Black-Scholes price of the European option as control variate for American and Bermudan options.
+    ///
+    /// # Returns
+    ///
+    /// The model with the control variate enabled.
+    pub fn with_control_variate(mut self) -> Self {
+        self.control_variate = true;
+        self
+    }
 
-        // Risk-neutral probability of an upward movement for a call option
-        // let p = ((self.risk_free_rate * dt).exp() - d) / (u - d);
-        let p = (((self.risk_free_rate - option.instrument().continuous_dividend_yield) * dt)
-            .exp()
-            - d)
-            / (u - d);
+    /// Get the number of steps of the lattice scheme closest to a number of steps.
+    ///
+    /// # Arguments
+    ///
+    /// * `steps` - The requested number of steps.
+    ///
+    /// # Returns
+    ///
+    /// The number of steps (rounded up to an odd number for Leisen-Reimer trees).
+    fn scheme_steps(&self, steps: usize) -> usize {
+        match self.scheme {
+            LatticeScheme::LeisenReimer => steps.max(1) | 1,
+            _ => steps.max(1),
+        }
+    }
 
-        // Discount factor for each step
-        let discount_factor = (-self.risk_free_rate * dt).exp();
+    /// Build the lattice of the scheme over the life of an option.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument` - The instrument the tree is built on.
+    /// * `strike` - The strike price of the option (the centre of Leisen-Reimer trees).
+    /// * `ttm` - Time to maturity of the option.
+    /// * `steps` - The number of steps.
+    ///
+    /// # Returns
+    ///
+    /// The movement factors and probabilities of the lattice.
+    fn lattice(&self, instrument: &Instrument, strike: f64, ttm: f64, steps: usize) -> Lattice {
+        let dt = ttm / steps as f64;
+        let sigma = self.volatility;
+        let carry = self.risk_free_rate - instrument.continuous_dividend_yield;
+        let growth = (carry * dt).exp();
+        let binomial = |up: f64, down: f64| {
+            let p = (growth - down) / (up - down);
+            (up, down, vec![1.0 - p, p])
+        };
 
-        // Initialize option values at maturity
-        let mut option_values: Vec<f64> = (0..=self.steps)
-            .map(|i| {
-                option.payoff(Some(
-                    option.instrument().spot() * u.powi(i as i32) * d.powi((self.steps - i) as i32),
-                ))
-            })
-            .collect();
+        let (up, down, probabilities) = match self.scheme {
+            LatticeScheme::CoxRossRubinstein => {
+                let up = (sigma * dt.sqrt()).exp();
+                binomial(up, 1.0 / up)
+            }
+            LatticeScheme::JarrowRudd => {
+                let drift = (carry - 0.5 * sigma * sigma) * dt;
+                binomial(
+                    (drift + sigma * dt.sqrt()).exp(),
+                    (drift - sigma * dt.sqrt()).exp(),
+                )
+            }
+            LatticeScheme::Tian => {
+                let v = (sigma * sigma * dt).exp();
+                let root = (v * v + 2.0 * v - 3.0).sqrt();
+                binomial(
+                    0.5 * growth * v * (v + 1.0 + root),
+                    0.5 * growth * v * (v + 1.0 - root),
+                )
+            }
+            LatticeScheme::LeisenReimer => {
+                // Peizer-Pratt method 2 inversion of the normal distribution function
+                let n = steps as f64;
+                let inversion = |z: f64| {
+                    let x = z / (n + 1.0 / 3.0 + 0.1 / (n + 1.0));
+                    0.5 + 0.5 * z.signum() * (1.0 - (-x * x * (n + 1.0 / 6.0)).exp()).sqrt()
+                };
+                let spot = instrument.spot()
+                    - instrument.dividends_present_value(self.risk_free_rate, 0.0, ttm);
+                let d1 = ((spot / strike).ln() + (carry + 0.5 * sigma * sigma) * ttm)
+                    / (sigma * ttm.sqrt());
+                let d2 = d1 - sigma * ttm.sqrt();
+                let p = inversion(d2);
+                let up = growth * inversion(d1) / p;
+                let down = (growth - p * up) / (1.0 - p);
+                (up, down, vec![1.0 - p, p])
+            }
+            LatticeScheme::Trinomial => {
+                let stretch = 1.5_f64.sqrt();
+                let drift = (carry - 0.5 * sigma * sigma) * dt.sqrt() / (2.0 * stretch * sigma);
+                let outer = 0.5 / (stretch * stretch);
+                let up = (stretch * sigma * dt.sqrt()).exp();
+                (
+                    up,
+                    1.0 / up,
+                    vec![outer - drift, 1.0 - 2.0 * outer, outer + drift],
+                )
+            }
+        };
+
+        Lattice {
+            dt,
+            up,
+            down,
+            probabilities,
+            discount_factor: (-self.risk_free_rate * dt).exp(),
+        }
+    }
+
+    /// Get the instrument the tree is built on.
+    ///
+    /// The tree is one-dimensional, so rainbow options on the maximum (minimum) of several assets are approximated
+    /// on the currently best (worst) performing asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to price.
+    ///
+    /// # Returns
+    ///
+    /// The instrument to build the tree on.
+    fn tree_instrument<T: Option>(option: &T) -> &Instrument {
+        match option.style() {
+            OptionStyle::Rainbow(BestOf | CallOnMax | PutOnMax) => {
+                option.instrument().best_performer()
+            }
+            OptionStyle::Rainbow(WorstOf | CallOnMin | PutOnMin) => {
+                option.instrument().worst_performer()
+            }
+            _ => option.instrument(),
+        }
+    }
+
+    /// Get the spot prices at the nodes of the tree.
+    ///
+    /// The tree is built on the escrowed spot price and the nodes at time `t` are shifted by the present value of the
+    /// cash dividends going ex in `(t, T]`. A tree extended before the valuation date starts at the spot price from
+    /// which its central node at the valuation date is reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument` - The instrument the tree is built on.
+    /// * `lattice` - The lattice of the tree.
+    /// * `ttm` - Time to maturity of the option.
+    /// * `extension` - The number of steps of the tree before the valuation date.
+    ///
+    /// # Returns
+    ///
+    /// The spot price at the node with index `i` (counted from the lowest node) after `step` steps, or an error if
+    /// the cash dividends exceed the spot price.
+    fn node_spots<'a>(
+        &'a self,
+        instrument: &'a Instrument,
+        lattice: &'a Lattice,
+        ttm: f64,
+        extension: usize,
+    ) -> Result<impl Fn(usize, usize) -> f64 + 'a, PricingError> {
+        let escrowed =
+            instrument.spot() - instrument.dividends_present_value(self.risk_free_rate, 0.0, ttm);
+        if !instrument.cash_dividends.is_empty() && escrowed <= 0.0 {
+            return Err(PricingError::invalid_input(
+                "Cash dividends must not exceed the spot price",
+            ));
+        }
+
+        let root = escrowed / lattice.growth(extension, lattice.nodes(extension) / 2);
+
+        Ok(move |step: usize, i: usize| {
+            root * lattice.growth(step, i)
+                + instrument.dividends_present_value(
+                    self.risk_free_rate,
+                    (step as f64 - extension as f64) * lattice.dt,
+                    ttm,
+                )
+        })
+    }
+
+    /// Evaluate a quantity of the tree with the number of steps of the model, extrapolated to an infinite number of
+    /// steps if Richardson extrapolation is enabled for a Leisen-Reimer tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to evaluate the quantity for.
+    /// * `quantity` - The quantity as a function of the number of steps of the tree.
+    ///
+    /// # Returns
+    ///
+    /// The (extrapolated) quantity or the error of the tree.
+    fn extrapolate<T: Option>(
+        &self,
+        option: &T,
+        quantity: impl Fn(usize) -> Result<f64, PricingError>,
+    ) -> Result<f64, PricingError> {
+        let steps = self.scheme_steps(self.steps);
+        let value = quantity(steps)?;
+        if !self.richardson_extrapolation || self.scheme != LatticeScheme::LeisenReimer || steps < 2
+        {
+            return Ok(value);
+        }
+
+        // The error of Leisen-Reimer trees decreases with the square of the number of steps unless the early
+        // exercise boundary dominates it
+        let coarse_steps = self.scheme_steps(steps / 2);
+        let order = match option.style() {
+            OptionStyle::European => 2,
+            _ => 1,
+        };
+        let ratio = (steps as f64 / coarse_steps as f64).powi(order);
+        Ok((ratio * value - quantity(coarse_steps)?) / (ratio - 1.0))
+    }
+
+    /// Calculate the price, delta, gamma and theta of an option on a tree with a number of steps.
+    ///
+    /// American and Bermudan options are corrected by the error of the tree for the European option if the control
+    /// variate is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to evaluate.
+    /// * `steps` - The number of steps of the tree after the valuation date.
+    ///
+    /// # Returns
+    ///
+    /// The values of the option or an error if the cash dividends exceed the spot price.
+    fn tree_values<T: Option>(&self, option: &T, steps: usize) -> Result<TreeValues, PricingError> {
+        let values = self.backward_induction(option, steps, true)?;
+        if !(self.control_variate
+            && matches!(
+                option.style(),
+                OptionStyle::American | OptionStyle::Bermudan
+            ))
+        {
+            return Ok(values);
+        }
 
-        // Backward induction
-        for step in (0..self.steps).rev() {
-            for i in 0..=step {
-                let expected_value =
-                    discount_factor * (p * option_values[i + 1] + (1.0 - p) * option_values[i]);
+        let european = EuropeanOption::new(
+            option.instrument().clone(),
+            option.strike(),
+            option.time_to_maturity(),
+            option.option_type(),
+        );
+        let exact = BlackScholesModel::new(self.risk_free_rate, self.volatility);
+        let tree = self.backward_induction(option, steps, false)?;
+        Ok(TreeValues {
+            price: values.price + exact.try_price(&european)? - tree.price,
+            delta: values.delta + exact.try_delta(&european)? - tree.delta,
+            gamma: values.gamma + exact.try_gamma(&european)? - tree.gamma,
+            theta: values.theta + exact.try_theta(&european)? - tree.theta,
+        })
+    }
 
-                if matches!(option.style(), OptionStyle::American)
+    /// Calculate the price, delta, gamma and theta of an option by backward induction.
+    ///
+    /// The tree starts two steps before the valuation date, so that delta and gamma are read off the three central
+    /// nodes at the valuation date and theta off the root and the central node.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to evaluate.
+    /// * `steps` - The number of steps of the tree after the valuation date.
+    /// * `early_exercise` - Whether American and Bermudan options may be exercised early (otherwise the option is
+    ///   priced as European).
+    ///
+    /// # Returns
+    ///
+    /// The values of the option or an error if the cash dividends exceed the spot price.
+    fn backward_induction<T: Option>(
+        &self,
+        option: &T,
+        steps: usize,
+        early_exercise: bool,
+    ) -> Result<TreeValues, PricingError> {
+        let instrument = Self::tree_instrument(option);
+        let ttm = option.time_to_maturity();
+        let lattice = self.lattice(instrument, option.strike(), ttm, steps);
+        let node_spot = self.node_spots(instrument, &lattice, ttm, EXTENSION)?;
+        let exercisable = |step: usize| {
+            early_exercise
+                && (matches!(option.style(), OptionStyle::American)
                     || matches!(option.style(), OptionStyle::Bermudan)
                         && option
                             .expiration_dates()
                             .unwrap()
-                            .contains(&(step as f64 * dt))
-                {
-                    let early_exercise = option.payoff(Some(
-                        option.instrument().spot() * u.powi(i as i32) * d.powi((step - i) as i32),
-                    ));
-                    option_values[i] = expected_value.max(early_exercise);
+                            .contains(&((step as f64 - EXTENSION as f64) * lattice.dt)))
+        };
+
+        // Initialize option values at maturity
+        let last = steps + EXTENSION;
+        let mut option_values: Vec<f64> = (0..lattice.nodes(last))
+            .map(|i| option.payoff(Some(node_spot(last, i))))
+            .collect();
+
+        // Backward induction, keeping the central nodes at the valuation date
+        let centre = lattice.nodes(EXTENSION) / 2;
+        let mut valuation_nodes = [(0.0, 0.0); 3];
+        for step in (0..last).rev() {
+            for i in 0..lattice.nodes(step) {
+                let expected_value = lattice.expected(&option_values, i);
+                option_values[i] = if exercisable(step) {
+                    expected_value.max(option.payoff(Some(node_spot(step, i))))
                 } else {
-                    option_values[i] = expected_value;
+                    expected_value
+                };
+            }
+            if step == EXTENSION {
+                for (k, node) in valuation_nodes.iter_mut().enumerate() {
+                    let i = centre + k - 1;
+                    *node = (node_spot(step, i), option_values[i]);
                 }
             }
         }
 
-        if matches!(option.style(), OptionStyle::American)
-            || matches!(option.style(), OptionStyle::Bermudan)
-                && option.expiration_dates().unwrap().contains(&0.0)
-        {
-            option_values[0].max(option.payoff(Some(option.instrument().spot())))
+        let [(spot_down, down), (spot, price), (spot_up, up)] = valuation_nodes;
+        let delta = (up - down) / (spot_up - spot_down);
+        let gamma = 2.0 * ((up - price) / (spot_up - spot) - (price - down) / (spot - spot_down))
+            / (spot_up - spot_down);
+
+        // The root lies two steps before the valuation date, at a different spot price unless u d = 1
+        let shift = spot - node_spot(0, 0);
+        let earlier = option_values[0] + delta * shift + 0.5 * gamma * shift * shift;
+        Ok(TreeValues {
+            price,
+            delta,
+            gamma,
+            theta: (earlier - price) / (EXTENSION as f64 * lattice.dt),
+        })
+    }
+
+    /// Price a (single or double) barrier option by backward induction.
+    ///
+    /// Knock-out options are reset to the rebate at the monitored nodes beyond the barrier. Knock-in options are
+    /// priced on two trees: one for the knocked-in (vanilla) option and one for the option that has not been
+    /// knocked in yet, which switches to the first whenever a monitored node lies beyond the barrier.
+    ///
+    /// Since the effective barrier is the first layer of nodes beyond it, the price converges slowly and in an
+    /// oscillating way unless the number of steps places the barrier close to a layer of nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The barrier option to price.
+    /// * `steps` - The number of steps of the tree.
+    ///
+    /// # Returns
+    ///
+    /// The price of the option or an error if the cash dividends exceed the spot price.
+    fn price_barrier<T: Option>(&self, option: &T, steps: usize) -> Result<f64, PricingError> {
+        let barrier_type = match option.style() {
+            OptionStyle::Barrier(barrier_type) | OptionStyle::DoubleBarrier(barrier_type, _) => {
+                *barrier_type
+            }
+            _ => panic!("Not a barrier option"),
+        };
+        let barrier = option
+            .barrier()
+            .expect("Barrier options must provide a barrier");
+
+        let ttm = option.time_to_maturity();
+        let lattice = self.lattice(option.instrument(), option.strike(), ttm, steps);
+
+        // Steps at which the barrier is monitored (discrete dates are snapped to the closest step)
+        let mut monitored = vec![false; steps + 1];
+        match barrier.monitoring {
+            Monitoring::Continuous => monitored.fill(true),
+            Monitoring::Discrete(dates) => {
+                let dates = dates.max(1);
+                for date in 1..=dates {
+                    let step = (date as f64 * steps as f64 / dates as f64).round() as usize;
+                    monitored[step.min(steps)] = true;
+                }
+            }
+        }
+
+        let spot = option.instrument().spot();
+        let node_spot = self.node_spots(option.instrument(), &lattice, ttm, 0)?;
+        let vanilla = |price: f64| match option.option_type() {
+            OptionType::Call => (price - option.strike()).max(0.0),
+            OptionType::Put => (option.strike() - price).max(0.0),
+        };
+        let breached = |step: usize, i: usize| {
+            monitored[step] && barrier.is_breached(barrier_type, node_spot(step, i))
+        };
+        let knock_in = barrier_type.is_knock_in();
+
+        // Values of the vanilla (knocked-in) option and of the option that is still alive / not knocked in
+        let mut vanilla_values: Vec<f64> = (0..lattice.nodes(steps))
+            .map(|i| vanilla(node_spot(steps, i)))
+            .collect();
+        let mut values: Vec<f64> = (0..lattice.nodes(steps))
+            .map(|i| match (knock_in, breached(steps, i)) {
+                (true, true) => vanilla_values[i],
+                (false, false) => vanilla_values[i],
+                _ => barrier.rebate,
+            })
+            .collect();
+
+        for step in (0..steps).rev() {
+            for i in 0..lattice.nodes(step) {
+                let expected_value = lattice.expected(&values, i);
+                let expected_vanilla = lattice.expected(&vanilla_values, i);
+                vanilla_values[i] = expected_vanilla;
+                values[i] = match (knock_in, breached(step, i)) {
+                    (true, true) => expected_vanilla,
+                    (false, true) => barrier.rebate,
+                    _ => expected_value,
+                };
+            }
+        }
+
+        Ok(if barrier.is_breached(barrier_type, spot) {
+            if knock_in {
+                vanilla_values[0]
+            } else {
+                barrier.rebate
+            }
         } else {
-            option_values[0] // Return the root node value
+            values[0]
+        })
+    }
+}
+
+impl OptionPricing for BinomialTreeModel {
+    fn price<T: Option>(&self, option: &T) -> f64 {
+        self.try_price(option).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Calculate the price of an option, extrapolated to an infinite number of steps if Richardson extrapolation
+    /// is enabled. At expiry the option is worth its payoff.
+    fn try_price<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if option.time_to_maturity() <= 0.0 {
+            return Ok(option.payoff(None));
+        }
+        if !is_barrier(option) {
+            return self.extrapolate(option, |steps| Ok(self.tree_values(option, steps)?.price));
+        }
+        if option.barrier().is_none() {
+            return Err(PricingError::invalid_input(
+                "Barrier options must provide a barrier",
+            ));
+        }
+        self.extrapolate(option, |steps| self.price_barrier(option, steps))
+    }
+
+    fn implied_volatility<T: Option>(&self, option: &T, market_price: f64) -> f64 {
+        self.try_implied_volatility(option, market_price)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_implied_volatility<T: Option>(
+        &self,
+        _option: &T,
+        _market_price: f64,
+    ) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Implied volatility"))
+    }
+}
+
+/// Delta, gamma and theta are read off a tree extended two steps before the valuation date, vega and rho are calculated
+/// by bumping and repricing. Barrier options are bumped and repriced for every Greek.
+///
+/// Theta is the change of the price over the passage of time, i.e. cash dividends keep their ex-dividend dates. At
+/// expiry, delta is the slope of the payoff at the spot price and gamma and theta vanish.
+impl OptionGreeks for BinomialTreeModel {
+    fn try_delta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        if is_barrier(option) {
+            return NumericalGreeks::new(self.clone()).try_delta(option);
+        }
+        validate_option(option)?;
+        if option.time_to_maturity() <= 0.0 {
+            return Ok(expiry_delta(option));
+        }
+        self.extrapolate(option, |steps| Ok(self.tree_values(option, steps)?.delta))
+    }
+
+    fn try_gamma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        if is_barrier(option) {
+            return NumericalGreeks::new(self.clone()).try_gamma(option);
+        }
+        validate_option(option)?;
+        if option.time_to_maturity() <= 0.0 {
+            return Ok(0.0);
         }
+        self.extrapolate(option, |steps| Ok(self.tree_values(option, steps)?.gamma))
+    }
+
+    fn try_theta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        if is_barrier(option) {
+            return NumericalGreeks::new(self.clone()).try_theta(option);
+        }
+        validate_option(option)?;
+        if option.time_to_maturity() <= 0.0 {
+            return Ok(0.0);
+        }
+        self.extrapolate(option, |steps| Ok(self.tree_values(option, steps)?.theta))
+    }
+
+    fn try_vega<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        NumericalGreeks::new(self.clone()).try_vega(option)
+    }
+
+    fn try_rho<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        NumericalGreeks::new(self.clone()).try_rho(option)
+    }
+}
+
+/// Calculate the delta of an option at expiry, i.e. the slope of its payoff at the spot price of the tree.
+fn expiry_delta<T: Option>(option: &T) -> f64 {
+    let spot = BinomialTreeModel::tree_instrument(option).spot();
+    let h = 1e-6 * spot.max(1.0);
+    let (up, down) = (spot + h, (spot - h).max(0.0));
+    (option.payoff(Some(up)) - option.payoff(Some(down))) / (up - down)
+}
+
+/// Check whether an option is a (single or double) barrier option.
+fn is_barrier<T: Option>(option: &T) -> bool {
+    matches!(
+        option.style(),
+        OptionStyle::Barrier(_) | OptionStyle::DoubleBarrier(_, _)
+    )
+}
+
+impl ModelParameters for BinomialTreeModel {
+    fn risk_free_rate(&self) -> f64 {
+        self.risk_free_rate
+    }
+
+    fn set_risk_free_rate(&mut self, risk_free_rate: f64) {
+        self.risk_free_rate = risk_free_rate;
+    }
+
+    fn volatility(&self) -> f64 {
+        self.volatility
     }
 
-    fn implied_volatility<T: Option>(&self, _option: &T, _market_price: f64) -> f64 {
-        panic!("BinomialTreeModel does not support implied volatility calculation yet");
+    fn set_volatility(&mut self, volatility: f64) {
+        self.volatility = volatility;
     }
 }
 
@@ -2,19 +2,26 @@
 //! Assumes constant risk-free interest rate r and the futures price F(t) of a particular underlying is log-normal with constant volatility σ.
 //! https://en.wikipedia.org/wiki/Black_model
 //! https://www.glynholton.com/notes/black_1976/
+//!
+//! With a [`SabrModel`] as volatility source (see [`Black76Model::with_sabr`]), every option is priced with the SABR
+//! lognormal volatility at its forward, strike and expiry. The Greeks hold this volatility fixed when the forward
+//! moves (sticky strike).
 
 use crate::options::{
-    Instrument, Option, OptionGreeks, OptionPricing, OptionStrategy, OptionStyle, OptionType,
+    Instrument, ModelParameters, Option, OptionGreeks, OptionPricing, OptionStrategy, OptionStyle,
+    OptionType, PricingError, SabrModel, numerics, validate_market_price, validate_option,
 };
 use statrs::distribution::{Continuous, ContinuousCDF, Normal};
 
 /// Black76 option pricing model.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Black76Model {
     /// Risk-free interest rate (e.g., 0.05 for 5%).
     pub risk_free_rate: f64,
     /// Volatility of the underlying asset (e.g., 0.2 for 20%).
     pub volatility: f64,
+    /// SABR model replacing the flat volatility with its lognormal volatility at the strike of every option.
+    pub sabr: std::option::Option<SabrModel>,
 }
 
 impl Black76Model {
@@ -32,9 +39,36 @@ impl Black76Model {
         Self {
             risk_free_rate,
             volatility,
+            sabr: None,
         }
     }
 
+    /// Set a SABR model as the volatility source, which replaces the flat volatility.
+    ///
+    /// # Arguments
+    ///
+    /// * `sabr` - The SABR model of the underlying's expiry.
+    ///
+    /// # Returns
+    ///
+    /// The model with the SABR volatility set.
+    pub fn with_sabr(mut self, sabr: SabrModel) -> Self {
+        self.sabr = Some(sabr);
+        self
+    }
+
+    /// Get the flat model with the SABR volatility at the forward, strike and expiry of an option.
+    fn flattened<T: Option>(&self, option: &T) -> std::option::Option<Self> {
+        let sabr = self.sabr.as_ref()?;
+        let ttm = option.time_to_maturity();
+        let forward = option.instrument().calculate_adjusted_spot(ttm);
+        Some(Self {
+            volatility: sabr.lognormal_volatility(forward, option.strike(), ttm),
+            sabr: None,
+            ..self.clone()
+        })
+    }
+
     /// Calculate d1 and d2 for the Black-76 formula.
     ///
     /// # Arguments
@@ -110,30 +144,93 @@ impl Black76Model {
 }
 
 impl OptionPricing for Black76Model {
-    #[rustfmt::skip]
     fn price<T: Option>(&self, option: &T) -> f64 {
+        self.try_price(option).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    #[rustfmt::skip]
+    fn try_price<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some(model) = self.flattened(option) {
+            return model.try_price(option);
+        }
         let normal = Normal::new(0.0, 1.0).unwrap();
-        match (option.option_type(), option.style()) {
+        Ok(match (option.option_type(), option.style()) {
             (OptionType::Call, OptionStyle::European) => self.price_euro_call(option.instrument(), option.strike(),option.time_to_maturity(), &normal),
             (OptionType::Put, OptionStyle::European) => self.price_euro_put(option.instrument(), option.strike(), option.time_to_maturity(),&normal),
-            _ => panic!("Black76Model does not support this option type or style"),
-        }
+            _ => return Err(PricingError::unsupported_style("Black76Model", *option.style())),
+        })
+    }
+
+    fn implied_volatility<T: Option>(&self, option: &T, market_price: f64) -> f64 {
+        self.try_implied_volatility(option, market_price)
+            .unwrap_or_else(|err| panic!("{err}"))
     }
 
-    fn implied_volatility<T: Option>(&self, _option: &T, _market_price: f64) -> f64 {
-        panic!("Black76Model does not support implied volatility calculation yet");
+    /// Calculate the implied volatility of a European option without panicking.
+    ///
+    /// Newton's method with analytic vega is safeguarded by bisection and started from the Corrado-Miller
+    /// approximation on the out-of-the-money side of put-call parity. Market prices outside the no-arbitrage bounds
+    /// are rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to calculate the implied volatility for.
+    /// * `market_price` - The market price of the option.
+    ///
+    /// # Returns
+    ///
+    /// The implied volatility of the option or the reason why it cannot be calculated.
+    fn try_implied_volatility<T: Option>(
+        &self,
+        option: &T,
+        market_price: f64,
+    ) -> Result<f64, PricingError> {
+        validate_market_price(option, market_price)?;
+        if *option.style() != OptionStyle::European {
+            return Err(PricingError::unsupported_style(
+                "Black76Model",
+                *option.style(),
+            ));
+        }
+        let ttm = option.time_to_maturity();
+        if ttm <= 0.0 {
+            return Err(PricingError::invalid_input(
+                "Time to maturity must be positive to imply a volatility",
+            ));
+        }
+
+        let discount = (-self.risk_free_rate * ttm).exp();
+        let discounted_forward = option.instrument().spot() * discount;
+        let discounted_strike = option.strike() * discount;
+        let with_volatility = |volatility| Self {
+            volatility,
+            ..self.clone()
+        };
+        numerics::european_implied_volatility(
+            |volatility, option| with_volatility(volatility).price(option),
+            |volatility, option| with_volatility(volatility).vega(option),
+            option,
+            market_price,
+            discounted_forward,
+            discounted_strike,
+        )
     }
 }
 
 impl OptionGreeks for Black76Model {
-    fn delta<T: Option>(&self, option: &T) -> f64 {
+    fn try_delta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some(model) = self.flattened(option) {
+            return model.try_delta(option);
+        }
         let (d1, d2) = self.calculate_d1_d2(
             option.instrument(),
             option.strike(),
             option.time_to_maturity(),
         );
         let normal = Normal::new(0.0, 1.0).unwrap();
-        match option.style() {
+        Ok(match option.style() {
             OptionStyle::European => match option.option_type() {
                 OptionType::Call => {
                     (-self.risk_free_rate * option.time_to_maturity()).exp() * normal.cdf(d1)
@@ -143,11 +240,20 @@ impl OptionGreeks for Black76Model {
                         * (normal.cdf(d1) - 1.0)
                 }
             },
-            _ => panic!("Unsupported option style for delta calculation"),
-        }
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "Black76Model",
+                    *option.style(),
+                ));
+            }
+        })
     }
 
-    fn gamma<T: Option>(&self, option: &T) -> f64 {
+    fn try_gamma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some(model) = self.flattened(option) {
+            return model.try_gamma(option);
+        }
         let (d1, d2) = self.calculate_d1_d2(
             option.instrument(),
             option.strike(),
@@ -158,16 +264,25 @@ impl OptionGreeks for Black76Model {
             .calculate_adjusted_spot(option.time_to_maturity());
         let normal = Normal::new(0.0, 1.0).unwrap();
 
-        match option.style() {
+        Ok(match option.style() {
             OptionStyle::European => {
                 (-self.risk_free_rate * option.time_to_maturity()).exp() * normal.pdf(d1)
                     / (adjusted_spot * self.volatility * option.time_to_maturity().sqrt())
             }
-            _ => panic!("Unsupported option style for gamma calculation"),
-        }
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "Black76Model",
+                    *option.style(),
+                ));
+            }
+        })
     }
 
-    fn theta<T: Option>(&self, option: &T) -> f64 {
+    fn try_theta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some(model) = self.flattened(option) {
+            return model.try_theta(option);
+        }
         let (d1, d2) = self.calculate_d1_d2(
             option.instrument(),
             option.strike(),
@@ -178,7 +293,7 @@ impl OptionGreeks for Black76Model {
             .calculate_adjusted_spot(option.time_to_maturity());
         let normal = Normal::new(0.0, 1.0).unwrap();
 
-        match option.style() {
+        Ok(match option.style() {
             OptionStyle::European => match option.option_type() {
                 OptionType::Call => {
                     -adjusted_spot
@@ -211,11 +326,20 @@ impl OptionGreeks for Black76Model {
                             * normal.cdf(-d2)
                 }
             },
-            _ => panic!("Unsupported option style for theta calculation"),
-        }
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "Black76Model",
+                    *option.style(),
+                ));
+            }
+        })
     }
 
-    fn vega<T: Option>(&self, option: &T) -> f64 {
+    fn try_vega<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some(model) = self.flattened(option) {
+            return model.try_vega(option);
+        }
         let (d1, d2) = self.calculate_d1_d2(
             option.instrument(),
             option.strike(),
@@ -226,18 +350,27 @@ impl OptionGreeks for Black76Model {
             .calculate_adjusted_spot(option.time_to_maturity());
         let normal = Normal::new(0.0, 1.0).unwrap();
 
-        match option.style() {
+        Ok(match option.style() {
             OptionStyle::European => {
                 adjusted_spot
                     * (-self.risk_free_rate * option.time_to_maturity()).exp()
                     * normal.pdf(d1)
                     * option.time_to_maturity().sqrt()
             }
-            _ => panic!("Unsupported option style for vega calculation"),
-        }
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "Black76Model",
+                    *option.style(),
+                ));
+            }
+        })
     }
 
-    fn rho<T: Option>(&self, option: &T) -> f64 {
+    fn try_rho<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some(model) = self.flattened(option) {
+            return model.try_rho(option);
+        }
         let (d1, d2) = self.calculate_d1_d2(
             option.instrument(),
             option.strike(),
@@ -245,7 +378,7 @@ impl OptionGreeks for Black76Model {
         );
         let normal = Normal::new(0.0, 1.0).unwrap();
 
-        match option.style() {
+        Ok(match option.style() {
             OptionStyle::European => match option.option_type() {
                 OptionType::Call => {
                     -option.time_to_maturity()
@@ -266,8 +399,275 @@ impl OptionGreeks for Black76Model {
                         )
                 }
             },
-            _ => panic!("Unsupported option style for rho calculation"),
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "Black76Model",
+                    *option.style(),
+                ));
+            }
+        })
+    }
+
+    fn try_lambda<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Ok(self.try_delta(option)? * option.instrument().spot() / self.try_price(option)?)
+    }
+
+    /// The futures price already contains the cost of carry, so the price does not depend on a dividend yield.
+    fn try_epsilon<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some(model) = self.flattened(option) {
+            return model.try_epsilon(option);
+        }
+        Ok(match option.style() {
+            OptionStyle::European => 0.0,
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "Black76Model",
+                    *option.style(),
+                ));
+            }
+        })
+    }
+
+    fn try_vanna<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some(model) = self.flattened(option) {
+            return model.try_vanna(option);
+        }
+        let (d1, d2) = self.calculate_d1_d2(
+            option.instrument(),
+            option.strike(),
+            option.time_to_maturity(),
+        );
+        let normal = Normal::new(0.0, 1.0).unwrap();
+
+        Ok(match option.style() {
+            OptionStyle::European => {
+                -(-self.risk_free_rate * option.time_to_maturity()).exp() * normal.pdf(d1) * d2
+                    / self.volatility
+            }
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "Black76Model",
+                    *option.style(),
+                ));
+            }
+        })
+    }
+
+    /// Charm is the change of delta over the passage of (calendar) time, like theta.
+    fn try_charm<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some(model) = self.flattened(option) {
+            return model.try_charm(option);
+        }
+        let (d1, d2) = self.calculate_d1_d2(
+            option.instrument(),
+            option.strike(),
+            option.time_to_maturity(),
+        );
+        let normal = Normal::new(0.0, 1.0).unwrap();
+
+        Ok(match option.style() {
+            OptionStyle::European => {
+                self.risk_free_rate * self.delta(option)
+                    + (-self.risk_free_rate * option.time_to_maturity()).exp() * normal.pdf(d1) * d2
+                        / (2.0 * option.time_to_maturity())
+            }
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "Black76Model",
+                    *option.style(),
+                ));
+            }
+        })
+    }
+
+    fn try_vomma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some(model) = self.flattened(option) {
+            return model.try_vomma(option);
+        }
+        let (d1, d2) = self.calculate_d1_d2(
+            option.instrument(),
+            option.strike(),
+            option.time_to_maturity(),
+        );
+
+        Ok(match option.style() {
+            OptionStyle::European => self.vega(option) * d1 * d2 / self.volatility,
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "Black76Model",
+                    *option.style(),
+                ));
+            }
+        })
+    }
+
+    /// Veta is the change of vega over the passage of (calendar) time, like theta.
+    fn try_veta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some(model) = self.flattened(option) {
+            return model.try_veta(option);
+        }
+        let (d1, d2) = self.calculate_d1_d2(
+            option.instrument(),
+            option.strike(),
+            option.time_to_maturity(),
+        );
+
+        Ok(match option.style() {
+            OptionStyle::European => {
+                self.vega(option)
+                    * (self.risk_free_rate - (1.0 + d1 * d2) / (2.0 * option.time_to_maturity()))
+            }
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "Black76Model",
+                    *option.style(),
+                ));
+            }
+        })
+    }
+
+    fn try_vera<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some(model) = self.flattened(option) {
+            return model.try_vera(option);
+        }
+        Ok(match option.style() {
+            OptionStyle::European => -option.time_to_maturity() * self.vega(option),
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "Black76Model",
+                    *option.style(),
+                ));
+            }
+        })
+    }
+
+    fn try_speed<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some(model) = self.flattened(option) {
+            return model.try_speed(option);
+        }
+        let (d1, d2) = self.calculate_d1_d2(
+            option.instrument(),
+            option.strike(),
+            option.time_to_maturity(),
+        );
+        let adjusted_spot = option
+            .instrument()
+            .calculate_adjusted_spot(option.time_to_maturity());
+
+        Ok(match option.style() {
+            OptionStyle::European => {
+                -self.gamma(option) / adjusted_spot
+                    * (d1 / (self.volatility * option.time_to_maturity().sqrt()) + 1.0)
+            }
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "Black76Model",
+                    *option.style(),
+                ));
+            }
+        })
+    }
+
+    fn try_zomma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some(model) = self.flattened(option) {
+            return model.try_zomma(option);
+        }
+        let (d1, d2) = self.calculate_d1_d2(
+            option.instrument(),
+            option.strike(),
+            option.time_to_maturity(),
+        );
+
+        Ok(match option.style() {
+            OptionStyle::European => self.gamma(option) * (d1 * d2 - 1.0) / self.volatility,
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "Black76Model",
+                    *option.style(),
+                ));
+            }
+        })
+    }
+
+    /// Color is the change of gamma over the passage of (calendar) time, like theta.
+    fn try_color<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some(model) = self.flattened(option) {
+            return model.try_color(option);
+        }
+        let (d1, d2) = self.calculate_d1_d2(
+            option.instrument(),
+            option.strike(),
+            option.time_to_maturity(),
+        );
+
+        Ok(match option.style() {
+            OptionStyle::European => {
+                self.gamma(option)
+                    * (self.risk_free_rate + (1.0 - d1 * d2) / (2.0 * option.time_to_maturity()))
+            }
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "Black76Model",
+                    *option.style(),
+                ));
+            }
+        })
+    }
+
+    fn try_ultima<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some(model) = self.flattened(option) {
+            return model.try_ultima(option);
+        }
+        let (d1, d2) = self.calculate_d1_d2(
+            option.instrument(),
+            option.strike(),
+            option.time_to_maturity(),
+        );
+
+        Ok(match option.style() {
+            OptionStyle::European => {
+                -self.vega(option) / self.volatility.powi(2)
+                    * (d1 * d2 * (1.0 - d1 * d2) + d1.powi(2) + d2.powi(2))
+            }
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "Black76Model",
+                    *option.style(),
+                ));
+            }
+        })
+    }
+}
+
+impl ModelParameters for Black76Model {
+    fn risk_free_rate(&self) -> f64 {
+        self.risk_free_rate
+    }
+
+    fn set_risk_free_rate(&mut self, risk_free_rate: f64) {
+        self.risk_free_rate = risk_free_rate;
+    }
+
+    fn volatility(&self) -> f64 {
+        self.volatility
+    }
+
+    /// Set the volatility, shifting the SABR volatilities in parallel by the change of the volatility.
+    fn set_volatility(&mut self, volatility: f64) {
+        if let Some(sabr) = &mut self.sabr {
+            *sabr = sabr.shifted(volatility - self.volatility);
         }
+        self.volatility = volatility;
     }
 }
 
@@ -15,6 +15,19 @@
 //! including the current price of the underlying asset, the strike price of the option, the time to expiration, the risk-free interest rate,
 //! and the volatility of the underlying asset.
 //!
+//! Cash dividends are priced with the escrowed dividend model, in which the spot price less the present value of the
+//! dividends going ex before expiry follows a geometric Brownian motion.
+//!
+//! With a discount curve, a borrow curve or a volatility term structure, European and binary options are priced with
+//! the discount factor, the borrow cost and the forward variance to their expiry, i.e. with the zero rates and the
+//! implied volatility to the expiry. Their Greeks are those of the equivalent flat model, so rho and vega are the
+//! sensitivities to parallel shifts of the discount curve and the volatility term structure.
+//!
+//! With an implied volatility surface, European and binary options are priced with the implied volatility of the
+//! surface at their expiry and strike (sticky strike), and vega is the sensitivity to a parallel shift of the surface.
+//! A [`SabrModel`] can be used as the volatility source in the same way, with its lognormal volatility at the forward
+//! to the expiry of the option and its strike.
+//!
 //! ## References
 //!
 //! - [Wikipedia - Black-Scholes model](https://en.wikipedia.org/wiki/Black%E2%80%93Scholes_model)
@@ -23,6 +36,16 @@
 //! - [Asset or Nothing Options' Greeks](https://quantpie.co.uk/bsm_bin_a_formula/bs_bin_a_summary.php)
 //! - Musiela, M., Rutkowski, M. Martingale Methods in Financial Modelling, 2nd Ed Springer, 2007
 //! - Joshi, M. The Concepts and Practice of Mathematical Finance, 2nd Ed Cambridge University Press, 2008
+//! - Stulz, R. Options on the minimum or the maximum of two risky assets, Journal of Financial Economics 10, 1982
+//! - Levy, E. Pricing European average rate currency options, Journal of International Money and Finance 11, 1992
+//! - Gentle, D. Basket weaving, Risk 6, 1993
+//! - Kemna, A., Vorst, A. A pricing method for options based on average asset values, Journal of Banking and Finance 14, 1990
+//! - Turnbull, S., Wakeman, L. A quick algorithm for pricing European average options, Journal of Financial and Quantitative Analysis 26, 1991
+//! - Curran, M. Valuing Asian and portfolio options by conditioning on the geometric mean price, Management Science 40, 1994
+//! - Goldman, M., Sosin, H., Gatto, M. Path dependent options: buy at the low, sell at the high, Journal of Finance 34, 1979
+//! - Conze, A., Viswanathan. Path dependent options: the case of lookback options, Journal of Finance 46, 1991
+//! - Heynen, R., Kat, H. Selective memory, Risk 7, 1994
+//! - Broadie, M., Glasserman, P., Kou, S. Connecting discrete and continuous path-dependent options, Finance and Stochastics 3, 1999
 //!
 //! ## Example
 //!
@@ -38,20 +61,304 @@
 //! ```
 
 use crate::options::{
-    Instrument, Option, OptionGreeks, OptionPricing, OptionStrategy, OptionStyle, OptionType,
-    Permutation, RainbowType,
-    types::BinaryType::{AssetOrNothing, CashOrNothing},
+    AveragingSchedule, BarrierType, Instrument, ModelParameters, Monitoring, Option, OptionGreeks,
+    OptionPricing, OptionStrategy, OptionStyle, OptionType, Permutation, PricingError, RainbowType,
+    SabrModel, VolSurface, VolatilityTermStructure, YieldCurve, numerics,
+    types::BinaryType::{self, AssetOrNothing, CashOrNothing},
+    validate_market_price, validate_option,
 };
-use rand_distr::num_traits::Pow;
 use statrs::distribution::{Continuous, ContinuousCDF, Normal};
 
+/// Weights, forwards, volatilities and correlation matrix of the assets of a multi-asset option.
+struct AssetComponents {
+    weights: Vec<f64>,
+    forwards: Vec<f64>,
+    volatilities: Vec<f64>,
+    correlation: Vec<Vec<f64>>,
+}
+
+/// Observation times and weights of the remaining part of an average, and the part already fixed.
+pub(crate) struct Averaging {
+    /// Remaining observation times.
+    pub(crate) times: Vec<f64>,
+    /// Weights of the remaining observations in the average.
+    pub(crate) weights: Vec<f64>,
+    /// Contribution of the fixed observations to the arithmetic average.
+    pub(crate) fixed: f64,
+    /// Contribution of the fixed observations to the logarithm of the geometric average.
+    pub(crate) log_fixed: f64,
+}
+
+impl Averaging {
+    /// Number of Gauss-Legendre nodes approximating a continuous average.
+    const CONTINUOUS_NODES: usize = 64;
+
+    /// Average equally weighted observations at the given times.
+    pub(crate) fn equally_weighted(times: Vec<f64>) -> Self {
+        let weight = 1.0 / times.len() as f64;
+        Self {
+            weights: vec![weight; times.len()],
+            times,
+            fixed: 0.0,
+            log_fixed: 0.0,
+        }
+    }
+
+    /// Average continuously from the valuation date to maturity (with Gauss-Legendre quadrature).
+    pub(crate) fn continuous(time_to_maturity: f64) -> Self {
+        let (nodes, weights) = numerics::gauss_legendre(Self::CONTINUOUS_NODES);
+        Self {
+            times: nodes
+                .iter()
+                .map(|node| 0.5 * time_to_maturity * (node + 1.0))
+                .collect(),
+            weights: weights.iter().map(|weight| 0.5 * weight).collect(),
+            fixed: 0.0,
+            log_fixed: 0.0,
+        }
+    }
+
+    /// Average the fixings of a schedule.
+    ///
+    /// # Returns
+    ///
+    /// The averaging or an error if the schedule is empty, a fixing date lies outside `(0, T]` or a past fixing is not
+    /// positive.
+    pub(crate) fn from_schedule(
+        schedule: &AveragingSchedule,
+        time_to_maturity: f64,
+    ) -> Result<Self, PricingError> {
+        if schedule.fixings() == 0 {
+            return Err(PricingError::invalid_input(
+                "Averaging schedules must have at least one fixing",
+            ));
+        }
+        if schedule
+            .fixing_dates
+            .iter()
+            .any(|&date| !(date > 0.0 && date <= time_to_maturity))
+        {
+            return Err(PricingError::invalid_input(
+                "Fixing dates must lie after the valuation date and not after maturity",
+            ));
+        }
+        if schedule
+            .past_fixings
+            .iter()
+            .any(|&fixing| !fixing.is_finite() || fixing <= 0.0)
+        {
+            return Err(PricingError::invalid_input("Past fixings must be positive"));
+        }
+
+        let n = schedule.fixings() as f64;
+        let mut times = schedule.fixing_dates.clone();
+        times.sort_by(f64::total_cmp);
+        Ok(Self {
+            times,
+            weights: vec![1.0 / n; schedule.fixing_dates.len()],
+            fixed: schedule.past_fixings.iter().sum::<f64>() / n,
+            log_fixed: schedule.past_fixings.iter().map(|f| f.ln()).sum::<f64>() / n,
+        })
+    }
+}
+
+/// Closed form or approximation used to price fixed strike Asian options.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum AsianApproximation {
+    /// Exact price of an option on the geometric average (Kemna-Vorst).
+    #[default]
+    KemnaVorst,
+    /// Option on the arithmetic average, approximated by a log-normal average with the same first two moments
+    /// (Turnbull-Wakeman).
+    TurnbullWakeman,
+    /// Option on the arithmetic average, approximated by conditioning on the geometric average (Curran).
+    Curran,
+}
+
+/// Higher-order Greeks of a cash-or-nothing (`e^(-rT) N(±d2)`) or asset-or-nothing (`S e^(-qT) N(±d1)`) digital.
+///
+/// European options are portfolios of these digitals, so their Greeks are combined from the same closed forms.
+struct DigitalGreeks {
+    vanna: f64,
+    charm: f64,
+    vomma: f64,
+    veta: f64,
+    vera: f64,
+    speed: f64,
+    zomma: f64,
+    color: f64,
+    ultima: f64,
+    epsilon: f64,
+}
+
+/// Market parameters of the continuously monitored lookback formulas.
+///
+/// With the cost of carry `b = r - q`, the formulas contain `σ² / 2b`; they have a removable singularity at `b = 0`
+/// and are evaluated slightly off it.
+struct LookbackFormula<'a> {
+    spot: f64,
+    rate: f64,
+    carry: f64,
+    volatility: f64,
+    ttm: f64,
+    normal: &'a Normal,
+}
+
+impl LookbackFormula<'_> {
+    /// Smallest cost of carry the formulas are evaluated at.
+    const MIN_CARRY: f64 = 1e-8;
+
+    /// Calculate the discounted expectation of the maximum (`eta = 1`) or minimum (`eta = -1`) of a level and the
+    /// extremum of the spot until maturity (Goldman-Sosin-Gatto).
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - The level (not below the spot for the maximum, not above it for the minimum).
+    /// * `eta` - `1` for the maximum, `-1` for the minimum.
+    ///
+    /// # Returns
+    ///
+    /// The discounted expectation `e^(-rT) E[max(level, M_T)]` or `e^(-rT) E[min(level, m_T)]`.
+    fn expected_extremum(&self, level: f64, eta: f64) -> f64 {
+        let (s, r, b, v, t) = (self.spot, self.rate, self.carry, self.volatility, self.ttm);
+        let phi = |x: f64| self.normal.cdf(x);
+        let sqrt_t = t.sqrt();
+        let d1 = ((s / level).ln() + (b + 0.5 * v * v) * t) / (v * sqrt_t);
+        let d2 = d1 - v * sqrt_t;
+        let ratio = 0.5 * v * v / b;
+
+        level * (-r * t).exp() * phi(-eta * d2)
+            + s * ((b - r) * t).exp() * (1.0 + ratio) * phi(eta * d1)
+            - s * (-r * t).exp()
+                * ratio
+                * (s / level).powf(-1.0 / ratio)
+                * phi(eta * (d1 - 2.0 * b * sqrt_t / v))
+    }
+
+    /// Calculate the price of a partial-time floating strike lookback option whose extremum is observed until
+    /// `t1` (Heynen-Kat).
+    ///
+    /// # Arguments
+    ///
+    /// * `extremum` - The observed minimum (calls) or maximum (puts).
+    /// * `lambda` - The factor of the extremum in the payoff `max(η (S_T - λ extremum), 0)`.
+    /// * `t1` - The end of the lookback period (in `(0, T)`).
+    /// * `eta` - `1` for calls, `-1` for puts.
+    ///
+    /// # Returns
+    ///
+    /// The price of the option.
+    fn partial_floating(&self, extremum: f64, lambda: f64, t1: f64, eta: f64) -> f64 {
+        let (s, r, b, v, t) = (self.spot, self.rate, self.carry, self.volatility, self.ttm);
+        let phi = |x: f64| self.normal.cdf(x);
+        let phi2 = numerics::bivariate_normal_cdf;
+        let (sqrt_t, sqrt_t1, sqrt_tau) = (t.sqrt(), t1.sqrt(), (t - t1).sqrt());
+        let (rho1, rho2) = ((t1 / t).sqrt(), (1.0 - t1 / t).sqrt());
+        let ratio = 0.5 * v * v / b;
+
+        let d1 = ((s / extremum).ln() + (b + 0.5 * v * v) * t) / (v * sqrt_t);
+        let d2 = d1 - v * sqrt_t;
+        let e1 = (b + 0.5 * v * v) * sqrt_tau / v;
+        let e2 = e1 - v * sqrt_tau;
+        let f1 = ((s / extremum).ln() + (b + 0.5 * v * v) * t1) / (v * sqrt_t1);
+        let f2 = f1 - v * sqrt_t1;
+        let g1 = lambda.ln() / (v * sqrt_t);
+        let g2 = lambda.ln() / (v * sqrt_tau);
+        let (carry_discount, discount) = (((b - r) * t).exp(), (-r * t).exp());
+
+        eta * (s * carry_discount * phi(eta * (d1 - g1))
+            - lambda * extremum * discount * phi(eta * (d2 - g1))
+            + s * discount
+                * lambda
+                * ratio
+                * ((s / extremum).powf(-1.0 / ratio)
+                    * phi2(
+                        -eta * (f1 - 2.0 * b * sqrt_t1 / v),
+                        -eta * (d1 - 2.0 * b * sqrt_t / v + g1),
+                        rho1,
+                    )
+                    - (b * t).exp()
+                        * lambda.powf(1.0 / ratio)
+                        * phi2(-eta * (d1 + g1), eta * (e1 + g2), -rho2))
+            + s * carry_discount * phi2(-eta * (d1 - g1), eta * (e1 - g2), -rho2)
+            + lambda * extremum * discount * phi2(-eta * f2, eta * (d2 - g1), -rho1)
+            - (-b * (t - t1)).exp()
+                * s
+                * carry_discount
+                * (1.0 + ratio)
+                * lambda
+                * phi(eta * (e2 - g2))
+                * phi(-eta * f1))
+    }
+
+    /// Calculate the price of a partial-time fixed strike lookback option whose extremum is observed from `t1`
+    /// (Heynen-Kat).
+    ///
+    /// # Arguments
+    ///
+    /// * `strike` - The strike price.
+    /// * `t1` - The start of the lookback period (in `(0, T)`).
+    /// * `eta` - `1` for calls, `-1` for puts.
+    ///
+    /// # Returns
+    ///
+    /// The price of the option.
+    fn partial_fixed(&self, strike: f64, t1: f64, eta: f64) -> f64 {
+        let (s, r, b, v, t) = (self.spot, self.rate, self.carry, self.volatility, self.ttm);
+        let phi = |x: f64| self.normal.cdf(x);
+        let phi2 = numerics::bivariate_normal_cdf;
+        let (sqrt_t, sqrt_t1, sqrt_tau) = (t.sqrt(), t1.sqrt(), (t - t1).sqrt());
+        let (rho1, rho2) = ((t1 / t).sqrt(), (1.0 - t1 / t).sqrt());
+        let ratio = 0.5 * v * v / b;
+
+        let d1 = ((s / strike).ln() + (b + 0.5 * v * v) * t) / (v * sqrt_t);
+        let d2 = d1 - v * sqrt_t;
+        let e1 = (b + 0.5 * v * v) * sqrt_tau / v;
+        let e2 = e1 - v * sqrt_tau;
+        let f1 = ((s / strike).ln() + (b + 0.5 * v * v) * t1) / (v * sqrt_t1);
+        let f2 = f1 - v * sqrt_t1;
+        let (carry_discount, discount) = (((b - r) * t).exp(), (-r * t).exp());
+
+        eta * (s * carry_discount * phi(eta * d1) - strike * discount * phi(eta * d2)
+            + s * discount
+                * ratio
+                * ((b * t).exp() * phi2(eta * e1, eta * d1, rho2)
+                    - (s / strike).powf(-1.0 / ratio)
+                        * phi2(
+                            eta * (d1 - 2.0 * b * sqrt_t / v),
+                            -eta * (f1 - 2.0 * b * sqrt_t1 / v),
+                            -rho1,
+                        ))
+            - s * carry_discount * phi2(-eta * e1, eta * d1, -rho2)
+            - strike * discount * phi2(eta * f2, -eta * d2, -rho1)
+            + (-b * (t - t1)).exp()
+                * (1.0 - ratio)
+                * s
+                * carry_discount
+                * phi(eta * f1)
+                * phi(-eta * e2))
+    }
+}
+
 /// A struct representing a Black-Scholes model.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct BlackScholesModel {
     /// Risk-free interest rate (e.g., 0.05 for 5%).
     pub risk_free_rate: f64,
     /// Annualized standard deviation of an asset's continuous returns (e.g., 0.2 for 20%).
     pub volatility: f64,
+    /// Closed form or approximation used to price fixed strike Asian options.
+    pub asian_approximation: AsianApproximation,
+    /// Discount curve replacing the flat risk-free rate (`None` discounts with `risk_free_rate`).
+    pub discount_curve: std::option::Option<YieldCurve>,
+    /// Borrow (repo) curve of the underlying, whose zero rates add to its continuous dividend yield.
+    pub borrow_curve: std::option::Option<YieldCurve>,
+    /// Term structure of implied volatilities replacing the flat volatility (`None` uses `volatility`).
+    pub volatility_term_structure: std::option::Option<VolatilityTermStructure>,
+    /// Implied volatility surface replacing the flat volatility and the volatility term structure.
+    pub vol_surface: std::option::Option<VolSurface>,
+    /// SABR model replacing the flat volatility, the volatility term structure and the volatility surface.
+    pub sabr: std::option::Option<SabrModel>,
 }
 
 impl BlackScholesModel {
@@ -69,7 +376,167 @@ impl BlackScholesModel {
         Self {
             risk_free_rate,
             volatility,
+            asian_approximation: AsianApproximation::KemnaVorst,
+            discount_curve: None,
+            borrow_curve: None,
+            volatility_term_structure: None,
+            vol_surface: None,
+            sabr: None,
+        }
+    }
+
+    /// Set the closed form or approximation used to price fixed strike Asian options.
+    ///
+    /// # Arguments
+    ///
+    /// * `approximation` - The Asian option approximation.
+    ///
+    /// # Returns
+    ///
+    /// The model with the Asian option approximation set.
+    pub fn with_asian_approximation(mut self, approximation: AsianApproximation) -> Self {
+        self.asian_approximation = approximation;
+        self
+    }
+
+    /// Set the discount curve, which replaces the flat risk-free rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `curve` - The curve of zero rates used for discounting and as the growth rate of the underlying.
+    ///
+    /// # Returns
+    ///
+    /// The model with the discount curve set.
+    pub fn with_discount_curve(mut self, curve: YieldCurve) -> Self {
+        self.discount_curve = Some(curve);
+        self
+    }
+
+    /// Set the borrow (repo) curve of the underlying.
+    ///
+    /// # Arguments
+    ///
+    /// * `curve` - The curve of borrow rates, which reduce the growth rate of the underlying.
+    ///
+    /// # Returns
+    ///
+    /// The model with the borrow curve set.
+    pub fn with_borrow_curve(mut self, curve: YieldCurve) -> Self {
+        self.borrow_curve = Some(curve);
+        self
+    }
+
+    /// Set the volatility term structure, which replaces the flat volatility.
+    ///
+    /// # Arguments
+    ///
+    /// * `term_structure` - The term structure of implied volatilities.
+    ///
+    /// # Returns
+    ///
+    /// The model with the volatility term structure set.
+    pub fn with_volatility_term_structure(
+        mut self,
+        term_structure: VolatilityTermStructure,
+    ) -> Self {
+        self.volatility_term_structure = Some(term_structure);
+        self
+    }
+
+    /// Set the implied volatility surface, which replaces the flat volatility and the volatility term structure.
+    ///
+    /// Every option is priced with the implied volatility of the surface at its expiry and strike.
+    ///
+    /// # Arguments
+    ///
+    /// * `surface` - The implied volatility surface.
+    ///
+    /// # Returns
+    ///
+    /// The model with the volatility surface set.
+    pub fn with_vol_surface(mut self, surface: VolSurface) -> Self {
+        self.vol_surface = Some(surface);
+        self
+    }
+
+    /// Set a SABR model as the volatility source, which replaces the flat volatility, the volatility term structure
+    /// and the volatility surface.
+    ///
+    /// Every option is priced with the SABR lognormal volatility at its strike and at the forward to its expiry.
+    ///
+    /// # Arguments
+    ///
+    /// * `sabr` - The SABR model of the underlying's expiry.
+    ///
+    /// # Returns
+    ///
+    /// The model with the SABR volatility set.
+    pub fn with_sabr(mut self, sabr: SabrModel) -> Self {
+        self.sabr = Some(sabr);
+        self
+    }
+
+    /// Get the flat model and option equivalent to the term structures up to the expiry of an option.
+    ///
+    /// The flat model uses the zero rate and the implied volatility to the expiry (at the strike of the option if the
+    /// model has a volatility surface or a SABR model) and the borrow rate to the expiry is
+    /// added to the dividend yield of the option's underlying. Cash dividends are rescaled so that discounting them at
+    /// the flat rate gives their value on the discount curve.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to price.
+    ///
+    /// # Returns
+    ///
+    /// The equivalent flat model and option, or `None` if the model has no term structures.
+    fn flattened<T: Option>(&self, option: &T) -> std::option::Option<(Self, T)> {
+        if self.discount_curve.is_none()
+            && self.borrow_curve.is_none()
+            && self.volatility_term_structure.is_none()
+            && self.vol_surface.is_none()
+            && self.sabr.is_none()
+        {
+            return None;
+        }
+
+        let ttm = option.time_to_maturity();
+        let mut model = Self {
+            risk_free_rate: self
+                .discount_curve
+                .as_ref()
+                .map_or(self.risk_free_rate, |curve| curve.zero_rate(ttm)),
+            volatility: match (&self.vol_surface, &self.volatility_term_structure) {
+                (Some(surface), _) => surface.volatility(ttm, option.strike()),
+                (None, Some(term_structure)) => term_structure.volatility(ttm),
+                (None, None) => self.volatility,
+            },
+            discount_curve: None,
+            borrow_curve: None,
+            volatility_term_structure: None,
+            vol_surface: None,
+            sabr: None,
+            ..self.clone()
+        };
+
+        let mut option = option.clone();
+        let instrument = option.instrument_mut();
+        if let Some(curve) = &self.borrow_curve {
+            instrument.continuous_dividend_yield += curve.zero_rate(ttm);
+        }
+        if let Some(curve) = &self.discount_curve {
+            for dividend in &mut instrument.cash_dividends {
+                dividend.amount *= curve.discount_factor(dividend.ex_date)
+                    * (model.risk_free_rate * dividend.ex_date).exp();
+            }
+        }
+        if let Some(sabr) = &self.sabr {
+            let forward = instrument.escrowed_spot(model.risk_free_rate, ttm)
+                * ((model.risk_free_rate - instrument.continuous_dividend_yield) * ttm).exp();
+            model.volatility = sabr.lognormal_volatility(forward, option.strike(), ttm);
         }
+        Some((model, option))
     }
 
     /// Calculate d1 and d2 for the Black-Scholes formula.
@@ -86,7 +553,7 @@ impl BlackScholesModel {
     fn calculate_d1_d2(&self, instrument: &Instrument, strike: f64, ttm: f64) -> (f64, f64) {
         let sqrt_t = ttm.sqrt();
 
-        let d1 = ((instrument.calculate_adjusted_spot(ttm) / strike).ln()
+        let d1 = ((instrument.escrowed_spot(self.risk_free_rate, ttm) / strike).ln()
             + (self.risk_free_rate - instrument.continuous_dividend_yield
                 + 0.5 * self.volatility.powi(2))
                 * ttm)
@@ -117,282 +584,1419 @@ impl BlackScholesModel {
         normal: &Normal,
     ) -> f64 {
         let (d1, d2) = self.calculate_d1_d2(instrument, strike, ttm);
-        instrument.calculate_adjusted_spot(ttm)
+        instrument.escrowed_spot(self.risk_free_rate, ttm)
             * (-instrument.continuous_dividend_yield * ttm).exp()
             * normal.cdf(d1)
             - strike * (-self.risk_free_rate * ttm).exp() * normal.cdf(d2)
     }
 
-    /// Calculate the price of an European put option using the Black-Scholes formula.
-    ///
-    /// # Arguments
-    ///
-    /// * `instrument` - The instrument to price the put option for.
-    /// * `strike` - The strike price of the option.
-    /// * `ttm` - Time to maturity of the option.
-    /// * `normal` - The standard normal distribution.
-    ///
-    /// # Returns
-    ///
-    /// The price of the put option.
-    pub fn price_euro_put(
-        &self,
-        instrument: &Instrument,
+    /// Calculate the price of an European put option using the Black-Scholes formula.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument` - The instrument to price the put option for.
+    /// * `strike` - The strike price of the option.
+    /// * `ttm` - Time to maturity of the option.
+    /// * `normal` - The standard normal distribution.
+    ///
+    /// # Returns
+    ///
+    /// The price of the put option.
+    pub fn price_euro_put(
+        &self,
+        instrument: &Instrument,
+        strike: f64,
+        ttm: f64,
+        normal: &Normal,
+    ) -> f64 {
+        let (d1, d2) = self.calculate_d1_d2(instrument, strike, ttm);
+        strike * (-self.risk_free_rate * ttm).exp() * normal.cdf(-d2)
+            - instrument.escrowed_spot(self.risk_free_rate, ttm)
+                * (-instrument.continuous_dividend_yield * ttm).exp()
+                * normal.cdf(-d1)
+    }
+
+    /// Calculate the price of a binary cash-or-nothing European option using the Black-Scholes formula.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The binary option to price.
+    /// * `normal` - The standard normal distribution.
+    ///
+    /// # Returns
+    ///
+    /// The price of the binary option.
+    pub fn price_cash_or_nothing<T: Option>(&self, option: &T, normal: &Normal) -> f64 {
+        let (_, d2) = self.calculate_d1_d2(
+            option.instrument(),
+            option.strike(),
+            option.time_to_maturity(),
+        );
+
+        match option.option_type() {
+            OptionType::Call => {
+                (-self.risk_free_rate * option.time_to_maturity()).exp() * normal.cdf(d2)
+            }
+            OptionType::Put => {
+                (-self.risk_free_rate * option.time_to_maturity()).exp() * normal.cdf(-d2)
+            }
+        }
+    }
+
+    /// Calculate the price of a binary asset-or-nothing European option using the Black-Scholes formula.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The binary option to price.
+    /// * `normal` - The standard normal distribution.
+    ///
+    /// # Returns
+    ///
+    /// The price of the binary option.
+    pub fn price_asset_or_nothing<T: Option>(&self, option: &T, normal: &Normal) -> f64 {
+        let (d1, d2) = self.calculate_d1_d2(
+            option.instrument(),
+            option.strike(),
+            option.time_to_maturity(),
+        );
+
+        match option.option_type() {
+            OptionType::Call => {
+                option
+                    .instrument()
+                    .escrowed_spot(self.risk_free_rate, option.time_to_maturity())
+                    * (-option.instrument().continuous_dividend_yield * option.time_to_maturity())
+                        .exp()
+                    * normal.cdf(d1)
+            }
+            OptionType::Put => {
+                option
+                    .instrument()
+                    .escrowed_spot(self.risk_free_rate, option.time_to_maturity())
+                    * (-option.instrument().continuous_dividend_yield * option.time_to_maturity())
+                        .exp()
+                    * normal.cdf(-d1)
+            }
+        }
+    }
+
+    /// Calculate the price of a rainbow call using the Black-Scholes formula.
+    ///
+    /// Options on the average are priced as basket options (see [`Self::price_basket_levy`]), options on the
+    /// maximum or minimum with the Stulz formula (see [`Self::price_rainbow_max_min`]) and all-in-the-money or
+    /// all-out-of-the-money options on the joint distribution of the assets (see [`Self::price_rainbow_all`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to price.
+    /// * `normal` - The standard normal distribution.
+    ///
+    /// # Returns
+    ///
+    /// The price of the option or an error if the option is not a rainbow option or has too many assets.
+    pub fn price_rainbow_call<T: Option>(
+        &self,
+        option: &T,
+        normal: &Normal,
+    ) -> Result<f64, PricingError> {
+        match option.style() {
+            OptionStyle::Rainbow(_) if option.time_to_maturity() <= 0.0 => Ok(option.payoff(None)),
+            OptionStyle::Rainbow(RainbowType::AllITM | RainbowType::AllOTM) => {
+                self.price_rainbow_all(option, normal)
+            }
+            OptionStyle::Rainbow(RainbowType::CallOnAvg | RainbowType::PutOnAvg) => {
+                Ok(self.price_basket_levy(option, normal))
+            }
+            _ => self.price_rainbow_max_min(option, normal),
+        }
+    }
+
+    /// Calculate the price of a rainbow put using the Black-Scholes formula.
+    ///
+    /// Options on the average are priced as basket options (see [`Self::price_basket_levy`]), options on the
+    /// maximum or minimum with the Stulz formula (see [`Self::price_rainbow_max_min`]) and all-in-the-money or
+    /// all-out-of-the-money options on the joint distribution of the assets (see [`Self::price_rainbow_all`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to price.
+    /// * `normal` - The standard normal distribution.
+    ///
+    /// # Returns
+    ///
+    /// The price of the option or an error if the option is not a rainbow option or has too many assets.
+    pub fn price_rainbow_put<T: Option>(
+        &self,
+        option: &T,
+        normal: &Normal,
+    ) -> Result<f64, PricingError> {
+        match option.style() {
+            OptionStyle::Rainbow(_) if option.time_to_maturity() <= 0.0 => Ok(option.payoff(None)),
+            OptionStyle::Rainbow(RainbowType::AllITM | RainbowType::AllOTM) => {
+                self.price_rainbow_all(option, normal)
+            }
+            OptionStyle::Rainbow(RainbowType::CallOnAvg | RainbowType::PutOnAvg) => {
+                Ok(self.price_basket_levy(option, normal))
+            }
+            _ => self.price_rainbow_max_min(option, normal),
+        }
+    }
+
+    /// Calculate the price of a rainbow option on the maximum or minimum of (at most) two assets using the Stulz formula.
+    ///
+    /// Best-of and worst-of options are replicated with calls and puts on the maximum or minimum and the strike as
+    /// cash, i.e. `max(S₁, S₂, K) = K + max(max(S₁, S₂) - K, 0)` and `min(S₁, S₂, K) = K - max(K - min(S₁, S₂), 0)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to price.
+    /// * `normal` - The standard normal distribution.
+    ///
+    /// # Returns
+    ///
+    /// The price of the option or an error if the option is not a rainbow option on the maximum or minimum of at most
+    /// two assets.
+    pub fn price_rainbow_max_min<T: Option>(
+        &self,
+        option: &T,
+        normal: &Normal,
+    ) -> Result<f64, PricingError> {
+        let rainbow_type = match *option.style() {
+            OptionStyle::Rainbow(
+                rainbow_type @ (RainbowType::CallOnMax
+                | RainbowType::CallOnMin
+                | RainbowType::PutOnMax
+                | RainbowType::PutOnMin
+                | RainbowType::BestOf
+                | RainbowType::WorstOf),
+            ) => rainbow_type,
+            style => return Err(PricingError::unsupported_style("BlackScholesModel", style)),
+        };
+        let ttm = option.time_to_maturity();
+        if ttm <= 0.0 {
+            return Ok(option.payoff(None));
+        }
+        let strike = option.strike();
+        let discount = (-self.risk_free_rate * ttm).exp();
+        let AssetComponents {
+            forwards,
+            volatilities,
+            correlation,
+            ..
+        } = self.asset_components(option);
+
+        // Value of a call on a single asset and of the asset itself
+        let single = |i: usize| {
+            let call = discount
+                * Self::black_formula(
+                    forwards[i],
+                    strike,
+                    volatilities[i] * ttm.sqrt(),
+                    OptionType::Call,
+                    normal,
+                );
+            (call, discount * forwards[i])
+        };
+
+        // Values of a call on the maximum (minimum) and of the maximum (minimum) itself
+        let ((call_on_max, max), (call_on_min, min)) = match forwards.len() {
+            1 => (single(0), single(0)),
+            2 => {
+                let (f1, f2) = (forwards[0], forwards[1]);
+                let (v1, v2) = (volatilities[0] * ttm.sqrt(), volatilities[1] * ttm.sqrt());
+                let rho = correlation[0][1];
+                let v = (v1 * v1 + v2 * v2 - 2.0 * rho * v1 * v2).max(0.0).sqrt();
+                if v <= 1e-12 {
+                    // Without spread volatility the ratio of the assets is deterministic, so the maximum (minimum)
+                    // is always the asset with the higher (lower) forward
+                    let (high, low) = if f1 >= f2 { (0, 1) } else { (1, 0) };
+                    return Ok(Self::max_min_payoff(
+                        rainbow_type,
+                        strike,
+                        discount,
+                        single(high),
+                        single(low),
+                    ));
+                }
+                let rho1 = (v1 - rho * v2) / v;
+                let rho2 = (v2 - rho * v1) / v;
+
+                let d = ((f1 / f2).ln() + 0.5 * v * v) / v;
+                let y1 = ((f1 / strike).ln() + 0.5 * v1 * v1) / v1;
+                let y2 = ((f2 / strike).ln() + 0.5 * v2 * v2) / v2;
+                let m = numerics::bivariate_normal_cdf;
+
+                let call_on_max = discount
+                    * (f1 * m(y1, d, rho1) + f2 * m(y2, v - d, rho2)
+                        - strike * (1.0 - m(v1 - y1, v2 - y2, rho)));
+                let call_on_min = discount
+                    * (f1 * m(y1, -d, -rho1) + f2 * m(y2, d - v, -rho2)
+                        - strike * m(y1 - v1, y2 - v2, rho));
+                let max = discount * (f1 * normal.cdf(d) + f2 * normal.cdf(v - d));
+                let min = discount * (f1 * normal.cdf(-d) + f2 * normal.cdf(d - v));
+                ((call_on_max, max), (call_on_min, min))
+            }
+            _ => {
+                return Err(PricingError::invalid_input(
+                    "Black-Scholes rainbow options on the maximum or minimum support at most two assets",
+                ));
+            }
+        };
+
+        Ok(Self::max_min_payoff(
+            rainbow_type,
+            strike,
+            discount,
+            (call_on_max, max),
+            (call_on_min, min),
+        ))
+    }
+
+    /// Combine the values of a call on the maximum (minimum) and of the maximum (minimum) into a rainbow price.
+    fn max_min_payoff(
+        rainbow_type: RainbowType,
+        strike: f64,
+        discount: f64,
+        (call_on_max, max): (f64, f64),
+        (call_on_min, min): (f64, f64),
+    ) -> f64 {
+        match rainbow_type {
+            RainbowType::CallOnMax => call_on_max,
+            RainbowType::CallOnMin => call_on_min,
+            RainbowType::PutOnMax => strike * discount - max + call_on_max,
+            RainbowType::PutOnMin => strike * discount - min + call_on_min,
+            RainbowType::BestOf => strike * discount + call_on_max,
+            RainbowType::WorstOf => min - call_on_min,
+            _ => unreachable!("Only called for rainbow options on the maximum or minimum"),
+        }
+    }
+
+    /// Calculate the price of an all-in-the-money or all-out-of-the-money rainbow option on (at most) two assets.
+    ///
+    /// The option pays the weighted average of the assets if all of them finish above (below) the strike. Under the
+    /// measure with asset `i` as numéraire, the log-price of asset `j` is shifted by `ρᵢⱼ σᵢ σⱼ T`, so each term of
+    /// the average is `e^(-rT) wᵢ Fᵢ M(±d₁⁽ⁱ⁾, ±d₂⁽ⁱ⁾, ρ)` with `dⱼ⁽ⁱ⁾ = (ln(Fⱼ / K) + (ρᵢⱼ σᵢ - σⱼ / 2) σⱼ T) / (σⱼ √T)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to price.
+    /// * `normal` - The standard normal distribution.
+    ///
+    /// # Returns
+    ///
+    /// The price of the option or an error if the option is not an all-in-the-money or all-out-of-the-money rainbow
+    /// option on at most two assets.
+    pub fn price_rainbow_all<T: Option>(
+        &self,
+        option: &T,
+        normal: &Normal,
+    ) -> Result<f64, PricingError> {
+        let sign = match *option.style() {
+            OptionStyle::Rainbow(RainbowType::AllITM) => 1.0,
+            OptionStyle::Rainbow(RainbowType::AllOTM) => -1.0,
+            style => return Err(PricingError::unsupported_style("BlackScholesModel", style)),
+        };
+        if option.instrument().assets.len() > 2 {
+            return Err(PricingError::invalid_input(
+                "Black-Scholes all-in-the-money and all-out-of-the-money options support at most two assets",
+            ));
+        }
+        let ttm = option.time_to_maturity();
+        if ttm <= 0.0 {
+            return Ok(option.payoff(None));
+        }
+        let strike = option.strike();
+        let discount = (-self.risk_free_rate * ttm).exp();
+        let AssetComponents {
+            weights,
+            forwards,
+            volatilities,
+            correlation,
+        } = self.asset_components(option);
+
+        // Probability of all assets finishing on the paying side of the strike with asset `i` as numéraire
+        let d = |i: usize, j: usize| {
+            ((forwards[j] / strike).ln()
+                + (correlation[i][j] * volatilities[i] - 0.5 * volatilities[j])
+                    * volatilities[j]
+                    * ttm)
+                / (volatilities[j] * ttm.sqrt())
+        };
+        let probability = |i: usize| match forwards.len() {
+            1 => normal.cdf(sign * d(i, 0)),
+            _ => numerics::bivariate_normal_cdf(sign * d(i, 0), sign * d(i, 1), correlation[0][1]),
+        };
+
+        Ok((0..forwards.len())
+            .map(|i| discount * weights[i] * forwards[i] * probability(i))
+            .sum())
+    }
+
+    /// Calculate the price of a lookback option using the Black-Scholes formula.
+    ///
+    /// Options looking back over their whole life are priced with the Goldman-Sosin-Gatto (floating strike) and
+    /// Conze-Viswanathan (fixed strike) formulas, partial-time options with the Heynen-Kat formulas. The extremum
+    /// starts from the spot and the running extremum of a seasoned option (unless the lookback period of a fixed strike
+    /// option has not started yet). Discretely monitored extrema are priced with the Broadie-Glasserman-Kou correction,
+    /// shifting the continuous extremum by `e^(±0.5826 σ √Δt)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to price.
+    /// * `normal` - The standard normal distribution.
+    ///
+    /// # Returns
+    ///
+    /// The price of the option or an error if a spot price or observed extremum is not positive, the partial time
+    /// is negative or the instrument pays cash dividends.
+    pub fn price_lookback<T: Option>(
+        &self,
+        option: &T,
+        normal: &Normal,
+    ) -> Result<f64, PricingError> {
+        let OptionStyle::Lookback(permutation) = *option.style() else {
+            return Err(PricingError::unsupported_style(
+                "BlackScholesModel",
+                *option.style(),
+            ));
+        };
+        let lookback = option.lookback().copied().unwrap_or_default();
+        let instrument = option.instrument();
+        let (max, min) = (lookback.max_spot(instrument), lookback.min_spot(instrument));
+        if instrument.spot() <= 0.0 || min <= 0.0 {
+            return Err(PricingError::invalid_input("Spot prices must be > 0"));
+        }
+        if !instrument.cash_dividends.is_empty() {
+            return Err(PricingError::not_implemented(
+                "Lookback pricing with cash dividends",
+            ));
+        }
+        if lookback
+            .partial_time
+            .is_some_and(|time| !time.is_finite() || time < 0.0)
+        {
+            return Err(PricingError::invalid_input(
+                "The partial time of a lookback option must be >= 0",
+            ));
+        }
+
+        let ttm = option.time_to_maturity();
+        let strike = option.strike();
+        let (start, end) = lookback.period(permutation, ttm);
+        let shift = match lookback.monitoring {
+            Monitoring::Continuous => 0.0,
+            // β = -ζ(1/2) / √(2π)
+            Monitoring::Discrete(dates) => {
+                0.5826 * self.volatility * ((end - start) / dates.max(1) as f64).sqrt()
+            }
+        };
+        let carry = self.risk_free_rate - instrument.continuous_dividend_yield;
+        let formula = LookbackFormula {
+            spot: instrument.spot(),
+            rate: self.risk_free_rate,
+            carry: if carry.abs() < LookbackFormula::MIN_CARRY {
+                LookbackFormula::MIN_CARRY
+            } else {
+                carry
+            },
+            volatility: self.volatility,
+            ttm,
+            normal,
+        };
+        let forward = instrument.spot() * (-instrument.continuous_dividend_yield * ttm).exp();
+        let discount = (-self.risk_free_rate * ttm).exp();
+        let (up, down) = (shift.exp(), (-shift).exp());
+
+        Ok(match (permutation, option.option_type()) {
+            // The extremum is fixed before the spot moves
+            (Permutation::Floating, OptionType::Call) if end <= 0.0 => {
+                self.price_euro_call(instrument, min, ttm, normal)
+            }
+            (Permutation::Floating, OptionType::Put) if end <= 0.0 => {
+                self.price_euro_put(instrument, max, ttm, normal)
+            }
+            (Permutation::Fixed, OptionType::Call) if start >= ttm => {
+                self.price_euro_call(instrument, strike, ttm, normal)
+            }
+            (Permutation::Fixed, OptionType::Put) if start >= ttm => {
+                self.price_euro_put(instrument, strike, ttm, normal)
+            }
+            // Partial-time lookback periods
+            (Permutation::Floating, OptionType::Call) if end < ttm => {
+                formula.partial_floating(min * down, up, end, 1.0)
+            }
+            (Permutation::Floating, OptionType::Put) if end < ttm => {
+                formula.partial_floating(max * up, down, end, -1.0)
+            }
+            (Permutation::Fixed, OptionType::Call) if start > 0.0 => {
+                down * formula.partial_fixed(strike * up, start, 1.0)
+            }
+            (Permutation::Fixed, OptionType::Put) if start > 0.0 => {
+                up * formula.partial_fixed(strike * down, start, -1.0)
+            }
+            // Lookback periods until maturity
+            (Permutation::Floating, OptionType::Call) => {
+                forward - up * formula.expected_extremum(min * down, -1.0)
+            }
+            (Permutation::Floating, OptionType::Put) => {
+                down * formula.expected_extremum(max * up, 1.0) - forward
+            }
+            (Permutation::Fixed, OptionType::Call) => {
+                down * formula.expected_extremum(max.max(strike) * up, 1.0) - strike * discount
+            }
+            (Permutation::Fixed, OptionType::Put) => {
+                strike * discount - up * formula.expected_extremum(min.min(strike) * down, -1.0)
+            }
+        })
+    }
+
+    /// Collect the weights, forwards, volatilities and correlation matrix of a multi-asset option's assets.
+    ///
+    /// An instrument without assets is treated as a single asset with weight one.
+    fn asset_components<T: Option>(&self, option: &T) -> AssetComponents {
+        let instrument = option.instrument();
+        let ttm = option.time_to_maturity();
+        let assets: Vec<(&Instrument, f64)> = if instrument.assets.is_empty() {
+            vec![(instrument, 1.0)]
+        } else {
+            instrument.assets.iter().map(|(a, w)| (a, *w)).collect()
+        };
+
+        AssetComponents {
+            weights: assets.iter().map(|(_, weight)| *weight).collect(),
+            forwards: assets
+                .iter()
+                .map(|(asset, _)| {
+                    asset.escrowed_spot(self.risk_free_rate, ttm)
+                        * ((self.risk_free_rate - asset.continuous_dividend_yield) * ttm).exp()
+                })
+                .collect(),
+            volatilities: assets
+                .iter()
+                .map(|(asset, _)| asset.volatility.unwrap_or(self.volatility))
+                .collect(),
+            correlation: instrument.correlation_matrix(),
+        }
+    }
+
+    /// Calculate the undiscounted Black price of an option on a log-normal forward.
+    fn black_formula(
+        forward: f64,
+        strike: f64,
+        total_volatility: f64,
+        option_type: OptionType,
+        normal: &Normal,
+    ) -> f64 {
+        let d1 = ((forward / strike).ln() + 0.5 * total_volatility.powi(2)) / total_volatility;
+        let d2 = d1 - total_volatility;
+        match option_type {
+            OptionType::Call => forward * normal.cdf(d1) - strike * normal.cdf(d2),
+            OptionType::Put => strike * normal.cdf(-d2) - forward * normal.cdf(-d1),
+        }
+    }
+
+    /// Calculate the price of a basket option using Levy's moment-matching approximation.
+    ///
+    /// The basket value at maturity is approximated by a log-normal variable with the same first two moments. At expiry
+    /// the option is worth its payoff.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to price.
+    /// * `normal` - The standard normal distribution.
+    ///
+    /// # Returns
+    ///
+    /// The price of the option.
+    pub fn price_basket_levy<T: Option>(&self, option: &T, normal: &Normal) -> f64 {
+        let ttm = option.time_to_maturity();
+        if ttm <= 0.0 {
+            return option.payoff(None);
+        }
+        let AssetComponents {
+            weights,
+            forwards,
+            volatilities,
+            correlation,
+        } = self.asset_components(option);
+        let forwards: Vec<f64> = forwards.iter().zip(&weights).map(|(f, w)| f * w).collect();
+
+        let first_moment: f64 = forwards.iter().sum();
+        let mut second_moment = 0.0;
+        for i in 0..forwards.len() {
+            for j in 0..forwards.len() {
+                second_moment += forwards[i]
+                    * forwards[j]
+                    * (correlation[i][j] * volatilities[i] * volatilities[j] * ttm).exp();
+            }
+        }
+        let total_volatility = (second_moment / first_moment.powi(2)).ln().max(0.0).sqrt();
+
+        (-self.risk_free_rate * ttm).exp()
+            * Self::black_formula_or_intrinsic(
+                first_moment,
+                option.strike(),
+                total_volatility,
+                option.option_type(),
+                normal,
+            )
+    }
+
+    /// Calculate the price of a basket option using Gentle's geometric approximation.
+    ///
+    /// The arithmetic basket is replaced by a geometric basket with forward-weighted exponents and the strike is
+    /// shifted by the difference of their expected values.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to price.
+    /// * `normal` - The standard normal distribution.
+    ///
+    /// # Returns
+    ///
+    /// The price of the option.
+    pub fn price_basket_gentle<T: Option>(&self, option: &T, normal: &Normal) -> f64 {
+        let ttm = option.time_to_maturity();
+        if ttm <= 0.0 {
+            return option.payoff(None);
+        }
+        let discount = (-self.risk_free_rate * ttm).exp();
+        let AssetComponents {
+            weights,
+            forwards,
+            volatilities,
+            correlation,
+        } = self.asset_components(option);
+        let forwards: Vec<f64> = forwards.iter().zip(&weights).map(|(f, w)| f * w).collect();
+
+        let first_moment: f64 = forwards.iter().sum();
+        let shares: Vec<f64> = forwards.iter().map(|f| f / first_moment).collect();
+        let mut geometric_variance = 0.0;
+        for i in 0..shares.len() {
+            for j in 0..shares.len() {
+                geometric_variance +=
+                    shares[i] * shares[j] * correlation[i][j] * volatilities[i] * volatilities[j];
+            }
+        }
+        let convexity: f64 = shares
+            .iter()
+            .zip(&volatilities)
+            .map(|(a, sigma)| a * sigma.powi(2))
+            .sum();
+        let geometric_forward = first_moment * (0.5 * (geometric_variance - convexity) * ttm).exp();
+        let adjusted_strike = option.strike() - (first_moment - geometric_forward);
+
+        if adjusted_strike <= 0.0 {
+            return match option.option_type() {
+                OptionType::Call => discount * (first_moment - option.strike()),
+                OptionType::Put => 0.0,
+            };
+        }
+
+        discount
+            * Self::black_formula_or_intrinsic(
+                geometric_forward,
+                adjusted_strike,
+                (geometric_variance.max(0.0) * ttm).sqrt(),
+                option.option_type(),
+                normal,
+            )
+    }
+
+    /// Calculate the price of a fixed strike Asian option.
+    ///
+    /// Options on the geometric average are priced exactly (Kemna-Vorst), options on the arithmetic average with the
+    /// Turnbull-Wakeman or Curran approximation. The average runs over the option's averaging schedule, including the
+    /// prices already fixed, or continuously until maturity if the option has no schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The Asian option to price.
+    /// * `normal` - The standard normal distribution.
+    ///
+    /// # Returns
+    ///
+    /// The price of the option or an error if the averaging schedule is invalid.
+    pub fn price_asian<T: Option>(&self, option: &T, normal: &Normal) -> Result<f64, PricingError> {
+        let ttm = option.time_to_maturity();
+        let averaging = match option.averaging_schedule() {
+            Some(schedule) => Averaging::from_schedule(schedule, ttm)?,
+            None => Averaging::continuous(ttm),
+        };
+        let spot = option.instrument().escrowed_spot(self.risk_free_rate, ttm);
+
+        Ok(match self.asian_approximation {
+            AsianApproximation::KemnaVorst => {
+                self.price_geometric_asian(option, spot, &averaging, normal)
+            }
+            AsianApproximation::TurnbullWakeman => {
+                self.price_turnbull_wakeman(option, spot, &averaging, normal)
+            }
+            AsianApproximation::Curran => self.price_curran(option, spot, &averaging, normal),
+        })
+    }
+
+    /// Calculate the price of a fixed strike option on the geometric average.
+    ///
+    /// The logarithm of the geometric average is normally distributed, so the option is priced with the Black formula.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The Asian option to price.
+    /// * `spot` - The spot price of the underlying asset.
+    /// * `averaging` - The observations of the average.
+    /// * `normal` - The standard normal distribution.
+    ///
+    /// # Returns
+    ///
+    /// The price of the option.
+    pub(crate) fn price_geometric_asian<T: Option>(
+        &self,
+        option: &T,
+        spot: f64,
+        averaging: &Averaging,
+        normal: &Normal,
+    ) -> f64 {
+        let ttm = option.time_to_maturity();
+        let drift = self.risk_free_rate
+            - option.instrument().continuous_dividend_yield
+            - 0.5 * self.volatility.powi(2);
+        let mean = averaging.log_fixed
+            + averaging
+                .times
+                .iter()
+                .zip(&averaging.weights)
+                .map(|(time, weight)| weight * (spot.ln() + drift * time))
+                .sum::<f64>();
+        let variance = self.volatility.powi(2) * Self::covariance_sum(averaging);
+        let forward = (mean + 0.5 * variance).exp();
+
+        (-self.risk_free_rate * ttm).exp()
+            * Self::black_formula_or_intrinsic(
+                forward,
+                option.strike(),
+                variance.sqrt(),
+                option.option_type(),
+                normal,
+            )
+    }
+
+    /// Calculate the price of a fixed strike option on the arithmetic average with the Turnbull-Wakeman approximation.
+    ///
+    /// The remaining part of the average is approximated by a log-normal variable with the same first two moments and
+    /// the strike is reduced by the part already fixed.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The Asian option to price.
+    /// * `spot` - The spot price of the underlying asset.
+    /// * `averaging` - The observations of the average.
+    /// * `normal` - The standard normal distribution.
+    ///
+    /// # Returns
+    ///
+    /// The price of the option.
+    fn price_turnbull_wakeman<T: Option>(
+        &self,
+        option: &T,
+        spot: f64,
+        averaging: &Averaging,
+        normal: &Normal,
+    ) -> f64 {
+        let ttm = option.time_to_maturity();
+        let discount = (-self.risk_free_rate * ttm).exp();
+        let forwards = self.average_forwards(option, spot, averaging);
+        let first_moment: f64 = forwards.iter().sum();
+        let strike = option.strike() - averaging.fixed;
+        if strike <= 0.0 {
+            return Self::exercised_asian(option, discount, first_moment, strike);
+        }
+
+        let mut second_moment = 0.0;
+        for (i, (forward_i, time_i)) in forwards.iter().zip(&averaging.times).enumerate() {
+            for (forward_j, time_j) in forwards.iter().zip(&averaging.times).skip(i) {
+                let product =
+                    forward_i * forward_j * (self.volatility.powi(2) * time_i.min(*time_j)).exp();
+                second_moment += if time_i == time_j {
+                    product
+                } else {
+                    2.0 * product
+                };
+            }
+        }
+        let total_volatility = (second_moment / first_moment.powi(2)).ln().max(0.0).sqrt();
+
+        discount
+            * Self::black_formula_or_intrinsic(
+                first_moment,
+                strike,
+                total_volatility,
+                option.option_type(),
+                normal,
+            )
+    }
+
+    /// Calculate the price of a fixed strike option on the arithmetic average with Curran's approximation.
+    ///
+    /// The call is split on the event that the geometric average of the remaining observations exceeds a critical
+    /// level approximating the exercise boundary. Given the geometric average, the observations are jointly log-normal,
+    /// so the expected payoff is available in closed form. Puts follow from put-call parity.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The Asian option to price.
+    /// * `spot` - The spot price of the underlying asset.
+    /// * `averaging` - The observations of the average.
+    /// * `normal` - The standard normal distribution.
+    ///
+    /// # Returns
+    ///
+    /// The price of the option.
+    fn price_curran<T: Option>(
+        &self,
+        option: &T,
+        spot: f64,
+        averaging: &Averaging,
+        normal: &Normal,
+    ) -> f64 {
+        let ttm = option.time_to_maturity();
+        let discount = (-self.risk_free_rate * ttm).exp();
+        let forwards = self.average_forwards(option, spot, averaging);
+        let expected_average: f64 = forwards.iter().sum();
+        let strike = option.strike() - averaging.fixed;
+        let remaining_weight: f64 = averaging.weights.iter().sum();
+        if strike <= 0.0 || remaining_weight <= 0.0 {
+            return Self::exercised_asian(option, discount, expected_average, strike);
+        }
+
+        // Log-normal observations x_i and their normalised geometric average G
+        let drift = self.risk_free_rate
+            - option.instrument().continuous_dividend_yield
+            - 0.5 * self.volatility.powi(2);
+        let variance = self.volatility.powi(2);
+        let normalised: Vec<f64> = averaging
+            .weights
+            .iter()
+            .map(|weight| weight / remaining_weight)
+            .collect();
+        let means: Vec<f64> = averaging
+            .times
+            .iter()
+            .map(|time| spot.ln() + drift * time)
+            .collect();
+        let covariances: Vec<f64> = averaging
+            .times
+            .iter()
+            .map(|time_i| {
+                variance
+                    * averaging
+                        .times
+                        .iter()
+                        .zip(&normalised)
+                        .map(|(time_j, weight)| weight * time_i.min(*time_j))
+                        .sum::<f64>()
+            })
+            .collect();
+        let geometric_mean: f64 = normalised.iter().zip(&means).map(|(w, m)| w * m).sum();
+        let geometric_variance: f64 = normalised
+            .iter()
+            .zip(&covariances)
+            .map(|(w, c)| w * c)
+            .sum();
+        let strike = strike / remaining_weight;
+        let expected_average = expected_average / remaining_weight;
+        let parity = discount * remaining_weight * (expected_average - strike);
+        if geometric_variance <= 0.0 {
+            let call = discount * remaining_weight * (expected_average - strike).max(0.0);
+            return match option.option_type() {
+                OptionType::Call => call,
+                OptionType::Put => call - parity,
+            };
+        }
+
+        let geometric_std_dev = geometric_variance.sqrt();
+        let critical_level = 2.0 * strike
+            - (0..averaging.times.len())
+                .map(|i| {
+                    normalised[i]
+                        * (means[i]
+                            + covariances[i] / geometric_variance * (strike.ln() - geometric_mean)
+                            + 0.5
+                                * (variance * averaging.times[i]
+                                    - covariances[i].powi(2) / geometric_variance))
+                            .exp()
+                })
+                .sum::<f64>();
+        let call = if critical_level <= 0.0 {
+            parity
+        } else {
+            let d = (geometric_mean - critical_level.ln()) / geometric_std_dev;
+            discount
+                * remaining_weight
+                * ((0..averaging.times.len())
+                    .map(|i| {
+                        normalised[i]
+                            * (means[i] + 0.5 * variance * averaging.times[i]).exp()
+                            * normal.cdf(d + covariances[i] / geometric_std_dev)
+                    })
+                    .sum::<f64>()
+                    - strike * normal.cdf(d))
+        };
+        match option.option_type() {
+            OptionType::Call => call,
+            OptionType::Put => call - parity,
+        }
+    }
+
+    /// Calculate the forwards of the remaining observations of an average, scaled by their weights.
+    fn average_forwards<T: Option>(
+        &self,
+        option: &T,
+        spot: f64,
+        averaging: &Averaging,
+    ) -> Vec<f64> {
+        let growth = self.risk_free_rate - option.instrument().continuous_dividend_yield;
+        averaging
+            .times
+            .iter()
+            .zip(&averaging.weights)
+            .map(|(time, weight)| weight * spot * (growth * time).exp())
+            .collect()
+    }
+
+    /// Calculate `Σ_i Σ_j w_i w_j min(t_i, t_j)`, the variance of the weighted sum of the remaining observations of a
+    /// standard Brownian motion.
+    fn covariance_sum(averaging: &Averaging) -> f64 {
+        averaging
+            .times
+            .iter()
+            .zip(&averaging.weights)
+            .map(|(time_i, weight_i)| {
+                weight_i
+                    * averaging
+                        .times
+                        .iter()
+                        .zip(&averaging.weights)
+                        .map(|(time_j, weight_j)| weight_j * time_i.min(*time_j))
+                        .sum::<f64>()
+            })
+            .sum()
+    }
+
+    /// Calculate the price of an Asian option whose remaining strike is not positive, i.e. a call certainly exercised
+    /// (worth the discounted expected average less the strike) or a worthless put.
+    fn exercised_asian<T: Option>(
+        option: &T,
+        discount: f64,
+        expected_average: f64,
+        strike: f64,
+    ) -> f64 {
+        match option.option_type() {
+            OptionType::Call => discount * (expected_average - strike),
+            OptionType::Put => 0.0,
+        }
+    }
+
+    /// Calculate the undiscounted Black price, or the intrinsic value of the forward without volatility.
+    fn black_formula_or_intrinsic(
+        forward: f64,
         strike: f64,
-        ttm: f64,
+        total_volatility: f64,
+        option_type: OptionType,
         normal: &Normal,
     ) -> f64 {
-        let (d1, d2) = self.calculate_d1_d2(instrument, strike, ttm);
-        strike * (-self.risk_free_rate * ttm).exp() * normal.cdf(-d2)
-            - instrument.calculate_adjusted_spot(ttm)
-                * (-instrument.continuous_dividend_yield * ttm).exp()
-                * normal.cdf(-d1)
+        if total_volatility > 0.0 {
+            return Self::black_formula(forward, strike, total_volatility, option_type, normal);
+        }
+        match option_type {
+            OptionType::Call => (forward - strike).max(0.0),
+            OptionType::Put => (strike - forward).max(0.0),
+        }
     }
 
-    /// Calculate the price of a binary cash-or-nothing European option using the Black-Scholes formula.
+    /// Calculate the price of a European option ignoring any barrier.
     ///
     /// # Arguments
     ///
-    /// * `option` - The binary option to price.
+    /// * `option` - The option to price.
     /// * `normal` - The standard normal distribution.
     ///
     /// # Returns
     ///
-    /// The price of the binary option.
-    pub fn price_cash_or_nothing<T: Option>(&self, option: &T, normal: &Normal) -> f64 {
-        let (_, d2) = self.calculate_d1_d2(
-            option.instrument(),
-            option.strike(),
-            option.time_to_maturity(),
-        );
-
+    /// The price of the vanilla option.
+    fn price_vanilla<T: Option>(&self, option: &T, normal: &Normal) -> f64 {
         match option.option_type() {
-            OptionType::Call => {
-                (-self.risk_free_rate * option.time_to_maturity()).exp() * normal.cdf(d2)
-            }
-            OptionType::Put => {
-                (-self.risk_free_rate * option.time_to_maturity()).exp() * normal.cdf(-d2)
-            }
+            OptionType::Call => self.price_euro_call(
+                option.instrument(),
+                option.strike(),
+                option.time_to_maturity(),
+                normal,
+            ),
+            OptionType::Put => self.price_euro_put(
+                option.instrument(),
+                option.strike(),
+                option.time_to_maturity(),
+                normal,
+            ),
         }
     }
 
-    /// Calculate the price of a binary asset-or-nothing European option using the Black-Scholes formula.
+    /// Shift a discretely monitored barrier away from the spot using the Broadie-Glasserman-Kou correction.
     ///
     /// # Arguments
     ///
-    /// * `option` - The binary option to price.
-    /// * `normal` - The standard normal distribution.
+    /// * `level` - The barrier level.
+    /// * `down` - Whether the barrier lies below the spot price.
+    /// * `monitoring` - The monitoring frequency of the barrier.
+    /// * `ttm` - Time to maturity of the option.
     ///
     /// # Returns
     ///
-    /// The price of the binary option.
-    pub fn price_asset_or_nothing<T: Option>(&self, option: &T, normal: &Normal) -> f64 {
-        let (d1, d2) = self.calculate_d1_d2(
-            option.instrument(),
-            option.strike(),
-            option.time_to_maturity(),
-        );
-
-        match option.option_type() {
-            OptionType::Call => {
-                option.instrument().spot()
-                    * (-option.instrument().continuous_dividend_yield * option.time_to_maturity())
-                        .exp()
-                    * normal.cdf(d1)
-            }
-            OptionType::Put => {
-                option.instrument().spot()
-                    * (-option.instrument().continuous_dividend_yield * option.time_to_maturity())
-                        .exp()
-                    * normal.cdf(-d1)
+    /// The continuously monitored barrier level equivalent to the monitored barrier.
+    fn adjusted_barrier(&self, level: f64, down: bool, monitoring: Monitoring, ttm: f64) -> f64 {
+        match monitoring {
+            Monitoring::Continuous => level,
+            Monitoring::Discrete(dates) => {
+                // β = -ζ(1/2) / √(2π)
+                let shift = 0.5826 * self.volatility * (ttm / dates.max(1) as f64).sqrt();
+                if down {
+                    level * (-shift).exp()
+                } else {
+                    level * shift.exp()
+                }
             }
         }
     }
 
-    /// Calculate the price of a rainbow call using the Black-Scholes formula.
+    /// Calculate the price of a single barrier option using the Reiner-Rubinstein formulas.
     ///
     /// # Arguments
     ///
-    /// * `option` - The option to price.
+    /// * `option` - The barrier option to price.
     /// * `normal` - The standard normal distribution.
     ///
     /// # Returns
     ///
-    /// The price of the option.
-    pub fn price_rainbow_call<T: Option>(&self, option: &T, normal: &Normal) -> f64 {
-        if matches!(option.style(), OptionStyle::Rainbow(RainbowType::AllITM))
-            && option.payoff(None) <= 0.0
-        {
-            return 0.0;
-        }
-        let price = self.price_euro_call(
-            option.instrument(),
-            option.strike(),
-            option.time_to_maturity(),
-            normal,
-        );
+    /// The price of the option or an error if the option is not a barrier option or has no barrier.
+    pub fn price_barrier<T: Option>(
+        &self,
+        option: &T,
+        normal: &Normal,
+    ) -> Result<f64, PricingError> {
+        let OptionStyle::Barrier(barrier_type) = option.style() else {
+            return Err(PricingError::unsupported_style(
+                "BlackScholesModel",
+                *option.style(),
+            ));
+        };
+        let Some(barrier) = option.barrier() else {
+            return Err(PricingError::invalid_input(
+                "Barrier options must provide a barrier",
+            ));
+        };
+        let t = option.time_to_maturity();
+        let s = option.instrument().escrowed_spot(self.risk_free_rate, t);
+        let k = option.strike();
+        let r = self.risk_free_rate;
+        let carry = r - option.instrument().continuous_dividend_yield;
+        let vol = self.volatility;
+        let rebate = barrier.rebate;
 
-        if matches!(option.style(), OptionStyle::Rainbow(RainbowType::BestOf))
-            || matches!(option.style(), OptionStyle::Rainbow(RainbowType::WorstOf))
-        {
-            panic!("BestOf/WorstOf options not supported by Black-Scholes model");
+        if barrier.is_breached(*barrier_type, s) {
+            return Ok(if barrier_type.is_knock_in() {
+                self.price_vanilla(option, normal)
+            } else {
+                rebate
+            });
         }
-        price
+
+        let h = self.adjusted_barrier(barrier.level, barrier_type.is_down(), barrier.monitoring, t);
+        let phi = match option.option_type() {
+            OptionType::Call => 1.0,
+            OptionType::Put => -1.0,
+        };
+        let eta = if barrier_type.is_down() { 1.0 } else { -1.0 };
+
+        let vol_t = vol * t.sqrt();
+        let mu = (carry - 0.5 * vol * vol) / (vol * vol);
+        let lambda = (mu * mu + 2.0 * r / (vol * vol)).sqrt();
+        let x1 = (s / k).ln() / vol_t + (1.0 + mu) * vol_t;
+        let x2 = (s / h).ln() / vol_t + (1.0 + mu) * vol_t;
+        let y1 = (h * h / (s * k)).ln() / vol_t + (1.0 + mu) * vol_t;
+        let y2 = (h / s).ln() / vol_t + (1.0 + mu) * vol_t;
+        let z = (h / s).ln() / vol_t + lambda * vol_t;
+
+        let n = |x: f64| normal.cdf(x);
+        let dividend_discount = ((carry - r) * t).exp();
+        let discount = (-r * t).exp();
+        let ratio = h / s;
+
+        let a = phi * s * dividend_discount * n(phi * x1)
+            - phi * k * discount * n(phi * x1 - phi * vol_t);
+        let b = phi * s * dividend_discount * n(phi * x2)
+            - phi * k * discount * n(phi * x2 - phi * vol_t);
+        let c = phi * s * dividend_discount * ratio.powf(2.0 * (mu + 1.0)) * n(eta * y1)
+            - phi * k * discount * ratio.powf(2.0 * mu) * n(eta * y1 - eta * vol_t);
+        let d = phi * s * dividend_discount * ratio.powf(2.0 * (mu + 1.0)) * n(eta * y2)
+            - phi * k * discount * ratio.powf(2.0 * mu) * n(eta * y2 - eta * vol_t);
+        let e = rebate
+            * discount
+            * (n(eta * x2 - eta * vol_t) - ratio.powf(2.0 * mu) * n(eta * y2 - eta * vol_t));
+        let f = rebate
+            * (ratio.powf(mu + lambda) * n(eta * z)
+                + ratio.powf(mu - lambda) * n(eta * z - 2.0 * eta * lambda * vol_t));
+
+        let above = k > h;
+        Ok(match (barrier_type, option.option_type()) {
+            (BarrierType::DownAndIn, OptionType::Call) if above => c + e,
+            (BarrierType::DownAndIn, OptionType::Call) => a - b + d + e,
+            (BarrierType::UpAndIn, OptionType::Call) if above => a + e,
+            (BarrierType::UpAndIn, OptionType::Call) => b - c + d + e,
+            (BarrierType::DownAndIn, OptionType::Put) if above => b - c + d + e,
+            (BarrierType::DownAndIn, OptionType::Put) => a + e,
+            (BarrierType::UpAndIn, OptionType::Put) if above => a - b + d + e,
+            (BarrierType::UpAndIn, OptionType::Put) => c + e,
+            (BarrierType::DownAndOut, OptionType::Call) if above => a - c + f,
+            (BarrierType::DownAndOut, OptionType::Call) => b - d + f,
+            (BarrierType::UpAndOut, OptionType::Call) if above => f,
+            (BarrierType::UpAndOut, OptionType::Call) => a - b + c - d + f,
+            (BarrierType::DownAndOut, OptionType::Put) if above => a - b + c - d + f,
+            (BarrierType::DownAndOut, OptionType::Put) => f,
+            (BarrierType::UpAndOut, OptionType::Put) if above => b - d + f,
+            (BarrierType::UpAndOut, OptionType::Put) => a - c + f,
+        })
     }
 
-    /// Calculate the price of a rainbow put using the Black-Scholes formula.
+    /// Calculate the discounted asset and cash parts of a double knock-out payoff using the Ikeda-Kunitomo series.
+    ///
+    /// The parts are the values of receiving the asset (or one unit of cash) at maturity if the terminal price lies
+    /// in `(from, to)` and neither barrier has been hit.
     ///
     /// # Arguments
     ///
-    /// * `option` - The option to price.
+    /// * `spot` - The spot price of the underlying asset.
+    /// * `dividend_yield` - The continuous dividend yield of the underlying asset.
+    /// * `lower` - The lower barrier.
+    /// * `upper` - The upper barrier.
+    /// * `ttm` - Time to maturity.
+    /// * `from` - The lower bound of the payoff region.
+    /// * `to` - The upper bound of the payoff region.
     /// * `normal` - The standard normal distribution.
     ///
     /// # Returns
     ///
-    /// The price of the option.
-    pub fn price_rainbow_put<T: Option>(&self, option: &T, normal: &Normal) -> f64 {
-        if matches!(option.style(), OptionStyle::Rainbow(RainbowType::AllOTM))
-            && option.payoff(None) <= 0.0
-        {
-            return 0.0;
+    /// A tuple containing the asset part and the cash part.
+    #[allow(clippy::too_many_arguments)]
+    fn double_barrier_parts(
+        &self,
+        spot: f64,
+        dividend_yield: f64,
+        lower: f64,
+        upper: f64,
+        ttm: f64,
+        from: f64,
+        to: f64,
+        normal: &Normal,
+    ) -> (f64, f64) {
+        let r = self.risk_free_rate;
+        let b = r - dividend_yield;
+        let vol_t = self.volatility * ttm.sqrt();
+        let mu = 2.0 * b / self.volatility.powi(2) + 1.0;
+        let d = |x: f64| (x.ln() + (b + 0.5 * self.volatility.powi(2)) * ttm) / vol_t;
+        let n = |x: f64| normal.cdf(x);
+
+        let (mut asset, mut cash) = (0.0, 0.0);
+        for i in -5..=5 {
+            let (u_n, l_n) = (upper.powi(i), lower.powi(i));
+            let d1 = d(spot * u_n * u_n / (from * l_n * l_n));
+            let d2 = d(spot * u_n * u_n / (to * l_n * l_n));
+            let d3 = d(lower.powi(2 * i + 2) / (from * spot * u_n * u_n));
+            let d4 = d(lower.powi(2 * i + 2) / (to * spot * u_n * u_n));
+            let direct = u_n / l_n;
+            let reflected = lower.powi(i + 1) / (u_n * spot);
+
+            asset += direct.powf(mu) * (n(d1) - n(d2)) - reflected.powf(mu) * (n(d3) - n(d4));
+            cash += direct.powf(mu - 2.0) * (n(d1 - vol_t) - n(d2 - vol_t))
+                - reflected.powf(mu - 2.0) * (n(d3 - vol_t) - n(d4 - vol_t));
         }
-        self.price_euro_put(
-            option.instrument(),
-            option.strike(),
-            option.time_to_maturity(),
-            normal,
+
+        (
+            spot * (-dividend_yield * ttm).exp() * asset,
+            (-r * ttm).exp() * cash,
         )
     }
 
-    /// Calculate the price of a lookback option using the Black-Scholes formula.
+    /// Calculate the price of a double barrier option using the Ikeda-Kunitomo formula.
     ///
     /// # Arguments
     ///
-    /// * `option` - The option to price.
+    /// * `option` - The double barrier option to price.
     /// * `normal` - The standard normal distribution.
     ///
     /// # Returns
     ///
-    /// The price of the option.
-    pub fn price_lookback<T: Option>(&self, option: &T, normal: &Normal) -> f64 {
-        let max = option.instrument().max_spot();
-        let min = option.instrument().min_spot();
-        let sqrt_t = option.time_to_maturity().sqrt();
-        let s = option.instrument().spot();
-        let r = self.risk_free_rate;
+    /// The price of the option or an error if the option is not a double barrier option or lacks a barrier.
+    pub fn price_double_barrier<T: Option>(
+        &self,
+        option: &T,
+        normal: &Normal,
+    ) -> Result<f64, PricingError> {
+        let OptionStyle::DoubleBarrier(barrier_type, _) = option.style() else {
+            return Err(PricingError::unsupported_style(
+                "BlackScholesModel",
+                *option.style(),
+            ));
+        };
+        let Some(barrier) = option.barrier() else {
+            return Err(PricingError::invalid_input(
+                "Barrier options must provide a barrier",
+            ));
+        };
+        let Some(upper) = barrier.upper_level else {
+            return Err(PricingError::invalid_input(
+                "Double barrier options require an upper barrier",
+            ));
+        };
         let t = option.time_to_maturity();
-        let vola = self.volatility;
-
-        assert!(s > 0.0 && max > 0.0 && min > 0.0, "Spot prices must be > 0");
-
-        println!("max: {max}, min: {min}");
+        let s = option.instrument().escrowed_spot(self.risk_free_rate, t);
+        let q = option.instrument().continuous_dividend_yield;
+        let k = option.strike();
+        let rebate = barrier.rebate;
+        let knock_in = barrier_type.is_knock_in();
 
-        let a1 = |s: f64, h: f64| ((s / h).ln() + (r + 0.5 * vola.powi(2)) * t) / (vola * sqrt_t);
-        let a2 = |s: f64, h: f64| a1(s, h) - vola * sqrt_t;
-        let a3 = |s: f64, h: f64| a1(s, h) - 2.0 * r * sqrt_t / vola;
+        if barrier.is_breached(*barrier_type, s) {
+            return Ok(if knock_in {
+                self.price_vanilla(option, normal)
+            } else {
+                rebate
+            });
+        }
 
-        let phi = |x: f64| normal.cdf(x);
+        let lower = self.adjusted_barrier(barrier.level, true, barrier.monitoring, t);
+        let upper = self.adjusted_barrier(upper, false, barrier.monitoring, t);
+        let parts = |from: f64, to: f64, ttm: f64| {
+            if from >= to {
+                (0.0, 0.0)
+            } else {
+                self.double_barrier_parts(s, q, lower, upper, ttm, from, to, normal)
+            }
+        };
 
-        match option.option_type() {
+        let knock_out = match option.option_type() {
             OptionType::Call => {
-                s * phi(a1(s, min))
-                    - min * (-r * t).exp() * phi(a2(s, min))
-                    - (0.5 * s * vola.powi(2)) / (r)
-                        * (phi(-a1(s, min))
-                            - (-r * t).exp()
-                                * (min / s).pow((2f64 * r) / (vola.powi(2)))
-                                * phi(-a3(s, min)))
+                let (asset, cash) = parts(k.max(lower), upper, t);
+                asset - k * cash
             }
             OptionType::Put => {
-                -s * phi(-a1(s, max))
-                    + max * (-r * t).exp() * phi(-a2(s, max))
-                    + (0.5 * s * vola.powi(2)) / (r)
-                        * (phi(a1(s, max))
-                            - (-r * t).exp()
-                                * (max / s).pow((2f64 * r) / (vola.powi(2)))
-                                * phi(a3(s, max)))
+                let (asset, cash) = parts(lower, k.min(upper), t);
+                k * cash - asset
             }
-        }
+        };
+        let (_, survival) = parts(lower, upper, t);
+
+        Ok(if knock_in {
+            // The rebate is paid at maturity if neither barrier has been hit
+            self.price_vanilla(option, normal) - knock_out + rebate * survival
+        } else if rebate != 0.0 {
+            // The rebate is paid when a barrier is hit: E[e^{-rτ}; τ <= T] with the first passage time τ
+            let r = self.risk_free_rate;
+            let hit = |ttm: f64| 1.0 - (r * ttm).exp() * parts(lower, upper, ttm).1;
+            let integral = numerics::integrate(
+                |u| (-r * u).exp() * hit(u),
+                0.0,
+                t,
+                8,
+                &numerics::gauss_legendre(16),
+            );
+            knock_out + rebate * ((-r * t).exp() * hit(t) + r * integral)
+        } else {
+            knock_out
+        })
     }
 
-    /// Calculate the option price using the Black-Scholes formula with a given volatility.
+    /// Calculate the higher-order Greeks of a unit digital option in closed form.
+    ///
+    /// Derivatives with respect to time are taken with respect to the time to maturity, like the European theta.
     ///
     /// # Arguments
     ///
-    /// * `option` - The option to price.
-    /// * `volatility` - The volatility of the underlying asset.
-    /// * `normal` - The standard normal distribution.
+    /// * `option` - The option providing the instrument, strike and time to maturity.
+    /// * `binary_type` - Whether the digital pays one unit of cash or one unit of the asset.
+    /// * `sign` - `1.0` for a digital call and `-1.0` for a digital put.
     ///
     /// # Returns
     ///
-    /// The price of the option.
-    fn price_with_volatility<T: Option>(
+    /// The higher-order Greeks of the digital option.
+    fn digital_greeks<T: Option>(
         &self,
         option: &T,
-        volatility: f64,
-        normal: &Normal,
-    ) -> f64 {
-        let sqrt_t = option.time_to_maturity().sqrt();
-        let n_dividends = option
-            .instrument()
-            .dividend_times
-            .iter()
-            .filter(|&&t| t <= option.time_to_maturity())
-            .count() as f64;
-        let adjusted_spot = option.instrument().spot()
-            * (1.0 - option.instrument().discrete_dividend_yield).powf(n_dividends);
+        binary_type: BinaryType,
+        sign: f64,
+    ) -> DigitalGreeks {
+        let ttm = option.time_to_maturity();
+        let (d1, d2) = self.calculate_d1_d2(option.instrument(), option.strike(), ttm);
+        let spot = option.instrument().escrowed_spot(self.risk_free_rate, ttm);
+        let (r, q, sigma) = (
+            self.risk_free_rate,
+            option.instrument().continuous_dividend_yield,
+            self.volatility,
+        );
+        let sqrt_t = ttm.sqrt();
+        let vol = sigma * sqrt_t;
+        let normal = Normal::new(0.0, 1.0).unwrap();
 
-        let d1 = ((adjusted_spot / option.strike()).ln()
-            + (self.risk_free_rate - option.instrument().continuous_dividend_yield
-                + 0.5 * volatility.powi(2))
-                * option.time_to_maturity())
-            / (volatility * sqrt_t);
+        // Derivatives of d1 and d2 with respect to the time to maturity
+        let dd1_dt = (2.0 * (r - q) * ttm - d2 * vol) / (2.0 * ttm * vol);
+        let dd2_dt = (2.0 * (r - q) * ttm - d1 * vol) / (2.0 * ttm * vol);
 
-        let d2 = d1 - volatility * sqrt_t;
+        match binary_type {
+            CashOrNothing => {
+                let discount = (-r * ttm).exp();
+                let pdf = sign * discount * normal.pdf(d2);
+                let vega = -pdf * d1 / sigma;
+                let vomma = d1 * d1 * d2 - d1 - d2;
 
-        match option.option_type() {
-            OptionType::Call => {
-                let nd1 = normal.cdf(d1);
-                let nd2 = normal.cdf(d2);
-                adjusted_spot
-                    * (-option.instrument().continuous_dividend_yield * option.time_to_maturity())
-                        .exp()
-                    * nd1
-                    - option.strike()
-                        * (-self.risk_free_rate * option.time_to_maturity()).exp()
-                        * nd2
+                DigitalGreeks {
+                    vanna: pdf * (d1 * d2 - 1.0) / (spot * vol * sigma),
+                    charm: pdf / (spot * vol) * (-r - d2 * dd2_dt - 0.5 / ttm),
+                    vomma: -pdf * vomma / sigma.powi(2),
+                    veta: -pdf * (dd1_dt - d1 * (r + d2 * dd2_dt)) / sigma,
+                    vera: -ttm * vega + pdf * sqrt_t * (d1 * d2 - 1.0) / sigma.powi(2),
+                    speed: pdf * (d1 * d2 - 1.0 + 2.0 * d1 * vol) / (spot.powi(3) * vol.powi(3)),
+                    zomma: -pdf * (d1 * d1 * d2 - d2 - 2.0 * d1)
+                        / (spot.powi(2) * sigma.powi(3) * ttm),
+                    color: -pdf * (dd1_dt - d1 * (r + d2 * dd2_dt + 1.0 / ttm))
+                        / (spot.powi(2) * sigma.powi(2) * ttm),
+                    ultima: -pdf
+                        * (d1 * d2 * vomma - 2.0 * vomma - 2.0 * d1 * d2 * d2 - d1.powi(3)
+                            + d1
+                            + d2)
+                        / sigma.powi(3),
+                    epsilon: -pdf * sqrt_t / sigma,
+                }
             }
-            OptionType::Put => {
-                let nd1 = normal.cdf(-d1);
-                let nd2 = normal.cdf(-d2);
-                option.strike() * (-self.risk_free_rate * option.time_to_maturity()).exp() * nd2
-                    - adjusted_spot
-                        * (-option.instrument().continuous_dividend_yield
-                            * option.time_to_maturity())
-                        .exp()
-                        * nd1
+            AssetOrNothing => {
+                let discount = (-q * ttm).exp();
+                let pdf = sign * discount * normal.pdf(d1);
+                let price = spot * discount * normal.cdf(sign * d1);
+                let vomma = d1 * d2 * d2 - d1 - d2;
+
+                DigitalGreeks {
+                    vanna: pdf * (d1 * d2 - 1.0 - d2 * vol) / (sigma * vol),
+                    charm: -q * (pdf / vol + discount * normal.cdf(sign * d1))
+                        + pdf * (dd1_dt - (d1 * dd1_dt + 0.5 / ttm) / vol),
+                    vomma: -spot * pdf * vomma / sigma.powi(2),
+                    veta: -spot * pdf * (dd2_dt - d2 * (q + d1 * dd1_dt)) / sigma,
+                    vera: spot * pdf * sqrt_t * (d1 * d2 - 1.0) / sigma.powi(2),
+                    speed: pdf * (d1 * d2 - 1.0 + d2 * vol) / (spot.powi(2) * vol.powi(3)),
+                    zomma: -pdf * (d1 * d2 * d2 - d1 - 2.0 * d2) / (spot * sigma.powi(3) * ttm),
+                    color: -pdf * (dd2_dt - d2 * (q + d1 * dd1_dt + 1.0 / ttm))
+                        / (spot * sigma.powi(2) * ttm),
+                    ultima: -spot
+                        * pdf
+                        * (d1 * d2 * vomma - 2.0 * vomma - d2.powi(3) - 2.0 * d1 * d1 * d2
+                            + d1
+                            + d2)
+                        / sigma.powi(3),
+                    epsilon: -ttm * price - spot * pdf * sqrt_t / sigma,
+                }
             }
         }
     }
+
+    /// Calculate a higher-order Greek of a European or binary option.
+    ///
+    /// European options are decomposed into digitals, e.g. a call into an asset-or-nothing call less `K`
+    /// cash-or-nothing calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to calculate the Greek for.
+    /// * `greek` - Selects the Greek from the Greeks of a digital option.
+    ///
+    /// # Returns
+    ///
+    /// The value of the Greek or an error if the option style is not supported.
+    fn higher_order_greek<T: Option>(
+        &self,
+        option: &T,
+        greek: fn(&DigitalGreeks) -> f64,
+    ) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some((model, option)) = self.flattened(option) {
+            return model.higher_order_greek(&option, greek);
+        }
+        let sign = match option.option_type() {
+            OptionType::Call => 1.0,
+            OptionType::Put => -1.0,
+        };
+        let legs = match option.style() {
+            OptionStyle::European => vec![
+                (AssetOrNothing, sign),
+                (CashOrNothing, -sign * option.strike()),
+            ],
+            OptionStyle::Binary(binary_type) => vec![(*binary_type, 1.0)],
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "BlackScholesModel",
+                    *option.style(),
+                ));
+            }
+        };
+
+        Ok(legs
+            .into_iter()
+            .map(|(binary_type, weight)| {
+                weight * greek(&self.digital_greeks(option, binary_type, sign))
+            })
+            .sum())
+    }
 }
 
 impl OptionPricing for BlackScholesModel {
-    #[rustfmt::skip]
     fn price<T: Option>(&self, option: &T) -> f64 {
+        self.try_price(option).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    #[rustfmt::skip]
+    fn try_price<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some((model, option)) = self.flattened(option) {
+            return match option.style() {
+                OptionStyle::European | OptionStyle::Binary(_) => model.try_price(&option),
+                _ => Err(PricingError::not_implemented(
+                    "Term structures, volatility surfaces and SABR volatilities for path-dependent and multi-asset options",
+                )),
+            };
+        }
+        let instrument = option.instrument();
+        if !instrument.cash_dividends.is_empty()
+            && instrument.escrowed_spot(self.risk_free_rate, option.time_to_maturity()) <= 0.0
+        {
+            return Err(PricingError::invalid_input(
+                "Cash dividends must not exceed the spot price",
+            ));
+        }
         let normal = Normal::new(0.0, 1.0).unwrap();
-        match (option.option_type(), option.style()) {
+        Ok(match (option.option_type(), option.style()) {
             (OptionType::Call, OptionStyle::European) => self.price_euro_call(option.instrument(), option.strike(),option.time_to_maturity(), &normal),
             (OptionType::Put, OptionStyle::European) => self.price_euro_put(option.instrument(), option.strike(), option.time_to_maturity(),&normal),
             (_, OptionStyle::Binary(CashOrNothing)) => self.price_cash_or_nothing(option, &normal),
             (_, OptionStyle::Binary(AssetOrNothing)) => self.price_asset_or_nothing(option, &normal),
-            (OptionType::Call, OptionStyle::Rainbow(_)) => self.price_rainbow_call(option, &normal),
-            (OptionType::Put, OptionStyle::Rainbow(_)) => self.price_rainbow_put(option, &normal),
-            (_, OptionStyle::Lookback(Permutation::Floating)) => self.price_lookback(option, &normal),
-            _ => panic!("BlackScholesModel does not support this option type or style"),
-        }
+            (OptionType::Call, OptionStyle::Rainbow(_)) => self.price_rainbow_call(option, &normal)?,
+            (OptionType::Put, OptionStyle::Rainbow(_)) => self.price_rainbow_put(option, &normal)?,
+            (_, OptionStyle::Lookback(_)) => self.price_lookback(option, &normal)?,
+            (_, OptionStyle::Basket) => self.price_basket_levy(option, &normal),
+            (_, OptionStyle::Asian(Permutation::Fixed)) => self.price_asian(option, &normal)?,
+            (_, OptionStyle::Barrier(_)) => self.price_barrier(option, &normal)?,
+            (_, OptionStyle::DoubleBarrier(_, _)) => self.price_double_barrier(option, &normal)?,
+            _ => return Err(PricingError::unsupported_style("BlackScholesModel", *option.style())),
+        })
     }
 
-    /// Calculate the implied volatility of an option using the Newton-Raphson method.
+    /// Calculate the implied volatility of a European or binary option.
+    ///
+    /// See [`OptionPricing::try_implied_volatility`] for the method.
     ///
     /// # Arguments
     ///
@@ -402,56 +2006,105 @@ impl OptionPricing for BlackScholesModel {
     /// # Returns
     ///
     /// The implied volatility of the option.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the implied volatility cannot be calculated.
     fn implied_volatility<T: Option>(&self, option: &T, market_price: f64) -> f64 {
-        let mut sigma = 0.2; // Initial guess
-        let tolerance = 1e-5;
-        let max_iterations = 100;
-        let normal = Normal::new(0.0, 1.0).unwrap();
-        for _ in 0..max_iterations {
-            let price = self.price_with_volatility(option, sigma, &normal);
-            let bump = 1e-5;
-            let price_up = self.price_with_volatility(option, sigma + bump, &normal);
-            let vega = (price_up - price) / bump;
-            let diff = market_price - price;
-            if diff.abs() < tolerance {
-                return sigma;
-            }
-            if vega.abs() < 1e-12 {
-                break;
-            }
-            sigma += diff / vega;
-        }
-        sigma
-    }
-
-    // Calculate the implied volatility of an option using the Brent method.
-    //fn implied_volatility<T: Option>(&self, option: &T, market_price: f64) -> f64 {
-    //    let normal = Normal::new(0.0, 1.0).unwrap();
-    //    let f = |sigma: f64| self.price_with_volatility(option, sigma, &normal) - market_price;
-    //
-    //    let tol = 1e-5;
-    //    let lower_bound = 1e-5;
-    //    let upper_bound = 5.0;
-    //
-    //    // Ensure that the function values at the bounds have different signs
-    //    if f(lower_bound) * f(upper_bound) > 0.0 {
-    //        panic!("f(min) and f(max) must have different signs");
-    //    }
-    //
-    //    let problem = TestFunc::new(f);
-    //    let solver = BrentRoot::new(lower_bound, upper_bound, tol);
-    //
-    //    let res = Executor::new(problem, solver)
-    //        .configure(|state| state.max_iters(100))
-    //        .run()
-    //        .unwrap();
-    //
-    //    res.state().best_param.unwrap()
-    //}
+        self.try_implied_volatility(option, market_price)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Calculate the implied volatility of a European or binary option without panicking.
+    ///
+    /// Newton's method with analytic vega is safeguarded by bisection and started from the Corrado-Miller
+    /// approximation on the out-of-the-money side of put-call parity. Market prices outside the no-arbitrage bounds
+    /// (e.g., below the discounted intrinsic value of a European option) are rejected.
+    ///
+    /// Digital prices are not monotone in the volatility if the option is out-of-the-money (cash-or-nothing) or
+    /// in-the-money (asset-or-nothing) with respect to the forward. In that case, the price is maximal (minimal) at
+    /// `σ* = √(2 |ln(F / K)| / T)` and the lower of the two implied volatilities, i.e. the one below `σ*`, is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to calculate the implied volatility for.
+    /// * `market_price` - The market price of the option.
+    ///
+    /// # Returns
+    ///
+    /// The implied volatility of the option or the reason why it cannot be calculated.
+    fn try_implied_volatility<T: Option>(
+        &self,
+        option: &T,
+        market_price: f64,
+    ) -> Result<f64, PricingError> {
+        validate_market_price(option, market_price)?;
+        if let Some((model, option)) = self.flattened(option) {
+            return model.try_implied_volatility(&option, market_price);
+        }
+        let ttm = option.time_to_maturity();
+        if ttm <= 0.0 {
+            return Err(PricingError::invalid_input(
+                "Time to maturity must be positive to imply a volatility",
+            ));
+        }
+
+        let instrument = option.instrument();
+        let discounted_forward = instrument.escrowed_spot(self.risk_free_rate, ttm)
+            * (-instrument.continuous_dividend_yield * ttm).exp();
+        let discounted_strike = option.strike() * (-self.risk_free_rate * ttm).exp();
+        let log_moneyness = (discounted_forward / discounted_strike).ln();
+        let turning_point = (2.0 * log_moneyness.abs() / ttm).sqrt();
+        let (lower, upper) = numerics::VOLATILITY_BRACKET;
+        let with_volatility = |volatility| Self {
+            volatility,
+            ..self.clone()
+        };
+
+        let (bracket, guess) = match option.style() {
+            OptionStyle::European => {
+                return numerics::european_implied_volatility(
+                    |volatility, option| with_volatility(volatility).price(option),
+                    |volatility, option| with_volatility(volatility).vega(option),
+                    option,
+                    market_price,
+                    discounted_forward,
+                    discounted_strike,
+                );
+            }
+            OptionStyle::Binary(binary_type) => {
+                let non_monotone = turning_point > lower
+                    && match binary_type {
+                        CashOrNothing => log_moneyness < 0.0,
+                        AssetOrNothing => log_moneyness > 0.0,
+                    };
+                if non_monotone {
+                    ((lower, turning_point), 0.5 * turning_point)
+                } else {
+                    ((lower, upper), self.volatility)
+                }
+            }
+            style => {
+                return Err(PricingError::unsupported_style("BlackScholesModel", *style));
+            }
+        };
+
+        numerics::implied_volatility(
+            |volatility| with_volatility(volatility).price(option),
+            |volatility| with_volatility(volatility).vega(option),
+            market_price,
+            bracket,
+            guess,
+        )
+    }
 }
 
 impl OptionGreeks for BlackScholesModel {
-    fn delta<T: Option>(&self, option: &T) -> f64 {
+    fn try_delta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some((model, option)) = self.flattened(option) {
+            return model.try_delta(&option);
+        }
         let (d1, d2) = self.calculate_d1_d2(
             option.instrument(),
             option.strike(),
@@ -459,7 +2112,7 @@ impl OptionGreeks for BlackScholesModel {
         );
         let normal = Normal::new(0.0, 1.0).unwrap();
 
-        match option.style() {
+        Ok(match option.style() {
             OptionStyle::European => match option.option_type() {
                 OptionType::Call => {
                     (-option.instrument().continuous_dividend_yield * option.time_to_maturity())
@@ -506,11 +2159,20 @@ impl OptionGreeks for BlackScholesModel {
                             * normal.cdf(-d1)
                 }
             },
-            _ => panic!("Unsupported option style for delta calculation"),
-        }
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "BlackScholesModel",
+                    *option.style(),
+                ));
+            }
+        })
     }
 
-    fn gamma<T: Option>(&self, option: &T) -> f64 {
+    fn try_gamma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some((model, option)) = self.flattened(option) {
+            return model.try_gamma(&option);
+        }
         let (d1, d2) = self.calculate_d1_d2(
             option.instrument(),
             option.strike(),
@@ -518,12 +2180,13 @@ impl OptionGreeks for BlackScholesModel {
         );
         let adjusted_spot = option
             .instrument()
-            .calculate_adjusted_spot(option.time_to_maturity());
+            .escrowed_spot(self.risk_free_rate, option.time_to_maturity());
         let normal = Normal::new(0.0, 1.0).unwrap();
 
-        match option.style() {
+        Ok(match option.style() {
             OptionStyle::European => {
-                normal.pdf(d1)
+                (-option.instrument().continuous_dividend_yield * option.time_to_maturity()).exp()
+                    * normal.pdf(d1)
                     / (adjusted_spot * self.volatility * option.time_to_maturity().sqrt())
             }
             OptionStyle::Binary(CashOrNothing) => {
@@ -553,11 +2216,20 @@ impl OptionGreeks for BlackScholesModel {
                     OptionType::Put => -gamma,
                 }
             }
-            _ => panic!("Unsupported option style for gamma calculation"),
-        }
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "BlackScholesModel",
+                    *option.style(),
+                ));
+            }
+        })
     }
 
-    fn theta<T: Option>(&self, option: &T) -> f64 {
+    fn try_theta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some((model, option)) = self.flattened(option) {
+            return model.try_theta(&option);
+        }
         let (d1, d2) = self.calculate_d1_d2(
             option.instrument(),
             option.strike(),
@@ -565,13 +2237,18 @@ impl OptionGreeks for BlackScholesModel {
         );
         let adjusted_spot = option
             .instrument()
-            .calculate_adjusted_spot(option.time_to_maturity());
+            .escrowed_spot(self.risk_free_rate, option.time_to_maturity());
         let normal = Normal::new(0.0, 1.0).unwrap();
 
-        match option.style() {
+        Ok(match option.style() {
             OptionStyle::European => match option.option_type() {
                 OptionType::Call => {
-                    adjusted_spot * normal.pdf(d1) * self.volatility
+                    adjusted_spot
+                        * (-option.instrument().continuous_dividend_yield
+                            * option.time_to_maturity())
+                        .exp()
+                        * normal.pdf(d1)
+                        * self.volatility
                         / (2.0 * option.time_to_maturity().sqrt())
                         + self.risk_free_rate
                             * option.strike()
@@ -585,7 +2262,12 @@ impl OptionGreeks for BlackScholesModel {
                             * normal.cdf(d1)
                 }
                 OptionType::Put => {
-                    adjusted_spot * normal.pdf(d1) * self.volatility
+                    adjusted_spot
+                        * (-option.instrument().continuous_dividend_yield
+                            * option.time_to_maturity())
+                        .exp()
+                        * normal.pdf(d1)
+                        * self.volatility
                         / (2.0 * option.time_to_maturity().sqrt())
                         - self.risk_free_rate
                             * option.strike()
@@ -665,11 +2347,20 @@ impl OptionGreeks for BlackScholesModel {
                             + option.instrument().continuous_dividend_yield * -normal.cdf(d1))
                 }
             },
-            _ => panic!("Unsupported option style for theta calculation"),
-        }
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "BlackScholesModel",
+                    *option.style(),
+                ));
+            }
+        })
     }
 
-    fn vega<T: Option>(&self, option: &T) -> f64 {
+    fn try_vega<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some((model, option)) = self.flattened(option) {
+            return model.try_vega(&option);
+        }
         let (d1, d2) = self.calculate_d1_d2(
             option.instrument(),
             option.strike(),
@@ -677,10 +2368,10 @@ impl OptionGreeks for BlackScholesModel {
         );
         let adjusted_spot = option
             .instrument()
-            .calculate_adjusted_spot(option.time_to_maturity());
+            .escrowed_spot(self.risk_free_rate, option.time_to_maturity());
         let normal = Normal::new(0.0, 1.0).unwrap();
 
-        match option.style() {
+        Ok(match option.style() {
             OptionStyle::European => {
                 adjusted_spot
                     * (-option.instrument().continuous_dividend_yield * option.time_to_maturity())
@@ -711,11 +2402,20 @@ impl OptionGreeks for BlackScholesModel {
                     OptionType::Put => -vega,
                 }
             }
-            _ => panic!("Unsupported option style for vega calculation"),
-        }
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "BlackScholesModel",
+                    *option.style(),
+                ));
+            }
+        })
     }
 
-    fn rho<T: Option>(&self, option: &T) -> f64 {
+    fn try_rho<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        if let Some((model, option)) = self.flattened(option) {
+            return model.try_rho(&option);
+        }
         let (d1, d2) = self.calculate_d1_d2(
             option.instrument(),
             option.strike(),
@@ -723,7 +2423,7 @@ impl OptionGreeks for BlackScholesModel {
         );
         let normal = Normal::new(0.0, 1.0).unwrap();
 
-        match option.style() {
+        Ok(match option.style() {
             OptionStyle::European => match option.option_type() {
                 OptionType::Call => {
                     option.strike()
@@ -763,8 +2463,90 @@ impl OptionGreeks for BlackScholesModel {
                     OptionType::Put => -rho,
                 }
             }
-            _ => panic!("Unsupported option style for rho calculation"),
+            _ => {
+                return Err(PricingError::unsupported_style(
+                    "BlackScholesModel",
+                    *option.style(),
+                ));
+            }
+        })
+    }
+
+    fn try_lambda<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Ok(self.try_delta(option)? * option.instrument().spot() / self.try_price(option)?)
+    }
+
+    fn try_epsilon<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.higher_order_greek(option, |greeks| greeks.epsilon)
+    }
+
+    fn try_vanna<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.higher_order_greek(option, |greeks| greeks.vanna)
+    }
+
+    fn try_charm<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.higher_order_greek(option, |greeks| greeks.charm)
+    }
+
+    fn try_vomma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.higher_order_greek(option, |greeks| greeks.vomma)
+    }
+
+    fn try_veta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.higher_order_greek(option, |greeks| greeks.veta)
+    }
+
+    fn try_vera<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.higher_order_greek(option, |greeks| greeks.vera)
+    }
+
+    fn try_speed<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.higher_order_greek(option, |greeks| greeks.speed)
+    }
+
+    fn try_zomma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.higher_order_greek(option, |greeks| greeks.zomma)
+    }
+
+    fn try_color<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.higher_order_greek(option, |greeks| greeks.color)
+    }
+
+    fn try_ultima<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.higher_order_greek(option, |greeks| greeks.ultima)
+    }
+}
+
+impl ModelParameters for BlackScholesModel {
+    fn risk_free_rate(&self) -> f64 {
+        self.risk_free_rate
+    }
+
+    /// Set the risk-free interest rate, shifting the discount curve in parallel by the change of the rate.
+    fn set_risk_free_rate(&mut self, risk_free_rate: f64) {
+        if let Some(curve) = &mut self.discount_curve {
+            *curve = curve.shifted(risk_free_rate - self.risk_free_rate);
+        }
+        self.risk_free_rate = risk_free_rate;
+    }
+
+    fn volatility(&self) -> f64 {
+        self.volatility
+    }
+
+    /// Set the volatility, shifting the volatility term structure, surface and SABR volatilities in parallel by the
+    /// change of the volatility.
+    fn set_volatility(&mut self, volatility: f64) {
+        if let Some(term_structure) = &mut self.volatility_term_structure {
+            *term_structure = term_structure.shifted(volatility - self.volatility);
+        }
+        if let Some(surface) = &mut self.vol_surface {
+            *surface = surface.shifted(volatility - self.volatility);
+        }
+        if let Some(sabr) = &mut self.sabr {
+            *sabr = sabr.shifted(volatility - self.volatility);
         }
+        self.volatility = volatility;
     }
 }
 
@@ -1,36 +1,577 @@
 //! Module for finite difference option pricing model.
+//!
+//! The finite difference model prices options by solving the Black-Scholes partial differential equation
+//! numerically on a grid. The PDE is written in the log-spot `x = ln(S)` and time to maturity `τ`:
+//!
+//! ```text
+//! ∂V/∂τ = ½σ² ∂²V/∂x² + (r - q - ½σ²) ∂V/∂x - rV
+//! ```
+//!
+//! where:
+//! - `V` is the option value.
+//! - `σ` is the volatility of the underlying asset.
+//! - `r` is the risk-free interest rate.
+//! - `q` is the continuous dividend yield.
+//!
+//! Starting from the payoff at maturity, the solution is marched backwards in time with a θ-scheme:
+//!
+//! - **Explicit** (`θ = 0`): cheap but only stable for small time steps, the number of time steps is increased
+//!   automatically until the scheme is stable.
+//! - **Implicit** (`θ = 1`): unconditionally stable, first-order accurate in time.
+//! - **Crank-Nicolson** (`θ = ½`): unconditionally stable and second-order accurate in time. The first steps are
+//!   replaced by implicit half-steps (Rannacher start-up) to damp the oscillations caused by the non-smooth payoff.
+//!
+//! American options are priced by solving the linear complementarity problem at each time step with projected
+//! successive over-relaxation (PSOR). Bermudan options are exercised at the time steps closest to their
+//! exercise dates.
+//!
+//! With a local volatility surface, `σ` is the Dupire local volatility `σ(t, S)` of the surface at every node. The
+//! coefficients of the PDE are then recomputed at the middle of every time step.
+//!
+//! Cash dividends are handled by solving the PDE for the escrowed spot price `S* = S - PV(D)` and exercising at
+//! `S* + PV(D)` for the dividends going ex after the exercise date.
+//!
+//! Since the whole grid is available after pricing, delta, gamma and theta are read directly off the grid.
+//!
+//! ## References
+//!
+//! - [Wikipedia - Finite difference methods for option pricing](https://en.wikipedia.org/wiki/Finite_difference_methods_for_option_pricing)
+//! - [Wilmott, P. - Paul Wilmott on Quantitative Finance](https://www.wiley.com/en-us/Paul+Wilmott+on+Quantitative+Finance%2C+3+Volume+Set%2C+2nd+Edition-p-9780470018705)
+//! - [Rannacher, R. - Finite element solution of diffusion problems with irregular data](https://doi.org/10.1007/BF01389633)
+//!
+//! ## Example
+//!
+//! ```
+//! use quantrs::options::{OptionPricing, FiniteDiffModel, AmericanOption, Instrument, OptionType};
+//!
+//! let instrument = Instrument::new().with_spot(100.0);
+//! let option = AmericanOption::new(instrument, 100.0, 1.0, OptionType::Put);
+//! let model = FiniteDiffModel::crank_nicolson(0.05, 0.2, 200, 200);
+//!
+//! let price = model.price(&option);
+//! println!("Option price: {price}");
+//! ```
 
-use crate::options::{Option, OptionPricing, OptionStrategy};
+use crate::options::{
+    ModelParameters, Option, OptionGreeks, OptionPricing, OptionStrategy, OptionStyle,
+    PricingError, VolSurface, numerics, validate_option,
+};
+
+/// Number of standard deviations covered by the grid on each side of the spot.
+const GRID_STD_DEVS: f64 = 5.0;
+
+/// Enum for the time-stepping schemes of the finite difference model.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum FiniteDiffScheme {
+    /// Explicit (forward Euler) scheme.
+    Explicit,
+    /// Fully implicit (backward Euler) scheme.
+    Implicit,
+    /// Crank-Nicolson scheme with Rannacher start-up.
+    #[default]
+    CrankNicolson,
+}
+
+impl FiniteDiffScheme {
+    /// Weight `θ` of the implicit part of the scheme.
+    fn theta(&self) -> f64 {
+        match self {
+            FiniteDiffScheme::Explicit => 0.0,
+            FiniteDiffScheme::Implicit => 1.0,
+            FiniteDiffScheme::CrankNicolson => 0.5,
+        }
+    }
+}
 
 /// Finite difference option pricing model.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct FiniteDiffModel {
     /// Risk-free interest rate (e.g., 0.05 for 5%).
     pub risk_free_rate: f64,
     /// Volatility of the underlying asset (e.g., 0.2 for 20%).
     pub volatility: f64,
-    /// Number of steps in the binomial tree.
+    /// Number of time steps.
     pub steps: usize,
+    /// Number of intervals of the log-spot grid.
+    pub space_steps: usize,
+    /// Time-stepping scheme.
+    pub scheme: FiniteDiffScheme,
+    /// Number of initial Crank-Nicolson steps replaced by two implicit half-steps.
+    pub rannacher_steps: usize,
+    /// Implied volatility surface whose Dupire local volatility replaces the flat volatility.
+    pub local_volatility: std::option::Option<VolSurface>,
+}
+
+/// Solution of the PDE at the valuation date.
+struct GridSolution {
+    /// Escrowed spot price at the centre of the grid.
+    spot: f64,
+    /// Log-spot spacing of the grid.
+    dx: f64,
+    /// Index of the current spot on the grid.
+    index: usize,
+    /// Option values at the valuation date.
+    values: Vec<f64>,
+    /// Option values one time step after the valuation date.
+    previous: Vec<f64>,
+    /// Size of the last time step.
+    dt: f64,
 }
 
 impl FiniteDiffModel {
     /// Create a new `FiniteDiffModel`.
-    pub fn new(risk_free_rate: f64, volatility: f64, steps: usize) -> Self {
+    ///
+    /// # Arguments
+    ///
+    /// * `risk_free_rate` - Risk-free interest rate (e.g., 0.05 for 5%).
+    /// * `volatility` - Annualized standard deviation of an asset's continuous returns (e.g., 0.2 for 20%).
+    /// * `steps` - The number of time steps.
+    /// * `space_steps` - The number of intervals of the log-spot grid.
+    /// * `scheme` - The time-stepping scheme.
+    ///
+    /// # Returns
+    ///
+    /// A new `FiniteDiffModel`.
+    pub fn new(
+        risk_free_rate: f64,
+        volatility: f64,
+        steps: usize,
+        space_steps: usize,
+        scheme: FiniteDiffScheme,
+    ) -> Self {
         Self {
+            risk_free_rate,
+            volatility,
+            steps: steps.max(1),
+            // Even number of intervals so that the spot lies on the central node
+            space_steps: space_steps.max(4).next_multiple_of(2),
+            scheme,
+            rannacher_steps: match scheme {
+                FiniteDiffScheme::CrankNicolson => 2,
+                _ => 0,
+            },
+            local_volatility: None,
+        }
+    }
+
+    /// Create a new `FiniteDiffModel` using the explicit scheme.
+    ///
+    /// # Arguments
+    ///
+    /// * `risk_free_rate` - Risk-free interest rate (e.g., 0.05 for 5%).
+    /// * `volatility` - Annualized standard deviation of an asset's continuous returns (e.g., 0.2 for 20%).
+    /// * `steps` - The (minimum) number of time steps.
+    /// * `space_steps` - The number of intervals of the log-spot grid.
+    ///
+    /// # Returns
+    ///
+    /// A new `FiniteDiffModel`.
+    pub fn explicit(
+        risk_free_rate: f64,
+        volatility: f64,
+        steps: usize,
+        space_steps: usize,
+    ) -> Self {
+        Self::new(
+            risk_free_rate,
+            volatility,
+            steps,
+            space_steps,
+            FiniteDiffScheme::Explicit,
+        )
+    }
+
+    /// Create a new `FiniteDiffModel` using the fully implicit scheme.
+    ///
+    /// # Arguments
+    ///
+    /// * `risk_free_rate` - Risk-free interest rate (e.g., 0.05 for 5%).
+    /// * `volatility` - Annualized standard deviation of an asset's continuous returns (e.g., 0.2 for 20%).
+    /// * `steps` - The number of time steps.
+    /// * `space_steps` - The number of intervals of the log-spot grid.
+    ///
+    /// # Returns
+    ///
+    /// A new `FiniteDiffModel`.
+    pub fn implicit(
+        risk_free_rate: f64,
+        volatility: f64,
+        steps: usize,
+        space_steps: usize,
+    ) -> Self {
+        Self::new(
             risk_free_rate,
             volatility,
             steps,
+            space_steps,
+            FiniteDiffScheme::Implicit,
+        )
+    }
+
+    /// Create a new `FiniteDiffModel` using the Crank-Nicolson scheme with Rannacher start-up.
+    ///
+    /// # Arguments
+    ///
+    /// * `risk_free_rate` - Risk-free interest rate (e.g., 0.05 for 5%).
+    /// * `volatility` - Annualized standard deviation of an asset's continuous returns (e.g., 0.2 for 20%).
+    /// * `steps` - The number of time steps.
+    /// * `space_steps` - The number of intervals of the log-spot grid.
+    ///
+    /// # Returns
+    ///
+    /// A new `FiniteDiffModel`.
+    pub fn crank_nicolson(
+        risk_free_rate: f64,
+        volatility: f64,
+        steps: usize,
+        space_steps: usize,
+    ) -> Self {
+        Self::new(
+            risk_free_rate,
+            volatility,
+            steps,
+            space_steps,
+            FiniteDiffScheme::CrankNicolson,
+        )
+    }
+
+    /// Set the number of Rannacher start-up steps (only used by the Crank-Nicolson scheme).
+    ///
+    /// # Arguments
+    ///
+    /// * `rannacher_steps` - The number of initial steps replaced by two implicit half-steps.
+    ///
+    /// # Returns
+    ///
+    /// The model with the number of start-up steps set.
+    pub fn with_rannacher_steps(mut self, rannacher_steps: usize) -> Self {
+        self.rannacher_steps = rannacher_steps;
+        self
+    }
+
+    /// Set the implied volatility surface whose Dupire local volatility replaces the flat volatility.
+    ///
+    /// # Arguments
+    ///
+    /// * `surface` - The implied volatility surface.
+    ///
+    /// # Returns
+    ///
+    /// The model with the local volatility set.
+    pub fn with_local_volatility(mut self, surface: VolSurface) -> Self {
+        self.local_volatility = Some(surface);
+        self
+    }
+
+    /// Solve the pricing PDE backwards from maturity to the valuation date.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to price.
+    ///
+    /// # Returns
+    ///
+    /// The option values on the grid at the valuation date or an error if the option cannot be priced.
+    fn solve<T: Option>(&self, option: &T) -> Result<GridSolution, PricingError> {
+        validate_option(option)?;
+        let american = match option.style() {
+            OptionStyle::European => false,
+            OptionStyle::American => true,
+            OptionStyle::Bermudan => false,
+            style => return Err(PricingError::unsupported_style("FiniteDiffModel", *style)),
+        };
+
+        let instrument = option.instrument();
+        let ttm = option.time_to_maturity();
+        let r = self.risk_free_rate;
+        let spot = instrument.spot() - instrument.dividends_present_value(r, 0.0, ttm);
+        if !instrument.cash_dividends.is_empty() && spot <= 0.0 {
+            return Err(PricingError::invalid_input(
+                "Cash dividends must not exceed the spot price",
+            ));
+        }
+        let q = instrument.continuous_dividend_yield;
+        // The grid covers the at-the-money implied volatility of a local volatility surface
+        let sigma = match &self.local_volatility {
+            Some(surface) => surface.volatility(ttm, surface.forward(ttm)),
+            None => self.volatility,
+        };
+
+        // Log-spot grid centred on the current spot, wide enough to contain the strike
+        let n = self.space_steps;
+        let half_width = (GRID_STD_DEVS * sigma * ttm.sqrt())
+            .max(1.2 * (option.strike() / spot).ln().abs())
+            .max(0.05);
+        let dx = 2.0 * half_width / n as f64;
+        let spots: Vec<f64> = (0..=n)
+            .map(|i| spot * (-half_width + i as f64 * dx).exp())
+            .collect();
+        // Payoffs at time to maturity τ, adding back the dividends going ex after the exercise date
+        let exercise = |tau: f64| -> Vec<f64> {
+            let dividends = instrument.dividends_present_value(r, ttm - tau, ttm);
+            spots
+                .iter()
+                .map(|&s| option.payoff(Some(s + dividends)))
+                .collect()
+        };
+        let mut payoffs = exercise(0.0);
+
+        // Spatial operator L V_i = a_i V_{i-1} + b_i V_i + c_i V_{i+1} given the volatilities at the nodes
+        let operator = |volatilities: &[f64]| -> Vec<[f64; 3]> {
+            volatilities
+                .iter()
+                .map(|sigma| {
+                    let drift = r - q - 0.5 * sigma * sigma;
+                    let diffusion = 0.5 * sigma * sigma / (dx * dx);
+                    [
+                        diffusion - drift / (2.0 * dx),
+                        -2.0 * diffusion - r,
+                        diffusion + drift / (2.0 * dx),
+                    ]
+                })
+                .collect()
+        };
+        // Local volatilities at the nodes at time to maturity τ (at the spot including the dividends going ex later)
+        let local_volatilities = |tau: f64| {
+            self.local_volatility.as_ref().map(|surface| {
+                let dividends = instrument.dividends_present_value(r, ttm - tau, ttm);
+                spots
+                    .iter()
+                    .map(|&s| surface.local_volatility(ttm - tau, s + dividends))
+                    .collect::<Vec<f64>>()
+            })
+        };
+        let mut coefficients = operator(&vec![sigma; n + 1]);
+
+        let mut steps = self.steps;
+        if self.scheme == FiniteDiffScheme::Explicit {
+            // Stability requires 1 + Δτ b_i >= 0, use half of the limit to avoid odd-even oscillations
+            let max_diffusion = (0..=10)
+                .filter_map(|i| local_volatilities(ttm * i as f64 / 10.0))
+                .map(|volatilities| operator(&volatilities))
+                .chain(std::iter::once(coefficients.clone()))
+                .flatten()
+                .map(|[a, _, c]| a + c)
+                .fold(0.0, f64::max);
+            steps = steps.max((2.0 * ttm * (max_diffusion + r.abs())).ceil() as usize);
+        }
+        let dt = ttm / steps as f64;
+
+        // Exercise dates of a Bermudan option, snapped to the time grid (in steps from maturity)
+        let exercise_steps: Vec<usize> = match option.style() {
+            OptionStyle::Bermudan => option
+                .expiration_dates()
+                .map(|dates| {
+                    dates
+                        .iter()
+                        .filter(|&&date| date < ttm)
+                        .map(|&date| (((ttm - date) / dt).round() as usize).min(steps))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        };
+
+        // Dirichlet boundaries: discounted payoff of the forward (or intrinsic value if exercisable)
+        let boundary = |s: f64, tau: f64| {
+            let value = option.payoff(Some(s * ((r - q) * tau).exp())) * (-r * tau).exp();
+            if american {
+                let dividends = instrument.dividends_present_value(r, ttm - tau, ttm);
+                value.max(option.payoff(Some(s + dividends)))
+            } else {
+                value
+            }
+        };
+
+        let mut values = payoffs.clone();
+        let mut previous = values.clone();
+        let mut tau = 0.0;
+        for step in 1..=steps {
+            previous.clone_from(&values);
+            let sub_steps: &[(f64, f64)] =
+                if self.scheme == FiniteDiffScheme::CrankNicolson && step <= self.rannacher_steps {
+                    &[(1.0, 0.5), (1.0, 0.5)]
+                } else {
+                    &[(self.scheme.theta(), 1.0)]
+                };
+
+            for &(theta, fraction) in sub_steps {
+                let h = fraction * dt;
+                tau += h;
+                if !instrument.cash_dividends.is_empty() {
+                    payoffs = exercise(tau);
+                }
+                if let Some(volatilities) = local_volatilities(tau - 0.5 * h) {
+                    coefficients = operator(&volatilities);
+                }
+                let lower = boundary(spots[0], tau);
+                let upper = boundary(spots[n], tau);
+
+                // Right-hand side (I + (1 - θ) h L) V for the interior nodes
+                let mut rhs: Vec<f64> = (1..n)
+                    .map(|i| {
+                        let [a, b, c] = coefficients[i];
+                        values[i]
+                            + (1.0 - theta)
+                                * h
+                                * (a * values[i - 1] + b * values[i] + c * values[i + 1])
+                    })
+                    .collect();
+                rhs[0] += theta * h * coefficients[1][0] * lower;
+                rhs[n - 2] += theta * h * coefficients[n - 1][2] * upper;
+
+                let interior = if theta == 0.0 {
+                    rhs
+                } else {
+                    let interior = &coefficients[1..n];
+                    let sub: Vec<f64> = interior.iter().map(|[a, _, _]| -theta * h * a).collect();
+                    let diag: Vec<f64> = interior
+                        .iter()
+                        .map(|[_, b, _]| 1.0 - theta * h * b)
+                        .collect();
+                    let sup: Vec<f64> = interior.iter().map(|[_, _, c]| -theta * h * c).collect();
+                    if american {
+                        numerics::psor(
+                            &sub,
+                            &diag,
+                            &sup,
+                            &rhs,
+                            &payoffs[1..n],
+                            &values[1..n],
+                            1.2,
+                            1e-10,
+                            10_000,
+                        )
+                    } else {
+                        numerics::solve_tridiagonal(&sub, &diag, &sup, &rhs)
+                    }
+                };
+
+                values[0] = lower;
+                values[1..n].copy_from_slice(&interior);
+                values[n] = upper;
+                if american && theta == 0.0 {
+                    for (value, payoff) in values.iter_mut().zip(&payoffs) {
+                        *value = value.max(*payoff);
+                    }
+                }
+            }
+
+            if exercise_steps.contains(&step) {
+                for (value, payoff) in values.iter_mut().zip(&payoffs) {
+                    *value = value.max(*payoff);
+                }
+            }
         }
+
+        Ok(GridSolution {
+            spot,
+            dx,
+            index: n / 2,
+            values,
+            previous,
+            dt,
+        })
     }
 }
 
 impl OptionPricing for FiniteDiffModel {
     fn price<T: Option>(&self, option: &T) -> f64 {
-        panic!("FiniteDiffModel does not support price calculation yet");
+        self.try_price(option).unwrap_or_else(|err| panic!("{err}"))
     }
 
-    fn implied_volatility<T: Option>(&self, _option: &T, _market_price: f64) -> f64 {
-        panic!("FiniteDiffModel does not support implied volatility calculation yet");
+    fn try_price<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        if option.time_to_maturity() <= 0.0 {
+            validate_option(option)?;
+            return Ok(option.payoff(None));
+        }
+        let solution = self.solve(option)?;
+        Ok(solution.values[solution.index])
+    }
+
+    fn implied_volatility<T: Option>(&self, option: &T, market_price: f64) -> f64 {
+        self.try_implied_volatility(option, market_price)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_implied_volatility<T: Option>(
+        &self,
+        _option: &T,
+        _market_price: f64,
+    ) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Implied volatility"))
+    }
+}
+
+impl OptionGreeks for FiniteDiffModel {
+    fn try_delta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let solution = self.solve(option)?;
+        let (i, dx, v) = (solution.index, solution.dx, &solution.values);
+        Ok((v[i + 1] - v[i - 1]) / (2.0 * dx) / solution.spot)
+    }
+
+    fn try_gamma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let solution = self.solve(option)?;
+        let (i, dx, v) = (solution.index, solution.dx, &solution.values);
+        let first = (v[i + 1] - v[i - 1]) / (2.0 * dx);
+        let second = (v[i + 1] - 2.0 * v[i] + v[i - 1]) / (dx * dx);
+        Ok((second - first) / solution.spot.powi(2))
+    }
+
+    fn try_theta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        if option.time_to_maturity() <= 0.0 {
+            validate_option(option)?;
+            return Ok(0.0);
+        }
+        let solution = self.solve(option)?;
+        let i = solution.index;
+        Ok((solution.values[i] - solution.previous[i]) / solution.dt)
+    }
+
+    fn try_vega<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let h = 1e-4;
+        let mut up = self.clone();
+        up.set_volatility(self.volatility + h);
+        let mut down = self.clone();
+        down.set_volatility(self.volatility - h);
+
+        Ok((up.try_price(option)? - down.try_price(option)?) / (2.0 * h))
+    }
+
+    fn try_rho<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let h = 1e-4;
+        let up = Self {
+            risk_free_rate: self.risk_free_rate + h,
+            ..self.clone()
+        };
+        let down = Self {
+            risk_free_rate: self.risk_free_rate - h,
+            ..self.clone()
+        };
+
+        Ok((up.try_price(option)? - down.try_price(option)?) / (2.0 * h))
+    }
+}
+
+impl ModelParameters for FiniteDiffModel {
+    fn risk_free_rate(&self) -> f64 {
+        self.risk_free_rate
+    }
+
+    fn set_risk_free_rate(&mut self, risk_free_rate: f64) {
+        self.risk_free_rate = risk_free_rate;
+    }
+
+    fn volatility(&self) -> f64 {
+        self.volatility
+    }
+
+    /// Set the volatility, shifting the local volatility surface in parallel by the change of the volatility.
+    fn set_volatility(&mut self, volatility: f64) {
+        if let Some(surface) = &mut self.local_volatility {
+            *surface = surface.shifted(volatility - self.volatility);
+        }
+        self.volatility = volatility;
     }
 }
 
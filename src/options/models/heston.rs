@@ -1,37 +1,685 @@
 //! Module for Heston option pricing model.
+//!
+//! The Heston model is a stochastic volatility model in which the variance of the underlying asset follows a
+//! mean-reverting square-root (CIR) process that is correlated with the asset price.
+//! Unlike the Black-Scholes model, it can reproduce the volatility smile and skew observed in option markets.
+//!
+//! ## Dynamics
+//!
+//! ```text
+//! dS = (r - q) S dt + √v S dW₁
+//! dv = κ (θ - v) dt + ξ √v dW₂
+//! dW₁ dW₂ = ρ dt
+//! ```
+//!
+//! where:
+//! - `v0` is the initial variance.
+//! - `κ` (kappa) is the speed of mean reversion of the variance.
+//! - `θ` (theta) is the long-run variance.
+//! - `ξ` (xi) is the volatility of the variance ("vol of vol").
+//! - `ρ` (rho) is the correlation between the asset and its variance.
+//!
+//! ## Pricing
+//!
+//! European options are priced semi-analytically by Fourier inversion of the characteristic function of `ln S(T)`:
+//!
+//! ```text
+//! C = S e^(-qT) P1 - K e^(-rT) P2
+//! Pj = 1/2 + 1/π ∫ Re[e^(-iu ln K) fj(u) / (iu)] du
+//! ```
+//!
+//! The characteristic function uses the "little Heston trap" formulation of Albrecher et al., which avoids
+//! the branch cut discontinuities of the original formula for long maturities.
+//! Puts are priced by put-call parity. Cash dividends are handled with the escrowed dividend model, i.e. `S` is the
+//! spot price less the present value of the dividends going ex before expiry.
+//!
+//! ## References
+//!
+//! - [Wikipedia - Heston model](https://en.wikipedia.org/wiki/Heston_model)
+//! - Heston, S. L. A Closed-Form Solution for Options with Stochastic Volatility with Applications to Bond and Currency Options, The Review of Financial Studies, 1993
+//! - Albrecher, H., Mayer, P., Schoutens, W., Tistaert, J. The Little Heston Trap, Wilmott Magazine, 2007
+//!
+//! ## Example
+//!
+//! ```
+//! use quantrs::options::{OptionPricing, HestonModel, EuropeanOption, Instrument, OptionType};
+//!
+//! let instrument = Instrument::new().with_spot(100.0);
+//! let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+//! let model = HestonModel::new(0.05, 0.04, 2.0, 0.04, 0.3, -0.7);
+//!
+//! let price = model.price(&option);
+//! println!("Option price: {price}");
+//! ```
+//!
+//! ## Calibration
+//!
+//! The five Heston parameters can be fitted to a set of market quotes on a single underlying with a
+//! [`HestonCalibrator`], which minimises the squared price or implied volatility residuals with a bounded
+//! Levenberg-Marquardt optimiser.
+//!
+//! ```
+//! use quantrs::options::{HestonModel, Instrument, MarketQuote, OptionType};
+//!
+//! let instrument = Instrument::new().with_spot(100.0);
+//! let quotes = [
+//!     MarketQuote::implied_volatility(90.0, 0.5, OptionType::Put, 0.24),
+//!     MarketQuote::implied_volatility(100.0, 0.5, OptionType::Call, 0.21),
+//!     MarketQuote::implied_volatility(110.0, 0.5, OptionType::Call, 0.19),
+//!     MarketQuote::implied_volatility(90.0, 1.0, OptionType::Put, 0.235),
+//!     MarketQuote::implied_volatility(100.0, 1.0, OptionType::Call, 0.215),
+//!     MarketQuote::implied_volatility(110.0, 1.0, OptionType::Call, 0.2),
+//! ];
+//!
+//! let initial = HestonModel::new(0.02, 0.04, 1.0, 0.04, 0.5, -0.5);
+//! let calibration = initial.calibrate(&instrument, &quotes).unwrap();
+//! println!("Fitted model: {:?}", calibration.model);
+//! println!("Residuals: {:?}", calibration.residuals);
+//! ```
 
-use crate::options::{Option, OptionPricing, OptionStrategy};
+use crate::options::{
+    BlackScholesModel, EuropeanOption, Instrument, MarketQuote, ModelParameters, Option,
+    OptionGreeks, OptionPricing, OptionStrategy, OptionStyle, OptionType, PricingError, QuoteValue,
+    numerics, validate_option,
+};
+use num_complex::Complex64;
+use rayon::prelude::*;
 
 /// Heston option pricing model.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct HestonModel {
     /// Risk-free interest rate (e.g., 0.05 for 5%).
     pub risk_free_rate: f64,
-    /// Volatility of the underlying asset (e.g., 0.2 for 20%).
-    pub volatility: f64,
-    /// Number of steps in the binomial tree.
-    pub steps: usize,
+    /// Initial variance of the underlying asset (e.g., 0.04 for 20% volatility).
+    pub v0: f64,
+    /// Speed of mean reversion of the variance.
+    pub kappa: f64,
+    /// Long-run variance (e.g., 0.04 for 20% volatility).
+    pub theta: f64,
+    /// Volatility of the variance (aka vol of vol).
+    pub xi: f64,
+    /// Correlation between the asset and its variance (between -1 and 1).
+    pub rho: f64,
 }
 
 impl HestonModel {
     /// Create a new `HestonModel`.
-    pub fn new(risk_free_rate: f64, volatility: f64, steps: usize) -> Self {
+    ///
+    /// # Arguments
+    ///
+    /// * `risk_free_rate` - Risk-free interest rate (e.g., 0.05 for 5%).
+    /// * `v0` - Initial variance of the underlying asset (e.g., 0.04 for 20% volatility).
+    /// * `kappa` - Speed of mean reversion of the variance.
+    /// * `theta` - Long-run variance.
+    /// * `xi` - Volatility of the variance.
+    /// * `rho` - Correlation between the asset and its variance.
+    ///
+    /// # Returns
+    ///
+    /// A new `HestonModel`.
+    pub fn new(risk_free_rate: f64, v0: f64, kappa: f64, theta: f64, xi: f64, rho: f64) -> Self {
         Self {
             risk_free_rate,
-            volatility,
-            steps,
+            v0,
+            kappa,
+            theta,
+            xi,
+            rho,
+        }
+    }
+
+    /// Check whether the Feller condition `2κθ > ξ²` holds, i.e. whether the variance stays strictly positive.
+    ///
+    /// # Returns
+    ///
+    /// True if the Feller condition is satisfied, false otherwise.
+    pub fn feller_condition(&self) -> bool {
+        2.0 * self.kappa * self.theta > self.xi.powi(2)
+    }
+
+    /// Expected integrated variance `∫ E[v(t)] dt` over `[0, ttm]`.
+    fn integrated_variance(&self, ttm: f64) -> f64 {
+        if self.kappa.abs() < 1e-12 {
+            return self.v0 * ttm;
+        }
+        self.theta * ttm + (self.v0 - self.theta) * (1.0 - (-self.kappa * ttm).exp()) / self.kappa
+    }
+
+    /// Calculate the characteristic function of the log-price `ln S(T)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - The (complex) argument of the characteristic function.
+    /// * `spot` - The spot price of the underlying asset.
+    /// * `dividend_yield` - The continuous dividend yield of the underlying asset.
+    /// * `ttm` - Time to maturity of the option.
+    ///
+    /// # Returns
+    ///
+    /// The value of the characteristic function `E[exp(iu ln S(T))]`.
+    pub fn characteristic_function(
+        &self,
+        u: Complex64,
+        spot: f64,
+        dividend_yield: f64,
+        ttm: f64,
+    ) -> Complex64 {
+        let i = Complex64::i();
+        let one = Complex64::new(1.0, 0.0);
+        let xi2 = self.xi * self.xi;
+
+        let beta = self.kappa - self.rho * self.xi * i * u;
+        let d = (beta * beta + xi2 * (u * u + i * u)).sqrt();
+        let g = (beta - d) / (beta + d);
+        let exp_dt = (-d * ttm).exp();
+
+        let c = i * u * (spot.ln() + (self.risk_free_rate - dividend_yield) * ttm)
+            + self.kappa * self.theta / xi2
+                * ((beta - d) * ttm - 2.0 * ((one - g * exp_dt) / (one - g)).ln());
+        let d_term = (beta - d) / xi2 * (one - exp_dt) / (one - g * exp_dt);
+
+        (c + d_term * self.v0).exp()
+    }
+
+    /// Calculate the probabilities `P1` and `P2` of the Heston pricing formula.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot` - The spot price of the underlying asset.
+    /// * `strike` - The strike price of the option.
+    /// * `dividend_yield` - The continuous dividend yield of the underlying asset.
+    /// * `ttm` - Time to maturity of the option.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing `P1` (delta probability) and `P2` (exercise probability).
+    fn probabilities(&self, spot: f64, strike: f64, dividend_yield: f64, ttm: f64) -> (f64, f64) {
+        let i = Complex64::i();
+        let ln_k = strike.ln();
+        let forward = spot * ((self.risk_free_rate - dividend_yield) * ttm).exp();
+
+        // The integrand decays roughly like exp(-w u² / 2) where w is the integrated variance
+        let w = self.integrated_variance(ttm).max(1e-8);
+        let upper = (12.0 / w.sqrt()).clamp(50.0, 5_000.0);
+        let intervals = (upper / 2.0).ceil() as usize;
+        let rule = numerics::gauss_legendre(16);
+
+        let integral = |shift: Complex64, norm: f64| {
+            numerics::integrate(
+                |u| {
+                    let z = Complex64::new(u, 0.0);
+                    let phi = self.characteristic_function(z + shift, spot, dividend_yield, ttm);
+                    ((-i * z * ln_k).exp() * phi / (i * z * norm)).re
+                },
+                0.0,
+                upper,
+                intervals,
+                &rule,
+            )
+        };
+
+        let p1 = 0.5 + integral(-i, forward) / std::f64::consts::PI;
+        let p2 = 0.5 + integral(Complex64::new(0.0, 0.0), 1.0) / std::f64::consts::PI;
+
+        (p1, p2)
+    }
+
+    /// Calculate the price of a European option under the Heston model.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot` - The spot price of the underlying asset.
+    /// * `strike` - The strike price of the option.
+    /// * `dividend_yield` - The continuous dividend yield of the underlying asset.
+    /// * `ttm` - Time to maturity of the option.
+    /// * `option_type` - The type of the option (Call or Put).
+    ///
+    /// # Returns
+    ///
+    /// The price of the option.
+    fn price_european(
+        &self,
+        spot: f64,
+        strike: f64,
+        dividend_yield: f64,
+        ttm: f64,
+        option_type: OptionType,
+    ) -> f64 {
+        if ttm <= 0.0 {
+            return match option_type {
+                OptionType::Call => (spot - strike).max(0.0),
+                OptionType::Put => (strike - spot).max(0.0),
+            };
+        }
+
+        let discounted_spot = spot * (-dividend_yield * ttm).exp();
+        let discounted_strike = strike * (-self.risk_free_rate * ttm).exp();
+
+        // Without vol of vol the variance is deterministic, so Black-Scholes with the integrated variance is exact
+        if self.xi < 1e-6 {
+            let volatility = (self.integrated_variance(ttm) / ttm).sqrt();
+            let option = EuropeanOption::new(
+                Instrument::new()
+                    .with_spot(spot)
+                    .with_continuous_dividend_yield(dividend_yield),
+                strike,
+                ttm,
+                option_type,
+            );
+            return BlackScholesModel::new(self.risk_free_rate, volatility).price(&option);
+        }
+
+        let (p1, p2) = self.probabilities(spot, strike, dividend_yield, ttm);
+        let call = (discounted_spot * p1 - discounted_strike * p2).max(0.0);
+
+        match option_type {
+            OptionType::Call => call,
+            OptionType::Put => (call - discounted_spot + discounted_strike).max(0.0),
+        }
+    }
+
+    /// Calibrate the model to market quotes using the default [`HestonCalibrator`].
+    ///
+    /// The current parameters of the model are used as the initial guess.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument` - The underlying instrument of the quoted options.
+    /// * `quotes` - The market quotes to fit.
+    ///
+    /// # Returns
+    ///
+    /// The result of the calibration or an error if a quote is invalid.
+    pub fn calibrate(
+        &self,
+        instrument: &Instrument,
+        quotes: &[MarketQuote],
+    ) -> Result<HestonCalibration, PricingError> {
+        HestonCalibrator::default().calibrate(self, instrument, quotes)
+    }
+
+    /// Price a (European) option with bumped inputs.
+    fn price_bumped<T: Option>(&self, option: &T, spot: f64, ttm: f64) -> f64 {
+        self.price_european(
+            spot,
+            option.strike(),
+            option.instrument().continuous_dividend_yield,
+            ttm,
+            option.option_type(),
+        )
+    }
+
+    /// Get the escrowed spot price of the option's underlying, on which the Heston dynamics are applied.
+    fn escrowed_spot<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let instrument = option.instrument();
+        let spot = instrument.escrowed_spot(self.risk_free_rate, option.time_to_maturity());
+        if !instrument.cash_dividends.is_empty() && spot <= 0.0 {
+            return Err(PricingError::invalid_input(
+                "Cash dividends must not exceed the spot price",
+            ));
+        }
+        Ok(spot)
+    }
+
+    /// Check that the option is valid and its style is supported by the Heston model.
+    fn check_option<T: Option>(option: &T) -> Result<(), PricingError> {
+        validate_option(option)?;
+        match option.style() {
+            OptionStyle::European => Ok(()),
+            style => Err(PricingError::unsupported_style("HestonModel", *style)),
         }
     }
 }
 
 impl OptionPricing for HestonModel {
     fn price<T: Option>(&self, option: &T) -> f64 {
-        panic!("HestonModel does not support price calculation yet");
+        self.try_price(option).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_price<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Self::check_option(option)?;
+        Ok(self.price_bumped(
+            option,
+            self.escrowed_spot(option)?,
+            option.time_to_maturity(),
+        ))
+    }
+
+    /// Calculate the Black-Scholes implied volatility of a given market price.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to calculate the implied volatility for.
+    /// * `market_price` - The market price of the option.
+    ///
+    /// # Returns
+    ///
+    /// The implied volatility of the option.
+    fn implied_volatility<T: Option>(&self, option: &T, market_price: f64) -> f64 {
+        BlackScholesModel::new(self.risk_free_rate, self.v0.sqrt())
+            .implied_volatility(option, market_price)
+    }
+
+    fn try_implied_volatility<T: Option>(
+        &self,
+        option: &T,
+        market_price: f64,
+    ) -> Result<f64, PricingError> {
+        BlackScholesModel::new(self.risk_free_rate, self.v0.sqrt())
+            .try_implied_volatility(option, market_price)
+    }
+}
+
+impl OptionGreeks for HestonModel {
+    fn try_delta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Self::check_option(option)?;
+        let instrument = option.instrument();
+        let ttm = option.time_to_maturity();
+        let q = instrument.continuous_dividend_yield;
+        let spot = self.escrowed_spot(option)?;
+
+        if self.xi < 1e-6 {
+            let h = 1e-4 * spot;
+            return Ok((self.price_bumped(option, spot + h, ttm)
+                - self.price_bumped(option, spot - h, ttm))
+                / (2.0 * h));
+        }
+
+        let (p1, _) = self.probabilities(spot, option.strike(), q, ttm);
+        Ok(match option.option_type() {
+            OptionType::Call => (-q * ttm).exp() * p1,
+            OptionType::Put => (-q * ttm).exp() * (p1 - 1.0),
+        })
     }
 
-    fn implied_volatility<T: Option>(&self, _option: &T, _market_price: f64) -> f64 {
-        panic!("HestonModel does not support implied volatility calculation yet");
+    fn try_gamma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Self::check_option(option)?;
+        let spot = self.escrowed_spot(option)?;
+        let ttm = option.time_to_maturity();
+        let h = 1e-3 * spot;
+
+        Ok(
+            (self.price_bumped(option, spot + h, ttm) - 2.0 * self.price_bumped(option, spot, ttm)
+                + self.price_bumped(option, spot - h, ttm))
+                / (h * h),
+        )
+    }
+
+    /// Vega with respect to the initial variance `v0`.
+    fn try_vega<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Self::check_option(option)?;
+        let h = 1e-4 * self.v0.max(1e-4);
+        let up = Self {
+            v0: self.v0 + h,
+            ..self.clone()
+        };
+        let down = Self {
+            v0: (self.v0 - h).max(0.0),
+            ..self.clone()
+        };
+
+        Ok((up.try_price(option)? - down.try_price(option)?) / (up.v0 - down.v0))
+    }
+
+    fn try_theta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Self::check_option(option)?;
+        let spot = self.escrowed_spot(option)?;
+        let ttm = option.time_to_maturity();
+        let h = 1e-4 * ttm.max(1e-2);
+
+        Ok((self.price_bumped(option, spot, ttm + h)
+            - self.price_bumped(option, spot, (ttm - h).max(0.0)))
+            / (ttm + h - (ttm - h).max(0.0)))
+    }
+
+    fn try_rho<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Self::check_option(option)?;
+        let h = 1e-4;
+        let up = Self {
+            risk_free_rate: self.risk_free_rate + h,
+            ..self.clone()
+        };
+        let down = Self {
+            risk_free_rate: self.risk_free_rate - h,
+            ..self.clone()
+        };
+
+        Ok((up.try_price(option)? - down.try_price(option)?) / (2.0 * h))
+    }
+}
+
+/// The volatility of the Heston model is its initial volatility `√v0`.
+impl ModelParameters for HestonModel {
+    fn risk_free_rate(&self) -> f64 {
+        self.risk_free_rate
+    }
+
+    fn set_risk_free_rate(&mut self, risk_free_rate: f64) {
+        self.risk_free_rate = risk_free_rate;
+    }
+
+    fn volatility(&self) -> f64 {
+        self.v0.sqrt()
+    }
+
+    fn set_volatility(&mut self, volatility: f64) {
+        self.v0 = volatility.powi(2);
     }
 }
 
 impl OptionStrategy for HestonModel {}
+
+/// Enum for the space in which calibration residuals are measured.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum CalibrationTarget {
+    /// Residuals are differences between model and market prices.
+    Price,
+    /// Residuals are differences between model and market Black-Scholes implied volatilities.
+    #[default]
+    ImpliedVolatility,
+}
+
+/// Residual assigned to a quote that cannot be priced with the trial parameters of a calibration.
+const FAILED_PRICING_RESIDUAL: f64 = 1e6;
+
+/// Calibrator fitting the parameters `[v0, κ, θ, ξ, ρ]` of a [`HestonModel`] to market quotes.
+#[derive(Debug, Clone)]
+pub struct HestonCalibrator {
+    /// Space in which the residuals are measured.
+    pub target: CalibrationTarget,
+    /// Maximum number of optimiser iterations.
+    pub max_iterations: usize,
+    /// Tolerance on the relative improvement of the objective.
+    pub tolerance: f64,
+    /// Lower bounds of `[v0, κ, θ, ξ, ρ]`.
+    pub lower_bounds: [f64; 5],
+    /// Upper bounds of `[v0, κ, θ, ξ, ρ]`.
+    pub upper_bounds: [f64; 5],
+}
+
+impl Default for HestonCalibrator {
+    fn default() -> Self {
+        Self {
+            target: CalibrationTarget::ImpliedVolatility,
+            max_iterations: 200,
+            tolerance: 1e-10,
+            lower_bounds: [1e-4, 1e-3, 1e-4, 1e-3, -0.999],
+            upper_bounds: [2.0, 20.0, 2.0, 5.0, 0.999],
+        }
+    }
+}
+
+/// Result of a Heston calibration.
+#[derive(Debug, Clone)]
+pub struct HestonCalibration {
+    /// The calibrated model.
+    pub model: HestonModel,
+    /// Model minus market residual of each quote (in the calibration target space).
+    pub residuals: Vec<f64>,
+    /// Root mean squared residual.
+    pub rmse: f64,
+    /// Number of optimiser iterations.
+    pub iterations: usize,
+    /// Whether the optimiser converged before reaching the iteration limit.
+    pub converged: bool,
+}
+
+impl HestonCalibrator {
+    /// Create a new `HestonCalibrator` with default bounds and tolerances.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - Space in which the residuals are measured.
+    ///
+    /// # Returns
+    ///
+    /// A new `HestonCalibrator`.
+    pub fn new(target: CalibrationTarget) -> Self {
+        Self {
+            target,
+            ..Self::default()
+        }
+    }
+
+    /// Set the parameter bounds of the calibrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `lower_bounds` - Lower bounds of `[v0, κ, θ, ξ, ρ]`.
+    /// * `upper_bounds` - Upper bounds of `[v0, κ, θ, ξ, ρ]`.
+    ///
+    /// # Returns
+    ///
+    /// The calibrator with the bounds set.
+    pub fn with_bounds(mut self, lower_bounds: [f64; 5], upper_bounds: [f64; 5]) -> Self {
+        self.lower_bounds = lower_bounds;
+        self.upper_bounds = upper_bounds;
+        self
+    }
+
+    /// Set the maximum number of optimiser iterations.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_iterations` - The maximum number of iterations.
+    ///
+    /// # Returns
+    ///
+    /// The calibrator with the iteration limit set.
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Calibrate a Heston model to market quotes.
+    ///
+    /// # Arguments
+    ///
+    /// * `initial` - The initial guess (its risk-free rate is kept fixed).
+    /// * `instrument` - The underlying instrument of the quoted options.
+    /// * `quotes` - The market quotes to fit.
+    ///
+    /// # Returns
+    ///
+    /// The result of the calibration or an error if a quote is invalid, e.g. a price outside the no-arbitrage bounds.
+    pub fn calibrate(
+        &self,
+        initial: &HestonModel,
+        instrument: &Instrument,
+        quotes: &[MarketQuote],
+    ) -> Result<HestonCalibration, PricingError> {
+        let r = initial.risk_free_rate;
+        let options: Vec<EuropeanOption> = quotes
+            .iter()
+            .map(|q| {
+                EuropeanOption::new(
+                    instrument.clone(),
+                    q.strike,
+                    q.time_to_maturity,
+                    q.option_type,
+                )
+            })
+            .collect();
+
+        // Express all market quotes in the calibration target space
+        let market = quotes
+            .iter()
+            .zip(&options)
+            .map(|(q, option)| {
+                let bs = match q.value {
+                    QuoteValue::ImpliedVolatility(vol) if !(vol > 0.0 && vol.is_finite()) => {
+                        return Err(PricingError::InvalidInput(format!(
+                            "Implied volatility quotes must be finite and positive, got {vol}"
+                        )));
+                    }
+                    QuoteValue::ImpliedVolatility(vol) => BlackScholesModel::new(r, vol),
+                    QuoteValue::Price(_) => BlackScholesModel::new(r, initial.v0.sqrt()),
+                };
+                match (self.target, q.value) {
+                    (CalibrationTarget::Price, QuoteValue::Price(price)) => {
+                        // Reject prices outside the no-arbitrage bounds
+                        bs.try_implied_volatility(option, price)?;
+                        Ok(price)
+                    }
+                    (CalibrationTarget::Price, QuoteValue::ImpliedVolatility(_)) => {
+                        bs.try_price(option)
+                    }
+                    (CalibrationTarget::ImpliedVolatility, QuoteValue::ImpliedVolatility(vol)) => {
+                        validate_option(option)?;
+                        Ok(vol)
+                    }
+                    (CalibrationTarget::ImpliedVolatility, QuoteValue::Price(price)) => {
+                        bs.try_implied_volatility(option, price)
+                    }
+                }
+            })
+            .collect::<Result<Vec<f64>, PricingError>>()?;
+
+        let model_from = |p: &[f64]| HestonModel::new(r, p[0], p[1], p[2], p[3], p[4]);
+        let residuals = |p: &[f64]| -> Vec<f64> {
+            let model = model_from(p);
+            options
+                .par_iter()
+                .zip(&market)
+                .map(|(option, market_value)| {
+                    let value = model.try_price(option).and_then(|price| match self.target {
+                        CalibrationTarget::Price => Ok(price),
+                        CalibrationTarget::ImpliedVolatility => {
+                            BlackScholesModel::new(r, market_value.abs())
+                                .try_implied_volatility(option, price)
+                        }
+                    });
+                    // Penalise trial parameters that cannot price the quote instead of aborting
+                    value.map_or(FAILED_PRICING_RESIDUAL, |value| value - market_value)
+                })
+                .collect()
+        };
+
+        let result = numerics::levenberg_marquardt(
+            residuals,
+            &[
+                initial.v0,
+                initial.kappa,
+                initial.theta,
+                initial.xi,
+                initial.rho,
+            ],
+            &self.lower_bounds,
+            &self.upper_bounds,
+            self.max_iterations,
+            self.tolerance,
+        );
+
+        let rmse = (result.residuals.iter().map(|r| r * r).sum::<f64>()
+            / result.residuals.len().max(1) as f64)
+            .sqrt();
+
+        Ok(HestonCalibration {
+            model: model_from(&result.params),
+            residuals: result.residuals,
+            rmse,
+            iterations: result.iterations,
+            converged: result.converged,
+        })
+    }
+}
@@ -0,0 +1,806 @@
+//! Module for the Merton and Kou jump-diffusion models.
+//!
+//! Jump-diffusion models add a compound Poisson process of jumps in the log-price to the geometric Brownian motion,
+//! which produces the steep short-dated smiles and skews that diffusions cannot:
+//!
+//! ```text
+//! dS / S = (r - q - λκ) dt + σ dW + (e^J - 1) dN
+//! ```
+//!
+//! where:
+//! - `N` is a Poisson process with intensity `λ` (the expected number of jumps per year).
+//! - `J` is the log jump size, which is normal with mean `μ` and standard deviation `δ` in the Merton model and
+//!   double exponential in the Kou model: an upward jump `Exp(η₁)` with probability `p` and a downward jump
+//!   `-Exp(η₂)` with probability `1 - p`.
+//! - `κ = E[e^J] - 1` is the mean relative jump size, which compensates the drift.
+//!
+//! ## Pricing
+//!
+//! - **Merton**: European options are priced with Merton's series of Black-Scholes prices conditional on the number
+//!   of jumps, `C = Σ e^(-λ'T) (λ'T)ⁿ / n! BS(S, K, T, rₙ, σₙ)` with `λ' = λ (1 + κ)`, `σₙ² = σ² + n δ² / T` and
+//!   `rₙ = r - λκ + n ln(1 + κ) / T`.
+//! - **Kou**: European options are priced by Fourier inversion of the characteristic function of `ln S(T)`, as in the
+//!   Heston model.
+//!
+//! Cash dividends are handled with the escrowed dividend model. Path-dependent options are priced by simulating the
+//! same jump processes in a [`MonteCarloModel`](crate::options::MonteCarloModel) (see `with_jumps`).
+//!
+//! ## References
+//!
+//! - Merton, R. C. Option pricing when underlying stock returns are discontinuous, Journal of Financial Economics 3,
+//!   1976
+//! - Kou, S. G. A Jump-Diffusion Model for Option Pricing, Management Science 48, 2002
+//!
+//! ## Example
+//!
+//! ```
+//! use quantrs::options::{
+//!     AsianOption, EuropeanOption, Instrument, KouJumpModel, MertonJumpModel, MonteCarloModel, OptionPricing,
+//!     OptionType,
+//! };
+//!
+//! let instrument = Instrument::new().with_spot(100.0);
+//! let option = EuropeanOption::new(instrument.clone(), 100.0, 0.25, OptionType::Put);
+//!
+//! let merton = MertonJumpModel::new(0.05, 0.2, 0.5, -0.1, 0.15);
+//! println!("Merton put: {}", merton.price(&option));
+//!
+//! let kou = KouJumpModel::new(0.05, 0.2, 1.0, 0.4, 10.0, 5.0);
+//! println!("Kou put: {}", kou.price(&option));
+//!
+//! // Path-dependent options on the same jump process
+//! let model = MonteCarloModel::arithmetic(0.05, 0.2, 10_000, 50)
+//!     .with_seed(42)
+//!     .with_jumps(kou.jump_process());
+//! let asian = AsianOption::fixed(instrument, 100.0, 0.25, OptionType::Put);
+//! println!("Kou Asian put: {}", model.price(&asian));
+//! ```
+
+use crate::options::{
+    BlackScholesModel, EuropeanOption, Instrument, ModelParameters, Option, OptionGreeks,
+    OptionPricing, OptionStrategy, OptionStyle, OptionType, PricingError, numerics,
+    validate_option,
+};
+use num_complex::Complex64;
+use rand::{SeedableRng, rngs::StdRng};
+use rand_distr::{Distribution, Open01};
+use statrs::distribution::{ContinuousCDF, Normal};
+
+/// Maximum number of jumps of the Merton series and of a simulated step.
+const MAX_JUMPS: usize = 200;
+
+/// Enum for the compound Poisson processes of jumps in the log-price.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JumpProcess {
+    /// Normal log jump sizes.
+    Merton {
+        /// Expected number of jumps per year.
+        intensity: f64,
+        /// Mean of the log jump size.
+        mean: f64,
+        /// Standard deviation of the log jump size.
+        volatility: f64,
+    },
+    /// Double exponential log jump sizes.
+    Kou {
+        /// Expected number of jumps per year.
+        intensity: f64,
+        /// Probability of an upward jump.
+        up_probability: f64,
+        /// Rate of the exponential upward jumps (above 1, so the mean relative jump is finite).
+        up_rate: f64,
+        /// Rate of the exponential downward jumps.
+        down_rate: f64,
+    },
+}
+
+impl JumpProcess {
+    /// Get the expected number of jumps per year.
+    pub fn intensity(&self) -> f64 {
+        match *self {
+            JumpProcess::Merton { intensity, .. } | JumpProcess::Kou { intensity, .. } => intensity,
+        }
+    }
+
+    /// Calculate the mean relative jump size `κ = E[e^J] - 1`, which compensates the drift.
+    pub fn compensator(&self) -> f64 {
+        match *self {
+            JumpProcess::Merton {
+                mean, volatility, ..
+            } => (mean + 0.5 * volatility * volatility).exp() - 1.0,
+            JumpProcess::Kou {
+                up_probability,
+                up_rate,
+                down_rate,
+                ..
+            } => {
+                up_probability * up_rate / (up_rate - 1.0)
+                    + (1.0 - up_probability) * down_rate / (down_rate + 1.0)
+                    - 1.0
+            }
+        }
+    }
+
+    /// Calculate the characteristic function of the log jump size.
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - The (complex) argument of the characteristic function.
+    ///
+    /// # Returns
+    ///
+    /// The value of `E[exp(iuJ)]`.
+    pub fn characteristic_function(&self, u: Complex64) -> Complex64 {
+        let i = Complex64::i();
+        match *self {
+            JumpProcess::Merton {
+                mean, volatility, ..
+            } => (i * u * mean - 0.5 * volatility * volatility * u * u).exp(),
+            JumpProcess::Kou {
+                up_probability,
+                up_rate,
+                down_rate,
+                ..
+            } => {
+                up_probability * up_rate / (up_rate - i * u)
+                    + (1.0 - up_probability) * down_rate / (down_rate + i * u)
+            }
+        }
+    }
+
+    /// Check that the parameters of the process are valid.
+    pub(crate) fn validate(&self) -> Result<(), PricingError> {
+        let valid = match *self {
+            JumpProcess::Merton {
+                intensity,
+                mean,
+                volatility,
+            } => intensity >= 0.0 && mean.is_finite() && volatility >= 0.0,
+            JumpProcess::Kou {
+                intensity,
+                up_probability,
+                up_rate,
+                down_rate,
+            } => {
+                intensity >= 0.0
+                    && (0.0..=1.0).contains(&up_probability)
+                    && up_rate > 1.0
+                    && down_rate > 0.0
+            }
+        };
+        if valid && self.intensity().is_finite() {
+            Ok(())
+        } else {
+            Err(PricingError::invalid_input(
+                "Jump intensity and jump size parameters are invalid",
+            ))
+        }
+    }
+
+    /// Simulate the sum of the log jumps over a time step.
+    ///
+    /// The number of jumps is the Poisson quantile of the count shock. Merton jumps are summed exactly with the size
+    /// shock; the first Kou jump is the double exponential quantile of the size shock and any further jumps of the
+    /// step are drawn from a generator seeded with it.
+    ///
+    /// # Arguments
+    ///
+    /// * `dt` - The length of the time step.
+    /// * `count_shock` - The standard normal shock determining the number of jumps.
+    /// * `size_shock` - The standard normal shock determining the jump sizes.
+    ///
+    /// # Returns
+    ///
+    /// The sum of the log jump sizes.
+    pub(crate) fn log_jumps(&self, dt: f64, count_shock: f64, size_shock: f64) -> f64 {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let mean_count = self.intensity() * dt;
+        if mean_count <= 0.0 {
+            return 0.0;
+        }
+
+        // Invert the Poisson distribution from the survival probability to keep precision in the tail
+        let survival = normal.cdf(-count_shock);
+        let (mut count, mut probability) = (0, (-mean_count).exp());
+        let mut tail = 1.0 - probability;
+        while tail > survival && count < MAX_JUMPS {
+            count += 1;
+            probability *= mean_count / count as f64;
+            tail -= probability;
+        }
+        if count == 0 {
+            return 0.0;
+        }
+
+        match *self {
+            JumpProcess::Merton {
+                mean, volatility, ..
+            } => count as f64 * mean + (count as f64).sqrt() * volatility * size_shock,
+            JumpProcess::Kou {
+                up_probability,
+                up_rate,
+                down_rate,
+                ..
+            } => {
+                let jump = |u: f64| {
+                    if u < 1.0 - up_probability {
+                        (u / (1.0 - up_probability)).ln() / down_rate
+                    } else {
+                        -((1.0 - u) / up_probability).ln() / up_rate
+                    }
+                };
+                let mut rng = StdRng::seed_from_u64(size_shock.to_bits());
+                jump(normal.cdf(size_shock).clamp(1e-300, 1.0 - 1e-16))
+                    + (1..count)
+                        .map(|_| jump(Distribution::<f64>::sample(&Open01, &mut rng)))
+                        .sum::<f64>()
+            }
+        }
+    }
+}
+
+/// Merton jump-diffusion model with normal log jump sizes.
+#[derive(Debug, Default, Clone)]
+pub struct MertonJumpModel {
+    /// Risk-free interest rate (e.g., 0.05 for 5%).
+    pub risk_free_rate: f64,
+    /// Volatility of the diffusion (e.g., 0.2 for 20%).
+    pub volatility: f64,
+    /// Expected number of jumps per year.
+    pub intensity: f64,
+    /// Mean of the log jump size.
+    pub jump_mean: f64,
+    /// Standard deviation of the log jump size.
+    pub jump_volatility: f64,
+}
+
+impl MertonJumpModel {
+    /// Create a new `MertonJumpModel`.
+    ///
+    /// # Arguments
+    ///
+    /// * `risk_free_rate` - Risk-free interest rate (e.g., 0.05 for 5%).
+    /// * `volatility` - Volatility of the diffusion (e.g., 0.2 for 20%).
+    /// * `intensity` - Expected number of jumps per year.
+    /// * `jump_mean` - Mean of the log jump size.
+    /// * `jump_volatility` - Standard deviation of the log jump size.
+    ///
+    /// # Returns
+    ///
+    /// A new `MertonJumpModel`.
+    pub fn new(
+        risk_free_rate: f64,
+        volatility: f64,
+        intensity: f64,
+        jump_mean: f64,
+        jump_volatility: f64,
+    ) -> Self {
+        Self {
+            risk_free_rate,
+            volatility,
+            intensity,
+            jump_mean,
+            jump_volatility,
+        }
+    }
+
+    /// Get the jump process of the model, e.g. to simulate it in a `MonteCarloModel`.
+    pub fn jump_process(&self) -> JumpProcess {
+        JumpProcess::Merton {
+            intensity: self.intensity,
+            mean: self.jump_mean,
+            volatility: self.jump_volatility,
+        }
+    }
+
+    /// Calculate the terms of the Merton series of an option.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The (European) option to price.
+    /// * `ttm` - Time to maturity of the option.
+    ///
+    /// # Returns
+    ///
+    /// The Poisson weight, the Black-Scholes model conditional on the number of jumps and the option on the escrowed
+    /// spot of every term.
+    fn terms<T: Option>(
+        &self,
+        option: &T,
+        ttm: f64,
+    ) -> Result<Vec<(f64, BlackScholesModel, EuropeanOption)>, PricingError> {
+        let (spot, dividend_yield) = escrowed_spot(option, self.risk_free_rate, ttm)?;
+        let european = EuropeanOption::new(
+            Instrument::new()
+                .with_spot(spot)
+                .with_continuous_dividend_yield(dividend_yield),
+            option.strike(),
+            ttm,
+            option.option_type(),
+        );
+        let kappa = self.jump_process().compensator();
+        let jump_intensity = self.intensity * (1.0 + kappa) * ttm;
+
+        let mut terms = Vec::new();
+        let mut weight = (-jump_intensity).exp();
+        for n in 0..MAX_JUMPS {
+            if n > 0 {
+                weight *= jump_intensity / n as f64;
+            }
+            let n = n as f64;
+            let (rate, volatility) = if ttm > 0.0 {
+                (
+                    self.risk_free_rate - self.intensity * kappa + n * (1.0 + kappa).ln() / ttm,
+                    (self.volatility.powi(2) + n * self.jump_volatility.powi(2) / ttm).sqrt(),
+                )
+            } else {
+                (self.risk_free_rate, self.volatility)
+            };
+            terms.push((
+                weight,
+                BlackScholesModel::new(rate, volatility),
+                european.clone(),
+            ));
+            if n > jump_intensity && weight < 1e-16 {
+                break;
+            }
+        }
+        Ok(terms)
+    }
+
+    /// Sum a quantity of the Black-Scholes models over the Merton series.
+    fn series<T, F>(&self, option: &T, ttm: f64, value: F) -> Result<f64, PricingError>
+    where
+        T: Option,
+        F: Fn(&BlackScholesModel, &EuropeanOption) -> Result<f64, PricingError>,
+    {
+        check_option(option, "MertonJumpModel")?;
+        self.jump_process().validate()?;
+        self.terms(option, ttm)?
+            .iter()
+            .map(|(weight, model, european)| Ok(weight * value(model, european)?))
+            .sum()
+    }
+}
+
+impl OptionPricing for MertonJumpModel {
+    fn price<T: Option>(&self, option: &T) -> f64 {
+        self.try_price(option).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_price<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.series(option, option.time_to_maturity(), |model, european| {
+            model.try_price(european)
+        })
+    }
+
+    /// Calculate the Black-Scholes implied volatility of a given market price.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to calculate the implied volatility for.
+    /// * `market_price` - The market price of the option.
+    ///
+    /// # Returns
+    ///
+    /// The implied volatility of the option.
+    fn implied_volatility<T: Option>(&self, option: &T, market_price: f64) -> f64 {
+        self.try_implied_volatility(option, market_price)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_implied_volatility<T: Option>(
+        &self,
+        option: &T,
+        market_price: f64,
+    ) -> Result<f64, PricingError> {
+        BlackScholesModel::new(self.risk_free_rate, self.volatility)
+            .try_implied_volatility(option, market_price)
+    }
+}
+
+/// Delta, gamma, vega and rho are summed over the Merton series, theta is calculated by bumping the time to maturity.
+impl OptionGreeks for MertonJumpModel {
+    fn try_delta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.series(option, option.time_to_maturity(), |model, european| {
+            model.try_delta(european)
+        })
+    }
+
+    fn try_gamma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.series(option, option.time_to_maturity(), |model, european| {
+            model.try_gamma(european)
+        })
+    }
+
+    /// Vega with respect to the volatility of the diffusion.
+    fn try_vega<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.series(option, option.time_to_maturity(), |model, european| {
+            // The conditional volatility σₙ depends on σ through dσₙ/dσ = σ / σₙ
+            let sensitivity = if model.volatility > 0.0 {
+                self.volatility / model.volatility
+            } else {
+                1.0
+            };
+            Ok(model.try_vega(european)? * sensitivity)
+        })
+    }
+
+    fn try_theta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let ttm = option.time_to_maturity();
+        let h = 1e-4 * ttm.max(1e-2);
+        let price = |ttm| self.series(option, ttm, |model, european| model.try_price(european));
+
+        Ok((price(ttm + h)? - price((ttm - h).max(0.0))?) / (ttm + h - (ttm - h).max(0.0)))
+    }
+
+    fn try_rho<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.series(option, option.time_to_maturity(), |model, european| {
+            model.try_rho(european)
+        })
+    }
+}
+
+/// The volatility of the Merton model is the volatility of its diffusion.
+impl ModelParameters for MertonJumpModel {
+    fn risk_free_rate(&self) -> f64 {
+        self.risk_free_rate
+    }
+
+    fn set_risk_free_rate(&mut self, risk_free_rate: f64) {
+        self.risk_free_rate = risk_free_rate;
+    }
+
+    fn volatility(&self) -> f64 {
+        self.volatility
+    }
+
+    fn set_volatility(&mut self, volatility: f64) {
+        self.volatility = volatility;
+    }
+}
+
+impl OptionStrategy for MertonJumpModel {}
+
+/// Kou jump-diffusion model with double exponential log jump sizes.
+#[derive(Debug, Default, Clone)]
+pub struct KouJumpModel {
+    /// Risk-free interest rate (e.g., 0.05 for 5%).
+    pub risk_free_rate: f64,
+    /// Volatility of the diffusion (e.g., 0.2 for 20%).
+    pub volatility: f64,
+    /// Expected number of jumps per year.
+    pub intensity: f64,
+    /// Probability of an upward jump.
+    pub up_probability: f64,
+    /// Rate of the exponential upward jumps (above 1, i.e. a mean upward jump below 100%).
+    pub up_rate: f64,
+    /// Rate of the exponential downward jumps.
+    pub down_rate: f64,
+}
+
+impl KouJumpModel {
+    /// Create a new `KouJumpModel`.
+    ///
+    /// # Arguments
+    ///
+    /// * `risk_free_rate` - Risk-free interest rate (e.g., 0.05 for 5%).
+    /// * `volatility` - Volatility of the diffusion (e.g., 0.2 for 20%).
+    /// * `intensity` - Expected number of jumps per year.
+    /// * `up_probability` - Probability of an upward jump.
+    /// * `up_rate` - Rate `η₁` of the exponential upward jumps (mean upward log jump `1 / η₁`).
+    /// * `down_rate` - Rate `η₂` of the exponential downward jumps (mean downward log jump `1 / η₂`).
+    ///
+    /// # Returns
+    ///
+    /// A new `KouJumpModel`.
+    pub fn new(
+        risk_free_rate: f64,
+        volatility: f64,
+        intensity: f64,
+        up_probability: f64,
+        up_rate: f64,
+        down_rate: f64,
+    ) -> Self {
+        Self {
+            risk_free_rate,
+            volatility,
+            intensity,
+            up_probability,
+            up_rate,
+            down_rate,
+        }
+    }
+
+    /// Get the jump process of the model, e.g. to simulate it in a `MonteCarloModel`.
+    pub fn jump_process(&self) -> JumpProcess {
+        JumpProcess::Kou {
+            intensity: self.intensity,
+            up_probability: self.up_probability,
+            up_rate: self.up_rate,
+            down_rate: self.down_rate,
+        }
+    }
+
+    /// Calculate the characteristic function of the log-price `ln S(T)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - The (complex) argument of the characteristic function.
+    /// * `spot` - The spot price of the underlying asset.
+    /// * `dividend_yield` - The continuous dividend yield of the underlying asset.
+    /// * `ttm` - Time to maturity of the option.
+    ///
+    /// # Returns
+    ///
+    /// The value of the characteristic function `E[exp(iu ln S(T))]`.
+    pub fn characteristic_function(
+        &self,
+        u: Complex64,
+        spot: f64,
+        dividend_yield: f64,
+        ttm: f64,
+    ) -> Complex64 {
+        let jumps = self.jump_process();
+        let i = Complex64::i();
+        let variance = self.volatility * self.volatility;
+        let drift = self.risk_free_rate
+            - dividend_yield
+            - 0.5 * variance
+            - self.intensity * jumps.compensator();
+
+        (i * u * (spot.ln() + drift * ttm) - 0.5 * variance * u * u * ttm
+            + self.intensity * ttm * (jumps.characteristic_function(u) - 1.0))
+            .exp()
+    }
+
+    /// Calculate the probabilities `P1` and `P2` of the Fourier pricing formula.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot` - The spot price of the underlying asset.
+    /// * `strike` - The strike price of the option.
+    /// * `dividend_yield` - The continuous dividend yield of the underlying asset.
+    /// * `ttm` - Time to maturity of the option.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing `P1` (delta probability) and `P2` (exercise probability).
+    fn probabilities(&self, spot: f64, strike: f64, dividend_yield: f64, ttm: f64) -> (f64, f64) {
+        let i = Complex64::i();
+        let ln_k = strike.ln();
+        let forward = spot * ((self.risk_free_rate - dividend_yield) * ttm).exp();
+
+        // The integrand decays like exp(-σ²T u² / 2), the jumps only dampen it
+        let w = (self.volatility.powi(2) * ttm).max(1e-8);
+        let upper = (12.0 / w.sqrt()).clamp(50.0, 5_000.0);
+        let intervals = (upper / 2.0).ceil() as usize;
+        let rule = numerics::gauss_legendre(16);
+
+        let integral = |shift: Complex64, norm: f64| {
+            numerics::integrate(
+                |u| {
+                    let z = Complex64::new(u, 0.0);
+                    let phi = self.characteristic_function(z + shift, spot, dividend_yield, ttm);
+                    ((-i * z * ln_k).exp() * phi / (i * z * norm)).re
+                },
+                0.0,
+                upper,
+                intervals,
+                &rule,
+            )
+        };
+
+        let p1 = 0.5 + integral(-i, forward) / std::f64::consts::PI;
+        let p2 = 0.5 + integral(Complex64::new(0.0, 0.0), 1.0) / std::f64::consts::PI;
+
+        (p1, p2)
+    }
+
+    /// Calculate the price of a European option under the Kou model.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot` - The spot price of the underlying asset.
+    /// * `strike` - The strike price of the option.
+    /// * `dividend_yield` - The continuous dividend yield of the underlying asset.
+    /// * `ttm` - Time to maturity of the option.
+    /// * `option_type` - The type of the option (Call or Put).
+    ///
+    /// # Returns
+    ///
+    /// The price of the option.
+    fn price_european(
+        &self,
+        spot: f64,
+        strike: f64,
+        dividend_yield: f64,
+        ttm: f64,
+        option_type: OptionType,
+    ) -> f64 {
+        if ttm <= 0.0 {
+            return match option_type {
+                OptionType::Call => (spot - strike).max(0.0),
+                OptionType::Put => (strike - spot).max(0.0),
+            };
+        }
+
+        let discounted_spot = spot * (-dividend_yield * ttm).exp();
+        let discounted_strike = strike * (-self.risk_free_rate * ttm).exp();
+        let (p1, p2) = self.probabilities(spot, strike, dividend_yield, ttm);
+        let call = (discounted_spot * p1 - discounted_strike * p2).max(0.0);
+
+        match option_type {
+            OptionType::Call => call,
+            OptionType::Put => (call - discounted_spot + discounted_strike).max(0.0),
+        }
+    }
+
+    /// Price a (European) option with bumped inputs.
+    fn price_bumped<T: Option>(&self, option: &T, ttm: f64) -> Result<f64, PricingError> {
+        check_option(option, "KouJumpModel")?;
+        self.jump_process().validate()?;
+        let (spot, dividend_yield) = escrowed_spot(option, self.risk_free_rate, ttm)?;
+        Ok(self.price_european(
+            spot,
+            option.strike(),
+            dividend_yield,
+            ttm,
+            option.option_type(),
+        ))
+    }
+}
+
+impl OptionPricing for KouJumpModel {
+    fn price<T: Option>(&self, option: &T) -> f64 {
+        self.try_price(option).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_price<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.price_bumped(option, option.time_to_maturity())
+    }
+
+    /// Calculate the Black-Scholes implied volatility of a given market price.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to calculate the implied volatility for.
+    /// * `market_price` - The market price of the option.
+    ///
+    /// # Returns
+    ///
+    /// The implied volatility of the option.
+    fn implied_volatility<T: Option>(&self, option: &T, market_price: f64) -> f64 {
+        self.try_implied_volatility(option, market_price)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_implied_volatility<T: Option>(
+        &self,
+        option: &T,
+        market_price: f64,
+    ) -> Result<f64, PricingError> {
+        BlackScholesModel::new(self.risk_free_rate, self.volatility)
+            .try_implied_volatility(option, market_price)
+    }
+}
+
+/// Delta is read off the Fourier pricing formula, the other Greeks are calculated by bumping and repricing.
+impl OptionGreeks for KouJumpModel {
+    fn try_delta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        check_option(option, "KouJumpModel")?;
+        self.jump_process().validate()?;
+        let ttm = option.time_to_maturity();
+        let (spot, q) = escrowed_spot(option, self.risk_free_rate, ttm)?;
+        if ttm <= 0.0 {
+            return Ok(match option.option_type() {
+                OptionType::Call if spot > option.strike() => 1.0,
+                OptionType::Put if spot < option.strike() => -1.0,
+                _ => 0.0,
+            });
+        }
+
+        let (p1, _) = self.probabilities(spot, option.strike(), q, ttm);
+        Ok(match option.option_type() {
+            OptionType::Call => (-q * ttm).exp() * p1,
+            OptionType::Put => (-q * ttm).exp() * (p1 - 1.0),
+        })
+    }
+
+    fn try_gamma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        check_option(option, "KouJumpModel")?;
+        self.jump_process().validate()?;
+        let ttm = option.time_to_maturity();
+        let (spot, q) = escrowed_spot(option, self.risk_free_rate, ttm)?;
+        let h = 1e-3 * spot;
+        let price = |spot| self.price_european(spot, option.strike(), q, ttm, option.option_type());
+
+        Ok((price(spot + h) - 2.0 * price(spot) + price(spot - h)) / (h * h))
+    }
+
+    /// Vega with respect to the volatility of the diffusion.
+    fn try_vega<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let h = 1e-4;
+        let up = Self {
+            volatility: self.volatility + h,
+            ..self.clone()
+        };
+        let down = Self {
+            volatility: (self.volatility - h).max(0.0),
+            ..self.clone()
+        };
+
+        Ok((up.try_price(option)? - down.try_price(option)?) / (up.volatility - down.volatility))
+    }
+
+    fn try_theta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let ttm = option.time_to_maturity();
+        let h = 1e-4 * ttm.max(1e-2);
+
+        Ok(
+            (self.price_bumped(option, ttm + h)?
+                - self.price_bumped(option, (ttm - h).max(0.0))?)
+                / (ttm + h - (ttm - h).max(0.0)),
+        )
+    }
+
+    fn try_rho<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let h = 1e-4;
+        let up = Self {
+            risk_free_rate: self.risk_free_rate + h,
+            ..self.clone()
+        };
+        let down = Self {
+            risk_free_rate: self.risk_free_rate - h,
+            ..self.clone()
+        };
+
+        Ok((up.try_price(option)? - down.try_price(option)?) / (2.0 * h))
+    }
+}
+
+/// The volatility of the Kou model is the volatility of its diffusion.
+impl ModelParameters for KouJumpModel {
+    fn risk_free_rate(&self) -> f64 {
+        self.risk_free_rate
+    }
+
+    fn set_risk_free_rate(&mut self, risk_free_rate: f64) {
+        self.risk_free_rate = risk_free_rate;
+    }
+
+    fn volatility(&self) -> f64 {
+        self.volatility
+    }
+
+    fn set_volatility(&mut self, volatility: f64) {
+        self.volatility = volatility;
+    }
+}
+
+impl OptionStrategy for KouJumpModel {}
+
+/// Check that the option is valid and its style is supported by the jump-diffusion models.
+fn check_option<T: Option>(option: &T, model: &'static str) -> Result<(), PricingError> {
+    validate_option(option)?;
+    match option.style() {
+        OptionStyle::European => Ok(()),
+        style => Err(PricingError::unsupported_style(model, *style)),
+    }
+}
+
+/// Get the escrowed spot price and the dividend yield of the option's underlying, on which the dynamics are applied.
+fn escrowed_spot<T: Option>(
+    option: &T,
+    risk_free_rate: f64,
+    ttm: f64,
+) -> Result<(f64, f64), PricingError> {
+    let instrument = option.instrument();
+    let spot = instrument.escrowed_spot(risk_free_rate, ttm);
+    if !instrument.cash_dividends.is_empty() && spot <= 0.0 {
+        return Err(PricingError::invalid_input(
+            "Cash dividends must not exceed the spot price",
+        ));
+    }
+    Ok((spot, instrument.continuous_dividend_yield))
+}
@@ -0,0 +1,341 @@
+//! Module for the Dupire local volatility model.
+//!
+//! In the local volatility model the volatility of the underlying is a deterministic function of time and spot:
+//!
+//! ```text
+//! dS = (r - q) S dt + σ_loc(t, S) S dW
+//! ```
+//!
+//! Dupire showed that there is a unique local volatility reproducing the prices of all European options of an
+//! implied volatility surface. It is calculated from the total implied variance `w(T, k)` of a [`VolSurface`] at the
+//! log-moneyness `k = ln(S / F(T))`:
+//!
+//! ```text
+//! σ_loc² = (∂w/∂T) / ((1 - k w' / (2w))² - w'² / 4 (1 / w + 1 / 4) + w'' / 2)
+//! ```
+//!
+//! Since the model is calibrated to the vanilla smile by construction, exotic options such as barriers and lookbacks
+//! are priced consistently with the European options of the surface.
+//!
+//! ## Pricing
+//!
+//! - **Finite differences**: European, American and Bermudan options are priced on the one-dimensional pricing PDE
+//!   with local volatility coefficients (see [`FiniteDiffModel`]).
+//! - **Monte Carlo**: Any single-asset option supported by [`MonteCarloModel`] (including barrier, lookback and Asian
+//!   options) is priced on paths with state-dependent volatility.
+//!
+//! ## References
+//!
+//! - Dupire, B. Pricing with a smile, Risk 7, 1994
+//! - Gatheral, J. The Volatility Surface: A Practitioner's Guide, Wiley, 2006
+//!
+//! ## Example
+//!
+//! ```
+//! use quantrs::options::{BarrierOption, EuropeanOption, Instrument, LocalVolModel, MonteCarloModel, OptionPricing, OptionType};
+//!
+//! let instrument = Instrument::new().with_spot(100.0);
+//! let strikes = [70.0, 80.0, 90.0, 100.0, 110.0, 120.0, 130.0];
+//! let volatilities = vec![
+//!     vec![0.3, 0.26, 0.23, 0.21, 0.2, 0.205, 0.215],
+//!     vec![0.28, 0.25, 0.225, 0.21, 0.2, 0.2, 0.205],
+//! ];
+//! let model = LocalVolModel::from_implied_volatilities(&instrument, 0.03, &[0.5, 1.0], &strikes, &volatilities).unwrap();
+//!
+//! // Finite differences for vanilla options
+//! let option = EuropeanOption::new(instrument.clone(), 95.0, 0.75, OptionType::Put);
+//! println!("Put price: {}", model.price(&option));
+//!
+//! // Monte Carlo for path-dependent options
+//! let model = model.with_monte_carlo(MonteCarloModel::brownian(0.0, 0.0, 10_000, 100).with_seed(42));
+//! let barrier = BarrierOption::down_and_out(instrument, 100.0, 1.0, OptionType::Call, 85.0);
+//! println!("Barrier price: {}", model.price(&barrier));
+//! ```
+
+use crate::options::{
+    BlackScholesModel, FiniteDiffModel, Instrument, MarketQuote, ModelParameters, MonteCarloModel,
+    NumericalGreeks, Option, OptionGreeks, OptionPricing, OptionStrategy, OptionType, PricingError,
+    SmileModel, VolSurface,
+};
+
+/// Enum for the numerical methods of the local volatility model.
+#[derive(Debug, Clone)]
+pub enum LocalVolMethod {
+    /// Finite differences on the pricing PDE (the rate and volatility of the model are replaced).
+    FiniteDiff(FiniteDiffModel),
+    /// Monte Carlo simulation of the paths (the rate, volatility, term structures and jumps of the model are replaced).
+    MonteCarlo(Box<MonteCarloModel>),
+}
+
+/// Dupire local volatility model.
+#[derive(Debug, Clone)]
+pub struct LocalVolModel {
+    /// Risk-free interest rate (e.g., 0.05 for 5%).
+    pub risk_free_rate: f64,
+    /// Implied volatility surface from which the local volatility is derived.
+    pub surface: VolSurface,
+    /// Numerical method used to price options.
+    pub method: LocalVolMethod,
+}
+
+impl LocalVolModel {
+    /// Create a new `LocalVolModel` priced with Crank-Nicolson finite differences on a 200 x 200 grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `risk_free_rate` - Risk-free interest rate (e.g., 0.05 for 5%).
+    /// * `surface` - The implied volatility surface.
+    ///
+    /// # Returns
+    ///
+    /// A new `LocalVolModel`.
+    pub fn new(risk_free_rate: f64, surface: VolSurface) -> Self {
+        Self {
+            risk_free_rate,
+            surface,
+            method: LocalVolMethod::FiniteDiff(FiniteDiffModel::crank_nicolson(
+                risk_free_rate,
+                0.0,
+                200,
+                200,
+            )),
+        }
+    }
+
+    /// Create a new `LocalVolModel` from a grid of implied volatilities.
+    ///
+    /// The smile of every expiry is fitted with the raw SVI parametrisation (or with an SSVI slice if there are fewer
+    /// than five strikes), and the total implied variance is interpolated linearly in time between the expiries.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument` - The underlying instrument.
+    /// * `risk_free_rate` - Risk-free interest rate (e.g., 0.05 for 5%).
+    /// * `expiries` - The expiries (times to maturity in years) of the grid.
+    /// * `strikes` - The strikes of the grid.
+    /// * `volatilities` - The implied volatilities of every expiry at the strikes.
+    ///
+    /// # Returns
+    ///
+    /// A new `LocalVolModel` or an error if the grid does not match or cannot be fitted.
+    pub fn from_implied_volatilities(
+        instrument: &Instrument,
+        risk_free_rate: f64,
+        expiries: &[f64],
+        strikes: &[f64],
+        volatilities: &[Vec<f64>],
+    ) -> Result<Self, PricingError> {
+        if volatilities.len() != expiries.len()
+            || volatilities
+                .iter()
+                .any(|smile| smile.len() != strikes.len())
+        {
+            return Err(PricingError::invalid_input(
+                "Implied volatilities must have one row per expiry and one column per strike",
+            ));
+        }
+        let quotes: Vec<MarketQuote> = expiries
+            .iter()
+            .zip(volatilities)
+            .flat_map(|(&expiry, smile)| {
+                strikes
+                    .iter()
+                    .zip(smile)
+                    .map(move |(&strike, &volatility)| {
+                        MarketQuote::implied_volatility(
+                            strike,
+                            expiry,
+                            OptionType::Call,
+                            volatility,
+                        )
+                    })
+            })
+            .collect();
+        let smile_model = if strikes.len() >= 5 {
+            SmileModel::Svi
+        } else {
+            SmileModel::Ssvi
+        };
+
+        let surface = VolSurface::fit(instrument, risk_free_rate, &quotes, smile_model)?;
+        Ok(Self::new(risk_free_rate, surface))
+    }
+
+    /// Price with finite differences.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The finite difference model providing the grid and the scheme.
+    ///
+    /// # Returns
+    ///
+    /// The model priced with finite differences.
+    pub fn with_finite_diff(mut self, model: FiniteDiffModel) -> Self {
+        self.method = LocalVolMethod::FiniteDiff(model);
+        self
+    }
+
+    /// Price with Monte Carlo simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The Monte Carlo model providing the number of paths and steps, the seed and the variance reduction.
+    ///
+    /// # Returns
+    ///
+    /// The model priced with Monte Carlo simulation.
+    pub fn with_monte_carlo(mut self, model: MonteCarloModel) -> Self {
+        self.method = LocalVolMethod::MonteCarlo(Box::new(model));
+        self
+    }
+
+    /// Calculate the local volatility at a time and a spot price.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time in years.
+    /// * `spot` - The spot price of the underlying at that time.
+    ///
+    /// # Returns
+    ///
+    /// The Dupire local volatility of the surface.
+    pub fn local_volatility(&self, time: f64, spot: f64) -> f64 {
+        self.surface.local_volatility(time, spot)
+    }
+
+    /// Evaluate a function of the pricing engine of the method, set up with the rate and the surface of the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `finite_diff` - The function evaluated with the finite difference model.
+    /// * `monte_carlo` - The function evaluated with the Monte Carlo model.
+    ///
+    /// # Returns
+    ///
+    /// The value of the function for the method of the model.
+    fn with_engine<R>(
+        &self,
+        finite_diff: impl FnOnce(&FiniteDiffModel) -> R,
+        monte_carlo: impl FnOnce(&MonteCarloModel) -> R,
+    ) -> R {
+        let volatility = self.surface.volatility_shift;
+        match &self.method {
+            LocalVolMethod::FiniteDiff(model) => finite_diff(&FiniteDiffModel {
+                risk_free_rate: self.risk_free_rate,
+                volatility,
+                local_volatility: Some(self.surface.clone()),
+                ..model.clone()
+            }),
+            LocalVolMethod::MonteCarlo(model) => monte_carlo(&MonteCarloModel {
+                risk_free_rate: self.risk_free_rate,
+                volatility,
+                discount_curve: None,
+                borrow_curve: None,
+                volatility_term_structure: None,
+                local_volatility: Some(self.surface.clone()),
+                jumps: None,
+                ..model.as_ref().clone()
+            }),
+        }
+    }
+}
+
+impl OptionPricing for LocalVolModel {
+    fn price<T: Option>(&self, option: &T) -> f64 {
+        self.try_price(option).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_price<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.with_engine(
+            |model| model.try_price(option),
+            |model| model.try_price(option),
+        )
+    }
+
+    /// Calculate the Black-Scholes implied volatility of a given market price.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to calculate the implied volatility for.
+    /// * `market_price` - The market price of the option.
+    ///
+    /// # Returns
+    ///
+    /// The implied volatility of the option.
+    fn implied_volatility<T: Option>(&self, option: &T, market_price: f64) -> f64 {
+        self.try_implied_volatility(option, market_price)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_implied_volatility<T: Option>(
+        &self,
+        option: &T,
+        market_price: f64,
+    ) -> Result<f64, PricingError> {
+        let guess = self
+            .surface
+            .volatility(option.time_to_maturity(), option.strike());
+        BlackScholesModel::new(self.risk_free_rate, guess)
+            .try_implied_volatility(option, market_price)
+    }
+}
+
+/// Finite difference Greeks are read off the grid, Monte Carlo Greeks are calculated by bumping and repricing.
+impl OptionGreeks for LocalVolModel {
+    fn try_delta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.with_engine(
+            |model| model.try_delta(option),
+            |model| NumericalGreeks::new(model.clone()).try_delta(option),
+        )
+    }
+
+    fn try_gamma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.with_engine(
+            |model| model.try_gamma(option),
+            |model| NumericalGreeks::new(model.clone()).try_gamma(option),
+        )
+    }
+
+    /// Vega with respect to a parallel shift of the local volatility.
+    fn try_vega<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.with_engine(
+            |model| model.try_vega(option),
+            |model| NumericalGreeks::new(model.clone()).try_vega(option),
+        )
+    }
+
+    fn try_theta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.with_engine(
+            |model| model.try_theta(option),
+            |model| NumericalGreeks::new(model.clone()).try_theta(option),
+        )
+    }
+
+    fn try_rho<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.with_engine(
+            |model| model.try_rho(option),
+            |model| NumericalGreeks::new(model.clone()).try_rho(option),
+        )
+    }
+}
+
+/// The volatility of the local volatility model is the parallel shift of its local volatility.
+impl ModelParameters for LocalVolModel {
+    fn risk_free_rate(&self) -> f64 {
+        self.risk_free_rate
+    }
+
+    fn set_risk_free_rate(&mut self, risk_free_rate: f64) {
+        self.risk_free_rate = risk_free_rate;
+    }
+
+    fn volatility(&self) -> f64 {
+        self.surface.volatility_shift
+    }
+
+    fn set_volatility(&mut self, volatility: f64) {
+        self.surface.volatility_shift = volatility;
+    }
+}
+
+impl OptionStrategy for LocalVolModel {}
@@ -12,11 +12,24 @@
 //! - **Simulations**: The number of simulations to run.
 //! - **Steps**: The number of steps in each simulation.
 //! - **Averaging Method**: The method used to average the simulated prices (geometric or arithmetic).
+//! - **Seed**: Optional seed of the random number generator for reproducible prices.
+//! - **Variance Reduction**: Optional antithetic variates, control variates and moment matching.
+//! - **Target Standard Error**: Optional standard error at which to stop simulating further batches of paths.
+//! - **Sampling**: Pseudo-random numbers or (scrambled) Sobol sequences, optionally with Brownian bridge path
+//!   construction.
+//! - **Regression Basis**: Basis of the Longstaff-Schwartz regression pricing American and Bermudan options.
+//! - **Term Structures**: Optional discount curve, borrow curve and volatility term structure, simulated with the
+//!   forward rates and forward variances of every step.
+//! - **Local Volatility**: Optional implied volatility surface whose Dupire local volatility drives the paths.
+//! - **Jumps**: Optional Merton or Kou jump process added to the paths (without a Brownian bridge).
 //!
 //! ## Example
 //!
 //! ```rust
-//! use quantrs::options::{MonteCarloModel, OptionPricing, Instrument, OptionType, EuropeanOption};
+//! use quantrs::options::{
+//!     AmericanOption, EuropeanOption, Instrument, MonteCarloModel, OptionPricing, OptionType, RegressionBasis,
+//!     Sampling,
+//! };
 //!
 //! let instrument = Instrument::new().with_spot(100.0);
 //! let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
@@ -24,11 +37,45 @@
 //!
 //! let price = model.price(&option);
 //! println!("Monte Carlo Call Price: {price}");
+//!
+//! // Reproducible prices with variance reduction
+//! let model = MonteCarloModel::brownian(0.05, 0.2, 10_000, 1)
+//!     .with_seed(42)
+//!     .with_antithetic_variates()
+//!     .with_control_variates();
+//! assert_eq!(model.price(&option), model.price(&option));
+//!
+//! // Standard error and confidence interval of the price
+//! let result = model.price_statistics(&option).unwrap();
+//! println!("{} ± {} ({} paths)", result.price, result.standard_error, result.paths);
+//!
+//! // Quasi-Monte Carlo with a Brownian bridge
+//! let model = MonteCarloModel::brownian(0.05, 0.2, 4_096, 64)
+//!     .with_sampling(Sampling::Sobol)
+//!     .with_brownian_bridge();
+//! let price = model.price(&option);
+//!
+//! // Least-squares Monte Carlo for early exercise
+//! let option = AmericanOption::new(Instrument::new().with_spot(100.0), 100.0, 1.0, OptionType::Put);
+//! let model = MonteCarloModel::brownian(0.05, 0.2, 10_000, 50)
+//!     .with_regression_basis(RegressionBasis::Laguerre(3));
+//! let price = model.price(&option);
 //! ```
 
-use crate::options::{Option, OptionPricing, OptionStrategy, OptionStyle, SimMethod};
-use rand::rngs::ThreadRng;
+use super::black_scholes::Averaging;
+use crate::options::{
+    Barrier, BlackScholesModel, EuropeanOption, Instrument, JumpProcess, ModelParameters,
+    Monitoring, Option, OptionPricing, OptionStrategy, OptionStyle, OptionType, PricingError,
+    RainbowType, VolSurface, VolatilityTermStructure, YieldCurve, numerics,
+    numerics::SobolSequence, types::Permutation, validate_option,
+};
+use rand::{SeedableRng, rngs::StdRng};
+use rand_distr::{Distribution, StandardNormal};
 use rayon::prelude::*;
+use statrs::distribution::{ContinuousCDF, Normal};
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::time::{Duration, Instant};
 
 /// Enum for averaging methods.
 #[derive(Debug, Default, Clone, Copy)]
@@ -39,6 +86,244 @@ pub enum AvgMethod {
     Brownian,
 }
 
+/// Source of the random numbers driving the simulated paths.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Sampling {
+    /// Pseudo-random numbers.
+    #[default]
+    PseudoRandom,
+    /// Sobol low-discrepancy sequence with the direction numbers of Joe and Kuo.
+    Sobol,
+    /// Sobol sequence with Owen scrambling seeded from the model's seed.
+    ScrambledSobol,
+}
+
+/// Regression basis of the continuation value in the Longstaff-Schwartz algorithm, in terms of the moneyness
+/// `x = S / K`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RegressionBasis {
+    /// Monomials `1, x, ..., x^n` up to the given degree.
+    Polynomial(usize),
+    /// Weighted Laguerre polynomials `exp(-x / 2) L_k(x)` up to the given degree, as used by Longstaff and Schwartz.
+    Laguerre(usize),
+}
+
+impl Default for RegressionBasis {
+    fn default() -> Self {
+        RegressionBasis::Laguerre(3)
+    }
+}
+
+impl RegressionBasis {
+    /// Evaluate the basis functions.
+    fn functions(&self, x: f64) -> Vec<f64> {
+        match *self {
+            RegressionBasis::Polynomial(degree) => {
+                (0..=degree as i32).map(|power| x.powi(power)).collect()
+            }
+            RegressionBasis::Laguerre(degree) => {
+                let weight = (-0.5 * x).exp();
+                let mut functions = Vec::with_capacity(degree + 1);
+                let (mut previous, mut current) = (0.0, 1.0);
+                for k in 0..=degree {
+                    functions.push(weight * current);
+                    let next = ((2 * k + 1) as f64 - x) * current - k as f64 * previous;
+                    (previous, current) = (current, next / (k + 1) as f64);
+                }
+                functions
+            }
+        }
+    }
+
+    /// Fit the coefficients of the basis functions to samples `(x, y)` by least squares.
+    ///
+    /// # Returns
+    ///
+    /// The coefficients, or `None` if there are too few samples or the normal equations are singular.
+    fn fit(&self, samples: &[(f64, f64)]) -> std::option::Option<Vec<f64>> {
+        let size = self.functions(1.0).len();
+        if samples.len() <= size {
+            return None;
+        }
+        let mut normal_matrix = vec![vec![0.0; size]; size];
+        let mut rhs = vec![0.0; size];
+        for &(x, y) in samples {
+            let functions = self.functions(x);
+            for (i, fi) in functions.iter().enumerate() {
+                rhs[i] += fi * y;
+                for (j, fj) in functions.iter().enumerate() {
+                    normal_matrix[i][j] += fi * fj;
+                }
+            }
+        }
+        numerics::solve_linear_system(normal_matrix, rhs)
+    }
+
+    /// Evaluate the fitted regression.
+    fn evaluate(&self, coefficients: &[f64], x: f64) -> f64 {
+        self.functions(x)
+            .iter()
+            .zip(coefficients)
+            .map(|(function, coefficient)| function * coefficient)
+            .sum()
+    }
+}
+
+/// Price of a Monte Carlo simulation with its statistical error.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonteCarloResult {
+    /// Estimated price of the option.
+    pub price: f64,
+    /// Standard error of the estimated price.
+    pub standard_error: f64,
+    /// 95% confidence interval of the price.
+    pub confidence_interval: (f64, f64),
+    /// Number of simulated paths.
+    pub paths: usize,
+    /// Wall-clock time of the simulation.
+    pub elapsed: Duration,
+}
+
+/// Standard normal shocks driving the simulated paths.
+///
+/// The shocks of a path are drawn from a generator seeded with the seed of the pricing run and the index of the path
+/// (of the pair of paths for antithetic variates), or from the point of the Sobol sequence with that index, so prices
+/// do not depend on how the paths are distributed over the threads.
+struct Shocks {
+    /// Seed of the pricing run.
+    seed: u64,
+    /// Number of shocks per path.
+    dimension: usize,
+    /// Whether odd paths mirror the shocks of the preceding even path.
+    antithetic: bool,
+    /// Sobol sequence replacing the pseudo-random numbers.
+    sobol: std::option::Option<SobolSequence>,
+    /// Sample mean and standard deviation of every shock across the paths (for moment matching).
+    moments: std::option::Option<Vec<(f64, f64)>>,
+    /// Brownian bridge turning the shocks into the increments of a path.
+    bridge: std::option::Option<BrownianBridge>,
+}
+
+impl Shocks {
+    /// Draw the shocks of a path before moment matching.
+    fn raw(&self, path: usize) -> Vec<f64> {
+        let (stream, sign) = if self.antithetic {
+            (path / 2, if path % 2 == 0 { 1.0 } else { -1.0 })
+        } else {
+            (path, 1.0)
+        };
+        if let Some(sobol) = &self.sobol {
+            // The origin of the plain sequence maps to infinite shocks
+            let offset = if sobol.is_scrambled() { 0 } else { 1 };
+            let normal = Normal::new(0.0, 1.0).unwrap();
+            return sobol
+                .point(stream as u64 + offset)
+                .into_iter()
+                .map(|u| sign * normal.inverse_cdf(u))
+                .collect();
+        }
+
+        let mut key = [0u8; 32];
+        key[..8].copy_from_slice(&self.seed.to_le_bytes());
+        key[8..16].copy_from_slice(&(stream as u64).to_le_bytes());
+        let mut rng = StdRng::from_seed(key);
+        (0..self.dimension)
+            .map(|_| sign * Distribution::<f64>::sample(&StandardNormal, &mut rng))
+            .collect()
+    }
+
+    /// Draw the shocks of a path.
+    fn path(&self, path: usize) -> Vec<f64> {
+        let mut shocks = self.raw(path);
+        if let Some(moments) = &self.moments {
+            for (shock, (mean, std_dev)) in shocks.iter_mut().zip(moments) {
+                *shock = (*shock - mean) / std_dev;
+            }
+        }
+        match &self.bridge {
+            Some(bridge) => bridge.increments(&shocks),
+            None => shocks,
+        }
+    }
+}
+
+/// A step of the Brownian bridge construction: the value at the middle point given the values at the end points.
+struct BridgeStep {
+    left: usize,
+    middle: usize,
+    right: usize,
+    left_weight: f64,
+    right_weight: f64,
+    std_dev: f64,
+}
+
+/// Brownian bridge construction of the increments of a Brownian motion on an equidistant grid.
+///
+/// The first shock determines the terminal value and the following shocks successively fill in the midpoints, so the
+/// leading (best distributed) dimensions of a quasi-random sequence drive the largest movements of the path.
+struct BrownianBridge {
+    /// Number of increments.
+    steps: usize,
+    /// Construction of the interior points in the order of the shocks.
+    plan: Vec<BridgeStep>,
+}
+
+impl BrownianBridge {
+    /// Plan the construction of a path with `steps` increments.
+    fn new(steps: usize) -> Self {
+        let mut plan = Vec::with_capacity(steps.saturating_sub(1));
+        let mut intervals = VecDeque::from([(0, steps)]);
+        while let Some((left, right)) = intervals.pop_front() {
+            if right - left < 2 {
+                continue;
+            }
+            let middle = (left + right) / 2;
+            let (a, b) = ((middle - left) as f64, (right - middle) as f64);
+            plan.push(BridgeStep {
+                left,
+                middle,
+                right,
+                left_weight: b / (a + b),
+                right_weight: a / (a + b),
+                std_dev: (a * b / (a + b)).sqrt(),
+            });
+            intervals.push_back((left, middle));
+            intervals.push_back((middle, right));
+        }
+        Self { steps, plan }
+    }
+
+    /// Turn standard normal shocks into the standard normal increments of a Brownian path.
+    fn increments(&self, shocks: &[f64]) -> Vec<f64> {
+        if self.steps == 0 {
+            return Vec::new();
+        }
+        let mut path = vec![0.0; self.steps + 1];
+        path[self.steps] = (self.steps as f64).sqrt() * shocks[0];
+        for (step, shock) in self.plan.iter().zip(&shocks[1..]) {
+            path[step.middle] = step.left_weight * path[step.left]
+                + step.right_weight * path[step.right]
+                + step.std_dev * shock;
+        }
+        path.windows(2).map(|w| w[1] - w[0]).collect()
+    }
+}
+
+/// Discounted payoff and control of a path given its shocks.
+type PathSample<'a> = Box<dyn Fn(&[f64]) -> (f64, f64) + Sync + 'a>;
+
+/// Simulation of the discounted payoff of an option along a path.
+struct PathSampler<'a> {
+    /// Number of shocks per path.
+    dimension: usize,
+    /// Expectation of the discounted control (`None` if it is unknown, which disables the control variate).
+    control_mean: std::option::Option<f64>,
+    /// Whether the shocks are the increments of a path (and can be constructed with a Brownian bridge).
+    brownian: bool,
+    /// Discounted payoff and control of a path given its shocks.
+    sample: PathSample<'a>,
+}
+
 /// A struct representing a Monte Carlo Simulation model for option pricing.
 #[derive(Debug, Default, Clone)]
 pub struct MonteCarloModel {
@@ -52,6 +337,35 @@ pub struct MonteCarloModel {
     pub steps: usize,
     /// average method
     pub method: AvgMethod,
+    /// Seed of the random number generator (`None` draws fresh random numbers for every price).
+    pub seed: std::option::Option<u64>,
+    /// Whether to simulate pairs of paths with mirrored shocks.
+    pub antithetic: bool,
+    /// Whether to correct the estimate with a control variate of known expectation.
+    pub control_variate: bool,
+    /// Whether to rescale the shocks to zero sample mean and unit sample variance.
+    pub moment_matching: bool,
+    /// Standard error at which to stop simulating further batches of paths (`None` simulates a single batch).
+    pub target_standard_error: std::option::Option<f64>,
+    /// Maximum number of paths simulated to reach the target standard error.
+    pub max_simulations: usize,
+    /// Source of the random numbers.
+    pub sampling: Sampling,
+    /// Whether to construct the paths with a Brownian bridge.
+    pub brownian_bridge: bool,
+    /// Basis of the regression estimating continuation values of American and Bermudan options.
+    pub regression_basis: RegressionBasis,
+    /// Discount curve replacing the flat risk-free rate (`None` discounts with `risk_free_rate`).
+    pub discount_curve: std::option::Option<YieldCurve>,
+    /// Borrow (repo) curve of the underlying, whose forward rates reduce its drift.
+    pub borrow_curve: std::option::Option<YieldCurve>,
+    /// Term structure of implied volatilities replacing the flat volatility (`None` uses `volatility`).
+    pub volatility_term_structure: std::option::Option<VolatilityTermStructure>,
+    /// Implied volatility surface whose Dupire local volatility drives the paths (`None` uses `volatility` or the
+    /// volatility term structure).
+    pub local_volatility: std::option::Option<VolSurface>,
+    /// Compound Poisson process of jumps in the log-price (`None` simulates a pure diffusion).
+    pub jumps: std::option::Option<JumpProcess>,
 }
 
 impl MonteCarloModel {
@@ -81,6 +395,305 @@ impl MonteCarloModel {
             simulations,
             steps: steps.max(1),
             method,
+            seed: None,
+            antithetic: false,
+            control_variate: false,
+            moment_matching: false,
+            target_standard_error: None,
+            max_simulations: 0,
+            sampling: Sampling::PseudoRandom,
+            brownian_bridge: false,
+            regression_basis: RegressionBasis::default(),
+            discount_curve: None,
+            borrow_curve: None,
+            volatility_term_structure: None,
+            local_volatility: None,
+            jumps: None,
+        }
+    }
+
+    /// Seed the random number generator.
+    ///
+    /// A seeded model reproduces its prices exactly, so prices of bumped options are computed with common random
+    /// numbers (e.g. for numerical Greeks).
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed of the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// The model with the seed set.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Use antithetic variates, i.e. simulate every other path with the negated shocks of its predecessor.
+    ///
+    /// # Returns
+    ///
+    /// The model with antithetic variates enabled.
+    pub fn with_antithetic_variates(mut self) -> Self {
+        self.antithetic = true;
+        self
+    }
+
+    /// Use control variates, i.e. correct the estimate by the simulation error of a correlated quantity with a known
+    /// expectation.
+    ///
+    /// The control is the geometric Asian payoff for fixed strike Asian options, the vanilla payoff for barrier
+    /// options, the weighted terminal spots for basket and rainbow options and the simulated (average) spot
+    /// otherwise. Its coefficient is estimated by regressing the payoffs on the control.
+    ///
+    /// # Returns
+    ///
+    /// The model with control variates enabled.
+    pub fn with_control_variates(mut self) -> Self {
+        self.control_variate = true;
+        self
+    }
+
+    /// Use moment matching, i.e. rescale every shock to zero sample mean and unit sample variance across the paths.
+    ///
+    /// # Returns
+    ///
+    /// The model with moment matching enabled.
+    pub fn with_moment_matching(mut self) -> Self {
+        self.moment_matching = true;
+        self
+    }
+
+    /// Set the source of the random numbers.
+    ///
+    /// Sobol sequences converge faster than pseudo-random numbers for smooth payoffs, especially combined with a
+    /// Brownian bridge. The standard error of the plain Sobol sequence is not a valid error estimate, as its points
+    /// are not independent; scrambled sequences are seeded from the model's seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `sampling` - The source of the random numbers.
+    ///
+    /// # Returns
+    ///
+    /// The model with the sampling set.
+    pub fn with_sampling(mut self, sampling: Sampling) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    /// Construct the simulated paths with a Brownian bridge, i.e. the first random number of a path determines its
+    /// terminal value and the following ones successively fill in the midpoints.
+    ///
+    /// # Returns
+    ///
+    /// The model with Brownian bridge path construction.
+    pub fn with_brownian_bridge(mut self) -> Self {
+        self.brownian_bridge = true;
+        self
+    }
+
+    /// Set the basis of the Longstaff-Schwartz regression of continuation values on the spot (default: weighted
+    /// Laguerre polynomials up to degree 3).
+    ///
+    /// # Arguments
+    ///
+    /// * `basis` - The regression basis.
+    ///
+    /// # Returns
+    ///
+    /// The model with the regression basis set.
+    pub fn with_regression_basis(mut self, basis: RegressionBasis) -> Self {
+        self.regression_basis = basis;
+        self
+    }
+
+    /// Set the discount curve, which replaces the flat risk-free rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `curve` - The curve of zero rates used for discounting and as the drift of the paths.
+    ///
+    /// # Returns
+    ///
+    /// The model with the discount curve set.
+    pub fn with_discount_curve(mut self, curve: YieldCurve) -> Self {
+        self.discount_curve = Some(curve);
+        self
+    }
+
+    /// Set the borrow (repo) curve of the underlying.
+    ///
+    /// # Arguments
+    ///
+    /// * `curve` - The curve of borrow rates, which reduce the drift of the paths.
+    ///
+    /// # Returns
+    ///
+    /// The model with the borrow curve set.
+    pub fn with_borrow_curve(mut self, curve: YieldCurve) -> Self {
+        self.borrow_curve = Some(curve);
+        self
+    }
+
+    /// Set the volatility term structure, which replaces the flat volatility.
+    ///
+    /// Every step of a path is simulated with the forward variance over the step.
+    ///
+    /// # Arguments
+    ///
+    /// * `term_structure` - The term structure of implied volatilities.
+    ///
+    /// # Returns
+    ///
+    /// The model with the volatility term structure set.
+    pub fn with_volatility_term_structure(
+        mut self,
+        term_structure: VolatilityTermStructure,
+    ) -> Self {
+        self.volatility_term_structure = Some(term_structure);
+        self
+    }
+
+    /// Set the implied volatility surface whose Dupire local volatility drives the paths.
+    ///
+    /// Every step of a path is simulated with the local volatility at the start of the step (Euler scheme in the log
+    /// spot), so the paths reproduce the prices of the European options of the surface as the number of steps grows.
+    /// Multi-asset options are not supported.
+    ///
+    /// # Arguments
+    ///
+    /// * `surface` - The implied volatility surface.
+    ///
+    /// # Returns
+    ///
+    /// The model with the local volatility set.
+    pub fn with_local_volatility(mut self, surface: VolSurface) -> Self {
+        self.local_volatility = Some(surface);
+        self
+    }
+
+    /// Add jumps to the simulated paths.
+    ///
+    /// The drift is compensated by the mean relative jump, so the expected spot prices are unchanged. Every increment
+    /// of a path draws two additional shocks for the number and the sizes of its jumps, and paths are not constructed
+    /// with a Brownian bridge.
+    ///
+    /// # Arguments
+    ///
+    /// * `jumps` - The jump process (e.g., `MertonJumpModel::jump_process`).
+    ///
+    /// # Returns
+    ///
+    /// The model with the jumps set.
+    pub fn with_jumps(mut self, jumps: JumpProcess) -> Self {
+        self.jumps = Some(jumps);
+        self
+    }
+
+    /// Simulate batches of `simulations` paths until the standard error of the price reaches a target.
+    ///
+    /// # Arguments
+    ///
+    /// * `target_standard_error` - The standard error at which to stop.
+    /// * `max_simulations` - The maximum number of paths to simulate.
+    ///
+    /// # Returns
+    ///
+    /// The model in adaptive mode.
+    pub fn with_target_standard_error(
+        mut self,
+        target_standard_error: f64,
+        max_simulations: usize,
+    ) -> Self {
+        self.target_standard_error = Some(target_standard_error);
+        self.max_simulations = max_simulations;
+        self
+    }
+
+    /// Price an option and estimate the statistical error of the price.
+    ///
+    /// The standard error is estimated from the payoffs after the control variate correction, with antithetic pairs
+    /// averaged first as only the pairs are independent. It ignores the dependence between paths introduced by
+    /// moment matching and is therefore conservative in that case. In adaptive mode, batches of `simulations` paths
+    /// are simulated until the standard error reaches the target or another batch would exceed `max_simulations`.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to price.
+    ///
+    /// # Returns
+    ///
+    /// The price with its standard error and confidence interval or the reason why the option cannot be priced.
+    pub fn price_statistics<T: Option>(
+        &self,
+        option: &T,
+    ) -> Result<MonteCarloResult, PricingError> {
+        let start = Instant::now();
+        validate_option(option)?;
+        if self.simulations == 0 {
+            return Err(PricingError::invalid_input(
+                "Monte Carlo pricing requires at least one simulation",
+            ));
+        }
+        if self.target_standard_error.is_some() && self.max_simulations == 0 {
+            return Err(PricingError::invalid_input(
+                "Adaptive Monte Carlo pricing requires a positive maximum number of simulations",
+            ));
+        }
+        if let Some(jumps) = &self.jumps {
+            jumps.validate()?;
+        }
+        let seed = self.seed.unwrap_or_else(rand::random);
+        let sampler = match option.style() {
+            OptionStyle::European | OptionStyle::Binary(_) => self.european_sampler(option),
+            OptionStyle::Basket => self.basket_sampler(option)?,
+            OptionStyle::Rainbow(rainbow_type) => self.rainbow_sampler(option, *rainbow_type)?,
+            OptionStyle::Barrier(_) | OptionStyle::DoubleBarrier(_, _)
+                if option.barrier().is_none() =>
+            {
+                return Err(PricingError::invalid_input(
+                    "Barrier options must provide a barrier",
+                ));
+            }
+            OptionStyle::Barrier(_) | OptionStyle::DoubleBarrier(_, _) => {
+                self.barrier_sampler(option)
+            }
+            OptionStyle::Asian(_) | OptionStyle::Lookback(_) => self.asian_sampler(option)?,
+            OptionStyle::American | OptionStyle::Bermudan => {
+                self.longstaff_schwartz_sampler(option, seed)?
+            }
+        };
+
+        let batch = self.simulations;
+        let mut samples: Vec<(f64, f64)> = Vec::with_capacity(batch);
+        loop {
+            let paths = samples.len()..samples.len() + batch;
+            let shocks = self.shocks(seed, &sampler, paths.clone());
+            samples.par_extend(
+                paths
+                    .into_par_iter()
+                    .map(|path| (sampler.sample)(&shocks.path(path))),
+            );
+
+            let (price, standard_error) = self.statistics(&samples, sampler.control_mean);
+            let done = match self.target_standard_error {
+                Some(target) => {
+                    standard_error <= target || samples.len() + batch > self.max_simulations
+                }
+                None => true,
+            };
+            if done {
+                // 97.5% quantile of the standard normal distribution
+                let half_width = 1.959_963_984_540_054 * standard_error;
+                return Ok(MonteCarloResult {
+                    price,
+                    standard_error,
+                    confidence_interval: (price - half_width, price + half_width),
+                    paths: samples.len(),
+                    elapsed: start.elapsed(),
+                });
+            }
         }
     }
 
@@ -165,124 +778,1106 @@ impl MonteCarloModel {
         )
     }
 
-    /// Simulate price paths and compute the expected discounted payoff.
+    /// Draw the shocks of a batch of paths.
     ///
     /// # Arguments
     ///
-    /// * `option` - The option to price.
+    /// * `seed` - The seed of the pricing run.
+    /// * `sampler` - The sampler of the simulated paths.
+    /// * `paths` - The indices of the paths in the batch (moments are matched within the batch).
     ///
     /// # Returns
     ///
-    /// The expected discounted payoff of the option.
-    fn simulate_price_paths<T: Option>(&self, option: &T) -> f64 {
-        let discount_factor = (-self.risk_free_rate * option.time_to_maturity()).exp();
-
-        // Use parallel iteration to simulate multiple price paths
-        let total_payoff: f64 = (0..self.simulations)
-            .into_par_iter() // Rayon parallel iterator
-            .map(|_| {
-                let mut rng = rand::rng();
-                let simulated_price = match self.method {
-                    AvgMethod::Geometric => option.instrument().simulate_geometric_average(
-                        &mut rng,
-                        SimMethod::Log,
-                        self.volatility,
-                        option.time_to_maturity(),
-                        self.risk_free_rate,
-                        self.steps,
-                    ),
-                    AvgMethod::Arithmetic => option.instrument().simulate_arithmetic_average(
-                        &mut rng,
-                        SimMethod::Log,
-                        self.volatility,
-                        option.time_to_maturity(),
-                        self.risk_free_rate,
-                        self.steps,
-                    ),
-                    AvgMethod::Brownian => option.instrument().simulate_geometric_brownian_motion(
-                        &mut rng,
-                        self.volatility,
-                        option.time_to_maturity(),
-                        self.risk_free_rate,
-                        self.steps,
-                    ),
-                };
+    /// The shocks of the batch.
+    fn shocks(&self, seed: u64, sampler: &PathSampler, paths: Range<usize>) -> Shocks {
+        let dimension = sampler.dimension * self.shocks_per_increment();
+        let mut shocks = Shocks {
+            seed,
+            dimension,
+            antithetic: self.antithetic,
+            sobol: match self.sampling {
+                Sampling::PseudoRandom => None,
+                Sampling::Sobol => Some(SobolSequence::new(dimension, None)),
+                Sampling::ScrambledSobol => Some(SobolSequence::new(dimension, Some(seed))),
+            },
+            moments: None,
+            bridge: (self.brownian_bridge && sampler.brownian && self.jumps.is_none())
+                .then(|| BrownianBridge::new(dimension)),
+        };
+
+        if self.moment_matching && paths.len() > 1 {
+            let n = paths.len() as f64;
+            let (sum, sum_of_squares) = paths
+                .into_par_iter()
+                .map(|path| {
+                    let raw = shocks.raw(path);
+                    let squares = raw.iter().map(|shock| shock * shock).collect();
+                    (raw, squares)
+                })
+                .reduce(
+                    || (vec![0.0; dimension], vec![0.0; dimension]),
+                    |(mut sum, mut sum_of_squares), (raw, squares): (Vec<f64>, Vec<f64>)| {
+                        sum.iter_mut().zip(&raw).for_each(|(a, b)| *a += b);
+                        sum_of_squares
+                            .iter_mut()
+                            .zip(&squares)
+                            .for_each(|(a, b)| *a += b);
+                        (sum, sum_of_squares)
+                    },
+                );
+            shocks.moments = Some(
+                sum.iter()
+                    .zip(&sum_of_squares)
+                    .map(|(sum, sum_of_squares)| {
+                        let mean = sum / n;
+                        let std_dev = (sum_of_squares / n - mean * mean).sqrt();
+                        (mean, if std_dev > 0.0 { std_dev } else { 1.0 })
+                    })
+                    .collect(),
+            );
+        }
+
+        shocks
+    }
+
+    /// Estimate the price and its standard error from the simulated paths.
+    ///
+    /// # Arguments
+    ///
+    /// * `samples` - The discounted payoffs and controls of all paths.
+    /// * `control_mean` - The expectation of the control (`None` to ignore the control).
+    ///
+    /// # Returns
+    ///
+    /// The estimated price and its standard error (infinite if it cannot be estimated).
+    fn statistics(
+        &self,
+        samples: &[(f64, f64)],
+        control_mean: std::option::Option<f64>,
+    ) -> (f64, f64) {
+        let n = samples.len() as f64;
+        let beta = if let (true, Some(_)) = (self.control_variate, control_mean) {
+            let payoff_mean = samples.iter().map(|(payoff, _)| payoff).sum::<f64>() / n;
+            let sample_mean = samples.iter().map(|(_, control)| control).sum::<f64>() / n;
+            let (covariance, variance) =
+                samples
+                    .iter()
+                    .fold((0.0, 0.0), |(covariance, variance), (payoff, control)| {
+                        (
+                            covariance + (payoff - payoff_mean) * (control - sample_mean),
+                            variance + (control - sample_mean).powi(2),
+                        )
+                    });
+            if variance > 0.0 {
+                covariance / variance
+            } else {
+                0.0
+            }
+        } else {
+            0.0
+        };
+
+        let adjusted: Vec<f64> = samples
+            .iter()
+            .map(|(payoff, control)| payoff - beta * (control - control_mean.unwrap_or(0.0)))
+            .collect();
+        let price = adjusted.iter().sum::<f64>() / n;
+
+        let independent: Vec<f64> = adjusted
+            .chunks(if self.antithetic { 2 } else { 1 })
+            .map(|chunk| chunk.iter().sum::<f64>() / chunk.len() as f64)
+            .collect();
+        let m = independent.len() as f64;
+        let standard_error = if independent.len() > 1 {
+            (independent
+                .iter()
+                .map(|value| (value - price).powi(2))
+                .sum::<f64>()
+                / ((m - 1.0) * m))
+                .sqrt()
+        } else {
+            f64::INFINITY
+        };
+
+        (price, standard_error)
+    }
+
+    /// Simulate the spot prices at the `steps` equidistant times `0, Δt, ..., T - Δt` with `Δt = T / steps`.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument` - The underlying instrument.
+    /// * `time_to_maturity` - Time to maturity.
+    /// * `shocks` - The `steps - 1` standard normal shocks of the path and one per cash dividend going ex until
+    ///   `T - Δt`.
+    ///
+    /// # Returns
+    ///
+    /// The simulated spot prices.
+    fn simulate_path(
+        &self,
+        instrument: &Instrument,
+        time_to_maturity: f64,
+        shocks: &[f64],
+    ) -> Vec<f64> {
+        let times: Vec<f64> = (1..self.steps)
+            .map(|step| time_to_maturity * step as f64 / self.steps as f64)
+            .collect();
+        std::iter::once(instrument.spot())
+            .chain(self.simulate_at(instrument, &times, shocks))
+            .collect()
+    }
 
-                option.payoff(Some(simulated_price))
+    /// Simulate the spot prices at increasing times after the valuation date.
+    ///
+    /// The spot drops by the amount of a cash dividend on its ex-dividend date (and is absorbed at zero), so the path is
+    /// also simulated at the ex-dividend dates before the last time.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument` - The underlying instrument.
+    /// * `times` - The simulation times.
+    /// * `shocks` - The standard normal shocks of the increments between the times and ex-dividend dates (see
+    ///   [`Self::dividend_shocks`]).
+    ///
+    /// # Returns
+    ///
+    /// The simulated spot prices.
+    fn simulate_at(&self, instrument: &Instrument, times: &[f64], shocks: &[f64]) -> Vec<f64> {
+        let mut dividends = instrument
+            .cash_dividends
+            .iter()
+            .filter(|dividend| dividend.ex_date > 0.0)
+            .peekable();
+        let mut shocks = shocks.iter();
+        let mut log_spot = instrument.spot().ln();
+        let mut previous = 0.0;
+        let mut diffuse = |log_spot: &mut f64, time: f64| {
+            let variance = match &self.local_volatility {
+                Some(surface) => {
+                    surface.local_volatility(previous, log_spot.exp()).powi(2) * (time - previous)
+                }
+                None => self.variance(previous, time),
+            };
+            *log_spot += self.carry(instrument, previous, time) - 0.5 * variance
+                + variance.sqrt() * shocks.next().unwrap_or(&0.0);
+            if let Some(jumps) = &self.jumps {
+                let dt = time - previous;
+                let (count, size) = (shocks.next(), shocks.next());
+                *log_spot += jumps.log_jumps(dt, *count.unwrap_or(&0.0), *size.unwrap_or(&0.0))
+                    - jumps.intensity() * jumps.compensator() * dt;
+            }
+            previous = time;
+        };
+        times
+            .iter()
+            .map(|&time| {
+                while let Some(dividend) = dividends.next_if(|dividend| dividend.ex_date <= time) {
+                    diffuse(&mut log_spot, dividend.ex_date);
+                    log_spot = (log_spot.exp() - dividend.amount).max(0.0).ln();
+                }
+                diffuse(&mut log_spot, time);
+                log_spot.exp()
             })
+            .collect()
+    }
+
+    /// Count the additional shocks needed to simulate a path until a time because of cash dividends.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument` - The underlying instrument.
+    /// * `horizon` - The last simulation time.
+    ///
+    /// # Returns
+    ///
+    /// The number of cash dividends going ex in `(0, horizon]`.
+    fn dividend_shocks(instrument: &Instrument, horizon: f64) -> usize {
+        instrument
+            .cash_dividends
+            .iter()
+            .filter(|dividend| dividend.ex_date > 0.0 && dividend.ex_date <= horizon)
+            .count()
+    }
+
+    /// Count the shocks of an increment of a path: the diffusion shock and, with jumps, the jump count and size shocks.
+    fn shocks_per_increment(&self) -> usize {
+        if self.jumps.is_some() { 3 } else { 1 }
+    }
+
+    /// Check if the model has a discount curve, a borrow curve or a volatility term structure.
+    fn has_term_structures(&self) -> bool {
+        self.discount_curve.is_some()
+            || self.borrow_curve.is_some()
+            || self.volatility_term_structure.is_some()
+    }
+
+    /// Compute the discount factor to a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time.
+    ///
+    /// # Returns
+    ///
+    /// The discount factor on the discount curve or at the flat risk-free rate.
+    fn discount_factor(&self, time: f64) -> f64 {
+        match &self.discount_curve {
+            Some(curve) => curve.discount_factor(time),
+            None => (-self.risk_free_rate * time).exp(),
+        }
+    }
+
+    /// Compute the variance of the log spot between two times.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The start of the period.
+    /// * `to` - The end of the period.
+    ///
+    /// # Returns
+    ///
+    /// The forward variance at the money forward on the local volatility surface, on the volatility term structure
+    /// or at the flat volatility.
+    fn variance(&self, from: f64, to: f64) -> f64 {
+        if let Some(surface) = &self.local_volatility {
+            let variance = |time: f64| surface.total_variance(time, surface.forward(time));
+            return (variance(to) - variance(from)).max(0.0);
+        }
+        match &self.volatility_term_structure {
+            Some(term_structure) => {
+                (term_structure.variance(to) - term_structure.variance(from)).max(0.0)
+            }
+            None => self.volatility.powi(2) * (to - from),
+        }
+    }
+
+    /// Check if the paths of an instrument are lognormal, so that Black-Scholes prices are the expectations of
+    /// controls.
+    fn lognormal_paths(&self, instrument: &Instrument) -> bool {
+        instrument.cash_dividends.is_empty()
+            && self.local_volatility.is_none()
+            && self.jumps.is_none()
+    }
+
+    /// Compute the expected growth of the log spot between two times, without the convexity correction.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument` - The underlying instrument.
+    /// * `from` - The start of the period.
+    /// * `to` - The end of the period.
+    ///
+    /// # Returns
+    ///
+    /// The integral of the forward rate less the dividend yield and the forward borrow rate over the period.
+    fn carry(&self, instrument: &Instrument, from: f64, to: f64) -> f64 {
+        let rate = match &self.discount_curve {
+            Some(curve) => (curve.discount_factor(from) / curve.discount_factor(to)).ln(),
+            None => self.risk_free_rate * (to - from),
+        };
+        let borrow = self.borrow_curve.as_ref().map_or(0.0, |curve| {
+            (curve.discount_factor(from) / curve.discount_factor(to)).ln()
+        });
+        rate - borrow - instrument.continuous_dividend_yield * (to - from)
+    }
+
+    /// Compute the escrowed spot price of an instrument.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument` - The underlying instrument.
+    /// * `time_to_maturity` - Time to maturity.
+    ///
+    /// # Returns
+    ///
+    /// The dividend-adjusted spot less the cash dividends going ex until maturity discounted on the discount curve.
+    fn escrowed_spot(&self, instrument: &Instrument, time_to_maturity: f64) -> f64 {
+        instrument.calculate_adjusted_spot(time_to_maturity)
+            - instrument
+                .cash_dividends
+                .iter()
+                .filter(|dividend| dividend.ex_date > 0.0 && dividend.ex_date <= time_to_maturity)
+                .map(|dividend| dividend.amount * self.discount_factor(dividend.ex_date))
+                .sum::<f64>()
+    }
+
+    /// Get the Black-Scholes model with the same parameters and term structures.
+    fn black_scholes(&self) -> BlackScholesModel {
+        BlackScholesModel {
+            discount_curve: self.discount_curve.clone(),
+            borrow_curve: self.borrow_curve.clone(),
+            volatility_term_structure: self.volatility_term_structure.clone(),
+            ..BlackScholesModel::new(self.risk_free_rate, self.volatility)
+        }
+    }
+
+    /// Compute the expected spot price at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument` - The underlying instrument.
+    /// * `time` - The time.
+    ///
+    /// # Returns
+    ///
+    /// The expected spot price, less the cash dividends going ex until the time compounded to the time.
+    fn expected_spot(&self, instrument: &Instrument, time: f64) -> f64 {
+        instrument.spot() * self.carry(instrument, 0.0, time).exp()
+            - instrument
+                .cash_dividends
+                .iter()
+                .filter(|dividend| dividend.ex_date > 0.0 && dividend.ex_date <= time)
+                .map(|dividend| {
+                    dividend.amount * self.carry(instrument, dividend.ex_date, time).exp()
+                })
+                .sum::<f64>()
+    }
+
+    /// Compute the mean and variance of the logarithm of the geometric average of a simulated path.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument` - The underlying instrument.
+    /// * `time_to_maturity` - Time to maturity.
+    ///
+    /// # Returns
+    ///
+    /// The mean and variance of the log geometric average.
+    fn log_geometric_average_moments(
+        &self,
+        instrument: &Instrument,
+        time_to_maturity: f64,
+    ) -> (f64, f64) {
+        let n = self.steps;
+        let times = (0..n).map(|i| time_to_maturity * i as f64 / n as f64);
+        // The covariance of the log spots at t_i <= t_j is the variance to t_i, which is shared by 2 (n - i) - 1 pairs
+        let (mean, variance) = times
+            .enumerate()
+            .fold((0.0, 0.0), |(mean, variance), (i, time)| {
+                let time_variance = self.variance(0.0, time);
+                (
+                    mean + self.carry(instrument, 0.0, time) - 0.5 * time_variance,
+                    variance + time_variance * (2 * (n - i) - 1) as f64,
+                )
+            });
+        (
+            instrument.spot().ln() + mean / n as f64,
+            variance / (n * n) as f64,
+        )
+    }
+
+    /// Compute the expected discounted average of a simulated path.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument` - The underlying instrument.
+    /// * `time_to_maturity` - Time to maturity.
+    /// * `geometric` - Whether to average geometrically instead of arithmetically.
+    ///
+    /// # Returns
+    ///
+    /// The expected discounted average.
+    fn expected_average(
+        &self,
+        instrument: &Instrument,
+        time_to_maturity: f64,
+        geometric: bool,
+    ) -> f64 {
+        let discount_factor = self.discount_factor(time_to_maturity);
+        if geometric {
+            let (mean, variance) = self.log_geometric_average_moments(instrument, time_to_maturity);
+            return discount_factor * (mean + 0.5 * variance).exp();
+        }
+        let dt = time_to_maturity / self.steps as f64;
+        let sum: f64 = (0..self.steps)
+            .map(|i| self.expected_spot(instrument, i as f64 * dt))
             .sum();
+        discount_factor * sum / self.steps as f64
+    }
 
-        (total_payoff / self.simulations as f64) * discount_factor
+    /// Simulate the (averaged) terminal price for European and binary options.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to price.
+    ///
+    /// # Returns
+    ///
+    /// The sampler of the discounted payoff.
+    fn european_sampler<'a, T: Option>(&'a self, option: &'a T) -> PathSampler<'a> {
+        let instrument = option.instrument();
+        let ttm = option.time_to_maturity();
+        let discount_factor = self.discount_factor(ttm);
+
+        // The geometric average has no closed-form expectation with cash dividends or local volatility
+        let last_time = ttm * (self.steps - 1) as f64 / self.steps as f64;
+        let (dimension, control_mean) = match self.method {
+            AvgMethod::Brownian => (
+                self.steps + Self::dividend_shocks(instrument, ttm),
+                Some(discount_factor * self.expected_spot(instrument, ttm)),
+            ),
+            AvgMethod::Arithmetic => (
+                self.steps - 1 + Self::dividend_shocks(instrument, last_time),
+                Some(self.expected_average(instrument, ttm, false)),
+            ),
+            AvgMethod::Geometric => (
+                self.steps - 1 + Self::dividend_shocks(instrument, last_time),
+                self.lognormal_paths(instrument)
+                    .then(|| self.expected_average(instrument, ttm, true)),
+            ),
+        };
+        let sample = move |shocks: &[f64]| {
+            let simulated_price = match self.method {
+                AvgMethod::Brownian => {
+                    let times: Vec<f64> = (1..=self.steps)
+                        .map(|step| ttm * step as f64 / self.steps as f64)
+                        .collect();
+                    let path = self.simulate_at(instrument, &times, shocks);
+                    path.last().copied().unwrap_or(instrument.spot())
+                }
+                AvgMethod::Arithmetic => {
+                    let path = self.simulate_path(instrument, ttm, shocks);
+                    path.iter().sum::<f64>() / path.len() as f64
+                }
+                AvgMethod::Geometric => {
+                    let path = self.simulate_path(instrument, ttm, shocks);
+                    (path.iter().map(|price| price.ln()).sum::<f64>() / path.len() as f64).exp()
+                }
+            };
+
+            (
+                option.payoff(Some(simulated_price)) * discount_factor,
+                simulated_price * discount_factor,
+            )
+        };
+
+        PathSampler {
+            dimension,
+            control_mean,
+            brownian: true,
+            sample: Box::new(sample),
+        }
     }
 }
 
 impl OptionPricing for MonteCarloModel {
     fn price<T: Option>(&self, option: &T) -> f64 {
-        match option.style() {
-            OptionStyle::European => self.simulate_price_paths(option),
-            OptionStyle::Basket => self.simulate_price_paths(option),
-            OptionStyle::Rainbow(_) => self.simulate_price_paths(option),
-            OptionStyle::Barrier(_) => self.simulate_price_paths(option),
-            OptionStyle::DoubleBarrier(_, _) => self.simulate_price_paths(option),
-            OptionStyle::Asian(_) => self.price_asian(option),
-            OptionStyle::Lookback(_) => self.price_asian(option),
-            OptionStyle::Binary(_) => self.simulate_price_paths(option),
-            _ => panic!("Monte Carlo model does not support this option style"),
-        }
+        self.try_price(option).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_price<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.price_statistics(option).map(|result| result.price)
+    }
+
+    fn implied_volatility<T: Option>(&self, option: &T, market_price: f64) -> f64 {
+        self.try_implied_volatility(option, market_price)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_implied_volatility<T: Option>(
+        &self,
+        _option: &T,
+        _market_price: f64,
+    ) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Implied volatility"))
+    }
+}
+
+impl MonteCarloModel {
+    /// Simulate price paths for Asian and lookback options.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The Asian or lookback option to price.
+    ///
+    /// # Returns
+    ///
+    /// The sampler of the discounted payoff or an error if the averaging method is not supported.
+    fn asian_sampler<'a, T: Option>(
+        &'a self,
+        option: &'a T,
+    ) -> Result<PathSampler<'a>, PricingError> {
+        let geometric = match self.method {
+            AvgMethod::Geometric => true,
+            AvgMethod::Arithmetic => false,
+            AvgMethod::Brownian => {
+                return Err(PricingError::invalid_input(
+                    "Asian and lookback options require geometric or arithmetic averaging",
+                ));
+            }
+        };
+        let instrument = option.instrument();
+        let ttm = option.time_to_maturity();
+        let discount_factor = self.discount_factor(ttm);
+        let fixed_asian = *option.style() == OptionStyle::Asian(Permutation::Fixed);
+
+        // Partial-time and discretely monitored lookbacks observe the spot on their monitoring dates (starting from the
+        // spot and running extrema if the lookback period has started), followed by the maturity
+        let lookback = match (option.style(), option.lookback()) {
+            (OptionStyle::Lookback(permutation), Some(lookback))
+                if lookback.partial_time.is_some()
+                    || lookback.monitoring != Monitoring::Continuous =>
+            {
+                let (start, end) = lookback.period(*permutation, ttm);
+                let dates = match lookback.monitoring {
+                    Monitoring::Continuous => self.steps,
+                    Monitoring::Discrete(dates) => dates.max(1),
+                };
+                let mut times: Vec<f64> = (0..=dates)
+                    .map(|date| start + (end - start) * date as f64 / dates as f64)
+                    .filter(|&time| time > 0.0)
+                    .collect();
+                times.dedup();
+                let observations = times.len();
+                if times.last().is_none_or(|&time| time < ttm) {
+                    times.push(ttm);
+                }
+                let (max, min) = if start <= 0.0 {
+                    (
+                        lookback.max_spot(option.instrument()),
+                        lookback.min_spot(option.instrument()),
+                    )
+                } else {
+                    (f64::NEG_INFINITY, f64::INFINITY)
+                };
+                Some((*permutation, times, observations, max, min))
+            }
+            _ => None,
+        };
+
+        // Fixings on the option's schedule (and the maturity for the terminal spot) or on the grid `0, Δt, ..., T - Δt`
+        // (lookbacks simulated on their monitoring dates do not average)
+        let (averaging, times) = match option.averaging_schedule() {
+            _ if lookback.is_some() => (
+                Averaging::equally_weighted(vec![ttm]),
+                lookback.as_ref().map(|(_, times, ..)| times.clone()),
+            ),
+            Some(schedule) => {
+                let averaging = Averaging::from_schedule(schedule, ttm)?;
+                let mut times = averaging.times.clone();
+                if !fixed_asian && times.last().is_none_or(|&time| time < ttm) {
+                    times.push(ttm);
+                }
+                (averaging, Some(times))
+            }
+            None => (
+                Averaging::equally_weighted(
+                    (0..self.steps)
+                        .map(|step| ttm * step as f64 / self.steps as f64)
+                        .collect(),
+                ),
+                None,
+            ),
+        };
+        let last_time = times
+            .as_ref()
+            .map_or(ttm * (self.steps - 1) as f64 / self.steps as f64, |times| {
+                times.last().copied().unwrap_or(0.0)
+            });
+        let dimension = times.as_ref().map_or(self.steps - 1, Vec::len)
+            + Self::dividend_shocks(instrument, last_time);
+
+        // Fixed strike Asian options are controlled by their geometric counterpart (with lognormal paths and without
+        // term structures), the others by the last spot
+        let control_mean = if fixed_asian {
+            (self.lognormal_paths(instrument) && !self.has_term_structures()).then(|| {
+                BlackScholesModel::new(self.risk_free_rate, self.volatility).price_geometric_asian(
+                    option,
+                    instrument.spot(),
+                    &averaging,
+                    &Normal::new(0.0, 1.0).unwrap(),
+                )
+            })
+        } else {
+            Some(discount_factor * self.expected_spot(instrument, last_time))
+        };
+
+        let sample = move |shocks: &[f64]| {
+            let path = match &times {
+                Some(times) => self.simulate_at(instrument, times, shocks),
+                None => self.simulate_path(instrument, ttm, shocks),
+            };
+            let fixings = path.iter().zip(&averaging.weights);
+            let geometric_average = (averaging.log_fixed
+                + fixings
+                    .clone()
+                    .map(|(price, weight)| weight * price.ln())
+                    .sum::<f64>())
+            .exp();
+            let average = if geometric {
+                geometric_average
+            } else {
+                averaging.fixed + fixings.map(|(price, weight)| weight * price).sum::<f64>()
+            };
+            let last = path.last().copied().unwrap_or(instrument.spot());
+
+            let payoff = match (option.style(), &lookback) {
+                (OptionStyle::Asian(Permutation::Fixed), _) => option.payoff(Some(average)),
+                (OptionStyle::Asian(Permutation::Floating), _) => match option.option_type() {
+                    OptionType::Call => (last - average).max(0.0),
+                    OptionType::Put => (average - last).max(0.0),
+                },
+                (_, Some((permutation, _, observations, max, min))) => {
+                    let (max, min) = path[..*observations]
+                        .iter()
+                        .fold((*max, *min), |(max, min), &price| {
+                            (max.max(price), min.min(price))
+                        });
+                    match (permutation, option.option_type()) {
+                        (Permutation::Fixed, OptionType::Call) => (max - option.strike()).max(0.0),
+                        (Permutation::Fixed, OptionType::Put) => (option.strike() - min).max(0.0),
+                        (Permutation::Floating, OptionType::Call) => (last - min).max(0.0),
+                        (Permutation::Floating, OptionType::Put) => (max - last).max(0.0),
+                    }
+                }
+                _ => {
+                    let mut option = option.clone();
+                    option.instrument_mut().spot = path;
+                    option.payoff(Some(average))
+                }
+            };
+            let control = if fixed_asian {
+                match option.option_type() {
+                    OptionType::Call => (geometric_average - option.strike()).max(0.0),
+                    OptionType::Put => (option.strike() - geometric_average).max(0.0),
+                }
+            } else {
+                last
+            };
+
+            (payoff * discount_factor, control * discount_factor)
+        };
+
+        Ok(PathSampler {
+            dimension,
+            control_mean,
+            brownian: true,
+            sample: Box::new(sample),
+        })
     }
 
-    fn implied_volatility<T: Option>(&self, _option: &T, _market_price: f64) -> f64 {
-        unimplemented!()
+    /// Simulate price paths for barrier options.
+    ///
+    /// Continuously monitored barriers are checked at every step after shifting them towards the spot with the
+    /// Broadie-Glasserman-Kou correction. Discretely monitored barriers are checked exactly on their monitoring dates.
+    /// Knock-out rebates are discounted from the hitting time, knock-in rebates from maturity.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The barrier option to price.
+    ///
+    /// # Returns
+    ///
+    /// The sampler of the discounted payoff.
+    fn barrier_sampler<'a, T: Option>(&'a self, option: &'a T) -> PathSampler<'a> {
+        let barrier_type = match option.style() {
+            OptionStyle::Barrier(barrier_type) | OptionStyle::DoubleBarrier(barrier_type, _) => {
+                *barrier_type
+            }
+            _ => panic!("Not a barrier option"),
+        };
+        let barrier = option
+            .barrier()
+            .expect("Barrier options must provide a barrier");
+        let ttm = option.time_to_maturity();
+        let spot = option.instrument().spot();
+
+        // Simulation grid: `stride` steps between two monitoring dates
+        let (steps, stride, shift) = match barrier.monitoring {
+            Monitoring::Continuous => (
+                self.steps,
+                1,
+                0.5826 * (self.variance(0.0, ttm) / self.steps as f64).sqrt(),
+            ),
+            Monitoring::Discrete(dates) => {
+                let dates = dates.max(1);
+                let stride = self.steps.div_ceil(dates).max(1);
+                (dates * stride, stride, 0.0)
+            }
+        };
+        let monitored_barrier = Barrier {
+            level: if barrier.upper_level.is_some() || barrier_type.is_down() {
+                barrier.level * shift.exp()
+            } else {
+                barrier.level * (-shift).exp()
+            },
+            upper_level: barrier.upper_level.map(|level| level * (-shift).exp()),
+            ..*barrier
+        };
+
+        let dt = ttm / steps as f64;
+        let times: Vec<f64> = (1..=steps).map(|step| step as f64 * dt).collect();
+        let knock_in = barrier_type.is_knock_in();
+        let vanilla = move |price: f64| match option.option_type() {
+            OptionType::Call => (price - option.strike()).max(0.0),
+            OptionType::Put => (option.strike() - price).max(0.0),
+        };
+        // The vanilla option on the simulated spot is the control (with lognormal paths)
+        let control_mean = self.lognormal_paths(option.instrument()).then(|| {
+            self.black_scholes().price(&EuropeanOption::new(
+                Instrument::new()
+                    .with_spot(spot)
+                    .with_continuous_dividend_yield(option.instrument().continuous_dividend_yield),
+                option.strike(),
+                ttm,
+                option.option_type(),
+            ))
+        });
+
+        let sample = move |shocks: &[f64]| {
+            let path = self.simulate_at(option.instrument(), &times, shocks);
+            let price = path.last().copied().unwrap_or(spot);
+            let hit_time = if barrier.is_breached(barrier_type, spot) {
+                Some(0.0)
+            } else {
+                (1..=steps)
+                    .zip(&path)
+                    .find(|&(step, &price)| {
+                        step % stride == 0 && monitored_barrier.is_breached(barrier_type, price)
+                    })
+                    .map(|(step, _)| step as f64 * dt)
+            };
+
+            let discount_factor = self.discount_factor(ttm);
+            let payoff = match (knock_in, hit_time) {
+                (false, Some(time)) => barrier.rebate * self.discount_factor(time),
+                (false, None) | (true, Some(_)) => vanilla(price) * discount_factor,
+                (true, None) => barrier.rebate * discount_factor,
+            };
+            (payoff, vanilla(price) * discount_factor)
+        };
+
+        PathSampler {
+            dimension: steps + Self::dividend_shocks(option.instrument(), ttm),
+            control_mean,
+            brownian: true,
+            sample: Box::new(sample),
+        }
     }
 }
 
 impl MonteCarloModel {
-    /// Simulate price paths and compute the expected discounted payoff for Asian options.
-    ///
-    /// # Arguments
-    ///
-    /// * `option` - The Asian option to price.
-    ///
-    /// # Returns
-    ///
-    /// The expected discounted payoff of the option.
-    fn price_asian<T: Option>(&self, option: &T) -> f64 {
-        let mut rng: ThreadRng = rand::rng();
-        let mut sum = 0.0;
-        let mut option_clone = option.clone();
-
-        for _ in 0..self.simulations {
-            // Get a mutable reference to the instrument
-            let avg_price = {
-                let instrument = option_clone.instrument_mut();
-                match self.method {
-                    AvgMethod::Geometric => instrument.simulate_geometric_average_mut(
-                        &mut rng,
-                        SimMethod::Log,
-                        self.volatility,
-                        option.time_to_maturity(),
-                        self.risk_free_rate,
-                        self.steps,
-                    ),
-                    AvgMethod::Arithmetic => instrument.simulate_arithmetic_average_mut(
-                        &mut rng,
-                        SimMethod::Log,
-                        self.volatility,
-                        option.time_to_maturity(),
-                        self.risk_free_rate,
-                        self.steps,
-                    ),
-                    _ => panic!("Invalid averaging method"),
+    /// Simulate American and Bermudan options with the Longstaff-Schwartz algorithm.
+    ///
+    /// The continuation values at the exercise dates are regressed on the spot of in-the-money paths of an independent
+    /// set of `simulations` pilot paths. The priced paths follow the resulting exercise strategy, which is suboptimal,
+    /// so the estimate is biased low (rather than high as with in-sample regression) and its standard error is valid.
+    /// American options can be exercised at every step of the simulation, Bermudan options at their expiration dates.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The American or Bermudan option to price.
+    /// * `seed` - The seed of the pricing run.
+    ///
+    /// # Returns
+    ///
+    /// The sampler of the discounted payoff or an error if a Bermudan option has no expiration dates.
+    fn longstaff_schwartz_sampler<'a, T: Option>(
+        &'a self,
+        option: &'a T,
+        seed: u64,
+    ) -> Result<PathSampler<'a>, PricingError> {
+        let instrument = option.instrument();
+        let spot = instrument.spot();
+        let strike = option.strike();
+        let ttm = option.time_to_maturity();
+        let q = instrument.continuous_dividend_yield;
+
+        // Exercise dates after the valuation date and whether the option can be exercised immediately
+        let (times, exercisable_now) = match option.style() {
+            OptionStyle::Bermudan => {
+                let dates = option.expiration_dates().ok_or_else(|| {
+                    PricingError::invalid_input("Bermudan options must provide expiration dates")
+                })?;
+                let mut times: Vec<f64> = dates
+                    .iter()
+                    .copied()
+                    .filter(|&date| date > 0.0 && date < ttm)
+                    .chain(std::iter::once(ttm))
+                    .collect();
+                times.sort_by(f64::total_cmp);
+                times.dedup();
+                (times, dates.iter().any(|&date| date <= 0.0))
+            }
+            _ => (
+                (1..=self.steps)
+                    .map(|step| ttm * step as f64 / self.steps as f64)
+                    .collect(),
+                true,
+            ),
+        };
+        let dates = times.len();
+        let dimension = dates + Self::dividend_shocks(instrument, ttm);
+
+        // Backward induction on the pilot paths
+        let pilot = Shocks {
+            seed: seed ^ 0x9e37_79b9_7f4a_7c15,
+            dimension: dimension * self.shocks_per_increment(),
+            antithetic: false,
+            sobol: None,
+            moments: None,
+            bridge: None,
+        };
+        let paths: Vec<Vec<f64>> = (0..self.simulations)
+            .into_par_iter()
+            .map(|path| self.simulate_at(instrument, &times, &pilot.path(path)))
+            .collect();
+        let mut values: Vec<f64> = paths
+            .iter()
+            .map(|path| option.payoff(Some(path[dates - 1])))
+            .collect();
+        let mut coefficients = vec![None; dates - 1];
+        for date in (0..dates - 1).rev() {
+            let discount_factor =
+                self.discount_factor(times[date + 1]) / self.discount_factor(times[date]);
+            values
+                .iter_mut()
+                .for_each(|value| *value *= discount_factor);
+
+            let in_the_money: Vec<usize> = (0..paths.len())
+                .filter(|&path| option.payoff(Some(paths[path][date])) > 0.0)
+                .collect();
+            let samples: Vec<(f64, f64)> = in_the_money
+                .iter()
+                .map(|&path| (paths[path][date] / strike, values[path]))
+                .collect();
+            coefficients[date] = self.regression_basis.fit(&samples);
+            if let Some(beta) = &coefficients[date] {
+                for &path in &in_the_money {
+                    let exercise = option.payoff(Some(paths[path][date]));
+                    if exercise
+                        >= self
+                            .regression_basis
+                            .evaluate(beta, paths[path][date] / strike)
+                    {
+                        values[path] = exercise;
+                    }
                 }
+            }
+        }
+        let continuation_value = self.discount_factor(times[0]) * values.iter().sum::<f64>()
+            / values.len().max(1) as f64;
+        let exercise_now = exercisable_now && option.payoff(Some(spot)) > continuation_value;
+
+        // The European option on the simulated spot is the control (with lognormal paths)
+        let control_mean = self.lognormal_paths(instrument).then(|| {
+            self.black_scholes().price(&EuropeanOption::new(
+                Instrument::new()
+                    .with_spot(spot)
+                    .with_continuous_dividend_yield(q),
+                strike,
+                ttm,
+                option.option_type(),
+            ))
+        });
+
+        let basis = self.regression_basis;
+        let sample =
+            move |shocks: &[f64]| {
+                let path = self.simulate_at(instrument, &times, shocks);
+                let european = option.payoff(Some(path[dates - 1])) * self.discount_factor(ttm);
+                if exercise_now {
+                    return (option.payoff(Some(spot)), european);
+                }
+                let exercised = path.iter().zip(&times).zip(&coefficients).find_map(
+                    |((&price, &time), beta)| {
+                        let exercise = option.payoff(Some(price));
+                        let continuation = basis.evaluate(beta.as_ref()?, price / strike);
+                        (exercise > 0.0 && exercise >= continuation)
+                            .then(|| exercise * self.discount_factor(time))
+                    },
+                );
+                (exercised.unwrap_or(european), european)
             };
 
-            // Now call payoff after the mutable borrow is done
-            sum += (-self.risk_free_rate * option_clone.time_to_maturity()).exp()
-                * option_clone.payoff(Some(avg_price));
+        Ok(PathSampler {
+            dimension,
+            control_mean,
+            brownian: true,
+            sample: Box::new(sample),
+        })
+    }
+
+    /// Simulate the terminal prices of correlated assets following geometric Brownian motions.
+    ///
+    /// The terminal prices are sampled exactly, so no intermediate steps are needed. Cash dividends follow the escrowed
+    /// dividend model and assets without their own volatility use the model's volatility term structure.
+    ///
+    /// # Arguments
+    ///
+    /// * `assets` - The assets to simulate.
+    /// * `cholesky` - The Cholesky factor of the assets' correlation matrix.
+    /// * `time_to_maturity` - Time to maturity.
+    /// * `shocks` - The independent standard normal shocks of the assets.
+    ///
+    /// # Returns
+    ///
+    /// The simulated terminal prices of the assets.
+    fn simulate_correlated_terminal_spots(
+        &self,
+        assets: &[(&Instrument, f64)],
+        cholesky: &[Vec<f64>],
+        time_to_maturity: f64,
+        shocks: &[f64],
+    ) -> Vec<f64> {
+        assets
+            .iter()
+            .zip(cholesky)
+            .map(|((asset, _), row)| {
+                let variance = asset.volatility.map_or_else(
+                    || self.variance(0.0, time_to_maturity),
+                    |volatility| volatility.powi(2) * time_to_maturity,
+                );
+                let shock: f64 = row.iter().zip(shocks).map(|(l, z)| l * z).sum();
+                self.escrowed_spot(asset, time_to_maturity)
+                    * (self.carry(asset, 0.0, time_to_maturity) - 0.5 * variance
+                        + variance.sqrt() * shock)
+                        .exp()
+            })
+            .collect()
+    }
+
+    /// Simulate the correlated terminal prices of the assets of a multi-asset option.
+    ///
+    /// Each asset uses its own volatility (falling back to the model's volatility) and the shocks are correlated
+    /// with the Cholesky factor of the instrument's correlation matrix. The control is the discounted weighted sum
+    /// of the terminal prices.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The multi-asset option to price.
+    /// * `payoff` - The payoff given the terminal prices and weights of the assets.
+    ///
+    /// # Returns
+    ///
+    /// The sampler of the discounted payoff or an error if the correlation matrix is not positive definite.
+    fn multi_asset_sampler<'a, T, F>(
+        &'a self,
+        option: &'a T,
+        payoff: F,
+    ) -> Result<PathSampler<'a>, PricingError>
+    where
+        T: Option,
+        F: Fn(&[f64], &[f64]) -> f64 + Sync + 'a,
+    {
+        if self.local_volatility.is_some() {
+            return Err(PricingError::not_implemented(
+                "Local volatility for multi-asset options",
+            ));
+        }
+        if self.jumps.is_some() {
+            return Err(PricingError::not_implemented(
+                "Jumps for multi-asset options",
+            ));
+        }
+        let instrument = option.instrument();
+        let ttm = option.time_to_maturity();
+        let discount_factor = self.discount_factor(ttm);
+        let assets: Vec<(&Instrument, f64)> = if instrument.assets.is_empty() {
+            vec![(instrument, 1.0)]
+        } else {
+            instrument.assets.iter().map(|(a, w)| (a, *w)).collect()
+        };
+        let weights: Vec<f64> = assets.iter().map(|(_, weight)| *weight).collect();
+        let cholesky = numerics::cholesky(&instrument.correlation_matrix()).ok_or_else(|| {
+            PricingError::invalid_input("Correlation matrix must be positive semi-definite")
+        })?;
+        let control_mean: f64 = assets
+            .iter()
+            .map(|(asset, weight)| {
+                weight
+                    * discount_factor
+                    * self.escrowed_spot(asset, ttm)
+                    * self.carry(asset, 0.0, ttm).exp()
+            })
+            .sum();
+
+        let dimension = assets.len();
+        let sample = move |shocks: &[f64]| {
+            let spots = self.simulate_correlated_terminal_spots(&assets, &cholesky, ttm, shocks);
+            let weighted_sum: f64 = spots.iter().zip(&weights).map(|(s, w)| s * w).sum();
+            (
+                payoff(&spots, &weights) * discount_factor,
+                weighted_sum * discount_factor,
+            )
+        };
+
+        Ok(PathSampler {
+            dimension,
+            control_mean: Some(control_mean),
+            brownian: false,
+            sample: Box::new(sample),
+        })
+    }
+
+    /// Simulate correlated asset prices for basket options.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The basket option to price.
+    ///
+    /// # Returns
+    ///
+    /// The sampler of the discounted payoff or an error if the correlation matrix is not positive definite.
+    fn basket_sampler<'a, T: Option>(
+        &'a self,
+        option: &'a T,
+    ) -> Result<PathSampler<'a>, PricingError> {
+        self.multi_asset_sampler(option, move |spots, weights| {
+            let basket_value: f64 = spots.iter().zip(weights).map(|(s, w)| s * w).sum();
+            option.payoff(Some(basket_value))
+        })
+    }
+
+    /// Simulate correlated asset prices for rainbow options.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The rainbow option to price.
+    /// * `rainbow_type` - The type of the rainbow option.
+    ///
+    /// # Returns
+    ///
+    /// The sampler of the discounted payoff or an error if the correlation matrix is not positive definite.
+    fn rainbow_sampler<'a, T: Option>(
+        &'a self,
+        option: &'a T,
+        rainbow_type: RainbowType,
+    ) -> Result<PathSampler<'a>, PricingError> {
+        self.multi_asset_sampler(option, move |spots, weights| {
+            rainbow_type.payoff(option.strike(), spots, weights)
+        })
+    }
+}
+
+impl ModelParameters for MonteCarloModel {
+    fn risk_free_rate(&self) -> f64 {
+        self.risk_free_rate
+    }
+
+    /// Set the risk-free interest rate, shifting the discount curve in parallel by the change of the rate.
+    fn set_risk_free_rate(&mut self, risk_free_rate: f64) {
+        if let Some(curve) = &mut self.discount_curve {
+            *curve = curve.shifted(risk_free_rate - self.risk_free_rate);
         }
+        self.risk_free_rate = risk_free_rate;
+    }
+
+    fn volatility(&self) -> f64 {
+        self.volatility
+    }
 
-        // Return average payoff
-        sum / self.simulations as f64
+    /// Set the volatility, shifting the volatility term structure and the local volatility surface in parallel by the
+    /// change of the volatility.
+    fn set_volatility(&mut self, volatility: f64) {
+        if let Some(term_structure) = &mut self.volatility_term_structure {
+            *term_structure = term_structure.shifted(volatility - self.volatility);
+        }
+        if let Some(surface) = &mut self.local_volatility {
+            *surface = surface.shifted(volatility - self.volatility);
+        }
+        self.volatility = volatility;
     }
 }
 
@@ -0,0 +1,563 @@
+//! Module for calculating the Greeks of any option pricing model numerically.
+//!
+//! `NumericalGreeks` wraps a pricing model and calculates every Greek of the `OptionGreeks` trait by bumping the
+//! spot price, volatility, risk-free rate, time to maturity and dividend yield and repricing the option with
+//! central finite differences, e.g.:
+//!
+//! ```text
+//! Δ = (V(S + h) - V(S - h)) / 2h
+//! Γ = (V(S + h) - 2V(S) + V(S - h)) / h²
+//! ```
+//!
+//! Mixed and higher-order Greeks are calculated by nesting the differences (e.g. vanna is the central difference
+//! of delta in the volatility). Time derivatives are taken with respect to the time to maturity and are not defined
+//! at expiry.
+//!
+//! The spot price is bumped relatively (all assets of a multi-asset instrument are scaled by the same factor), all
+//! other parameters are bumped by an absolute amount.
+//!
+//! Monte Carlo models should be seeded (see `MonteCarloModel::with_seed`), so that all bumped prices are simulated
+//! with common random numbers. Otherwise, the Monte Carlo noise dominates the finite differences.
+//!
+//! ## References
+//!
+//! - [Wikipedia - Finite difference](https://en.wikipedia.org/wiki/Finite_difference)
+//! - [Glasserman, P. - Monte Carlo Methods in Financial Engineering](https://doi.org/10.1007/978-0-387-21617-1)
+//!
+//! ## Example
+//!
+//! ```
+//! use quantrs::options::{AmericanOption, BinomialTreeModel, Instrument, NumericalGreeks, OptionGreeks, OptionType};
+//!
+//! let instrument = Instrument::new().with_spot(100.0);
+//! let option = AmericanOption::new(instrument, 100.0, 1.0, OptionType::Put);
+//! let model = NumericalGreeks::new(BinomialTreeModel::new(0.05, 0.2, 500)).with_spot_bump(0.02);
+//!
+//! let delta = model.delta(&option);
+//! let vega = model.vega(&option);
+//! println!("Delta: {delta}, Vega: {vega}");
+//! ```
+
+use crate::options::{
+    Instrument, ModelParameters, Option, OptionGreeks, OptionPricing, OptionStrategy, PricingError,
+};
+
+/// Shifts applied to the market parameters of an option and its pricing model.
+#[derive(Debug, Default, Clone, Copy)]
+struct Shift {
+    spot: f64,
+    volatility: f64,
+    rate: f64,
+    time: f64,
+    dividend: f64,
+}
+
+/// Wrapper calculating the Greeks of a pricing model by bumping and repricing.
+#[derive(Debug, Clone)]
+pub struct NumericalGreeks<M> {
+    /// The wrapped pricing model.
+    pub model: M,
+    /// Relative bump of the spot price (e.g., 0.01 for 1% of the spot price).
+    pub spot_bump: f64,
+    /// Absolute bump of the volatility (e.g., 0.01 for 1 vol point).
+    pub volatility_bump: f64,
+    /// Absolute bump of the risk-free interest rate (e.g., 0.0001 for 1 basis point).
+    pub rate_bump: f64,
+    /// Absolute bump of the time to maturity in years (capped at half the time to maturity).
+    pub time_bump: f64,
+    /// Absolute bump of the continuous dividend yield (e.g., 0.0001 for 1 basis point).
+    pub dividend_bump: f64,
+}
+
+impl<M: OptionPricing + ModelParameters> NumericalGreeks<M> {
+    /// Create a new `NumericalGreeks` wrapper with default bump sizes.
+    ///
+    /// The spot price is bumped by 1%, the volatility by 0.01, the risk-free rate and the dividend yield by one
+    /// basis point and the time to maturity by one day.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The pricing model to calculate the Greeks of.
+    ///
+    /// # Returns
+    ///
+    /// A new `NumericalGreeks`.
+    pub fn new(model: M) -> Self {
+        Self {
+            model,
+            spot_bump: 0.01,
+            volatility_bump: 0.01,
+            rate_bump: 1e-4,
+            time_bump: 1.0 / 365.0,
+            dividend_bump: 1e-4,
+        }
+    }
+
+    /// Set the relative bump of the spot price.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot_bump` - Relative bump of the spot price (e.g., 0.01 for 1% of the spot price).
+    ///
+    /// # Returns
+    ///
+    /// The wrapper with the spot bump set.
+    pub fn with_spot_bump(mut self, spot_bump: f64) -> Self {
+        self.spot_bump = spot_bump;
+        self
+    }
+
+    /// Set the absolute bump of the volatility.
+    ///
+    /// # Arguments
+    ///
+    /// * `volatility_bump` - Absolute bump of the volatility (e.g., 0.01 for 1 vol point).
+    ///
+    /// # Returns
+    ///
+    /// The wrapper with the volatility bump set.
+    pub fn with_volatility_bump(mut self, volatility_bump: f64) -> Self {
+        self.volatility_bump = volatility_bump;
+        self
+    }
+
+    /// Set the absolute bump of the risk-free interest rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate_bump` - Absolute bump of the risk-free interest rate (e.g., 0.0001 for 1 basis point).
+    ///
+    /// # Returns
+    ///
+    /// The wrapper with the rate bump set.
+    pub fn with_rate_bump(mut self, rate_bump: f64) -> Self {
+        self.rate_bump = rate_bump;
+        self
+    }
+
+    /// Set the absolute bump of the time to maturity.
+    ///
+    /// # Arguments
+    ///
+    /// * `time_bump` - Absolute bump of the time to maturity in years (e.g., 1/365 for one day).
+    ///
+    /// # Returns
+    ///
+    /// The wrapper with the time bump set.
+    pub fn with_time_bump(mut self, time_bump: f64) -> Self {
+        self.time_bump = time_bump;
+        self
+    }
+
+    /// Set the absolute bump of the continuous dividend yield.
+    ///
+    /// # Arguments
+    ///
+    /// * `dividend_bump` - Absolute bump of the continuous dividend yield (e.g., 0.0001 for 1 basis point).
+    ///
+    /// # Returns
+    ///
+    /// The wrapper with the dividend bump set.
+    pub fn with_dividend_bump(mut self, dividend_bump: f64) -> Self {
+        self.dividend_bump = dividend_bump;
+        self
+    }
+
+    /// Price the option with shifted market parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to price.
+    /// * `shift` - The shifts of the market parameters.
+    ///
+    /// # Returns
+    ///
+    /// The price of the option or the error of the wrapped model.
+    fn shifted_price<T: Option>(&self, option: &T, shift: Shift) -> Result<f64, PricingError> {
+        let mut model = self.model.clone();
+        model.set_volatility(model.volatility() + shift.volatility);
+        model.set_risk_free_rate(model.risk_free_rate() + shift.rate);
+
+        let mut option = option.clone();
+        let mut instrument = option.instrument().clone();
+        let spot_factor = 1.0 + shift.spot / instrument.spot();
+        Self::shift_instrument(&mut instrument, spot_factor, shift.dividend);
+        option.set_instrument(instrument);
+        option.set_time_to_maturity(option.time_to_maturity() + shift.time);
+
+        model.try_price(&option)
+    }
+
+    /// Scale the spot prices and shift the continuous dividend yield of an instrument and its assets.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument` - The instrument to shift.
+    /// * `spot_factor` - The factor to scale the spot prices by.
+    /// * `dividend` - The shift of the continuous dividend yield.
+    fn shift_instrument(instrument: &mut Instrument, spot_factor: f64, dividend: f64) {
+        instrument
+            .spot
+            .iter_mut()
+            .for_each(|spot| *spot *= spot_factor);
+        instrument.continuous_dividend_yield += dividend;
+        instrument
+            .assets
+            .iter_mut()
+            .for_each(|(asset, _)| Self::shift_instrument(asset, spot_factor, dividend));
+    }
+
+    /// Absolute spot bump for an option.
+    fn spot_step<T: Option>(&self, option: &T) -> f64 {
+        self.spot_bump * option.instrument().spot()
+    }
+
+    /// Time bump for an option (at most half the time to maturity).
+    ///
+    /// Fails if the bump vanishes, e.g. for an option at expiry, as the time derivatives are then undefined.
+    fn time_step<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        let step = self.time_bump.min(0.5 * option.time_to_maturity());
+        if step.is_nan() || step <= 0.0 {
+            return Err(PricingError::invalid_input(
+                "Time derivatives require a positive time bump and time to maturity",
+            ));
+        }
+        Ok(step)
+    }
+}
+
+/// Central difference approximation of the first derivative of `f` at 0.
+fn first_difference<F: Fn(f64) -> Result<f64, PricingError>>(
+    f: F,
+    h: f64,
+) -> Result<f64, PricingError> {
+    Ok((f(h)? - f(-h)?) / (2.0 * h))
+}
+
+/// Central difference approximation of the second derivative of `f` at 0.
+fn second_difference<F: Fn(f64) -> Result<f64, PricingError>>(
+    f: F,
+    h: f64,
+) -> Result<f64, PricingError> {
+    Ok((f(h)? - 2.0 * f(0.0)? + f(-h)?) / h.powi(2))
+}
+
+/// Central difference approximation of the third derivative of `f` at 0.
+fn third_difference<F: Fn(f64) -> Result<f64, PricingError>>(
+    f: F,
+    h: f64,
+) -> Result<f64, PricingError> {
+    Ok((f(2.0 * h)? - 2.0 * f(h)? + 2.0 * f(-h)? - f(-2.0 * h)?) / (2.0 * h.powi(3)))
+}
+
+impl<M: OptionPricing + ModelParameters> OptionPricing for NumericalGreeks<M> {
+    fn price<T: Option>(&self, option: &T) -> f64 {
+        self.model.price(option)
+    }
+
+    fn try_price<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        self.model.try_price(option)
+    }
+
+    fn implied_volatility<T: Option>(&self, option: &T, market_price: f64) -> f64 {
+        self.model.implied_volatility(option, market_price)
+    }
+
+    fn try_implied_volatility<T: Option>(
+        &self,
+        option: &T,
+        market_price: f64,
+    ) -> Result<f64, PricingError> {
+        self.model.try_implied_volatility(option, market_price)
+    }
+}
+
+impl<M: OptionPricing + ModelParameters> OptionGreeks for NumericalGreeks<M> {
+    fn try_delta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        first_difference(
+            |spot| {
+                self.shifted_price(
+                    option,
+                    Shift {
+                        spot,
+                        ..Shift::default()
+                    },
+                )
+            },
+            self.spot_step(option),
+        )
+    }
+
+    fn try_vega<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        first_difference(
+            |volatility| {
+                self.shifted_price(
+                    option,
+                    Shift {
+                        volatility,
+                        ..Shift::default()
+                    },
+                )
+            },
+            self.volatility_bump,
+        )
+    }
+
+    fn try_theta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        first_difference(
+            |time| {
+                self.shifted_price(
+                    option,
+                    Shift {
+                        time,
+                        ..Shift::default()
+                    },
+                )
+            },
+            self.time_step(option)?,
+        )
+    }
+
+    fn try_rho<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        first_difference(
+            |rate| {
+                self.shifted_price(
+                    option,
+                    Shift {
+                        rate,
+                        ..Shift::default()
+                    },
+                )
+            },
+            self.rate_bump,
+        )
+    }
+
+    fn try_lambda<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Ok(self.try_delta(option)? * option.instrument().spot() / self.try_price(option)?)
+    }
+
+    fn try_epsilon<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        first_difference(
+            |dividend| {
+                self.shifted_price(
+                    option,
+                    Shift {
+                        dividend,
+                        ..Shift::default()
+                    },
+                )
+            },
+            self.dividend_bump,
+        )
+    }
+
+    fn try_gamma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        second_difference(
+            |spot| {
+                self.shifted_price(
+                    option,
+                    Shift {
+                        spot,
+                        ..Shift::default()
+                    },
+                )
+            },
+            self.spot_step(option),
+        )
+    }
+
+    fn try_vanna<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        first_difference(
+            |volatility| {
+                first_difference(
+                    |spot| {
+                        self.shifted_price(
+                            option,
+                            Shift {
+                                spot,
+                                volatility,
+                                ..Shift::default()
+                            },
+                        )
+                    },
+                    self.spot_step(option),
+                )
+            },
+            self.volatility_bump,
+        )
+    }
+
+    fn try_charm<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        first_difference(
+            |time| {
+                first_difference(
+                    |spot| {
+                        self.shifted_price(
+                            option,
+                            Shift {
+                                spot,
+                                time,
+                                ..Shift::default()
+                            },
+                        )
+                    },
+                    self.spot_step(option),
+                )
+            },
+            self.time_step(option)?,
+        )
+    }
+
+    fn try_vomma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        second_difference(
+            |volatility| {
+                self.shifted_price(
+                    option,
+                    Shift {
+                        volatility,
+                        ..Shift::default()
+                    },
+                )
+            },
+            self.volatility_bump,
+        )
+    }
+
+    fn try_veta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        first_difference(
+            |time| {
+                first_difference(
+                    |volatility| {
+                        self.shifted_price(
+                            option,
+                            Shift {
+                                volatility,
+                                time,
+                                ..Shift::default()
+                            },
+                        )
+                    },
+                    self.volatility_bump,
+                )
+            },
+            self.time_step(option)?,
+        )
+    }
+
+    fn try_vera<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        first_difference(
+            |volatility| {
+                first_difference(
+                    |rate| {
+                        self.shifted_price(
+                            option,
+                            Shift {
+                                rate,
+                                volatility,
+                                ..Shift::default()
+                            },
+                        )
+                    },
+                    self.rate_bump,
+                )
+            },
+            self.volatility_bump,
+        )
+    }
+
+    fn try_speed<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        third_difference(
+            |spot| {
+                self.shifted_price(
+                    option,
+                    Shift {
+                        spot,
+                        ..Shift::default()
+                    },
+                )
+            },
+            self.spot_step(option),
+        )
+    }
+
+    fn try_zomma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        first_difference(
+            |volatility| {
+                second_difference(
+                    |spot| {
+                        self.shifted_price(
+                            option,
+                            Shift {
+                                spot,
+                                volatility,
+                                ..Shift::default()
+                            },
+                        )
+                    },
+                    self.spot_step(option),
+                )
+            },
+            self.volatility_bump,
+        )
+    }
+
+    fn try_color<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        first_difference(
+            |time| {
+                second_difference(
+                    |spot| {
+                        self.shifted_price(
+                            option,
+                            Shift {
+                                spot,
+                                time,
+                                ..Shift::default()
+                            },
+                        )
+                    },
+                    self.spot_step(option),
+                )
+            },
+            self.time_step(option)?,
+        )
+    }
+
+    fn try_ultima<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        third_difference(
+            |volatility| {
+                self.shifted_price(
+                    option,
+                    Shift {
+                        volatility,
+                        ..Shift::default()
+                    },
+                )
+            },
+            self.volatility_bump,
+        )
+    }
+
+    fn try_parmicharma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        second_difference(
+            |time| {
+                first_difference(
+                    |spot| {
+                        self.shifted_price(
+                            option,
+                            Shift {
+                                spot,
+                                time,
+                                ..Shift::default()
+                            },
+                        )
+                    },
+                    self.spot_step(option),
+                )
+            },
+            self.time_step(option)?,
+        )
+    }
+}
+
+impl<M: OptionPricing + ModelParameters> OptionStrategy for NumericalGreeks<M> {}
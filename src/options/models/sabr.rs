@@ -0,0 +1,425 @@
+//! Module for the SABR stochastic volatility model.
+//!
+//! The SABR model describes the forward price (or rate) of a single expiry with a stochastic volatility:
+//!
+//! ```text
+//! dF = α F^β dW₁
+//! dα = ν α dW₂
+//! dW₁ dW₂ = ρ dt
+//! ```
+//!
+//! where:
+//! - `α` (alpha) is the initial volatility of the forward.
+//! - `β` (beta) is the elasticity of the volatility, which sets the backbone of the smile (1 for lognormal, 0 for
+//!   normal dynamics). It is usually fixed by convention and not calibrated.
+//! - `ρ` (rho) is the correlation between the forward and its volatility, which sets the skew.
+//! - `ν` (nu) is the volatility of the volatility, which sets the curvature of the smile.
+//!
+//! ## Implied volatilities
+//!
+//! The model is used through the asymptotic expansions of its implied volatilities:
+//!
+//! - **Lognormal (Black) volatility**: the expansion of Hagan et al. or its refinement by Obloj, which is exact in
+//!   the strike at the leading order and stays consistent for `β < 1` away from the money (see [`SabrExpansion`]).
+//! - **Normal (Bachelier) volatility**: the expansion of Hagan et al.
+//!
+//! A `SabrModel` is a volatility source of the [`Black76Model`](crate::options::Black76Model) and the
+//! [`BlackScholesModel`](crate::options::BlackScholesModel) (see `with_sabr`), which price every option with the
+//! lognormal volatility at its strike. The parameters `α`, `ρ` and `ν` of an expiry are fitted to quoted implied
+//! volatilities with a [`SabrCalibrator`], keeping `β` fixed.
+//!
+//! ## References
+//!
+//! - Hagan, P. S., Kumar, D., Lesniewski, A. S., Woodward, D. E. Managing Smile Risk, Wilmott Magazine, 2002
+//! - Obloj, J. Fine-tune your smile: Correction to Hagan et al., Wilmott Magazine, 2008
+//!
+//! ## Example
+//!
+//! ```
+//! use quantrs::options::{Black76Model, EuropeanOption, Instrument, MarketQuote, OptionPricing, OptionType, SabrModel};
+//!
+//! let sabr = SabrModel::new(0.03, 0.5, -0.3, 0.4);
+//! println!("Lognormal volatility: {}", sabr.lognormal_volatility(0.03, 0.035, 2.0));
+//! println!("Normal volatility: {}", sabr.normal_volatility(0.03, 0.035, 2.0));
+//!
+//! // Price a caplet on the smile
+//! let model = Black76Model::new(0.02, 0.0).with_sabr(sabr.clone());
+//! let option = EuropeanOption::new(Instrument::new().with_spot(0.03), 0.035, 2.0, OptionType::Call);
+//! println!("Caplet price: {}", model.price(&option));
+//!
+//! // Fit alpha, rho and nu to the quotes of an expiry
+//! let quotes: Vec<MarketQuote> = [0.02, 0.025, 0.03, 0.035, 0.04]
+//!     .iter()
+//!     .map(|&strike| {
+//!         MarketQuote::implied_volatility(strike, 2.0, OptionType::Call, sabr.lognormal_volatility(0.03, strike, 2.0))
+//!     })
+//!     .collect();
+//! let calibration = SabrModel::new(0.02, 0.5, 0.0, 0.5).calibrate(0.03, &quotes).unwrap();
+//! println!("Fitted model: {:?}", calibration.model);
+//! ```
+
+use crate::options::{MarketQuote, PricingError, QuoteValue, numerics};
+
+/// Enum for the expansion of the lognormal implied volatility of a [`SabrModel`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum SabrExpansion {
+    /// Expansion of Hagan et al. (2002).
+    #[default]
+    Hagan,
+    /// Refined leading order term of Obloj (2008).
+    Obloj,
+}
+
+/// SABR stochastic volatility model of a forward.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SabrModel {
+    /// Initial volatility of the forward.
+    pub alpha: f64,
+    /// Elasticity of the volatility (between 0 and 1).
+    pub beta: f64,
+    /// Correlation between the forward and its volatility (between -1 and 1).
+    pub rho: f64,
+    /// Volatility of the volatility.
+    pub nu: f64,
+    /// Expansion of the lognormal implied volatility.
+    pub expansion: SabrExpansion,
+    /// Parallel shift of the lognormal implied volatilities (e.g., to bump the vega of a model).
+    pub volatility_shift: f64,
+}
+
+impl SabrModel {
+    /// Create a new `SabrModel` with the expansion of Hagan et al.
+    ///
+    /// # Arguments
+    ///
+    /// * `alpha` - The initial volatility of the forward.
+    /// * `beta` - The elasticity of the volatility (between 0 and 1).
+    /// * `rho` - The correlation between the forward and its volatility.
+    /// * `nu` - The volatility of the volatility.
+    ///
+    /// # Returns
+    ///
+    /// A new `SabrModel`.
+    pub fn new(alpha: f64, beta: f64, rho: f64, nu: f64) -> Self {
+        Self {
+            alpha,
+            beta,
+            rho,
+            nu,
+            expansion: SabrExpansion::Hagan,
+            volatility_shift: 0.0,
+        }
+    }
+
+    /// Set the expansion of the lognormal implied volatility.
+    ///
+    /// # Arguments
+    ///
+    /// * `expansion` - The expansion.
+    ///
+    /// # Returns
+    ///
+    /// The model with the expansion set.
+    pub fn with_expansion(mut self, expansion: SabrExpansion) -> Self {
+        self.expansion = expansion;
+        self
+    }
+
+    /// Calculate the lognormal (Black) implied volatility.
+    ///
+    /// # Arguments
+    ///
+    /// * `forward` - The forward price of the underlying.
+    /// * `strike` - The strike price of the option.
+    /// * `time_to_maturity` - The time to maturity of the option.
+    ///
+    /// # Returns
+    ///
+    /// The lognormal implied volatility (with the volatility shift), or zero if the forward or the strike are not
+    /// positive.
+    pub fn lognormal_volatility(&self, forward: f64, strike: f64, time_to_maturity: f64) -> f64 {
+        if forward <= 0.0 || strike <= 0.0 {
+            return 0.0;
+        }
+        let (alpha, beta, rho, nu) = (self.alpha, self.beta, self.rho, self.nu);
+        let one_minus_beta = 1.0 - beta;
+        let log_moneyness = (forward / strike).ln();
+        let geometric = (forward * strike).powf(0.5 * one_minus_beta);
+
+        let leading = match self.expansion {
+            SabrExpansion::Hagan => {
+                let z = nu / alpha * geometric * log_moneyness;
+                let l2 = log_moneyness * log_moneyness;
+                alpha
+                    / (geometric
+                        * (1.0
+                            + one_minus_beta.powi(2) / 24.0 * l2
+                            + one_minus_beta.powi(4) / 1920.0 * l2 * l2))
+                    * z_over_x(z, rho)
+            }
+            SabrExpansion::Obloj => {
+                // α (1 - β) ln(F / K) / (F^(1-β) - K^(1-β)), which tends to α F^(β-1) at the money and to α for β = 1
+                let backbone = if one_minus_beta.abs() < 1e-12 {
+                    alpha
+                } else if log_moneyness.abs() < 1e-12 {
+                    alpha * forward.powf(-one_minus_beta)
+                } else {
+                    alpha * one_minus_beta * log_moneyness
+                        / (forward.powf(one_minus_beta) - strike.powf(one_minus_beta))
+                };
+                let z = nu * log_moneyness / backbone;
+                backbone * z_over_x(z, rho)
+            }
+        };
+        let correction = 1.0
+            + (one_minus_beta.powi(2) / 24.0 * alpha * alpha / (geometric * geometric)
+                + 0.25 * rho * beta * nu * alpha / geometric
+                + (2.0 - 3.0 * rho * rho) / 24.0 * nu * nu)
+                * time_to_maturity;
+
+        (leading * correction + self.volatility_shift).max(0.0)
+    }
+
+    /// Calculate the normal (Bachelier) implied volatility with the expansion of Hagan et al.
+    ///
+    /// # Arguments
+    ///
+    /// * `forward` - The forward price of the underlying.
+    /// * `strike` - The strike price of the option.
+    /// * `time_to_maturity` - The time to maturity of the option.
+    ///
+    /// # Returns
+    ///
+    /// The normal implied volatility in units of the forward, or zero if the forward or the strike are not positive
+    /// (unless `β = 0`).
+    pub fn normal_volatility(&self, forward: f64, strike: f64, time_to_maturity: f64) -> f64 {
+        let (alpha, beta, rho, nu) = (self.alpha, self.beta, self.rho, self.nu);
+        if beta == 0.0 {
+            // Normal SABR: the expansion in F - K holds for any sign of the forward and the strike
+            let z = nu / alpha * (forward - strike);
+            let correction = 1.0 + (2.0 - 3.0 * rho * rho) / 24.0 * nu * nu * time_to_maturity;
+            return alpha * z_over_x(z, rho) * correction;
+        }
+        if forward <= 0.0 || strike <= 0.0 {
+            return 0.0;
+        }
+        let one_minus_beta = 1.0 - beta;
+        let log_moneyness = (forward / strike).ln();
+        let l2 = log_moneyness * log_moneyness;
+        let geometric = (forward * strike).powf(0.5 * one_minus_beta);
+        let z = nu / alpha * geometric * log_moneyness;
+
+        let leading =
+            alpha * (forward * strike).powf(0.5 * beta) * (1.0 + l2 / 24.0 + l2 * l2 / 1920.0)
+                / (1.0
+                    + one_minus_beta.powi(2) / 24.0 * l2
+                    + one_minus_beta.powi(4) / 1920.0 * l2 * l2)
+                * z_over_x(z, rho);
+        let correction = 1.0
+            + (-beta * (2.0 - beta) / 24.0 * alpha * alpha / (geometric * geometric)
+                + 0.25 * rho * beta * nu * alpha / geometric
+                + (2.0 - 3.0 * rho * rho) / 24.0 * nu * nu)
+                * time_to_maturity;
+
+        leading * correction
+    }
+
+    /// Shift the lognormal implied volatilities in parallel.
+    ///
+    /// # Arguments
+    ///
+    /// * `shift` - The shift added to every lognormal implied volatility.
+    ///
+    /// # Returns
+    ///
+    /// The shifted model.
+    pub fn shifted(&self, shift: f64) -> Self {
+        Self {
+            volatility_shift: self.volatility_shift + shift,
+            ..self.clone()
+        }
+    }
+
+    /// Calibrate `α`, `ρ` and `ν` to the quotes of an expiry using the default [`SabrCalibrator`].
+    ///
+    /// The current parameters of the model are used as the initial guess and `β` is kept fixed.
+    ///
+    /// # Arguments
+    ///
+    /// * `forward` - The forward price of the underlying to the expiry of the quotes.
+    /// * `quotes` - The implied volatility quotes of the expiry.
+    ///
+    /// # Returns
+    ///
+    /// The result of the calibration or an error if the quotes cannot be fitted.
+    pub fn calibrate(
+        &self,
+        forward: f64,
+        quotes: &[MarketQuote],
+    ) -> Result<SabrCalibration, PricingError> {
+        SabrCalibrator::default().calibrate(self, forward, quotes)
+    }
+}
+
+/// Calibrator fitting the parameters `[α, ρ, ν]` of a [`SabrModel`] to the implied volatilities of an expiry.
+#[derive(Debug, Clone)]
+pub struct SabrCalibrator {
+    /// Maximum number of optimiser iterations.
+    pub max_iterations: usize,
+    /// Tolerance on the relative improvement of the objective.
+    pub tolerance: f64,
+    /// Lower bounds of `[α, ρ, ν]`.
+    pub lower_bounds: [f64; 3],
+    /// Upper bounds of `[α, ρ, ν]`.
+    pub upper_bounds: [f64; 3],
+}
+
+impl Default for SabrCalibrator {
+    fn default() -> Self {
+        Self {
+            max_iterations: 200,
+            tolerance: 1e-12,
+            lower_bounds: [1e-8, -0.999, 1e-4],
+            upper_bounds: [f64::INFINITY, 0.999, 10.0],
+        }
+    }
+}
+
+/// Result of a SABR calibration.
+#[derive(Debug, Clone)]
+pub struct SabrCalibration {
+    /// The calibrated model.
+    pub model: SabrModel,
+    /// Model minus market implied volatility of each quote.
+    pub residuals: Vec<f64>,
+    /// Root mean squared residual.
+    pub rmse: f64,
+    /// Number of optimiser iterations.
+    pub iterations: usize,
+    /// Whether the optimiser converged before reaching the iteration limit.
+    pub converged: bool,
+}
+
+impl SabrCalibrator {
+    /// Set the parameter bounds of the calibrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `lower_bounds` - Lower bounds of `[α, ρ, ν]`.
+    /// * `upper_bounds` - Upper bounds of `[α, ρ, ν]`.
+    ///
+    /// # Returns
+    ///
+    /// The calibrator with the bounds set.
+    pub fn with_bounds(mut self, lower_bounds: [f64; 3], upper_bounds: [f64; 3]) -> Self {
+        self.lower_bounds = lower_bounds;
+        self.upper_bounds = upper_bounds;
+        self
+    }
+
+    /// Set the maximum number of optimiser iterations.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_iterations` - The maximum number of iterations.
+    ///
+    /// # Returns
+    ///
+    /// The calibrator with the iteration limit set.
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Calibrate a SABR model to the lognormal implied volatilities of an expiry.
+    ///
+    /// # Arguments
+    ///
+    /// * `initial` - The initial guess (its `β` and expansion are kept fixed).
+    /// * `forward` - The forward price of the underlying to the expiry of the quotes.
+    /// * `quotes` - The implied volatility quotes of the expiry.
+    ///
+    /// # Returns
+    ///
+    /// The result of the calibration or an error if there are fewer than three quotes, if the quotes are not implied
+    /// volatilities of the same expiry or if the forward is not positive.
+    pub fn calibrate(
+        &self,
+        initial: &SabrModel,
+        forward: f64,
+        quotes: &[MarketQuote],
+    ) -> Result<SabrCalibration, PricingError> {
+        if !(forward > 0.0 && forward.is_finite()) {
+            return Err(PricingError::invalid_input("Forward must be positive"));
+        }
+        if quotes.len() < 3 {
+            return Err(PricingError::invalid_input(
+                "SABR calibration needs at least 3 quotes",
+            ));
+        }
+        let time_to_maturity = quotes[0].time_to_maturity;
+        let market = quotes
+            .iter()
+            .map(|quote| match quote.value {
+                QuoteValue::ImpliedVolatility(volatility)
+                    if quote.time_to_maturity == time_to_maturity && quote.strike > 0.0 =>
+                {
+                    Ok((quote.strike, volatility))
+                }
+                QuoteValue::ImpliedVolatility(_) => Err(PricingError::invalid_input(
+                    "SABR quotes must have positive strikes and share the same expiry",
+                )),
+                QuoteValue::Price(_) => Err(PricingError::invalid_input(
+                    "SABR is calibrated to implied volatility quotes",
+                )),
+            })
+            .collect::<Result<Vec<(f64, f64)>, PricingError>>()?;
+
+        let model_from = |p: &[f64]| SabrModel {
+            alpha: p[0],
+            rho: p[1],
+            nu: p[2],
+            ..initial.clone()
+        };
+        let residuals = |p: &[f64]| -> Vec<f64> {
+            let model = model_from(p);
+            market
+                .iter()
+                .map(|&(strike, volatility)| {
+                    model.lognormal_volatility(forward, strike, time_to_maturity) - volatility
+                })
+                .collect()
+        };
+
+        let result = numerics::levenberg_marquardt(
+            residuals,
+            &[initial.alpha, initial.rho, initial.nu],
+            &self.lower_bounds,
+            &self.upper_bounds,
+            self.max_iterations,
+            self.tolerance,
+        );
+
+        let rmse = (result.residuals.iter().map(|r| r * r).sum::<f64>()
+            / result.residuals.len() as f64)
+            .sqrt();
+
+        Ok(SabrCalibration {
+            model: model_from(&result.params),
+            residuals: result.residuals,
+            rmse,
+            iterations: result.iterations,
+            converged: result.converged,
+        })
+    }
+}
+
+/// Calculate the ratio `z / x(z)` of the SABR expansions with `x(z) = ln((√(1 - 2ρz + z²) + z - ρ) / (1 - ρ))`.
+fn z_over_x(z: f64, rho: f64) -> f64 {
+    if z.abs() < 1e-8 {
+        // Expansion around the money
+        return 1.0 - 0.5 * rho * z;
+    }
+    let x = (((1.0 - 2.0 * rho * z + z * z).sqrt() + z - rho) / (1.0 - rho)).ln();
+    z / x
+}
@@ -0,0 +1,274 @@
+//! Sobol low-discrepancy sequence.
+//!
+//! The first dimension is the van der Corput sequence in base 2. The following dimensions use the primitive
+//! polynomials over GF(2) in increasing order of degree together with the direction numbers of Joe and Kuo
+//! (`new-joe-kuo-6.21201`) for the first 53 dimensions. Higher dimensions are initialised with pseudo-random
+//! direction numbers satisfying the usual conditions (odd `m_k < 2^k`), as suggested by Jäckel.
+//!
+//! ## References
+//!
+//! - Joe, S. & Kuo, F. Y. (2008). Constructing Sobol sequences with better two-dimensional projections.
+//! - Jäckel, P. (2002). Monte Carlo Methods in Finance.
+//! - Burley, B. (2020). Practical Hash-based Owen Scrambling.
+
+/// Number of bits of the generated points.
+const BITS: usize = 32;
+
+/// Initial direction numbers `m_1, ..., m_s` of Joe and Kuo for dimensions 2 to 53.
+const JOE_KUO: [&[u32]; 52] = [
+    &[1],
+    &[1, 3],
+    &[1, 3, 1],
+    &[1, 1, 1],
+    &[1, 1, 3, 3],
+    &[1, 3, 5, 13],
+    &[1, 1, 5, 5, 17],
+    &[1, 1, 5, 5, 5],
+    &[1, 1, 7, 11, 19],
+    &[1, 1, 5, 1, 1],
+    &[1, 1, 1, 3, 11],
+    &[1, 3, 5, 5, 31],
+    &[1, 3, 3, 9, 7, 49],
+    &[1, 1, 1, 15, 21, 21],
+    &[1, 3, 1, 13, 27, 49],
+    &[1, 1, 1, 15, 7, 5],
+    &[1, 3, 1, 15, 13, 25],
+    &[1, 1, 5, 5, 19, 61],
+    &[1, 3, 7, 11, 23, 15, 103],
+    &[1, 3, 7, 13, 13, 15, 69],
+    &[1, 1, 3, 13, 7, 35, 63],
+    &[1, 3, 5, 9, 1, 25, 53],
+    &[1, 3, 1, 13, 9, 35, 107],
+    &[1, 3, 1, 5, 27, 61, 31],
+    &[1, 1, 5, 11, 19, 41, 61],
+    &[1, 3, 5, 3, 3, 13, 69],
+    &[1, 1, 7, 13, 1, 19, 1],
+    &[1, 3, 7, 5, 13, 19, 59],
+    &[1, 1, 3, 9, 25, 29, 41],
+    &[1, 3, 5, 13, 23, 1, 55],
+    &[1, 3, 7, 3, 13, 59, 17],
+    &[1, 3, 1, 3, 5, 53, 69],
+    &[1, 1, 5, 5, 23, 33, 13],
+    &[1, 1, 7, 7, 1, 61, 123],
+    &[1, 1, 7, 9, 13, 61, 49],
+    &[1, 3, 3, 5, 3, 55, 33],
+    &[1, 3, 1, 15, 31, 13, 49, 245],
+    &[1, 3, 5, 15, 31, 59, 63, 97],
+    &[1, 3, 1, 11, 11, 11, 77, 249],
+    &[1, 3, 1, 11, 27, 43, 71, 9],
+    &[1, 1, 7, 15, 21, 11, 81, 45],
+    &[1, 3, 7, 3, 25, 31, 65, 79],
+    &[1, 3, 1, 1, 19, 11, 3, 205],
+    &[1, 1, 5, 9, 19, 21, 29, 157],
+    &[1, 3, 7, 11, 1, 33, 89, 185],
+    &[1, 3, 3, 3, 15, 9, 79, 71],
+    &[1, 3, 7, 11, 15, 39, 119, 27],
+    &[1, 1, 3, 1, 11, 31, 97, 225],
+    &[1, 1, 1, 3, 23, 43, 57, 177],
+    &[1, 3, 7, 7, 17, 17, 37, 71],
+    &[1, 3, 1, 5, 27, 63, 123, 213],
+    &[1, 1, 3, 5, 11, 43, 53, 133],
+];
+
+/// A Sobol sequence of points in the unit hypercube, optionally with Owen scrambling.
+#[derive(Debug, Clone)]
+pub(crate) struct SobolSequence {
+    /// Direction numbers `v_k` of every dimension.
+    directions: Vec<[u32; BITS]>,
+    /// Seeds of the nested uniform scrambling of every dimension (`None` for the plain sequence).
+    scrambling: Option<Vec<u32>>,
+}
+
+impl SobolSequence {
+    /// Create a new Sobol sequence.
+    ///
+    /// # Arguments
+    ///
+    /// * `dimension` - The dimension of the points.
+    /// * `scrambling_seed` - The seed of the Owen scrambling (`None` for the plain sequence).
+    ///
+    /// # Returns
+    ///
+    /// A new `SobolSequence`.
+    pub(crate) fn new(dimension: usize, scrambling_seed: Option<u64>) -> Self {
+        let mut directions = Vec::with_capacity(dimension);
+        if dimension > 0 {
+            directions.push(std::array::from_fn(|k| 1 << (BITS - 1 - k)));
+        }
+
+        let polynomials = primitive_polynomials(dimension.saturating_sub(1));
+        for (j, &(degree, coefficients)) in polynomials.iter().enumerate() {
+            let mut state = 0x5eed_0000_0000_0000 ^ j as u64;
+            let initial: Vec<u32> = match JOE_KUO.get(j) {
+                Some(initial) => initial.to_vec(),
+                None => (1..=degree)
+                    .map(|k| (splitmix64(&mut state) as u32 & ((1 << k) - 1)) | 1)
+                    .collect(),
+            };
+
+            let mut v = [0u32; BITS];
+            for k in 0..BITS {
+                v[k] = if k < degree {
+                    initial[k] << (BITS - 1 - k)
+                } else {
+                    let mut value = v[k - degree] ^ (v[k - degree] >> degree);
+                    for i in 1..degree {
+                        if (coefficients >> (degree - 1 - i)) & 1 == 1 {
+                            value ^= v[k - i];
+                        }
+                    }
+                    value
+                };
+            }
+            directions.push(v);
+        }
+
+        let scrambling = scrambling_seed.map(|seed| {
+            let mut state = seed;
+            (0..dimension)
+                .map(|_| splitmix64(&mut state) as u32)
+                .collect()
+        });
+
+        Self {
+            directions,
+            scrambling,
+        }
+    }
+
+    /// Check if the sequence is scrambled.
+    pub(crate) fn is_scrambled(&self) -> bool {
+        self.scrambling.is_some()
+    }
+
+    /// Compute a point of the sequence.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the point (the point `0` is the origin of the plain sequence).
+    ///
+    /// # Returns
+    ///
+    /// The coordinates of the point in `(0, 1)`, shifted to the centres of their binary intervals.
+    pub(crate) fn point(&self, index: u64) -> Vec<f64> {
+        let gray = index ^ (index >> 1);
+        self.directions
+            .iter()
+            .enumerate()
+            .map(|(j, v)| {
+                let mut x = (0..BITS)
+                    .filter(|k| (gray >> k) & 1 == 1)
+                    .fold(0u32, |x, k| x ^ v[k]);
+                if let Some(seeds) = &self.scrambling {
+                    x = nested_uniform_scramble(x, seeds[j]);
+                }
+                (x as f64 + 0.5) / (1u64 << BITS) as f64
+            })
+            .collect()
+    }
+}
+
+/// Enumerate the primitive polynomials over GF(2) in increasing order, skipping the trivial polynomial `x`.
+///
+/// # Arguments
+///
+/// * `count` - The number of polynomials.
+///
+/// # Returns
+///
+/// The degree `s` and the coefficients `a` of `x^s + a_1 x^(s-1) + ... + a_(s-1) x + 1` packed into the bits of `a`.
+fn primitive_polynomials(count: usize) -> Vec<(usize, u64)> {
+    let mut polynomials = Vec::with_capacity(count);
+    let mut degree = 1;
+    while polynomials.len() < count {
+        for coefficients in 0..1u64 << (degree - 1) {
+            if polynomials.len() == count {
+                break;
+            }
+            let polynomial = (1 << degree) | (coefficients << 1) | 1;
+            if is_primitive(polynomial, degree) {
+                polynomials.push((degree, coefficients));
+            }
+        }
+        degree += 1;
+    }
+    polynomials
+}
+
+/// Check if a polynomial over GF(2) is primitive, i.e. if `x` has order `2^s - 1` modulo the polynomial.
+fn is_primitive(polynomial: u64, degree: usize) -> bool {
+    let order = (1u64 << degree) - 1;
+    if degree == 1 {
+        return true;
+    }
+    if power_of_x(order, polynomial, degree) != 1 {
+        return false;
+    }
+
+    // x must not have a smaller order, i.e. (2^s - 1) / q for any prime factor q of 2^s - 1
+    let mut prime_factors = Vec::new();
+    let mut remaining = order;
+    let mut factor = 3;
+    while factor * factor <= remaining {
+        if remaining % factor == 0 {
+            prime_factors.push(factor);
+            while remaining % factor == 0 {
+                remaining /= factor;
+            }
+        }
+        factor += 2;
+    }
+    if remaining > 1 {
+        prime_factors.push(remaining);
+    }
+    prime_factors
+        .iter()
+        .all(|factor| power_of_x(order / factor, polynomial, degree) != 1)
+}
+
+/// Compute `x^exponent` modulo a polynomial over GF(2).
+fn power_of_x(mut exponent: u64, polynomial: u64, degree: usize) -> u64 {
+    let multiply = |mut a: u64, mut b: u64| {
+        let mut product = 0;
+        while b != 0 {
+            if b & 1 == 1 {
+                product ^= a;
+            }
+            b >>= 1;
+            a <<= 1;
+            if (a >> degree) & 1 == 1 {
+                a ^= polynomial;
+            }
+        }
+        product
+    };
+
+    let (mut result, mut base) = (1, 2);
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            result = multiply(result, base);
+        }
+        base = multiply(base, base);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Owen scrambling of a coordinate with the hash-based permutation of Burley.
+fn nested_uniform_scramble(x: u32, seed: u32) -> u32 {
+    let mut x = x.reverse_bits().wrapping_add(seed);
+    x ^= x.wrapping_mul(0x6c50_b47c);
+    x ^= x.wrapping_mul(0xb82f_1e52);
+    x ^= x.wrapping_mul(0xc7af_e638);
+    x ^= x.wrapping_mul(0x8d22_f6e6);
+    x.reverse_bits()
+}
+
+/// Advance a SplitMix64 generator and return its next output.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,350 @@
+//! Module for term structures of interest rates and volatilities.
+//!
+//! A `YieldCurve` gives the continuously compounded zero rates to a set of pillar times and is used as the discount
+//! curve or the borrow (repo) curve of an option model. A `VolatilityTermStructure` gives the implied volatilities to
+//! a set of pillar times, from which the forward variance between two times follows.
+//!
+//! Zero rates are interpolated linearly and total variances `σ(t)² t` are interpolated linearly between the pillars
+//! (starting from zero variance at time zero). Both are extrapolated flat beyond the last pillar.
+//!
+//! ## Example
+//!
+//! ```
+//! use quantrs::options::{BlackScholesModel, EuropeanOption, Instrument, OptionPricing, OptionType, VolatilityTermStructure, YieldCurve};
+//!
+//! let discount_curve = YieldCurve::new(vec![0.5, 1.0, 2.0], vec![0.03, 0.035, 0.04]);
+//! let volatilities = VolatilityTermStructure::new(vec![0.5, 1.0, 2.0], vec![0.25, 0.22, 0.2]);
+//!
+//! let model = BlackScholesModel::new(0.0, 0.0)
+//!     .with_discount_curve(discount_curve)
+//!     .with_volatility_term_structure(volatilities);
+//! let option = EuropeanOption::new(Instrument::new().with_spot(100.0), 100.0, 1.5, OptionType::Call);
+//!
+//! let price = model.price(&option);
+//! println!("Option price: {price}");
+//! ```
+
+use crate::options::PricingError;
+
+/// A curve of continuously compounded zero rates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct YieldCurve {
+    /// Pillar times in years (increasing).
+    pub times: Vec<f64>,
+    /// Zero rates to the pillar times (e.g., 0.05 for 5%).
+    pub rates: Vec<f64>,
+}
+
+impl YieldCurve {
+    /// Create a new `YieldCurve`.
+    ///
+    /// # Arguments
+    ///
+    /// * `times` - The pillar times in years (positive and increasing).
+    /// * `rates` - The continuously compounded zero rates to the pillar times.
+    ///
+    /// # Returns
+    ///
+    /// A new `YieldCurve`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pillars are invalid (see [`YieldCurve::try_new`]).
+    pub fn new(times: Vec<f64>, rates: Vec<f64>) -> Self {
+        Self::try_new(times, rates).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Create a new `YieldCurve` without panicking.
+    ///
+    /// # Arguments
+    ///
+    /// * `times` - The pillar times in years (positive and increasing).
+    /// * `rates` - The continuously compounded zero rates to the pillar times.
+    ///
+    /// # Returns
+    ///
+    /// A new `YieldCurve` or an error if the pillars are empty, do not match or are not finite, or if the times are
+    /// not increasing.
+    pub fn try_new(times: Vec<f64>, rates: Vec<f64>) -> Result<Self, PricingError> {
+        if times.is_empty() || times.len() != rates.len() {
+            return Err(PricingError::invalid_input(
+                "Yield curve needs the same positive number of times and rates",
+            ));
+        }
+        if !rates.iter().all(|rate| rate.is_finite()) {
+            return Err(PricingError::invalid_input(
+                "Yield curve rates must be finite",
+            ));
+        }
+        validate_pillar_times(&times)?;
+        Ok(Self { times, rates })
+    }
+
+    /// Create a flat `YieldCurve`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - The zero rate to any time.
+    ///
+    /// # Returns
+    ///
+    /// A new flat `YieldCurve`.
+    pub fn flat(rate: f64) -> Self {
+        Self::new(vec![1.0], vec![rate])
+    }
+
+    /// Calculate the zero rate to a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time in years.
+    ///
+    /// # Returns
+    ///
+    /// The continuously compounded zero rate, interpolated linearly between the pillars.
+    pub fn zero_rate(&self, time: f64) -> f64 {
+        interpolate(&self.times, &self.rates, time)
+    }
+
+    /// Calculate the discount factor to a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time in years.
+    ///
+    /// # Returns
+    ///
+    /// The discount factor `e^(-r(t) t)`.
+    pub fn discount_factor(&self, time: f64) -> f64 {
+        (-self.zero_rate(time) * time).exp()
+    }
+
+    /// Calculate the forward rate between two times.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The start of the period in years.
+    /// * `to` - The end of the period in years.
+    ///
+    /// # Returns
+    ///
+    /// The continuously compounded forward rate, or the zero rate to `from` if the period is empty.
+    pub fn forward_rate(&self, from: f64, to: f64) -> f64 {
+        if to <= from {
+            return self.zero_rate(from);
+        }
+        (self.zero_rate(to) * to - self.zero_rate(from) * from) / (to - from)
+    }
+
+    /// Shift the curve in parallel.
+    ///
+    /// # Arguments
+    ///
+    /// * `shift` - The shift added to every zero rate.
+    ///
+    /// # Returns
+    ///
+    /// The shifted curve.
+    pub fn shifted(&self, shift: f64) -> Self {
+        Self {
+            times: self.times.clone(),
+            rates: self.rates.iter().map(|rate| rate + shift).collect(),
+        }
+    }
+}
+
+/// A term structure of implied volatilities.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolatilityTermStructure {
+    /// Pillar times in years (increasing).
+    pub times: Vec<f64>,
+    /// Implied volatilities to the pillar times (e.g., 0.2 for 20%).
+    pub volatilities: Vec<f64>,
+}
+
+impl VolatilityTermStructure {
+    /// Create a new `VolatilityTermStructure`.
+    ///
+    /// # Arguments
+    ///
+    /// * `times` - The pillar times in years (positive and increasing).
+    /// * `volatilities` - The implied volatilities to the pillar times.
+    ///
+    /// # Returns
+    ///
+    /// A new `VolatilityTermStructure`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pillars are invalid (see [`VolatilityTermStructure::try_new`]).
+    pub fn new(times: Vec<f64>, volatilities: Vec<f64>) -> Self {
+        Self::try_new(times, volatilities).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Create a new `VolatilityTermStructure` without panicking.
+    ///
+    /// # Arguments
+    ///
+    /// * `times` - The pillar times in years (positive and increasing).
+    /// * `volatilities` - The implied volatilities to the pillar times.
+    ///
+    /// # Returns
+    ///
+    /// A new `VolatilityTermStructure` or an error if the pillars are empty, do not match or are not finite, if the
+    /// times are not increasing or if the total variance decreases (which implies a negative forward variance).
+    pub fn try_new(times: Vec<f64>, volatilities: Vec<f64>) -> Result<Self, PricingError> {
+        if times.is_empty() || times.len() != volatilities.len() {
+            return Err(PricingError::invalid_input(
+                "Volatility term structure needs the same positive number of times and volatilities",
+            ));
+        }
+        if !volatilities
+            .iter()
+            .all(|volatility| volatility.is_finite() && *volatility >= 0.0)
+        {
+            return Err(PricingError::invalid_input(
+                "Volatilities must be finite and non-negative",
+            ));
+        }
+        validate_pillar_times(&times)?;
+        if !times
+            .windows(2)
+            .zip(volatilities.windows(2))
+            .all(|(t, v)| v[1] * v[1] * t[1] >= v[0] * v[0] * t[0])
+        {
+            return Err(PricingError::invalid_input(
+                "Total variance must not decrease",
+            ));
+        }
+        Ok(Self {
+            times,
+            volatilities,
+        })
+    }
+
+    /// Create a flat `VolatilityTermStructure`.
+    ///
+    /// # Arguments
+    ///
+    /// * `volatility` - The implied volatility to any time.
+    ///
+    /// # Returns
+    ///
+    /// A new flat `VolatilityTermStructure`.
+    pub fn flat(volatility: f64) -> Self {
+        Self::new(vec![1.0], vec![volatility])
+    }
+
+    /// Calculate the total variance to a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time in years.
+    ///
+    /// # Returns
+    ///
+    /// The total variance `σ(t)² t`, interpolated linearly between the pillars.
+    pub fn variance(&self, time: f64) -> f64 {
+        if time <= 0.0 {
+            return 0.0;
+        }
+        let last = self.times.len() - 1;
+        if time >= self.times[last] {
+            return self.volatilities[last].powi(2) * time;
+        }
+        let variances: Vec<f64> = std::iter::once(0.0)
+            .chain(
+                self.times
+                    .iter()
+                    .zip(&self.volatilities)
+                    .map(|(t, v)| v * v * t),
+            )
+            .collect();
+        let times: Vec<f64> = std::iter::once(0.0)
+            .chain(self.times.iter().copied())
+            .collect();
+        interpolate(&times, &variances, time)
+    }
+
+    /// Calculate the implied volatility to a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time in years.
+    ///
+    /// # Returns
+    ///
+    /// The implied volatility `√(w(t) / t)`, or the volatility of the first pillar if the time is not positive.
+    pub fn volatility(&self, time: f64) -> f64 {
+        if time <= 0.0 {
+            return self.volatilities[0];
+        }
+        (self.variance(time) / time).sqrt()
+    }
+
+    /// Calculate the forward volatility between two times.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The start of the period in years.
+    /// * `to` - The end of the period in years.
+    ///
+    /// # Returns
+    ///
+    /// The forward volatility `√((w(to) - w(from)) / (to - from))`, or the implied volatility to `from` if the
+    /// period is empty.
+    pub fn forward_volatility(&self, from: f64, to: f64) -> f64 {
+        if to <= from {
+            return self.volatility(from);
+        }
+        ((self.variance(to) - self.variance(from)).max(0.0) / (to - from)).sqrt()
+    }
+
+    /// Shift the term structure in parallel.
+    ///
+    /// # Arguments
+    ///
+    /// * `shift` - The shift added to every implied volatility.
+    ///
+    /// # Returns
+    ///
+    /// The shifted term structure.
+    pub fn shifted(&self, shift: f64) -> Self {
+        Self {
+            times: self.times.clone(),
+            volatilities: self
+                .volatilities
+                .iter()
+                .map(|volatility| volatility + shift)
+                .collect(),
+        }
+    }
+}
+
+/// Check that the pillar times are finite, positive and increasing.
+fn validate_pillar_times(times: &[f64]) -> Result<(), PricingError> {
+    if !times.iter().all(|time| time.is_finite() && *time > 0.0) {
+        return Err(PricingError::invalid_input(
+            "Pillar times must be finite and positive",
+        ));
+    }
+    if !times.windows(2).all(|t| t[0] < t[1]) {
+        return Err(PricingError::invalid_input(
+            "Pillar times must be increasing",
+        ));
+    }
+    Ok(())
+}
+
+/// Interpolate linearly between pillars, extrapolating flat.
+fn interpolate(times: &[f64], values: &[f64], time: f64) -> f64 {
+    let index = times.partition_point(|&t| t < time);
+    if index == 0 {
+        return values[0];
+    }
+    if index == times.len() {
+        return values[index - 1];
+    }
+    let weight = (time - times[index - 1]) / (times[index] - times[index - 1]);
+    values[index - 1] + weight * (values[index] - values[index - 1])
+}
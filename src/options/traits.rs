@@ -1,10 +1,13 @@
 //! Module for various option traits.
 
+pub use model_parameters::ModelParameters;
 pub use option::Option;
 pub use option_greeks::OptionGreeks;
 pub use option_pricing::OptionPricing;
+pub(crate) use option_pricing::{validate_market_price, validate_option};
 pub use option_strategy::OptionStrategy;
 
+mod model_parameters;
 mod option;
 mod option_greeks;
 mod option_pricing;
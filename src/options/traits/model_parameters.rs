@@ -0,0 +1,26 @@
+//! Traits for accessing the market parameters of option pricing models.
+
+/// Trait for option pricing models with a risk-free interest rate and a volatility.
+///
+/// Used to shift the parameters of a model, e.g. to calculate Greeks numerically.
+pub trait ModelParameters: Clone {
+    /// Get the risk-free interest rate of the model.
+    fn risk_free_rate(&self) -> f64;
+
+    /// Set the risk-free interest rate of the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `risk_free_rate` - The new risk-free interest rate (e.g., 0.05 for 5%).
+    fn set_risk_free_rate(&mut self, risk_free_rate: f64);
+
+    /// Get the volatility of the model.
+    fn volatility(&self) -> f64;
+
+    /// Set the volatility of the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `volatility` - The new volatility (e.g., 0.2 for 20%).
+    fn set_volatility(&mut self, volatility: f64);
+}
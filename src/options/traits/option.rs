@@ -50,6 +50,33 @@ pub trait Option: Clone + Send + Sync {
         None
     }
 
+    /// Get the barrier of the option.
+    ///
+    /// # Returns
+    ///
+    /// The barrier specification of the option. (Only for Barrier options)
+    fn barrier(&self) -> std::option::Option<&Barrier> {
+        None
+    }
+
+    /// Get the averaging schedule of the option.
+    ///
+    /// # Returns
+    ///
+    /// The averaging schedule of the option. (Only for Asian options with discrete averaging)
+    fn averaging_schedule(&self) -> std::option::Option<&AveragingSchedule> {
+        None
+    }
+
+    /// Get the lookback specification of the option.
+    ///
+    /// # Returns
+    ///
+    /// The lookback period and observed extrema of the option. (Only for Lookback options)
+    fn lookback(&self) -> std::option::Option<&Lookback> {
+        None
+    }
+
     /// Set the time horizon (in years).
     ///
     /// # Arguments
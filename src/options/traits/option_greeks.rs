@@ -1,82 +1,174 @@
 //! Traits for calculating the Greeks of an option.
 
-use crate::options::Option;
+use crate::options::{Greeks, Option, PricingError};
 
 /// Trait for calculating the Greeks of an option.
 pub trait OptionGreeks {
     // First-order Greeks
     /// Delta measures the rate of change of the option price with respect to changes in the price of the underlying asset.
     fn delta<T: Option>(&self, option: &T) -> f64 {
-        panic!("Delta not implemented for this model");
+        self.try_delta(option).unwrap_or_else(|err| panic!("{err}"))
     }
     /// Vega measures the rate of change of the option price with respect to changes in the volatility of the underlying asset.
     fn vega<T: Option>(&self, option: &T) -> f64 {
-        panic!("Vega not implemented for this model");
+        self.try_vega(option).unwrap_or_else(|err| panic!("{err}"))
     }
     /// Theta measures the rate of change of the option price with respect to changes in time to maturity.
     fn theta<T: Option>(&self, option: &T) -> f64 {
-        panic!("Theta not implemented for this model");
+        self.try_theta(option).unwrap_or_else(|err| panic!("{err}"))
     }
     /// Rho measures the rate of change of the option price with respect to changes in the risk-free interest rate.
     fn rho<T: Option>(&self, option: &T) -> f64 {
-        panic!("Rho not implemented for this model");
+        self.try_rho(option).unwrap_or_else(|err| panic!("{err}"))
     }
-    /// Lambda measures the rate of change of the option delta with respect to changes in the risk-free interest rate.
+    /// Lambda (elasticity) measures the percentage change of the option price per percentage change in the price of the underlying asset.
     fn lambda<T: Option>(&self, option: &T) -> f64 {
-        panic!("Lambda not implemented for this model");
+        self.try_lambda(option)
+            .unwrap_or_else(|err| panic!("{err}"))
     }
-    /// Epsilon measures the rate of change of the option delta with respect to changes in the dividend yield.
+    /// Epsilon measures the rate of change of the option price with respect to changes in the dividend yield.
     fn epsilon<T: Option>(&self, option: &T) -> f64 {
-        panic!("Epsilon not implemented for this model");
+        self.try_epsilon(option)
+            .unwrap_or_else(|err| panic!("{err}"))
     }
 
     // Second-order Greeks
     /// Gamma measures the rate of change of the option delta with respect to changes in the price of the underlying asset.
     fn gamma<T: Option>(&self, option: &T) -> f64 {
-        panic!("Gamma not implemented for this model");
+        self.try_gamma(option).unwrap_or_else(|err| panic!("{err}"))
     }
 
     /// Vanna measures the rate of change of the option delta with respect to changes in the volatility of the underlying asset.
     fn vanna<T: Option>(&self, option: &T) -> f64 {
-        panic!("Vanna not implemented for this model");
+        self.try_vanna(option).unwrap_or_else(|err| panic!("{err}"))
     }
     /// Charm measures the rate of change of the option delta with respect to changes in time to maturity.
     fn charm<T: Option>(&self, option: &T) -> f64 {
-        panic!("Charm not implemented for this model");
+        self.try_charm(option).unwrap_or_else(|err| panic!("{err}"))
     }
     /// Vomma measures the rate of change of the option vega with respect to changes in the volatility of the underlying asset.
     fn vomma<T: Option>(&self, option: &T) -> f64 {
-        panic!("Vomma not implemented for this model");
+        self.try_vomma(option).unwrap_or_else(|err| panic!("{err}"))
     }
     /// Veta measures the rate of change of the option vega with respect to changes in time to maturity.
     fn veta<T: Option>(&self, option: &T) -> f64 {
-        panic!("Veta not implemented for this model");
+        self.try_veta(option).unwrap_or_else(|err| panic!("{err}"))
     }
-    /// Vera measures the rate of change of the option gamma with respect to changes in the volatility of the underlying asset.
+    /// Vera measures the rate of change of the option rho with respect to changes in the volatility of the underlying asset.
     fn vera<T: Option>(&self, option: &T) -> f64 {
-        panic!("Vera not implemented for this model");
+        self.try_vera(option).unwrap_or_else(|err| panic!("{err}"))
     }
 
     // Third-order Greeks
     /// Speed measures the rate of change of the option gamma with respect to changes in the price of the underlying asset.
     fn speed<T: Option>(&self, option: &T) -> f64 {
-        panic!("Speed not implemented for this model");
+        self.try_speed(option).unwrap_or_else(|err| panic!("{err}"))
     }
     /// Zomma measures the rate of change of the option gamma with respect to changes in the volatility of the underlying asset.
     fn zomma<T: Option>(&self, option: &T) -> f64 {
-        panic!("Zomma not implemented for this model");
+        self.try_zomma(option).unwrap_or_else(|err| panic!("{err}"))
     }
     /// Color measures the rate of change of the option gamma with respect to changes in time to maturity.
     fn color<T: Option>(&self, option: &T) -> f64 {
-        panic!("Color not implemented for this model");
+        self.try_color(option).unwrap_or_else(|err| panic!("{err}"))
     }
     /// Ultima measures the rate of change of the option vomma with respect to changes in the volatility of the underlying asset.
     fn ultima<T: Option>(&self, option: &T) -> f64 {
-        panic!("Ultima not implemented for this model");
+        self.try_ultima(option)
+            .unwrap_or_else(|err| panic!("{err}"))
     }
 
     /// Parmicharma measures the rate of change of charm over the passage of time.
     fn parmicharma<T: Option>(&self, option: &T) -> f64 {
-        panic!("Parmicharma not implemented for this model");
+        self.try_parmicharma(option)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    // Fallible Greeks (implemented by the models, the methods above panic on their errors)
+    /// Calculate delta without panicking.
+    fn try_delta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Delta"))
+    }
+    /// Calculate vega without panicking.
+    fn try_vega<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Vega"))
+    }
+    /// Calculate theta without panicking.
+    fn try_theta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Theta"))
+    }
+    /// Calculate rho without panicking.
+    fn try_rho<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Rho"))
+    }
+    /// Calculate lambda without panicking.
+    fn try_lambda<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Lambda"))
+    }
+    /// Calculate epsilon without panicking.
+    fn try_epsilon<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Epsilon"))
+    }
+    /// Calculate gamma without panicking.
+    fn try_gamma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Gamma"))
+    }
+    /// Calculate vanna without panicking.
+    fn try_vanna<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Vanna"))
+    }
+    /// Calculate charm without panicking.
+    fn try_charm<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Charm"))
+    }
+    /// Calculate vomma without panicking.
+    fn try_vomma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Vomma"))
+    }
+    /// Calculate veta without panicking.
+    fn try_veta<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Veta"))
+    }
+    /// Calculate vera without panicking.
+    fn try_vera<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Vera"))
+    }
+    /// Calculate speed without panicking.
+    fn try_speed<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Speed"))
+    }
+    /// Calculate zomma without panicking.
+    fn try_zomma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Zomma"))
+    }
+    /// Calculate color without panicking.
+    fn try_color<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Color"))
+    }
+    /// Calculate ultima without panicking.
+    fn try_ultima<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Ultima"))
+    }
+    /// Calculate parmicharma without panicking.
+    fn try_parmicharma<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        Err(PricingError::not_implemented("Parmicharma"))
+    }
+
+    /// Calculate all Greeks of an option without panicking.
+    ///
+    /// Greeks not implemented by the model are set to zero, any other error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option for which to calculate the Greeks.
+    ///
+    /// # Returns
+    ///
+    /// The Greeks of the option or the reason why they cannot be calculated.
+    fn try_greeks<T: Option>(&self, option: &T) -> Result<Greeks, PricingError>
+    where
+        Self: Sized,
+    {
+        Greeks::try_calculate(self, option)
     }
 }
@@ -1,6 +1,6 @@
 //! Traits for option pricing models.
 
-use crate::options::Option;
+use crate::options::{Instrument, Option, PricingError, numerics};
 
 /// Trait for option pricing models.
 pub trait OptionPricing {
@@ -13,8 +13,29 @@ pub trait OptionPricing {
     /// # Returns
     ///
     /// The price of the option.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the model cannot price the option (see [`OptionPricing::try_price`]).
     fn price<T: Option>(&self, option: &T) -> f64;
 
+    /// Calculate the option price without panicking.
+    ///
+    /// The default implementation validates the option and delegates to [`OptionPricing::price`]; models override
+    /// it to report unsupported styles and invalid inputs as errors.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option to price.
+    ///
+    /// # Returns
+    ///
+    /// The price of the option or the reason why it cannot be priced.
+    fn try_price<T: Option>(&self, option: &T) -> Result<f64, PricingError> {
+        validate_option(option)?;
+        Ok(self.price(option))
+    }
+
     /// Calculate the implied volatility for a given market price.
     ///
     /// # Arguments
@@ -26,4 +47,100 @@ pub trait OptionPricing {
     ///
     /// The implied volatility.
     fn implied_volatility<T: Option>(&self, option: &T, market_price: f64) -> f64;
+
+    /// Calculate the implied volatility for a given market price without panicking.
+    ///
+    /// The default implementation validates the inputs and delegates to [`OptionPricing::implied_volatility`].
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - The option for which to calculate the implied volatility.
+    /// * `market_price` - The market price of the option.
+    ///
+    /// # Returns
+    ///
+    /// The implied volatility or the reason why it cannot be calculated.
+    fn try_implied_volatility<T: Option>(
+        &self,
+        option: &T,
+        market_price: f64,
+    ) -> Result<f64, PricingError> {
+        validate_market_price(option, market_price)?;
+        Ok(self.implied_volatility(option, market_price))
+    }
+}
+
+/// Check that the spot price, strike, time to maturity and cash dividends of an option are finite and non-negative,
+/// and that the correlation matrix of the instrument is a valid correlation matrix of its assets.
+pub(crate) fn validate_option<T: Option>(option: &T) -> Result<(), PricingError> {
+    let inputs = [
+        ("Spot price", option.instrument().spot()),
+        ("Strike", option.strike()),
+        ("Time to maturity", option.time_to_maturity()),
+    ];
+    for (name, value) in inputs {
+        if !value.is_finite() || value < 0.0 {
+            return Err(PricingError::InvalidInput(format!(
+                "{name} must be finite and non-negative, got {value}"
+            )));
+        }
+    }
+    for dividend in &option.instrument().cash_dividends {
+        if !dividend.ex_date.is_finite() || !dividend.amount.is_finite() || dividend.amount < 0.0 {
+            return Err(PricingError::InvalidInput(format!(
+                "Cash dividends must have a finite ex-dividend date and a finite non-negative amount, got {} at {}",
+                dividend.amount, dividend.ex_date
+            )));
+        }
+    }
+    validate_correlation(option.instrument())
+}
+
+/// Check that the correlation matrix of an instrument (if set) is a symmetric positive semi-definite `n x n` matrix
+/// with a unit diagonal, where `n` is the number of assets.
+fn validate_correlation(instrument: &Instrument) -> Result<(), PricingError> {
+    let correlation = &instrument.correlation;
+    if correlation.is_empty() {
+        return Ok(());
+    }
+    let n = instrument.assets.len().max(1);
+    if correlation.len() != n || correlation.iter().any(|row| row.len() != n) {
+        return Err(PricingError::InvalidInput(format!(
+            "Correlation matrix must be {n}x{n} to match the number of assets"
+        )));
+    }
+    for (i, row) in correlation.iter().enumerate() {
+        if row[i].is_nan() || (row[i] - 1.0).abs() > 1e-12 {
+            return Err(PricingError::invalid_input(
+                "Correlation matrix must have a unit diagonal",
+            ));
+        }
+        for (j, &rho) in row.iter().enumerate().take(i) {
+            if rho.is_nan() || rho.abs() > 1.0 || (rho - correlation[j][i]).abs() > 1e-12 {
+                return Err(PricingError::invalid_input(
+                    "Correlation matrix must be symmetric with entries in [-1, 1]",
+                ));
+            }
+        }
+    }
+    if numerics::cholesky(correlation).is_none() {
+        return Err(PricingError::invalid_input(
+            "Correlation matrix must be positive semi-definite",
+        ));
+    }
+    Ok(())
+}
+
+/// Check the option and that the market price is finite and non-negative.
+pub(crate) fn validate_market_price<T: Option>(
+    option: &T,
+    market_price: f64,
+) -> Result<(), PricingError> {
+    validate_option(option)?;
+    if !market_price.is_finite() || market_price < 0.0 {
+        return Err(PricingError::InvalidInput(format!(
+            "Market price must be finite and non-negative, got {market_price}"
+        )));
+    }
+    Ok(())
 }
@@ -6,6 +6,8 @@
 
 pub use american_option::AmericanOption;
 pub use asian_option::AsianOption;
+pub use barrier_option::BarrierOption;
+pub use basket_option::BasketOption;
 pub use bermudan_option::BermudanOption;
 pub use binary_option::BinaryOption;
 pub use european_option::EuropeanOption;
@@ -14,12 +16,16 @@ pub use rainbow_option::RainbowOption;
 
 mod american_option;
 mod asian_option;
+mod barrier_option;
+mod basket_option;
 mod bermudan_option;
 mod binary_option;
 mod european_option;
 mod lookback_option;
 mod rainbow_option;
 
+use crate::options::Instrument;
+
 /// Enum representing the type of option.
 #[derive(Clone, Copy, Debug)]
 pub enum OptionType {
@@ -70,7 +76,40 @@ pub enum RainbowType {
     AllOTM,
 }
 
-/// Enum representing the type of a Binary option.
+impl RainbowType {
+    /// Calculate the payoff of a Rainbow option given the prices of all underlying assets.
+    ///
+    /// # Arguments
+    ///
+    /// * `strike` - The strike price of the option.
+    /// * `spots` - The prices of the underlying assets.
+    /// * `weights` - The weights of the underlying assets (used for the average).
+    ///
+    /// # Returns
+    ///
+    /// The payoff of the option.
+    pub fn payoff(&self, strike: f64, spots: &[f64], weights: &[f64]) -> f64 {
+        let max = spots.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let min = spots.iter().copied().fold(f64::INFINITY, f64::min);
+        let avg: f64 = spots.iter().zip(weights).map(|(s, w)| s * w).sum();
+
+        match self {
+            RainbowType::BestOf => max.max(strike),
+            RainbowType::WorstOf => min.min(strike),
+            RainbowType::CallOnMax => (max - strike).max(0.0),
+            RainbowType::CallOnMin => (min - strike).max(0.0),
+            RainbowType::PutOnMax => (strike - max).max(0.0),
+            RainbowType::PutOnMin => (strike - min).max(0.0),
+            RainbowType::CallOnAvg => (avg - strike).max(0.0),
+            RainbowType::PutOnAvg => (strike - avg).max(0.0),
+            RainbowType::AllITM if min > strike => avg,
+            RainbowType::AllOTM if max < strike => avg,
+            RainbowType::AllITM | RainbowType::AllOTM => 0.0,
+        }
+    }
+}
+
+/// Enum representing the type of a Barrier option.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum BarrierType {
     DownAndIn,
@@ -79,6 +118,149 @@ pub enum BarrierType {
     UpAndOut,
 }
 
+impl BarrierType {
+    /// Whether the option comes into existence when the barrier is hit.
+    pub fn is_knock_in(&self) -> bool {
+        matches!(self, BarrierType::DownAndIn | BarrierType::UpAndIn)
+    }
+
+    /// Whether the barrier lies below the spot price.
+    pub fn is_down(&self) -> bool {
+        matches!(self, BarrierType::DownAndIn | BarrierType::DownAndOut)
+    }
+}
+
+/// Enum representing how often the barrier of a Barrier option is monitored.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Monitoring {
+    /// The barrier is monitored continuously.
+    #[default]
+    Continuous,
+    /// The barrier is monitored on the given number of equally spaced dates (the last one at maturity).
+    Discrete(usize),
+}
+
+/// The barrier specification of a Barrier option.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Barrier {
+    /// Barrier level (lower barrier of a double barrier option).
+    pub level: f64,
+    /// Upper barrier level (only for double barrier options).
+    pub upper_level: std::option::Option<f64>,
+    /// Rebate paid when a knock-out option is knocked out (at the hitting time) or a knock-in option expires
+    /// without being knocked in (at maturity).
+    pub rebate: f64,
+    /// Monitoring frequency of the barrier.
+    pub monitoring: Monitoring,
+}
+
+impl Barrier {
+    /// Check whether the barrier is breached at a given spot price.
+    ///
+    /// # Arguments
+    ///
+    /// * `barrier_type` - The type of the barrier (only the direction is used for single barriers).
+    /// * `spot` - The spot price of the underlying asset.
+    ///
+    /// # Returns
+    ///
+    /// Whether the barrier is breached.
+    pub fn is_breached(&self, barrier_type: BarrierType, spot: f64) -> bool {
+        match self.upper_level {
+            Some(upper_level) => spot <= self.level || spot >= upper_level,
+            None if barrier_type.is_down() => spot <= self.level,
+            None => spot >= self.level,
+        }
+    }
+}
+
+/// The averaging schedule of an Asian option.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AveragingSchedule {
+    /// Remaining fixing dates (in years, after the valuation date and up to maturity).
+    pub fixing_dates: Vec<f64>,
+    /// Prices already fixed on past fixing dates.
+    pub past_fixings: Vec<f64>,
+}
+
+impl AveragingSchedule {
+    /// Create a new `AveragingSchedule` from the remaining fixing dates.
+    pub fn new(fixing_dates: Vec<f64>) -> Self {
+        Self {
+            fixing_dates,
+            past_fixings: Vec::new(),
+        }
+    }
+
+    /// Create a schedule of equally spaced fixing dates, the last one at maturity.
+    pub fn uniform(time_to_maturity: f64, fixings: usize) -> Self {
+        Self::new(
+            (1..=fixings)
+                .map(|fixing| time_to_maturity * fixing as f64 / fixings as f64)
+                .collect(),
+        )
+    }
+
+    /// Set the prices fixed on past fixing dates (of a seasoned option).
+    pub fn with_past_fixings(mut self, past_fixings: Vec<f64>) -> Self {
+        self.past_fixings = past_fixings;
+        self
+    }
+
+    /// Get the total number of past and remaining fixings.
+    pub fn fixings(&self) -> usize {
+        self.past_fixings.len() + self.fixing_dates.len()
+    }
+}
+
+/// The lookback period and observed extrema of a Lookback option.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Lookback {
+    /// Maximum observed before the valuation date (of a seasoned option).
+    pub running_max: std::option::Option<f64>,
+    /// Minimum observed before the valuation date (of a seasoned option).
+    pub running_min: std::option::Option<f64>,
+    /// End of the lookback period of a partial-time floating strike option or start of the lookback period of a
+    /// partial-time fixed strike option (`None` to look back over the whole life of the option).
+    pub partial_time: std::option::Option<f64>,
+    /// Monitoring frequency of the extremum over the lookback period.
+    pub monitoring: Monitoring,
+}
+
+impl Lookback {
+    /// Get the start and end of the remaining lookback period.
+    ///
+    /// # Arguments
+    ///
+    /// * `permutation` - Whether the option has a fixed or floating strike.
+    /// * `time_to_maturity` - Time to maturity of the option.
+    ///
+    /// # Returns
+    ///
+    /// The start and end of the lookback period (in years).
+    pub fn period(&self, permutation: Permutation, time_to_maturity: f64) -> (f64, f64) {
+        match (self.partial_time, permutation) {
+            (Some(time), Permutation::Fixed) => {
+                (time.clamp(0.0, time_to_maturity), time_to_maturity)
+            }
+            (Some(time), Permutation::Floating) => (0.0, time.clamp(0.0, time_to_maturity)),
+            (None, _) => (0.0, time_to_maturity),
+        }
+    }
+
+    /// Get the maximum of the spot prices of an instrument and the running maximum.
+    pub fn max_spot(&self, instrument: &Instrument) -> f64 {
+        self.running_max
+            .map_or(instrument.max_spot(), |max| max.max(instrument.max_spot()))
+    }
+
+    /// Get the minimum of the spot prices of an instrument and the running minimum.
+    pub fn min_spot(&self, instrument: &Instrument) -> f64 {
+        self.running_min
+            .map_or(instrument.min_spot(), |min| min.min(instrument.min_spot()))
+    }
+}
+
 /// Enum representing the type of a Lookback or Asian option.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Permutation {
@@ -92,3 +274,120 @@ pub enum BinaryType {
     AssetOrNothing,
     CashOrNothing,
 }
+
+/// Enum representing the quoted value of a market option quote.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QuoteValue {
+    /// Quoted option premium.
+    Price(f64),
+    /// Quoted Black-Scholes implied volatility (e.g., 0.2 for 20%).
+    ImpliedVolatility(f64),
+}
+
+/// A market quote of a European option used for model calibration.
+#[derive(Clone, Copy, Debug)]
+pub struct MarketQuote {
+    /// Strike price of the quoted option.
+    pub strike: f64,
+    /// Time to maturity (in years) of the quoted option.
+    pub time_to_maturity: f64,
+    /// Type of the quoted option (Call or Put).
+    pub option_type: OptionType,
+    /// Quoted price or implied volatility.
+    pub value: QuoteValue,
+}
+
+impl MarketQuote {
+    /// Create a new `MarketQuote` from a quoted option price.
+    pub fn price(strike: f64, time_to_maturity: f64, option_type: OptionType, price: f64) -> Self {
+        Self {
+            strike,
+            time_to_maturity,
+            option_type,
+            value: QuoteValue::Price(price),
+        }
+    }
+
+    /// Create a new `MarketQuote` from a quoted implied volatility.
+    pub fn implied_volatility(
+        strike: f64,
+        time_to_maturity: f64,
+        option_type: OptionType,
+        volatility: f64,
+    ) -> Self {
+        Self {
+            strike,
+            time_to_maturity,
+            option_type,
+            value: QuoteValue::ImpliedVolatility(volatility),
+        }
+    }
+}
+
+/// Errors that can occur when pricing an option or calculating its Greeks.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PricingError {
+    /// The model does not support the style of the option
+    UnsupportedStyle {
+        model: &'static str,
+        style: OptionStyle,
+    },
+
+    /// Invalid option or model input (e.g., negative spot price or time to maturity)
+    InvalidInput(String),
+
+    /// Iterative method did not converge within the maximum number of iterations
+    NoConvergence { iterations: usize },
+
+    /// Market price outside of the prices attainable by the model (e.g., below the intrinsic value)
+    PriceOutOfBounds { price: f64, lower: f64, upper: f64 },
+
+    /// Calculation (e.g., a Greek or the implied volatility) not implemented for the model
+    NotImplemented(String),
+}
+
+impl std::fmt::Display for PricingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PricingError::UnsupportedStyle { model, style } => {
+                write!(f, "{model} does not support this option style: {style:?}")
+            }
+            PricingError::InvalidInput(msg) => {
+                write!(f, "Invalid input: {msg}")
+            }
+            PricingError::NoConvergence { iterations } => {
+                write!(f, "No convergence after {iterations} iterations")
+            }
+            PricingError::PriceOutOfBounds {
+                price,
+                lower,
+                upper,
+            } => {
+                write!(
+                    f,
+                    "Market price {price} outside of the no-arbitrage bounds [{lower}, {upper}]"
+                )
+            }
+            PricingError::NotImplemented(calculation) => {
+                write!(f, "{calculation} not implemented for this model")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PricingError {}
+
+// Helper methods for creating common errors
+impl PricingError {
+    pub fn unsupported_style(model: &'static str, style: OptionStyle) -> Self {
+        Self::UnsupportedStyle { model, style }
+    }
+
+    pub fn invalid_input(msg: &str) -> Self {
+        Self::InvalidInput(msg.to_string())
+    }
+
+    pub fn not_implemented(calculation: &str) -> Self {
+        Self::NotImplemented(calculation.to_string())
+    }
+}
@@ -9,20 +9,29 @@
 //! - **Strike Price**: The price at which the option can be exercised (for fixed strike options).
 //! - **Option Type**: Specifies whether the option is a call (right to buy) or a put (right to sell).
 //! - **Asian Type**: Specifies whether the option is a fixed strike or floating strike.
+//! - **Averaging Schedule**: The fixing dates of the average and the prices already fixed (optional, models average
+//!   over their own time grid or continuously without a schedule).
 //!
 //! ## Example
 //!
 //! ```
-//! use quantrs::options::{Option, AsianOption, Instrument, OptionType, Permutation};
+//! use quantrs::options::{Option, AsianOption, AveragingSchedule, Instrument, OptionType, Permutation};
 //!
 //! let instrument = Instrument::new().with_spot(100.0);
-//! let option = AsianOption::new(instrument, 100.0, 1.0, OptionType::Call, Permutation::Fixed);
+//! let option = AsianOption::new(instrument.clone(), 100.0, 1.0, OptionType::Call, Permutation::Fixed);
+//!
+//! // Monthly fixings, three of which have already been fixed
+//! let schedule = AveragingSchedule::uniform(0.75, 9).with_past_fixings(vec![96.0, 98.5, 101.0]);
+//! let seasoned = AsianOption::fixed(instrument, 100.0, 0.75, OptionType::Call).with_schedule(schedule);
 //!
 //! println!("Option type: {:?}", option.option_type());
 //! println!("Strike price: {}", option.strike());
 //! println!("Option style: {:?}", option.style());
 //! ```
-use crate::options::{Instrument, Option, OptionStyle, OptionType, types::Permutation};
+use crate::options::{
+    Instrument, Option, OptionStyle, OptionType,
+    types::{AveragingSchedule, Permutation},
+};
 
 /// A struct representing an Asian option.
 #[derive(Clone, Debug)]
@@ -39,6 +48,8 @@ pub struct AsianOption {
     pub option_style: OptionStyle,
     /// The type of the Asian option (Fixed or Floating).
     pub asian_type: Permutation,
+    /// The averaging schedule (`None` to average over the model's time grid or continuously).
+    pub schedule: std::option::Option<AveragingSchedule>,
 }
 
 impl AsianOption {
@@ -57,6 +68,7 @@ impl AsianOption {
             option_type,
             option_style: OptionStyle::Asian(asian_type),
             asian_type,
+            schedule: None,
         }
     }
 
@@ -90,6 +102,12 @@ impl AsianOption {
             Permutation::Floating,
         )
     }
+
+    /// Set the averaging schedule of the option.
+    pub fn with_schedule(mut self, schedule: AveragingSchedule) -> Self {
+        self.schedule = Some(schedule);
+        self
+    }
 }
 
 impl Option for AsianOption {
@@ -113,6 +131,10 @@ impl Option for AsianOption {
         self.time_to_maturity
     }
 
+    fn averaging_schedule(&self) -> std::option::Option<&AveragingSchedule> {
+        self.schedule.as_ref()
+    }
+
     fn set_time_to_maturity(&mut self, time_to_maturity: f64) {
         self.time_to_maturity = time_to_maturity;
     }
@@ -144,13 +166,16 @@ impl Option for AsianOption {
             OptionType::Call => OptionType::Put,
             OptionType::Put => OptionType::Call,
         };
-        AsianOption::new(
-            self.instrument.clone(),
-            self.strike,
-            self.time_to_maturity,
-            flipped_option_type,
-            self.asian_type,
-        )
+        AsianOption {
+            schedule: self.schedule.clone(),
+            ..AsianOption::new(
+                self.instrument.clone(),
+                self.strike,
+                self.time_to_maturity,
+                flipped_option_type,
+                self.asian_type,
+            )
+        }
     }
 
     fn as_any(&self) -> &dyn std::any::Any {
@@ -0,0 +1,293 @@
+//! Module for Barrier option type.
+//!
+//! A Barrier option is a path-dependent option that is either activated (knock-in) or extinguished (knock-out)
+//! when the price of the underlying asset hits a barrier level before expiration.
+//! A double barrier option has a lower and an upper barrier and is knocked in or out when either of them is hit.
+//!
+//! ## Characteristics
+//!
+//! - **Underlying Instrument**: The asset on which the option is based.
+//! - **Strike Price**: The price at which the option can be exercised.
+//! - **Option Type**: Specifies whether the option is a call (right to buy) or a put (right to sell).
+//! - **Barrier Type**: Specifies the direction of the barrier (down or up) and whether the option is knocked in or out.
+//! - **Rebate**: Amount paid when a knock-out option is knocked out (at the hitting time) or a knock-in option expires
+//!   without being knocked in (at maturity).
+//! - **Monitoring**: Specifies whether the barrier is monitored continuously or on discrete dates.
+//!
+//! ## Example
+//!
+//! ```
+//! use quantrs::options::{Option, BarrierOption, Instrument, Monitoring, OptionType};
+//!
+//! let instrument = Instrument::new().with_spot(100.0);
+//! let option = BarrierOption::down_and_out(instrument, 100.0, 1.0, OptionType::Call, 90.0)
+//!     .with_rebate(2.0)
+//!     .with_monitoring(Monitoring::Discrete(252));
+//!
+//! println!("Option type: {:?}", option.option_type());
+//! println!("Strike price: {}", option.strike());
+//! println!("Option style: {:?}", option.style());
+//! println!("Barrier: {:?}", option.barrier());
+//! ```
+
+use std::any::Any;
+
+use super::{Barrier, BarrierType, Monitoring, OptionStyle, OptionType};
+use crate::options::{Instrument, Option};
+
+/// A struct representing a Barrier option.
+#[derive(Clone, Debug)]
+pub struct BarrierOption {
+    /// The underlying instrument.
+    pub instrument: Instrument,
+    /// Strike price of the option (aka exercise price).
+    pub strike: f64,
+    /// The time horizon (in years).
+    pub time_to_maturity: f64,
+    /// Type of the option (Call or Put).
+    pub option_type: OptionType,
+    /// Style of the option (Barrier or DoubleBarrier with specific types).
+    pub option_style: OptionStyle,
+    /// The barrier specification.
+    pub barrier: Barrier,
+}
+
+impl BarrierOption {
+    /// Create a new single `BarrierOption`.
+    pub fn new(
+        instrument: Instrument,
+        strike: f64,
+        time_to_maturity: f64,
+        option_type: OptionType,
+        barrier_type: BarrierType,
+        barrier: f64,
+    ) -> Self {
+        Self {
+            instrument,
+            strike,
+            time_to_maturity,
+            option_type,
+            option_style: OptionStyle::Barrier(barrier_type),
+            barrier: Barrier {
+                level: barrier,
+                upper_level: None,
+                rebate: 0.0,
+                monitoring: Monitoring::Continuous,
+            },
+        }
+    }
+
+    /// Create a new `DownAndIn` barrier option.
+    pub fn down_and_in(
+        instrument: Instrument,
+        strike: f64,
+        time_to_maturity: f64,
+        option_type: OptionType,
+        barrier: f64,
+    ) -> Self {
+        Self::new(
+            instrument,
+            strike,
+            time_to_maturity,
+            option_type,
+            BarrierType::DownAndIn,
+            barrier,
+        )
+    }
+
+    /// Create a new `DownAndOut` barrier option.
+    pub fn down_and_out(
+        instrument: Instrument,
+        strike: f64,
+        time_to_maturity: f64,
+        option_type: OptionType,
+        barrier: f64,
+    ) -> Self {
+        Self::new(
+            instrument,
+            strike,
+            time_to_maturity,
+            option_type,
+            BarrierType::DownAndOut,
+            barrier,
+        )
+    }
+
+    /// Create a new `UpAndIn` barrier option.
+    pub fn up_and_in(
+        instrument: Instrument,
+        strike: f64,
+        time_to_maturity: f64,
+        option_type: OptionType,
+        barrier: f64,
+    ) -> Self {
+        Self::new(
+            instrument,
+            strike,
+            time_to_maturity,
+            option_type,
+            BarrierType::UpAndIn,
+            barrier,
+        )
+    }
+
+    /// Create a new `UpAndOut` barrier option.
+    pub fn up_and_out(
+        instrument: Instrument,
+        strike: f64,
+        time_to_maturity: f64,
+        option_type: OptionType,
+        barrier: f64,
+    ) -> Self {
+        Self::new(
+            instrument,
+            strike,
+            time_to_maturity,
+            option_type,
+            BarrierType::UpAndOut,
+            barrier,
+        )
+    }
+
+    /// Create a new double knock-out barrier option.
+    pub fn double_knock_out(
+        instrument: Instrument,
+        strike: f64,
+        time_to_maturity: f64,
+        option_type: OptionType,
+        lower_barrier: f64,
+        upper_barrier: f64,
+    ) -> Self {
+        let mut option = Self::new(
+            instrument,
+            strike,
+            time_to_maturity,
+            option_type,
+            BarrierType::DownAndOut,
+            lower_barrier,
+        );
+        option.option_style =
+            OptionStyle::DoubleBarrier(BarrierType::DownAndOut, BarrierType::UpAndOut);
+        option.barrier.upper_level = Some(upper_barrier);
+        option
+    }
+
+    /// Create a new double knock-in barrier option.
+    pub fn double_knock_in(
+        instrument: Instrument,
+        strike: f64,
+        time_to_maturity: f64,
+        option_type: OptionType,
+        lower_barrier: f64,
+        upper_barrier: f64,
+    ) -> Self {
+        let mut option = Self::double_knock_out(
+            instrument,
+            strike,
+            time_to_maturity,
+            option_type,
+            lower_barrier,
+            upper_barrier,
+        );
+        option.option_style =
+            OptionStyle::DoubleBarrier(BarrierType::DownAndIn, BarrierType::UpAndIn);
+        option
+    }
+
+    /// Set the rebate of the option.
+    pub fn with_rebate(mut self, rebate: f64) -> Self {
+        self.barrier.rebate = rebate;
+        self
+    }
+
+    /// Set the monitoring frequency of the barrier.
+    pub fn with_monitoring(mut self, monitoring: Monitoring) -> Self {
+        self.barrier.monitoring = monitoring;
+        self
+    }
+
+    /// Get the barrier type (of the lower barrier for double barrier options).
+    pub fn barrier_type(&self) -> BarrierType {
+        match self.option_style {
+            OptionStyle::Barrier(barrier_type) => barrier_type,
+            OptionStyle::DoubleBarrier(barrier_type, _) => barrier_type,
+            _ => panic!("Not a barrier option"),
+        }
+    }
+}
+
+impl Option for BarrierOption {
+    fn instrument(&self) -> &Instrument {
+        &self.instrument
+    }
+
+    fn instrument_mut(&mut self) -> &mut Instrument {
+        &mut self.instrument
+    }
+
+    fn set_instrument(&mut self, instrument: Instrument) {
+        self.instrument = instrument;
+    }
+
+    fn strike(&self) -> f64 {
+        self.strike
+    }
+
+    fn time_to_maturity(&self) -> f64 {
+        self.time_to_maturity
+    }
+
+    fn barrier(&self) -> std::option::Option<&Barrier> {
+        Some(&self.barrier)
+    }
+
+    fn set_time_to_maturity(&mut self, time_to_maturity: f64) {
+        self.time_to_maturity = time_to_maturity;
+    }
+
+    fn option_type(&self) -> OptionType {
+        self.option_type
+    }
+
+    fn style(&self) -> &OptionStyle {
+        &self.option_style
+    }
+
+    fn flip(&self) -> Self {
+        let flipped_option_type = match self.option_type {
+            OptionType::Call => OptionType::Put,
+            OptionType::Put => OptionType::Call,
+        };
+        Self {
+            option_type: flipped_option_type,
+            ..self.clone()
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    /// Calculate the payoff of the option at maturity.
+    ///
+    /// The barrier is checked against the path stored in the underlying instrument and the given spot price.
+    /// The rebate is returned (undiscounted) if the option is knocked out or was never knocked in.
+    fn payoff(&self, spot: std::option::Option<f64>) -> f64 {
+        let spot = spot.unwrap_or(self.instrument.terminal_spot());
+        let barrier_type = self.barrier_type();
+        let hit = self
+            .instrument
+            .spot
+            .iter()
+            .chain(std::iter::once(&spot))
+            .any(|&price| self.barrier.is_breached(barrier_type, price));
+
+        if hit != barrier_type.is_knock_in() {
+            return self.barrier.rebate;
+        }
+        match self.option_type {
+            OptionType::Call => (spot - self.strike).max(0.0),
+            OptionType::Put => (self.strike - spot).max(0.0),
+        }
+    }
+}
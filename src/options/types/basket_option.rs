@@ -0,0 +1,140 @@
+//! Module for Basket option type.
+//!
+//! A Basket option is an option whose underlying is a weighted sum of multiple assets (e.g., an index-like portfolio).
+//! Its payoff at maturity is the vanilla payoff of the basket value `B = Σ wᵢ Sᵢ`.
+//!
+//! ## Characteristics
+//!
+//! - **Underlying Instrument**: The basket, i.e. an instrument with weighted assets and (optionally) their correlation matrix.
+//! - **Strike Price**: The price at which the option can be exercised.
+//! - **Option Type**: Specifies whether the option is a call (right to buy) or a put (right to sell).
+//!
+//! ## References
+//!
+//! - [Wikipedia - Basket option](https://en.wikipedia.org/wiki/Basket_option)
+//!
+//! ## Example
+//!
+//! ```
+//! use quantrs::options::{Instrument, Option, BasketOption, OptionType};
+//!
+//! let asset1 = Instrument::new().with_spot(100.0).with_volatility(0.2);
+//! let asset2 = Instrument::new().with_spot(110.0).with_volatility(0.3);
+//!
+//! let instrument = Instrument::new()
+//!     .with_weighted_assets(vec![(asset1, 0.6), (asset2, 0.4)])
+//!     .with_correlation_matrix(vec![vec![1.0, 0.5], vec![0.5, 1.0]]);
+//!
+//! let option = BasketOption::new(instrument, 100.0, 1.0, OptionType::Call);
+//!
+//! println!("Basket value: {}", option.instrument().spot()); // should be 104.0
+//! println!("Payoff: {}", option.payoff(None)); // should be 4.0
+//! ```
+
+use std::any::Any;
+
+use super::{OptionStyle, OptionType};
+use crate::options::{Instrument, Option};
+
+/// A struct representing a Basket option.
+#[derive(Clone, Debug)]
+pub struct BasketOption {
+    /// The underlying basket.
+    pub instrument: Instrument,
+    /// Strike price of the option (aka exercise price).
+    pub strike: f64,
+    /// The time horizon (in years).
+    pub time_to_maturity: f64,
+    /// Type of the option (Call or Put).
+    pub option_type: OptionType,
+    /// Style of the option (Basket).
+    pub option_style: OptionStyle,
+}
+
+impl BasketOption {
+    /// Create a new `BasketOption`.
+    pub fn new(
+        instrument: Instrument,
+        strike: f64,
+        time_to_maturity: f64,
+        option_type: OptionType,
+    ) -> Self {
+        Self {
+            instrument,
+            strike,
+            time_to_maturity,
+            option_type,
+            option_style: OptionStyle::Basket,
+        }
+    }
+
+    /// Create a new `BasketOption` call.
+    pub fn call(instrument: Instrument, strike: f64, ttm: f64) -> Self {
+        Self::new(instrument, strike, ttm, OptionType::Call)
+    }
+
+    /// Create a new `BasketOption` put.
+    pub fn put(instrument: Instrument, strike: f64, ttm: f64) -> Self {
+        Self::new(instrument, strike, ttm, OptionType::Put)
+    }
+}
+
+impl Option for BasketOption {
+    fn instrument(&self) -> &Instrument {
+        &self.instrument
+    }
+
+    fn instrument_mut(&mut self) -> &mut Instrument {
+        &mut self.instrument
+    }
+
+    fn set_instrument(&mut self, instrument: Instrument) {
+        self.instrument = instrument;
+    }
+
+    fn strike(&self) -> f64 {
+        self.strike
+    }
+
+    fn time_to_maturity(&self) -> f64 {
+        self.time_to_maturity
+    }
+
+    fn set_time_to_maturity(&mut self, time_to_maturity: f64) {
+        self.time_to_maturity = time_to_maturity;
+    }
+
+    fn option_type(&self) -> OptionType {
+        self.option_type
+    }
+
+    fn style(&self) -> &OptionStyle {
+        &self.option_style
+    }
+
+    fn flip(&self) -> Self {
+        let flipped_option_type = match self.option_type {
+            OptionType::Call => OptionType::Put,
+            OptionType::Put => OptionType::Call,
+        };
+        Self {
+            option_type: flipped_option_type,
+            ..self.clone()
+        }
+    }
+
+    /// Calculate the payoff of the option at maturity.
+    ///
+    /// The given spot price is interpreted as the value of the basket (defaults to the current basket value).
+    fn payoff(&self, spot: std::option::Option<f64>) -> f64 {
+        let basket_value = spot.unwrap_or_else(|| self.instrument.spot());
+        match self.option_type {
+            OptionType::Call => (basket_value - self.strike).max(0.0),
+            OptionType::Put => (self.strike - basket_value).max(0.0),
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -1,13 +1,58 @@
-use crate::options::{Instrument, Option, OptionStyle, OptionType, types::Permutation};
-
+//! Module for Lookback option type.
+//!
+//! A Lookback option is a path-dependent option whose payoff depends on the maximum or minimum price of the
+//! underlying asset over the lookback period. A fixed strike option pays the difference between the extremum and
+//! the strike, a floating strike option the difference between the terminal price and the extremum.
+//!
+//! ## Characteristics
+//!
+//! - **Underlying Instrument**: The asset on which the option is based.
+//! - **Strike Price**: The price at which the option can be exercised (for fixed strike options).
+//! - **Option Type**: Specifies whether the option is a call (right to buy) or a put (right to sell).
+//! - **Lookback Type**: Specifies whether the option is a fixed strike or floating strike.
+//! - **Lookback**: The extrema observed before the valuation date (of a seasoned option), the lookback period of a
+//!   partial-time option and whether the extremum is monitored continuously or on discrete dates.
+//!
+//! ## Example
+//!
+//! ```
+//! use quantrs::options::{Option, LookbackOption, Instrument, Monitoring, OptionType};
+//!
+//! let instrument = Instrument::new().with_spot(100.0);
+//! let option = LookbackOption::floating(instrument.clone(), 1.0, OptionType::Call);
+//!
+//! // Daily monitored fixed strike call, the maximum so far being 108
+//! let seasoned = LookbackOption::fixed(instrument, 105.0, 0.5, OptionType::Call)
+//!     .with_running_max(108.0)
+//!     .with_monitoring(Monitoring::Discrete(126));
+//!
+//! println!("Option type: {:?}", option.option_type());
+//! println!("Option style: {:?}", option.style());
+//! println!("Lookback: {:?}", seasoned.lookback());
+//! ```
+
+use crate::options::{
+    Instrument, Option, OptionStyle, OptionType,
+    types::{Lookback, Monitoring, Permutation},
+};
+
+/// A struct representing a Lookback option.
 #[derive(Clone, Debug)]
 pub struct LookbackOption {
+    /// The underlying instrument.
     pub instrument: Instrument,
+    /// The time horizon (in years).
     pub time_to_maturity: f64,
+    /// Strike price of the option (only for fixed strike options).
     pub strike: f64,
+    /// Type of the option (Call or Put).
     pub option_type: OptionType,
+    /// The style of the option (Lookback).
     pub option_style: OptionStyle,
+    /// The type of the Lookback option (Fixed or Floating).
     pub lookback_type: Permutation,
+    /// The lookback period and observed extrema.
+    pub lookback: Lookback,
 }
 
 impl LookbackOption {
@@ -26,6 +71,7 @@ impl LookbackOption {
             option_type,
             option_style: OptionStyle::Lookback(lookback_type),
             lookback_type,
+            lookback: Lookback::default(),
         }
     }
 
@@ -59,6 +105,31 @@ impl LookbackOption {
             Permutation::Floating,
         )
     }
+
+    /// Set the maximum observed before the valuation date (of a seasoned option).
+    pub fn with_running_max(mut self, running_max: f64) -> Self {
+        self.lookback.running_max = Some(running_max);
+        self
+    }
+
+    /// Set the minimum observed before the valuation date (of a seasoned option).
+    pub fn with_running_min(mut self, running_min: f64) -> Self {
+        self.lookback.running_min = Some(running_min);
+        self
+    }
+
+    /// Restrict the lookback period of a floating strike option to the first `partial_time` years, or the lookback
+    /// period of a fixed strike option to the time after `partial_time` years.
+    pub fn with_partial_time(mut self, partial_time: f64) -> Self {
+        self.lookback.partial_time = Some(partial_time);
+        self
+    }
+
+    /// Set the monitoring frequency of the extremum.
+    pub fn with_monitoring(mut self, monitoring: Monitoring) -> Self {
+        self.lookback.monitoring = monitoring;
+        self
+    }
 }
 
 impl Option for LookbackOption {
@@ -94,23 +165,28 @@ impl Option for LookbackOption {
         &self.option_style
     }
 
-    fn payoff(&self, avg_price: std::option::Option<f64>) -> f64 {
-        let avg_price = avg_price.unwrap_or(self.instrument.spot());
-        let max_spot = self.instrument.max_spot();
+    fn lookback(&self) -> std::option::Option<&Lookback> {
+        Some(&self.lookback)
+    }
 
+    fn payoff(&self, _avg_price: std::option::Option<f64>) -> f64 {
         match self.lookback_type {
             Permutation::Fixed => match self.option_type {
-                OptionType::Call => (self.instrument.max_spot() - self.strike).max(0.0),
-                OptionType::Put => (self.strike - self.instrument.min_spot()).max(0.0),
-            },
-            Permutation::Floating => match self.option_type {
                 OptionType::Call => {
-                    (self.instrument.terminal_spot() - self.instrument.min_spot()).max(0.0)
+                    (self.lookback.max_spot(&self.instrument) - self.strike).max(0.0)
                 }
                 OptionType::Put => {
-                    (self.instrument.max_spot() - self.instrument.terminal_spot()).max(0.0)
+                    (self.strike - self.lookback.min_spot(&self.instrument)).max(0.0)
                 }
             },
+            Permutation::Floating => match self.option_type {
+                OptionType::Call => (self.instrument.terminal_spot()
+                    - self.lookback.min_spot(&self.instrument))
+                .max(0.0),
+                OptionType::Put => (self.lookback.max_spot(&self.instrument)
+                    - self.instrument.terminal_spot())
+                .max(0.0),
+            },
         }
     }
 
@@ -119,13 +195,16 @@ impl Option for LookbackOption {
             OptionType::Call => OptionType::Put,
             OptionType::Put => OptionType::Call,
         };
-        LookbackOption::new(
-            self.instrument.clone(),
-            self.strike,
-            self.time_to_maturity,
-            flipped_option_type,
-            self.lookback_type,
-        )
+        LookbackOption {
+            lookback: self.lookback,
+            ..LookbackOption::new(
+                self.instrument.clone(),
+                self.strike,
+                self.time_to_maturity,
+                flipped_option_type,
+                self.lookback_type,
+            )
+        }
     }
 
     fn as_any(&self) -> &dyn std::any::Any {
@@ -15,6 +15,9 @@
 //! - `AllITM`: Pays the sum of the returns if all assets are in-the-money.
 //! - `AllOTM`: Pays the sum of the returns if all assets are out-of-the-money.
 //!
+//! The models price Rainbow options on the joint distribution of the assets, using their individual volatilities and
+//! the correlation matrix of the underlying instrument.
+//!
 //! ## References
 //!
 //! - [Wikipedia - Rainbow option](https://en.wikipedia.org/wiki/Rainbow_option)
@@ -44,16 +47,16 @@
 //! let put_on_max = RainbowOption::put_on_max(instrument.clone(), 120.0, 1.0);
 //! let put_on_min = RainbowOption::put_on_min(instrument.clone(), 105.0, 1.0);
 //!
-//! println!("Best-Of Payoff: {}", best_of.payoff(None)); // should be 115.0
-//! println!("Worst-Of Payoff: {}", worst_of.payoff(None)); // should be 86.0
-//! println!("Call-On-Avg Payoff: {}", call_on_avg.payoff(None)); // should be 1.6
-//! println!("Put-On-Avg Payoff: {}", put_on_avg.payoff(None)); // should be 8.3
+//! println!("Best-Of Payoff: {}", best_of.payoff(None)); // should be 110.0
+//! println!("Worst-Of Payoff: {}", worst_of.payoff(None)); // should be 90.0
+//! println!("Call-On-Avg Payoff: {}", call_on_avg.payoff(None)); // should be 0.0
+//! println!("Put-On-Avg Payoff: {}", put_on_avg.payoff(None)); // should be 10.0
 //! println!("All ITM Payoff: {}", all_itm.payoff(None)); // should be 0.0
 //! println!("All OTM Payoff: {}", all_otm.payoff(None)); // should be 0.0
-//! println!("Call-On-Max Payoff: {}", call_on_max.payoff(None)); // should be 10.0
-//! println!("Call-On-Min Payoff: {}", call_on_min.payoff(None)); // should be 6.0
-//! println!("Put-On-Max Payoff: {}", put_on_max.payoff(None)); // should be 5.0
-//! println!("Put-On-Min Payoff: {}", put_on_min.payoff(None)); // should be 19.0
+//! println!("Call-On-Max Payoff: {}", call_on_max.payoff(None)); // should be 5.0
+//! println!("Call-On-Min Payoff: {}", call_on_min.payoff(None)); // should be 10.0
+//! println!("Put-On-Max Payoff: {}", put_on_max.payoff(None)); // should be 10.0
+//! println!("Put-On-Min Payoff: {}", put_on_min.payoff(None)); // should be 15.0
 //! ```
 
 use super::{OptionStyle, OptionType, RainbowType, RainbowType::*};
@@ -162,19 +165,11 @@ impl RainbowOption {
 
 impl Option for RainbowOption {
     fn instrument(&self) -> &Instrument {
-        match self.rainbow_option_type() {
-            BestOf | CallOnMax | PutOnMax => self.instrument.best_performer(),
-            WorstOf | CallOnMin | PutOnMin => self.instrument.worst_performer(),
-            _ => &self.instrument,
-        }
+        &self.instrument
     }
 
     fn instrument_mut(&mut self) -> &mut Instrument {
-        match self.rainbow_option_type() {
-            BestOf | CallOnMax | PutOnMax => self.instrument.best_performer_mut(),
-            WorstOf | CallOnMin | PutOnMin => self.instrument.worst_performer_mut(),
-            _ => &mut self.instrument,
-        }
+        &mut self.instrument
     }
 
     fn set_instrument(&mut self, instrument: Instrument) {
@@ -229,8 +224,25 @@ impl Option for RainbowOption {
         )
     }
 
+    /// Calculate the payoff of the option at maturity.
+    ///
+    /// A given spot price is interpreted as the relevant price of the assets (i.e., the maximum, minimum or average).
+    /// Without a spot price, the payoff is calculated from the current prices of all underlying assets.
     fn payoff(&self, spot: std::option::Option<f64>) -> f64 {
-        let spot_price: f64 = spot.unwrap_or_else(|| self.instrument().spot());
+        let Some(spot_price) = spot else {
+            let (spots, weights): (Vec<f64>, Vec<f64>) = if self.instrument.assets.is_empty() {
+                (vec![self.instrument.spot()], vec![1.0])
+            } else {
+                self.instrument
+                    .assets
+                    .iter()
+                    .map(|(asset, weight)| (asset.spot(), *weight))
+                    .unzip()
+            };
+            return self
+                .rainbow_option_type()
+                .payoff(self.strike, &spots, &weights);
+        };
 
         match self.rainbow_option_type() {
             BestOf => spot_price.max(self.strike),
@@ -242,14 +254,14 @@ impl Option for RainbowOption {
             CallOnAvg => (spot_price - self.strike).max(0.0),
             PutOnAvg => (self.strike - spot_price).max(0.0),
             AllITM => {
-                if self.instrument().worst_performer().spot() > self.strike {
+                if self.instrument.worst_performer().spot() > self.strike {
                     spot_price
                 } else {
                     0.0
                 }
             }
             AllOTM => {
-                if self.instrument().best_performer().spot() < self.strike {
+                if self.instrument.best_performer().spot() < self.strike {
                     spot_price
                 } else {
                     0.0
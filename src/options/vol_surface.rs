@@ -0,0 +1,664 @@
+//! Module for implied volatility surfaces.
+//!
+//! A `VolSurface` is fitted to market quotes of European options, one smile per expiry. Each smile gives the total
+//! implied variance `w(k) = σ(k)² T` as a function of the log-moneyness `k = ln(K / F)` relative to the forward of the
+//! expiry, parametrised by the raw SVI parametrisation of Gatheral
+//!
+//! ```text
+//! w(k) = a + b (ρ (k - m) + √((k - m)² + σ²))
+//! ```
+//!
+//! or by a slice of the SSVI parametrisation of Gatheral and Jacquier with the ATM total variance `θ`, the
+//! correlation `ρ` and the curvature `φ`
+//!
+//! ```text
+//! w(k) = θ / 2 (1 + ρ φ k + √((φ k + ρ)² + 1 - ρ²))
+//! ```
+//!
+//! Between two expiries the total variance is interpolated linearly in time at constant log-moneyness, before the
+//! first expiry it is proportional to time and after the last expiry the implied volatilities are extrapolated flat.
+//! The forwards are interpolated log-linearly in time from the spot.
+//!
+//! The Dupire local volatility of the surface follows from the derivatives of the total implied variance in time and
+//! log-moneyness.
+//!
+//! The fitted surface can be checked for static arbitrage: butterfly arbitrage (a negative risk-neutral density,
+//! i.e. a negative Durrleman condition `g(k)`) within each smile and calendar arbitrage (a total variance decreasing
+//! with the expiry) between consecutive smiles.
+//!
+//! ## References
+//!
+//! - Gatheral, J. A parsimonious arbitrage-free implied volatility parameterization, Global Derivatives, 2004
+//! - Gatheral, J., Jacquier, A. Arbitrage-free SVI volatility surfaces, Quantitative Finance 14, 2014
+//! - Dupire, B. Pricing with a smile, Risk 7, 1994
+//! - Gatheral, J. The Volatility Surface: A Practitioner's Guide, Wiley, 2006
+//!
+//! ## Example
+//!
+//! ```
+//! use quantrs::options::{BlackScholesModel, EuropeanOption, Instrument, MarketQuote, OptionPricing, OptionType, SmileModel, VolSurface};
+//!
+//! let instrument = Instrument::new().with_spot(100.0);
+//! let quotes: Vec<MarketQuote> = [
+//!     (0.5, [0.3, 0.26, 0.23, 0.21, 0.2, 0.205, 0.215]),
+//!     (1.0, [0.28, 0.25, 0.225, 0.21, 0.2, 0.2, 0.205]),
+//! ]
+//!     .iter()
+//!     .flat_map(|(expiry, volatilities)| {
+//!         [70.0, 80.0, 90.0, 100.0, 110.0, 120.0, 130.0]
+//!             .iter()
+//!             .zip(volatilities)
+//!             .map(|(&strike, &volatility)| MarketQuote::implied_volatility(strike, *expiry, OptionType::Call, volatility))
+//!     })
+//!     .collect();
+//!
+//! let surface = VolSurface::fit(&instrument, 0.03, &quotes, SmileModel::Svi).unwrap();
+//! assert!(surface.arbitrage_violations().is_empty());
+//!
+//! let model = BlackScholesModel::new(0.03, 0.2).with_vol_surface(surface);
+//! let option = EuropeanOption::new(instrument, 95.0, 0.75, OptionType::Put);
+//! println!("Option price: {}", model.price(&option));
+//! ```
+
+use crate::options::{
+    BlackScholesModel, EuropeanOption, Instrument, MarketQuote, OptionPricing, PricingError,
+    QuoteValue, numerics,
+};
+
+/// Upper bound of the local variance, capping local volatilities at 200% in the wings of the surface.
+const MAX_LOCAL_VARIANCE: f64 = 4.0;
+
+/// Enum for the parametrisation of the smiles of a [`VolSurface`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum SmileModel {
+    /// Raw SVI parametrisation with the five parameters `a`, `b`, `ρ`, `m` and `σ`.
+    #[default]
+    Svi,
+    /// SSVI slice with the three parameters `θ`, `ρ` and `φ`.
+    Ssvi,
+}
+
+impl SmileModel {
+    /// Convert the fitted parameters of a smile to raw SVI parameters.
+    ///
+    /// SVI smiles are fitted as `[w_min, b, ρ, m, σ]`, where `w_min = a + b σ √(1 - ρ²)` is the minimum total
+    /// variance, and SSVI slices as `[θ, ρ, φ]`.
+    fn svi_parameters(self, p: &[f64]) -> SviParameters {
+        match self {
+            SmileModel::Svi => {
+                let a = p[0] - p[1] * p[4] * (1.0 - p[2] * p[2]).sqrt();
+                SviParameters::new(a, p[1], p[2], p[3], p[4])
+            }
+            SmileModel::Ssvi => SviParameters::from_ssvi(p[0], p[1], p[2]),
+        }
+    }
+}
+
+/// Raw SVI parameters of a smile.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SviParameters {
+    /// Level of the total variance.
+    pub a: f64,
+    /// Slope of the wings.
+    pub b: f64,
+    /// Correlation, rotating the smile.
+    pub rho: f64,
+    /// Log-moneyness of the vertex of the smile.
+    pub m: f64,
+    /// Curvature of the smile at its vertex.
+    pub sigma: f64,
+}
+
+impl SviParameters {
+    /// Create new raw `SviParameters`.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The level of the total variance.
+    /// * `b` - The slope of the wings.
+    /// * `rho` - The correlation.
+    /// * `m` - The log-moneyness of the vertex.
+    /// * `sigma` - The curvature at the vertex.
+    ///
+    /// # Returns
+    ///
+    /// New `SviParameters`.
+    pub fn new(a: f64, b: f64, rho: f64, m: f64, sigma: f64) -> Self {
+        Self {
+            a,
+            b,
+            rho,
+            m,
+            sigma,
+        }
+    }
+
+    /// Create the raw `SviParameters` of an SSVI slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `theta` - The ATM total variance.
+    /// * `rho` - The correlation.
+    /// * `phi` - The curvature.
+    ///
+    /// # Returns
+    ///
+    /// The equivalent raw `SviParameters`.
+    pub fn from_ssvi(theta: f64, rho: f64, phi: f64) -> Self {
+        Self {
+            a: 0.5 * theta * (1.0 - rho * rho),
+            b: 0.5 * theta * phi,
+            rho,
+            m: -rho / phi,
+            sigma: (1.0 - rho * rho).sqrt() / phi,
+        }
+    }
+
+    /// Calculate the total implied variance at a log-moneyness.
+    ///
+    /// # Arguments
+    ///
+    /// * `log_moneyness` - The log-moneyness `ln(K / F)`.
+    ///
+    /// # Returns
+    ///
+    /// The total implied variance `σ(k)² T`.
+    pub fn total_variance(&self, log_moneyness: f64) -> f64 {
+        let x = log_moneyness - self.m;
+        self.a + self.b * (self.rho * x + x.hypot(self.sigma))
+    }
+
+    /// Calculate the Durrleman condition at a log-moneyness.
+    ///
+    /// # Arguments
+    ///
+    /// * `log_moneyness` - The log-moneyness `ln(K / F)`.
+    ///
+    /// # Returns
+    ///
+    /// The value of `g(k) = (1 - k w' / (2w))² - w'² / 4 (1 / w + 1 / 4) + w'' / 2`, which is proportional to the
+    /// risk-neutral density and is negative where the smile admits butterfly arbitrage.
+    pub fn durrleman_condition(&self, log_moneyness: f64) -> f64 {
+        durrleman_condition(log_moneyness, self.derivatives(log_moneyness))
+    }
+
+    /// Calculate the total implied variance and its first two derivatives with respect to the log-moneyness.
+    fn derivatives(&self, log_moneyness: f64) -> [f64; 3] {
+        let x = log_moneyness - self.m;
+        let root = x.hypot(self.sigma);
+        [
+            self.total_variance(log_moneyness),
+            self.b * (self.rho + x / root),
+            self.b * self.sigma.powi(2) / root.powi(3),
+        ]
+    }
+}
+
+/// The fitted smile of one expiry of a [`VolSurface`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmileSlice {
+    /// Time to maturity (in years) of the expiry.
+    pub time_to_maturity: f64,
+    /// Forward price of the underlying to the expiry.
+    pub forward: f64,
+    /// Raw SVI parameters of the smile.
+    pub parameters: SviParameters,
+    /// Root mean squared implied volatility error of the fit.
+    pub rmse: f64,
+}
+
+/// Enum for the static arbitrage found in a [`VolSurface`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArbitrageViolation {
+    /// The smile of an expiry implies a negative risk-neutral density.
+    Butterfly {
+        /// Time to maturity of the smile.
+        time_to_maturity: f64,
+        /// First log-moneyness at which the Durrleman condition is negative.
+        log_moneyness: f64,
+    },
+    /// The total variance of an expiry is below the total variance of the previous expiry.
+    Calendar {
+        /// Time to maturity of the later smile.
+        time_to_maturity: f64,
+        /// First log-moneyness at which the total variance decreases.
+        log_moneyness: f64,
+    },
+}
+
+/// An implied volatility surface made of SVI smiles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolSurface {
+    /// Spot price of the underlying.
+    pub spot: f64,
+    /// Smiles ordered by expiry.
+    pub slices: Vec<SmileSlice>,
+    /// Parallel shift added to every implied volatility of the surface.
+    pub volatility_shift: f64,
+}
+
+impl VolSurface {
+    /// Create a new `VolSurface` from fitted smiles.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot` - The spot price of the underlying.
+    /// * `slices` - The smiles of the expiries.
+    ///
+    /// # Returns
+    ///
+    /// A new `VolSurface` with the smiles sorted by expiry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the smiles are invalid (see [`VolSurface::try_new`]).
+    pub fn new(spot: f64, slices: Vec<SmileSlice>) -> Self {
+        Self::try_new(spot, slices).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Create a new `VolSurface` from fitted smiles without panicking.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot` - The spot price of the underlying.
+    /// * `slices` - The smiles of the expiries.
+    ///
+    /// # Returns
+    ///
+    /// A new `VolSurface` with the smiles sorted by expiry or an error if there are no smiles, if the spot, an expiry
+    /// or a forward is not positive or if two smiles have the same expiry.
+    pub fn try_new(spot: f64, mut slices: Vec<SmileSlice>) -> Result<Self, PricingError> {
+        if slices.is_empty() {
+            return Err(PricingError::invalid_input(
+                "Volatility surface needs a smile",
+            ));
+        }
+        if !(spot > 0.0
+            && slices
+                .iter()
+                .all(|slice| slice.time_to_maturity > 0.0 && slice.forward > 0.0))
+        {
+            return Err(PricingError::invalid_input(
+                "Spot, expiries and forwards must be positive",
+            ));
+        }
+        slices.sort_by(|a, b| a.time_to_maturity.total_cmp(&b.time_to_maturity));
+        if slices
+            .windows(2)
+            .any(|pair| pair[0].time_to_maturity >= pair[1].time_to_maturity)
+        {
+            return Err(PricingError::invalid_input(
+                "Smiles must have distinct expiries",
+            ));
+        }
+        Ok(Self {
+            spot,
+            slices,
+            volatility_shift: 0.0,
+        })
+    }
+
+    /// Fit a `VolSurface` to market quotes of European options.
+    ///
+    /// The quotes are grouped by expiry and the smile of every expiry is fitted to the implied volatilities of its
+    /// quotes (quoted prices are converted with the Black-Scholes model) by the bounded Levenberg-Marquardt method.
+    /// The forwards follow from the spot, the dividends of the instrument and the risk-free rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `instrument` - The underlying instrument of the quoted options.
+    /// * `risk_free_rate` - The risk-free interest rate (e.g., 0.05 for 5%).
+    /// * `quotes` - The market quotes to fit.
+    /// * `smile_model` - The parametrisation of the smiles.
+    ///
+    /// # Returns
+    ///
+    /// The fitted surface or an error if an expiry has fewer quotes than parameters or a quote is invalid.
+    pub fn fit(
+        instrument: &Instrument,
+        risk_free_rate: f64,
+        quotes: &[MarketQuote],
+        smile_model: SmileModel,
+    ) -> Result<Self, PricingError> {
+        if quotes.is_empty() {
+            return Err(PricingError::invalid_input(
+                "Volatility surface needs market quotes",
+            ));
+        }
+        let mut quotes = quotes.to_vec();
+        quotes.sort_by(|a, b| a.time_to_maturity.total_cmp(&b.time_to_maturity));
+
+        let model = BlackScholesModel::new(risk_free_rate, 0.2);
+        let mut slices = Vec::new();
+        for expiry in quotes.chunk_by(|a, b| a.time_to_maturity == b.time_to_maturity) {
+            let ttm = expiry[0].time_to_maturity;
+            let forward = instrument.escrowed_spot(risk_free_rate, ttm)
+                * ((risk_free_rate - instrument.continuous_dividend_yield) * ttm).exp();
+            if !(ttm > 0.0 && forward > 0.0) {
+                return Err(PricingError::invalid_input(
+                    "Quotes must have positive expiries and forwards",
+                ));
+            }
+            let smile = expiry
+                .iter()
+                .map(|quote| {
+                    let volatility = match quote.value {
+                        QuoteValue::ImpliedVolatility(volatility) => volatility,
+                        QuoteValue::Price(price) => model.try_implied_volatility(
+                            &EuropeanOption::new(
+                                instrument.clone(),
+                                quote.strike,
+                                ttm,
+                                quote.option_type,
+                            ),
+                            price,
+                        )?,
+                    };
+                    if !(quote.strike > 0.0 && volatility > 0.0 && volatility.is_finite()) {
+                        return Err(PricingError::invalid_input(
+                            "Quotes must have positive strikes and implied volatilities",
+                        ));
+                    }
+                    Ok(((quote.strike / forward).ln(), volatility))
+                })
+                .collect::<Result<Vec<(f64, f64)>, PricingError>>()?;
+
+            let (parameters, rmse) = fit_smile(&smile, ttm, smile_model)?;
+            slices.push(SmileSlice {
+                time_to_maturity: ttm,
+                forward,
+                parameters,
+                rmse,
+            });
+        }
+
+        Self::try_new(instrument.spot(), slices)
+    }
+
+    /// Calculate the forward price to a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `time_to_maturity` - The time in years.
+    ///
+    /// # Returns
+    ///
+    /// The forward price, interpolated log-linearly between the spot and the forwards of the smiles.
+    pub fn forward(&self, time_to_maturity: f64) -> f64 {
+        // Pillars (0, S), (T₁, F₁), ..., extrapolated along the last segment beyond the last expiry
+        let pillar = |i: usize| match i {
+            0 => (0.0, self.spot),
+            _ => (
+                self.slices[i - 1].time_to_maturity,
+                self.slices[i - 1].forward,
+            ),
+        };
+        let index = self
+            .slices
+            .partition_point(|slice| slice.time_to_maturity < time_to_maturity);
+        let end = (index + 1).min(self.slices.len());
+        let ((t0, f0), (t1, f1)) = (pillar(end - 1), pillar(end));
+        let weight = (time_to_maturity - t0) / (t1 - t0);
+        (f0.ln() + weight * (f1.ln() - f0.ln())).exp()
+    }
+
+    /// Calculate the total implied variance at an expiry and a strike.
+    ///
+    /// # Arguments
+    ///
+    /// * `time_to_maturity` - The time to maturity in years.
+    /// * `strike` - The strike price.
+    ///
+    /// # Returns
+    ///
+    /// The total implied variance `σ² T` of the surface (without the volatility shift).
+    pub fn total_variance(&self, time_to_maturity: f64, strike: f64) -> f64 {
+        if time_to_maturity <= 0.0 {
+            return 0.0;
+        }
+        let k = (strike / self.forward(time_to_maturity)).ln();
+        self.variance_derivatives(time_to_maturity, k)[0].max(0.0)
+    }
+
+    /// Calculate the Dupire local volatility at a time and a spot price.
+    ///
+    /// The local variance follows from the total implied variance `w(T, k)` at the log-moneyness `k = ln(S / F(T))`:
+    ///
+    /// ```text
+    /// σ_loc² = (∂w/∂T) / g(k),   g(k) = (1 - k w' / (2w))² - w'² / 4 (1 / w + 1 / 4) + w'' / 2
+    /// ```
+    ///
+    /// Where the surface admits butterfly arbitrage (`g(k) <= 0`) the implied variance is used instead, and local
+    /// volatilities are capped at 200% in the wings.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The time in years.
+    /// * `spot` - The spot price of the underlying at that time.
+    ///
+    /// # Returns
+    ///
+    /// The local volatility, shifted by the volatility shift of the surface.
+    pub fn local_volatility(&self, time: f64, spot: f64) -> f64 {
+        let time = time.max(1e-6 * self.slices[0].time_to_maturity);
+        let k = (spot / self.forward(time)).ln();
+        let [w, dw_dt, dw_dk, d2w_dk2] = self.variance_derivatives(time, k);
+        let g = durrleman_condition(k, [w, dw_dk, d2w_dk2]);
+        let variance = if w > 0.0 && g > 1e-8 {
+            dw_dt.max(0.0) / g
+        } else {
+            w.max(0.0) / time
+        };
+        (variance.min(MAX_LOCAL_VARIANCE).sqrt() + self.volatility_shift).max(0.0)
+    }
+
+    /// Calculate the total implied variance and its derivatives at a time and a log-moneyness.
+    ///
+    /// # Returns
+    ///
+    /// The total variance `w` and its derivatives `∂w/∂T`, `∂w/∂k` and `∂²w/∂k²`.
+    fn variance_derivatives(&self, time: f64, log_moneyness: f64) -> [f64; 4] {
+        let index = self
+            .slices
+            .partition_point(|slice| slice.time_to_maturity < time);
+        let derivatives = |slice: &SmileSlice| slice.parameters.derivatives(log_moneyness);
+        // Linear in time between two smiles and proportional to time outside the smiles
+        let (before, after, weight, rate) = match (
+            index.checked_sub(1).map(|i| &self.slices[i]),
+            self.slices.get(index),
+        ) {
+            (Some(before), Some(after)) => {
+                let (wb, wa) = (derivatives(before), derivatives(after));
+                let dt = after.time_to_maturity - before.time_to_maturity;
+                let weight = (time - before.time_to_maturity) / dt;
+                (wb, wa, weight, (wa[0] - wb[0]) / dt)
+            }
+            (Some(slice), None) | (None, Some(slice)) => {
+                let w = derivatives(slice);
+                let scale = time / slice.time_to_maturity;
+                ([0.0; 3], w, scale, w[0] / slice.time_to_maturity)
+            }
+            (None, None) => ([0.0; 3], [0.0; 3], 0.0, 0.0),
+        };
+        let interpolate = |i: usize| before[i] + weight * (after[i] - before[i]);
+        [interpolate(0), rate, interpolate(1), interpolate(2)]
+    }
+
+    /// Calculate the implied volatility at an expiry and a strike.
+    ///
+    /// # Arguments
+    ///
+    /// * `time_to_maturity` - The time to maturity in years.
+    /// * `strike` - The strike price.
+    ///
+    /// # Returns
+    ///
+    /// The shifted implied volatility, or the shifted implied volatility of the first smile if the time is not
+    /// positive.
+    pub fn volatility(&self, time_to_maturity: f64, strike: f64) -> f64 {
+        let volatility = if time_to_maturity > 0.0 {
+            (self.total_variance(time_to_maturity, strike) / time_to_maturity).sqrt()
+        } else {
+            let first = &self.slices[0];
+            (self.total_variance(first.time_to_maturity, strike) / first.time_to_maturity).sqrt()
+        };
+        volatility + self.volatility_shift
+    }
+
+    /// Shift the implied volatilities of the surface in parallel.
+    ///
+    /// # Arguments
+    ///
+    /// * `shift` - The shift added to every implied volatility.
+    ///
+    /// # Returns
+    ///
+    /// The shifted surface.
+    pub fn shifted(&self, shift: f64) -> Self {
+        Self {
+            volatility_shift: self.volatility_shift + shift,
+            ..self.clone()
+        }
+    }
+
+    /// Check the smiles of the surface for static arbitrage.
+    ///
+    /// The Durrleman condition of every smile and the total variances of consecutive smiles are compared on a grid
+    /// of log-moneyness covering four ATM standard deviations of the later smile on either side of the forward.
+    ///
+    /// # Returns
+    ///
+    /// The butterfly and calendar arbitrage found, at most one of each kind per smile.
+    pub fn arbitrage_violations(&self) -> Vec<ArbitrageViolation> {
+        let grid = |slice: &SmileSlice| {
+            let width = 4.0 * slice.parameters.total_variance(0.0).max(1e-4).sqrt();
+            (0..=100).map(move |i| width * (i as f64 / 50.0 - 1.0))
+        };
+
+        let mut violations = Vec::new();
+        for (index, slice) in self.slices.iter().enumerate() {
+            if let Some(k) = grid(slice).find(|&k| slice.parameters.durrleman_condition(k) < 0.0) {
+                violations.push(ArbitrageViolation::Butterfly {
+                    time_to_maturity: slice.time_to_maturity,
+                    log_moneyness: k,
+                });
+            }
+            let Some(previous) = index.checked_sub(1).map(|i| &self.slices[i]) else {
+                continue;
+            };
+            // Compare the variances at the same strike relative to the forwards of both expiries
+            let shift = (slice.forward / previous.forward).ln();
+            if let Some(k) = grid(slice).find(|&k| {
+                slice.parameters.total_variance(k)
+                    < previous.parameters.total_variance(k + shift) - 1e-12
+            }) {
+                violations.push(ArbitrageViolation::Calendar {
+                    time_to_maturity: slice.time_to_maturity,
+                    log_moneyness: k,
+                });
+            }
+        }
+        violations
+    }
+}
+
+/// Fit a smile to implied volatilities.
+///
+/// Bounding the minimum total variance of SVI smiles keeps the total variance non-negative. Both parametrisations are
+/// started from a few correlations and the best fit is kept.
+///
+/// # Arguments
+///
+/// * `smile` - The log-moneyness and implied volatility of every quote.
+/// * `time_to_maturity` - The time to maturity of the smile.
+/// * `smile_model` - The parametrisation of the smile.
+///
+/// # Returns
+///
+/// The fitted raw SVI parameters and the root mean squared implied volatility error, or an error if there are fewer
+/// quotes than parameters.
+fn fit_smile(
+    smile: &[(f64, f64)],
+    time_to_maturity: f64,
+    smile_model: SmileModel,
+) -> Result<(SviParameters, f64), PricingError> {
+    let variances: Vec<f64> = smile
+        .iter()
+        .map(|(_, volatility)| volatility * volatility * time_to_maturity)
+        .collect();
+    let max_variance = variances.iter().copied().fold(0.0, f64::max);
+    let (atm_k, atm_variance) = smile
+        .iter()
+        .zip(&variances)
+        .map(|((k, _), w)| (*k, *w))
+        .min_by(|a, b| a.0.abs().total_cmp(&b.0.abs()))
+        .unwrap_or((0.0, max_variance));
+    let (min_k, max_k) = smile
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), (k, _)| {
+            (lo.min(*k), hi.max(*k))
+        });
+
+    let (lower, upper, starts): (Vec<f64>, Vec<f64>, Vec<Vec<f64>>) = match smile_model {
+        SmileModel::Svi => (
+            vec![0.0, 1e-6, -0.999, 2.0 * min_k - 1.0, 1e-4],
+            vec![
+                max_variance,
+                4.0 / time_to_maturity,
+                0.999,
+                2.0 * max_k + 1.0,
+                5.0,
+            ],
+            [-0.5, 0.0, 0.5]
+                .iter()
+                .map(|&rho| vec![0.5 * atm_variance, 0.1, rho, atm_k, 0.1])
+                .collect(),
+        ),
+        SmileModel::Ssvi => (
+            vec![1e-8, -0.999, 1e-4],
+            vec![4.0 * max_variance.max(1e-8), 0.999, 1e4],
+            [-0.5, 0.0, 0.5]
+                .iter()
+                .map(|&rho| {
+                    vec![
+                        atm_variance.max(1e-8),
+                        rho,
+                        1.0 / atm_variance.max(1e-8).sqrt(),
+                    ]
+                })
+                .collect(),
+        ),
+    };
+    if smile.len() < lower.len() {
+        return Err(PricingError::invalid_input(match smile_model {
+            SmileModel::Svi => "SVI smiles need at least 5 quotes per expiry",
+            SmileModel::Ssvi => "SSVI smiles need at least 3 quotes per expiry",
+        }));
+    }
+
+    let residuals = |p: &[f64]| -> Vec<f64> {
+        let svi = smile_model.svi_parameters(p);
+        smile
+            .iter()
+            .map(|(k, volatility)| {
+                (svi.total_variance(*k).max(0.0) / time_to_maturity).sqrt() - volatility
+            })
+            .collect()
+    };
+    let best = starts
+        .iter()
+        .map(|start| numerics::levenberg_marquardt(residuals, start, &lower, &upper, 500, 1e-12))
+        .min_by(|a, b| {
+            let cost = |r: &[f64]| r.iter().map(|v| v * v).sum::<f64>();
+            cost(&a.residuals).total_cmp(&cost(&b.residuals))
+        })
+        .expect("The smile fit has starting points");
+
+    let rmse = (best.residuals.iter().map(|r| r * r).sum::<f64>() / smile.len() as f64).sqrt();
+    Ok((smile_model.svi_parameters(&best.params), rmse))
+}
+
+/// Calculate the Durrleman condition from the total implied variance and its derivatives at a log-moneyness.
+fn durrleman_condition(log_moneyness: f64, [w, dw, d2w]: [f64; 3]) -> f64 {
+    (1.0 - log_moneyness * dw / (2.0 * w)).powi(2) - 0.25 * dw * dw * (1.0 / w + 0.25) + 0.5 * d2w
+}
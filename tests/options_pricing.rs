@@ -1,8 +1,13 @@
 use approx::assert_abs_diff_eq;
 use quantrs::options::{
-    AmericanOption, AsianOption, BermudanOption, BinaryOption, BinomialTreeModel, Black76Model,
-    BlackScholesModel, EuropeanOption, Greeks, Instrument, LookbackOption, MonteCarloModel, Option,
-    OptionGreeks, OptionPricing, OptionType, RainbowOption,
+    AmericanOption, AsianApproximation, AsianOption, AveragingSchedule, BachelierModel,
+    BarrierOption, BasketOption, BermudanOption, BinaryOption, BinomialTreeModel, Black76Model,
+    BlackScholesModel, CalibrationTarget, CashDividend, EuropeanOption, FiniteDiffModel, Greeks,
+    HestonCalibrator, HestonModel, Instrument, KouJumpModel, LatticeScheme, LocalVolModel,
+    LookbackOption, MarketQuote, MertonJumpModel, Monitoring, MonteCarloModel, MonteCarloResult,
+    NumericalGreeks, Option, OptionGreeks, OptionPricing, OptionType, PricingError, RainbowOption,
+    RainbowType, RegressionBasis, SabrCalibrator, SabrExpansion, SabrModel, Sampling, SmileModel,
+    SmileSlice, SviParameters, VolSurface, VolatilityTermStructure, YieldCurve,
 };
 
 struct MockModel {}
@@ -208,6 +213,218 @@ mod black_scholes_tests {
             let theta = model.theta(&option);
             assert_abs_diff_eq!(theta, 3.2350, epsilon = 0.0001);
         }
+
+        #[test]
+        fn test_higher_order_greeks() {
+            let option = EuropeanOption::new(
+                Instrument::new()
+                    .with_spot(110.0)
+                    .with_continuous_dividend_yield(0.03),
+                100.0,
+                0.43,
+                OptionType::Put,
+            );
+            let model = BlackScholesModel::new(0.05, 0.2);
+
+            assert_abs_diff_eq!(model.lambda(&option), -12.9015, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.epsilon(&option), 9.1276, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.vanna(&option), -0.9905, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.charm(&option), -0.1830, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.vomma(&option), 61.2908, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.veta(&option), 33.9541, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.vera(&option), -55.3037, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.speed(&option), -0.0012954, epsilon = 0.000001);
+            assert_abs_diff_eq!(model.zomma(&option), -0.0356, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.color(&option), -0.0113, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.ultima(&option), -736.7572, epsilon = 0.0001);
+
+            // By put-call parity, vanna, vomma and color are equal for calls and puts
+            let call = option.flip();
+            assert_abs_diff_eq!(model.vanna(&call), model.vanna(&option), epsilon = 1e-10);
+            assert_abs_diff_eq!(model.vomma(&call), model.vomma(&option), epsilon = 1e-10);
+            assert_abs_diff_eq!(model.color(&call), model.color(&option), epsilon = 1e-10);
+        }
+    }
+
+    mod cash_dividend_tests {
+        use super::*;
+
+        #[test]
+        fn test_escrowed_spot() {
+            let instrument = Instrument::new().with_spot(100.0).with_cash_dividends(vec![
+                CashDividend::new(0.5, 3.0),
+                CashDividend::new(0.25, 2.0),
+                CashDividend::new(1.5, 5.0),
+            ]);
+            let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+            let model = BlackScholesModel::new(0.05, 0.2);
+
+            assert_abs_diff_eq!(model.price(&option), 7.5641, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.price(&option.flip()), 7.5881, epsilon = 0.0001);
+
+            // The dividends going ex before expiry are deducted from the spot
+            let escrowed = EuropeanOption::new(
+                Instrument::new()
+                    .with_spot(100.0 - 2.0 * (-0.0125f64).exp() - 3.0 * (-0.025f64).exp()),
+                100.0,
+                1.0,
+                OptionType::Call,
+            );
+            assert_abs_diff_eq!(
+                model.price(&option),
+                model.price(&escrowed),
+                epsilon = 1e-10
+            );
+            assert_abs_diff_eq!(
+                model.price(&option.flip()),
+                model.price(&escrowed.flip()),
+                epsilon = 1e-10
+            );
+        }
+
+        #[test]
+        fn test_invalid() {
+            let model = BlackScholesModel::new(0.05, 0.2);
+            let option = |dividend| {
+                EuropeanOption::new(
+                    Instrument::new()
+                        .with_spot(100.0)
+                        .with_cash_dividends(vec![dividend]),
+                    100.0,
+                    1.0,
+                    OptionType::Call,
+                )
+            };
+
+            for dividend in [
+                CashDividend::new(0.5, -1.0),
+                CashDividend::new(f64::NAN, 1.0),
+                CashDividend::new(0.5, 120.0),
+            ] {
+                assert!(matches!(
+                    model.try_price(&option(dividend)),
+                    Err(PricingError::InvalidInput(_))
+                ));
+            }
+        }
+    }
+
+    mod term_structure_tests {
+        use super::*;
+
+        #[test]
+        fn test_forward_variance() {
+            let discount_curve = YieldCurve::new(vec![0.5, 1.0, 2.0], vec![0.03, 0.035, 0.04]);
+            let volatilities =
+                VolatilityTermStructure::new(vec![0.5, 1.0, 2.0], vec![0.25, 0.22, 0.2]);
+            let model = BlackScholesModel::new(0.0, 0.0)
+                .with_discount_curve(discount_curve)
+                .with_borrow_curve(YieldCurve::flat(0.01))
+                .with_volatility_term_structure(volatilities);
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.01);
+            let option = EuropeanOption::new(instrument, 100.0, 1.5, OptionType::Call);
+
+            assert_abs_diff_eq!(model.price(&option), 10.9653, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.price(&option.flip()), 8.4510, epsilon = 0.0001);
+
+            // Equivalent to the flat model with the zero rate and the implied volatility to expiry
+            let volatility = ((0.0484 + 0.5 * 0.0316) / 1.5f64).sqrt();
+            let flat = BlackScholesModel::new(0.0375, volatility);
+            let equivalent = EuropeanOption::new(
+                Instrument::new()
+                    .with_spot(100.0)
+                    .with_continuous_dividend_yield(0.02),
+                100.0,
+                1.5,
+                OptionType::Call,
+            );
+            assert_abs_diff_eq!(
+                model.price(&option),
+                flat.price(&equivalent),
+                epsilon = 1e-10
+            );
+            assert_abs_diff_eq!(
+                model.delta(&option),
+                flat.delta(&equivalent),
+                epsilon = 1e-10
+            );
+            assert_abs_diff_eq!(
+                model.implied_volatility(&option, model.price(&option)),
+                volatility,
+                epsilon = 1e-6
+            );
+        }
+
+        #[test]
+        fn test_flat_curves() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let option = EuropeanOption::new(instrument.clone(), 95.0, 0.75, OptionType::Put);
+            let flat = BlackScholesModel::new(0.05, 0.2);
+            let model = BlackScholesModel::new(0.05, 0.2)
+                .with_discount_curve(YieldCurve::flat(0.05))
+                .with_volatility_term_structure(VolatilityTermStructure::flat(0.2));
+
+            assert_abs_diff_eq!(model.price(&option), flat.price(&option), epsilon = 1e-12);
+            assert_abs_diff_eq!(model.vega(&option), flat.vega(&option), epsilon = 1e-10);
+            assert_abs_diff_eq!(model.rho(&option), flat.rho(&option), epsilon = 1e-10);
+
+            // Path-dependent styles are not supported with term structures
+            let barrier =
+                BarrierOption::down_and_out(instrument, 100.0, 0.75, OptionType::Call, 90.0);
+            assert!(matches!(
+                model.try_price(&barrier),
+                Err(PricingError::NotImplemented(_))
+            ));
+        }
+
+        #[test]
+        fn test_vol_surface() {
+            let surface = VolSurface::new(
+                100.0,
+                vec![
+                    SmileSlice {
+                        time_to_maturity: 0.5,
+                        forward: 101.0,
+                        parameters: SviParameters::new(0.01, 0.1, -0.4, 0.05, 0.2),
+                        rmse: 0.0,
+                    },
+                    SmileSlice {
+                        time_to_maturity: 1.0,
+                        forward: 102.0,
+                        parameters: SviParameters::new(0.02, 0.12, -0.35, 0.05, 0.25),
+                        rmse: 0.0,
+                    },
+                ],
+            );
+            let model = BlackScholesModel::new(0.03, 0.5).with_vol_surface(surface.clone());
+            let instrument = Instrument::new().with_spot(100.0);
+
+            // Every option is priced with the implied volatility at its expiry and strike
+            for (strike, ttm) in [(90.0, 0.5), (100.0, 0.75), (115.0, 1.5)] {
+                let option = EuropeanOption::new(instrument.clone(), strike, ttm, OptionType::Put);
+                let flat = BlackScholesModel::new(0.03, surface.volatility(ttm, strike));
+                assert_abs_diff_eq!(model.price(&option), flat.price(&option), epsilon = 1e-12);
+                assert_abs_diff_eq!(model.vega(&option), flat.vega(&option), epsilon = 1e-10);
+
+                let binary = BinaryOption::cash_or_nothing(
+                    instrument.clone(),
+                    strike,
+                    ttm,
+                    OptionType::Call,
+                );
+                assert_abs_diff_eq!(model.price(&binary), flat.price(&binary), epsilon = 1e-12);
+            }
+
+            // Vega is the sensitivity to a parallel shift of the surface
+            let option = EuropeanOption::new(instrument, 90.0, 0.5, OptionType::Put);
+            assert_abs_diff_eq!(
+                model.vega(&option),
+                NumericalGreeks::new(model.clone()).vega(&option),
+                epsilon = 0.01
+            );
+        }
     }
 
     mod binary_option_tests {
@@ -359,6 +576,48 @@ mod black_scholes_tests {
                 let theta = model.theta(&option);
                 assert_abs_diff_eq!(theta, -0.1930, epsilon = 0.0001);
             }
+
+            #[test]
+            fn test_higher_order_greeks() {
+                let option = BinaryOption::cash_or_nothing(
+                    Instrument::new().with_spot(100.0),
+                    100.0,
+                    1.0,
+                    OptionType::Call,
+                );
+                let model = BlackScholesModel::new(0.05, 0.2);
+
+                assert_abs_diff_eq!(model.lambda(&option), 3.5245, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.epsilon(&option), -1.8762, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.vanna(&option), -0.0889, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.charm(&option), -0.0105, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.vomma(&option), 4.5181, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.veta(&option), -0.2881, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.vera(&option), -8.2318, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.speed(&option), -0.0000379, epsilon = 0.0000001);
+                assert_abs_diff_eq!(model.zomma(&option), 0.0039007, epsilon = 0.0000001);
+                assert_abs_diff_eq!(model.color(&option), 0.0001843, epsilon = 0.0000001);
+                assert_abs_diff_eq!(model.ultima(&option), -64.6980, epsilon = 0.0001);
+
+                let option = BinaryOption::cash_or_nothing(
+                    Instrument::new().with_spot(110.0),
+                    100.0,
+                    0.43,
+                    OptionType::Put,
+                );
+
+                assert_abs_diff_eq!(model.lambda(&option), -10.5751, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.epsilon(&option), 0.9109, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.vanna(&option), 0.0203, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.charm(&option), 0.0117, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.vomma(&option), -7.1318, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.veta(&option), -0.0687, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.vera(&option), 0.3898, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.speed(&option), -0.0000037, epsilon = 0.0000001);
+                assert_abs_diff_eq!(model.zomma(&option), -0.0132357, epsilon = 0.0000001);
+                assert_abs_diff_eq!(model.color(&option), -0.0030345, epsilon = 0.0000001);
+                assert_abs_diff_eq!(model.ultima(&option), 29.4652, epsilon = 0.0001);
+            }
         }
 
         mod asset_or_nothing_tests {
@@ -508,6 +767,90 @@ mod black_scholes_tests {
                 let theta = model.theta(&option);
                 assert_abs_diff_eq!(theta, -16.0619, epsilon = 0.0001);
             }
+
+            #[test]
+            fn test_higher_order_greeks() {
+                let option = BinaryOption::asset_or_nothing(
+                    Instrument::new()
+                        .with_spot(105.0)
+                        .with_continuous_dividend_yield(0.06),
+                    100.0,
+                    2.1,
+                    OptionType::Call,
+                );
+                let model = BlackScholesModel::new(0.05, 0.2);
+
+                assert_abs_diff_eq!(model.lambda(&option), 3.2468, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.epsilon(&option), -375.6300, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.vanna(&option), -5.8801, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.charm(&option), -0.3842, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.vomma(&option), 171.4730, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.veta(&option), 15.9936, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.vera(&option), -1315.0252, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.speed(&option), -0.001306, epsilon = 0.000001);
+                assert_abs_diff_eq!(model.zomma(&option), 0.027535, epsilon = 0.000001);
+                assert_abs_diff_eq!(model.color(&option), 0.0025496, epsilon = 0.0000001);
+                assert_abs_diff_eq!(model.ultima(&option), -2610.8384, epsilon = 0.0001);
+
+                let option = BinaryOption::asset_or_nothing(
+                    Instrument::new().with_spot(110.0),
+                    100.0,
+                    0.43,
+                    OptionType::Put,
+                );
+
+                assert_abs_diff_eq!(model.lambda(&option), -10.3627, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.epsilon(&option), 83.0689, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.vanna(&option), 3.0736, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.charm(&option), 1.3205, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.vomma(&option), -785.0442, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.veta(&option), -38.1285, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.vera(&option), 96.0985, epsilon = 0.0001);
+                assert_abs_diff_eq!(model.speed(&option), 0.0009512, epsilon = 0.0000001);
+                assert_abs_diff_eq!(model.zomma(&option), -1.3050996, epsilon = 0.0000001);
+                assert_abs_diff_eq!(model.color(&option), -0.2927733, epsilon = 0.0000001);
+                assert_abs_diff_eq!(model.ultima(&option), 3748.8298, epsilon = 0.0001);
+            }
+        }
+
+        #[test]
+        fn test_iv() {
+            let guess = BlackScholesModel::new(0.05, 0.3);
+            for strike in [80.0, 100.0, 120.0] {
+                for option_type in [OptionType::Call, OptionType::Put] {
+                    let instrument = Instrument::new().with_spot(100.0);
+                    let options = [
+                        BinaryOption::cash_or_nothing(instrument.clone(), strike, 1.0, option_type),
+                        BinaryOption::asset_or_nothing(instrument, strike, 1.0, option_type),
+                    ];
+                    for option in options {
+                        let price = BlackScholesModel::new(0.05, 0.2).price(&option);
+                        let iv = guess.implied_volatility(&option, price);
+                        assert_abs_diff_eq!(iv, 0.2, epsilon = 1e-8);
+                    }
+                }
+            }
+
+            // The out-of-the-money cash-or-nothing call peaks at σ* = √(2 |ln(F / K)| / T) ≈ 0.5143,
+            // so the lower of the two volatilities matching the price is returned
+            let option = BinaryOption::cash_or_nothing(
+                Instrument::new().with_spot(100.0),
+                120.0,
+                1.0,
+                OptionType::Call,
+            );
+            let price = BlackScholesModel::new(0.05, 0.8).price(&option);
+            let iv = guess.implied_volatility(&option, price);
+            assert!(iv < 0.5143);
+            assert_abs_diff_eq!(
+                BlackScholesModel::new(0.05, iv).price(&option),
+                price,
+                epsilon = 1e-10
+            );
+            assert!(matches!(
+                guess.try_implied_volatility(&option, 0.5),
+                Err(PricingError::PriceOutOfBounds { .. })
+            ));
         }
     }
 
@@ -522,13 +865,8 @@ mod black_scholes_tests {
                 RainbowOption::best_of(Instrument::new().with_assets(vec![i1, i2]), 105.0, 1.0);
             let model = BlackScholesModel::new(0.05, 0.2);
 
-            assert!(
-                std::panic::catch_unwind(|| {
-                    _ = model.price(&option);
-                })
-                .is_err(),
-                "Expected panic for best_of option"
-            );
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 119.1473, epsilon = 0.0001);
         }
 
         #[test]
@@ -539,13 +877,8 @@ mod black_scholes_tests {
                 RainbowOption::worst_of(Instrument::new().with_assets(vec![i1, i2]), 105.0, 1.0);
             let model = BlackScholesModel::new(0.05, 0.2);
 
-            assert!(
-                std::panic::catch_unwind(|| {
-                    _ = model.price(&option);
-                })
-                .is_err(),
-                "Expected panic for worst_of option"
-            );
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 82.4763, epsilon = 0.0001);
         }
 
         #[test]
@@ -557,10 +890,10 @@ mod black_scholes_tests {
             let model = BlackScholesModel::new(0.05, 0.2);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 18.1497, epsilon = 0.0001);
+            assert_abs_diff_eq!(price, 19.2682, epsilon = 0.0001);
 
             let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 3.0288, epsilon = 0.0001);
+            assert_abs_diff_eq!(price, 1.9376, epsilon = 0.0001);
         }
 
         #[test]
@@ -572,10 +905,10 @@ mod black_scholes_tests {
             let model = BlackScholesModel::new(0.05, 0.2);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 8.7065, epsilon = 0.0001);
+            assert_abs_diff_eq!(price, 6.9656, epsilon = 0.0001);
 
             let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 9.5589, epsilon = 0.0001);
+            assert_abs_diff_eq!(price, 10.0277, epsilon = 0.0001);
         }
 
         #[test]
@@ -587,10 +920,10 @@ mod black_scholes_tests {
             let model = BlackScholesModel::new(0.05, 0.2);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 0.6993, epsilon = 0.0001);
+            assert_abs_diff_eq!(price, 0.2306, epsilon = 0.0001);
 
             let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 28.8468, epsilon = 0.0001);
+            assert_abs_diff_eq!(price, 30.5878, epsilon = 0.0001);
         }
 
         #[test]
@@ -602,10 +935,10 @@ mod black_scholes_tests {
             let model = BlackScholesModel::new(0.05, 0.2);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 16.3115, epsilon = 0.0001);
+            assert_abs_diff_eq!(price, 17.4028, epsilon = 0.0001);
 
             let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 2.4324, epsilon = 0.0001);
+            assert_abs_diff_eq!(price, 1.3140, epsilon = 0.0001);
         }
 
         #[test]
@@ -617,10 +950,10 @@ mod black_scholes_tests {
             let model = BlackScholesModel::new(0.05, 0.2);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 10.7713, epsilon = 0.0001);
+            assert_abs_diff_eq!(price, 8.6912, epsilon = 0.0001);
 
             let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 5.3942, epsilon = 0.0001);
+            assert_abs_diff_eq!(price, 3.3142, epsilon = 0.0001);
         }
 
         #[test]
@@ -632,36 +965,216 @@ mod black_scholes_tests {
             let model = BlackScholesModel::new(0.05, 0.2);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 10.4026, epsilon = 0.0001);
+            assert_abs_diff_eq!(price, 8.1673, epsilon = 0.0001);
 
             let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 6.2673, epsilon = 0.0001);
+            assert_abs_diff_eq!(price, 4.0321, epsilon = 0.0001);
         }
 
         #[test]
         fn test_all_itm() {
             let i1 = Instrument::new().with_spot(115.0);
             let i2 = Instrument::new().with_spot(104.0);
-            let i3 = Instrument::new().with_spot(86.0);
-            let option =
-                RainbowOption::all_itm(Instrument::new().with_assets(vec![i1, i2, i3]), 105.0, 1.0);
             let model = BlackScholesModel::new(0.05, 0.2);
 
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 0.0, epsilon = 0.0001);
+            // Out-of-the-money today, but positive value on the joint distribution
+            let option =
+                RainbowOption::all_itm(Instrument::new().with_assets(vec![i1, i2]), 105.0, 1.0);
+            assert_eq!(option.payoff(None), 0.0);
+            assert_abs_diff_eq!(model.price(&option), 47.9487, epsilon = 0.0001);
+
+            let option = RainbowOption::all_itm(correlated_assets(), 98.0, 0.5);
+            assert_abs_diff_eq!(model.price(&option), 49.0549, epsilon = 0.0001);
         }
 
         #[test]
         fn test_all_otm() {
             let i1 = Instrument::new().with_spot(115.0);
             let i2 = Instrument::new().with_spot(104.0);
-            let i3 = Instrument::new().with_spot(86.0);
+            let model = BlackScholesModel::new(0.05, 0.2);
+
             let option =
-                RainbowOption::all_otm(Instrument::new().with_assets(vec![i1, i2, i3]), 105.0, 1.0);
+                RainbowOption::all_otm(Instrument::new().with_assets(vec![i1, i2]), 105.0, 1.0);
+            assert_eq!(option.payoff(None), 0.0);
+            assert_abs_diff_eq!(model.price(&option), 10.9634, epsilon = 0.0001);
+
+            let option = RainbowOption::all_otm(correlated_assets(), 104.0, 0.5);
+            assert_abs_diff_eq!(model.price(&option), 45.7015, epsilon = 0.0001);
+        }
+
+        #[test]
+        fn test_all_itm_otm_more_than_two_assets() {
+            let i1 = Instrument::new().with_spot(115.0);
+            let i2 = Instrument::new().with_spot(104.0);
+            let i3 = Instrument::new().with_spot(86.0);
+            let instrument = Instrument::new().with_assets(vec![i1, i2, i3]);
             let model = BlackScholesModel::new(0.05, 0.2);
 
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 0.0, epsilon = 0.0001);
+            for option in [
+                RainbowOption::all_itm(instrument.clone(), 105.0, 1.0),
+                RainbowOption::all_otm(instrument.clone(), 105.0, 1.0),
+            ] {
+                assert!(matches!(
+                    model.try_price(&option),
+                    Err(PricingError::InvalidInput(_))
+                ));
+            }
+
+            let normal = statrs::distribution::Normal::new(0.0, 1.0).unwrap();
+            let option = RainbowOption::call_on_max(instrument, 105.0, 1.0);
+            assert!(matches!(
+                model.price_rainbow_max_min(&option, &normal),
+                Err(PricingError::InvalidInput(_))
+            ));
+            assert!(matches!(
+                model.price_rainbow_all(&option, &normal),
+                Err(PricingError::UnsupportedStyle { .. })
+            ));
+        }
+
+        fn correlated_assets() -> Instrument {
+            Instrument::new()
+                .with_assets(vec![
+                    Instrument::new()
+                        .with_spot(100.0)
+                        .with_volatility(0.11)
+                        .with_continuous_dividend_yield(0.06),
+                    Instrument::new()
+                        .with_spot(105.0)
+                        .with_volatility(0.16)
+                        .with_continuous_dividend_yield(0.09),
+                ])
+                .with_correlation_matrix(vec![vec![1.0, 0.63], vec![0.63, 1.0]])
+        }
+
+        #[test]
+        fn test_stulz_correlated() {
+            let model = BlackScholesModel::new(0.05, 0.2);
+
+            let option = RainbowOption::call_on_min(correlated_assets(), 98.0, 0.5);
+            assert_abs_diff_eq!(model.price(&option), 2.9339, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.price(&option.flip()), 3.5224, epsilon = 0.0001);
+
+            let option = RainbowOption::call_on_max(correlated_assets(), 98.0, 0.5);
+            assert_abs_diff_eq!(model.price(&option), 8.0701, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.price(&option.flip()), 1.2181, epsilon = 0.0001);
+
+            // max(S1, S2) + min(S1, S2) = S1 + S2
+            let call_on_max = model.price(&option);
+            let call_on_min =
+                model.price(&RainbowOption::call_on_min(correlated_assets(), 98.0, 0.5));
+            let call_1 = BlackScholesModel::new(0.05, 0.11).price(&EuropeanOption::new(
+                Instrument::new()
+                    .with_spot(100.0)
+                    .with_continuous_dividend_yield(0.06),
+                98.0,
+                0.5,
+                OptionType::Call,
+            ));
+            let call_2 = BlackScholesModel::new(0.05, 0.16).price(&EuropeanOption::new(
+                Instrument::new()
+                    .with_spot(105.0)
+                    .with_continuous_dividend_yield(0.09),
+                98.0,
+                0.5,
+                OptionType::Call,
+            ));
+            assert_abs_diff_eq!(call_on_max + call_on_min, call_1 + call_2, epsilon = 1e-8);
+        }
+
+        #[test]
+        fn test_stulz_perfect_correlation() {
+            // Equal volatilities and perfect correlation leave no spread volatility
+            let model = BlackScholesModel::new(0.05, 0.2);
+            let instrument = Instrument::new()
+                .with_assets(vec![
+                    Instrument::new().with_spot(115.0),
+                    Instrument::new().with_spot(86.0),
+                ])
+                .with_correlation_matrix(vec![vec![1.0, 1.0], vec![1.0, 1.0]]);
+            let call = |spot: f64| {
+                model.price(&EuropeanOption::new(
+                    Instrument::new().with_spot(spot),
+                    100.0,
+                    1.0,
+                    OptionType::Call,
+                ))
+            };
+
+            let call_on_max = RainbowOption::call_on_max(instrument.clone(), 100.0, 1.0);
+            assert_abs_diff_eq!(model.price(&call_on_max), call(115.0), epsilon = 1e-10);
+            let call_on_min = RainbowOption::call_on_min(instrument.clone(), 100.0, 1.0);
+            assert_abs_diff_eq!(model.price(&call_on_min), call(86.0), epsilon = 1e-10);
+            let worst_of = RainbowOption::worst_of(instrument, 100.0, 1.0);
+            assert_abs_diff_eq!(model.price(&worst_of), 86.0 - call(86.0), epsilon = 1e-10);
+        }
+
+        #[test]
+        fn test_rainbow_expiry() {
+            let model = BlackScholesModel::new(0.05, 0.2);
+            let instrument = Instrument::new().with_assets(vec![
+                Instrument::new().with_spot(115.0),
+                Instrument::new().with_spot(86.0),
+            ]);
+
+            for (rainbow_type, payoff) in [
+                (RainbowType::BestOf, 115.0),
+                (RainbowType::WorstOf, 86.0),
+                (RainbowType::CallOnMax, 10.0),
+                (RainbowType::CallOnMin, 0.0),
+                (RainbowType::PutOnMax, 0.0),
+                (RainbowType::PutOnMin, 19.0),
+                (RainbowType::CallOnAvg, 0.0),
+                (RainbowType::PutOnAvg, 4.5),
+                (RainbowType::AllITM, 0.0),
+                (RainbowType::AllOTM, 0.0),
+            ] {
+                for option_type in [OptionType::Call, OptionType::Put] {
+                    let option = RainbowOption::new(
+                        instrument.clone(),
+                        105.0,
+                        0.0,
+                        option_type,
+                        rainbow_type,
+                    );
+                    assert_abs_diff_eq!(model.price(&option), payoff, epsilon = 1e-12);
+                }
+            }
+        }
+
+        #[test]
+        fn test_stulz_single_asset() {
+            let model = BlackScholesModel::new(0.05, 0.2);
+            let instrument = Instrument::new().with_spot(100.0);
+            let european = EuropeanOption::new(instrument.clone(), 95.0, 1.0, OptionType::Put);
+
+            let put_on_max = RainbowOption::put_on_max(instrument.clone(), 95.0, 1.0);
+            assert_abs_diff_eq!(
+                model.price(&put_on_max),
+                model.price(&european),
+                epsilon = 1e-10
+            );
+            let best_of = RainbowOption::best_of(instrument, 95.0, 1.0);
+            assert_abs_diff_eq!(
+                model.price(&best_of),
+                95.0 * (-0.05f64).exp() + model.price(&european.flip()),
+                epsilon = 1e-10
+            );
+        }
+
+        #[test]
+        fn test_stulz_too_many_assets() {
+            let i1 = Instrument::new().with_spot(115.0);
+            let i2 = Instrument::new().with_spot(104.0);
+            let i3 = Instrument::new().with_spot(86.0);
+            let option = RainbowOption::call_on_max(
+                Instrument::new().with_assets(vec![i1, i2, i3]),
+                105.0,
+                1.0,
+            );
+            let model = BlackScholesModel::new(0.05, 0.2);
+
+            assert!(std::panic::catch_unwind(|| model.price(&option)).is_err());
         }
     }
 
@@ -704,6 +1217,127 @@ mod black_scholes_tests {
             });
             assert!(result.is_err(), "Expected panic for zero spot price");
         }
+
+        #[test]
+        fn test_fixed() {
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.02);
+            let option = LookbackOption::fixed(instrument.clone(), 100.0, 1.0, OptionType::Call);
+            let model = BlackScholesModel::new(0.05, 0.3);
+
+            assert_abs_diff_eq!(model.price(&option), 26.8608, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.price(&option.flip()), 19.6185, epsilon = 0.0001);
+
+            // The formulas are continuous in the cost of carry
+            let zero_carry = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.05);
+            let option = LookbackOption::floating(zero_carry.clone(), 1.0, OptionType::Call);
+            let nearby = LookbackOption::floating(
+                zero_carry.with_continuous_dividend_yield(0.0501),
+                1.0,
+                OptionType::Call,
+            );
+            assert_abs_diff_eq!(model.price(&option), 20.7142, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.price(&option), model.price(&nearby), epsilon = 0.01);
+        }
+
+        #[test]
+        fn test_seasoned() {
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.02);
+            let option = LookbackOption::fixed(instrument.clone(), 95.0, 1.0, OptionType::Call)
+                .with_running_max(110.0)
+                .with_running_min(90.0);
+            let model = BlackScholesModel::new(0.05, 0.3);
+
+            assert_abs_diff_eq!(model.price(&option), 32.8960, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.price(&option.flip()), 16.0924, epsilon = 0.0001);
+
+            // A fixed strike call below the running maximum is a floating strike put plus a forward
+            let floating =
+                LookbackOption::floating(instrument, 1.0, OptionType::Put).with_running_max(110.0);
+            assert_abs_diff_eq!(
+                model.price(&option),
+                model.price(&floating) + 100.0 * (-0.02f64).exp() - 95.0 * (-0.05f64).exp(),
+                epsilon = 1e-10
+            );
+        }
+
+        #[test]
+        fn test_partial() {
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.02);
+            let floating = LookbackOption::floating(instrument.clone(), 1.0, OptionType::Call);
+            let fixed = LookbackOption::fixed(instrument.clone(), 100.0, 1.0, OptionType::Call);
+            let model = BlackScholesModel::new(0.05, 0.3);
+
+            let option = floating.clone().with_partial_time(0.5);
+            assert_abs_diff_eq!(model.price(&option), 19.9909, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.price(&option.flip()), 19.4801, epsilon = 0.0001);
+
+            let option = fixed.clone().with_partial_time(0.5);
+            assert_abs_diff_eq!(model.price(&option), 22.9738, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.price(&option.flip()), 16.7435, epsilon = 0.0001);
+
+            // The lookback period tends to the whole life of the option
+            let option = floating.clone().with_partial_time(1.0 - 1e-6);
+            assert_abs_diff_eq!(
+                model.price(&option),
+                model.price(&floating),
+                epsilon = 0.001
+            );
+            let option = fixed.clone().with_partial_time(1e-6);
+            assert_abs_diff_eq!(model.price(&option), model.price(&fixed), epsilon = 0.001);
+
+            // Without a lookback period the options are European
+            let european = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+            let option = floating.clone().with_partial_time(0.0);
+            assert_abs_diff_eq!(
+                model.price(&option),
+                model.price(&european),
+                epsilon = 1e-10
+            );
+            let option = fixed.with_partial_time(1.0);
+            assert_abs_diff_eq!(
+                model.price(&option),
+                model.price(&european),
+                epsilon = 1e-10
+            );
+
+            let option = floating.with_partial_time(-0.5);
+            assert!(matches!(
+                model.try_price(&option),
+                Err(PricingError::InvalidInput(_))
+            ));
+        }
+
+        #[test]
+        fn test_discrete() {
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.02);
+            let floating = LookbackOption::floating(instrument.clone(), 1.0, OptionType::Call);
+            let fixed = LookbackOption::fixed(instrument, 100.0, 1.0, OptionType::Call);
+            let model = BlackScholesModel::new(0.05, 0.3);
+
+            let option = floating.clone().with_monitoring(Monitoring::Discrete(52));
+            assert_abs_diff_eq!(model.price(&option), 20.7335, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.price(&option.flip()), 21.1210, epsilon = 0.0001);
+
+            let option = fixed.clone().with_monitoring(Monitoring::Discrete(52));
+            assert_abs_diff_eq!(model.price(&option), 24.0179, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.price(&option.flip()), 17.8365, epsilon = 0.0001);
+
+            // Monitoring less often lowers the price of lookbacks
+            let daily = fixed.clone().with_monitoring(Monitoring::Discrete(252));
+            let monthly = fixed.clone().with_monitoring(Monitoring::Discrete(12));
+            assert!(model.price(&monthly) < model.price(&daily));
+            assert!(model.price(&daily) < model.price(&fixed));
+        }
     }
 
     #[test]
@@ -732,29 +1366,592 @@ mod black_scholes_tests {
             OptionType::Put,
         );
         let model = BlackScholesModel::new(0.05, 0.2);
-        let iv = model.implied_volatility(&option, 1200.0);
-        // Market price 1200 is unreachable for this put (max ~95.12), so Newton-Raphson
-        // diverges until vega vanishes. The old test expected 2947 due to using stale vega.
-        assert_abs_diff_eq!(iv, 32.0309, epsilon = 0.0001);
+        // Market price 1200 is unreachable for this put (max ~95.12)
+        let result = std::panic::catch_unwind(|| model.implied_volatility(&option, 1200.0));
+        assert!(
+            result.is_err(),
+            "Expected panic for price above the upper bound"
+        );
     }
-}
-
-// Binomial Tree Model Tests
-mod binomial_tree_tests {
-    use super::*;
-    mod european_option_tests {
-        use super::*;
-        #[test]
-        fn test_itm() {
-            let instrument = Instrument::new().with_spot(52.0);
-            let option = EuropeanOption::new(instrument, 50.0, 2.0, OptionType::Call);
-            let model = BinomialTreeModel::new(0.05, 0.182321557, 2);
 
-            assert_abs_diff_eq!(model.price(&option), 8.8258, epsilon = 0.0001);
-            assert_abs_diff_eq!(model.price(&option.flip()), 2.0677, epsilon = 0.0001);
+    #[test]
+    fn test_iv_round_trip() {
+        let instrument = Instrument::new()
+            .with_spot(100.0)
+            .with_continuous_dividend_yield(0.02);
+        let guess = BlackScholesModel::new(0.05, 0.3);
+        for ttm in [1.0 / 365.0, 0.25, 1.0, 5.0] {
+            for volatility in [0.05, 0.2, 0.8, 2.0] {
+                let model = BlackScholesModel::new(0.05, volatility);
+                for strike in [50.0, 80.0, 95.0, 100.0, 105.0, 130.0, 200.0] {
+                    for option_type in [OptionType::Call, OptionType::Put] {
+                        let option =
+                            EuropeanOption::new(instrument.clone(), strike, ttm, option_type);
+                        // Skip prices whose time value is lost in rounding
+                        if model.price(&option).min(model.price(&option.flip())) < 1e-6 {
+                            continue;
+                        }
+                        let iv = guess.implied_volatility(&option, model.price(&option));
+                        assert_abs_diff_eq!(iv, volatility, epsilon = 1e-8);
+                    }
+                }
+            }
         }
+    }
 
-        #[test]
+    #[test]
+    fn test_iv_bounds() {
+        let option = EuropeanOption::new(
+            Instrument::new().with_spot(100.0),
+            80.0,
+            1.0,
+            OptionType::Call,
+        );
+        let model = BlackScholesModel::new(0.05, 0.2);
+
+        // Below the discounted intrinsic value 100 - 80 e^(-0.05)
+        match model.try_implied_volatility(&option, 20.0) {
+            Err(PricingError::PriceOutOfBounds { lower, upper, .. }) => {
+                assert_abs_diff_eq!(lower, 100.0 - 80.0 * (-0.05_f64).exp(), epsilon = 1e-6);
+                assert_abs_diff_eq!(upper, 100.0, epsilon = 1e-6);
+            }
+            result => panic!("Expected price out of bounds, got {result:?}"),
+        }
+        assert!(matches!(
+            model.try_implied_volatility(&option, 100.5),
+            Err(PricingError::PriceOutOfBounds { .. })
+        ));
+
+        // At the lower bound the volatility is zero
+        let iv = model
+            .try_implied_volatility(&option, 100.0 - 80.0 * (-0.05_f64).exp())
+            .unwrap();
+        assert_abs_diff_eq!(iv, 0.0, epsilon = 1e-6);
+
+        let option = AmericanOption::new(
+            Instrument::new().with_spot(100.0),
+            80.0,
+            1.0,
+            OptionType::Put,
+        );
+        assert!(matches!(
+            model.try_implied_volatility(&option, 5.0),
+            Err(PricingError::UnsupportedStyle { .. })
+        ));
+    }
+
+    mod barrier_option_tests {
+        use super::*;
+
+        fn instrument() -> Instrument {
+            Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.04)
+        }
+
+        #[test]
+        fn test_knock_out() {
+            let model = BlackScholesModel::new(0.08, 0.25);
+
+            let option =
+                BarrierOption::down_and_out(instrument(), 90.0, 0.5, OptionType::Call, 95.0)
+                    .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 9.0246, epsilon = 0.0001);
+            let option =
+                BarrierOption::down_and_out(instrument(), 110.0, 0.5, OptionType::Call, 95.0)
+                    .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 4.8759, epsilon = 0.0001);
+            let option =
+                BarrierOption::up_and_out(instrument(), 90.0, 0.5, OptionType::Call, 105.0)
+                    .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 2.6789, epsilon = 0.0001);
+            let option =
+                BarrierOption::down_and_out(instrument(), 90.0, 0.5, OptionType::Put, 95.0)
+                    .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 2.2798, epsilon = 0.0001);
+            let option = BarrierOption::up_and_out(instrument(), 90.0, 0.5, OptionType::Put, 105.0)
+                .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 3.7760, epsilon = 0.0001);
+
+            let model = BlackScholesModel::new(0.08, 0.3);
+            let option =
+                BarrierOption::down_and_out(instrument(), 90.0, 0.5, OptionType::Call, 95.0)
+                    .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 8.8334, epsilon = 0.0001);
+        }
+
+        #[test]
+        fn test_knock_in() {
+            let model = BlackScholesModel::new(0.08, 0.25);
+
+            let option =
+                BarrierOption::down_and_in(instrument(), 90.0, 0.5, OptionType::Call, 95.0)
+                    .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 7.7627, epsilon = 0.0001);
+            let option = BarrierOption::up_and_in(instrument(), 90.0, 0.5, OptionType::Call, 105.0)
+                .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 14.1112, epsilon = 0.0001);
+            let option =
+                BarrierOption::down_and_in(instrument(), 100.0, 0.5, OptionType::Put, 95.0)
+                    .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 6.5677, epsilon = 0.0001);
+            let option = BarrierOption::up_and_in(instrument(), 110.0, 0.5, OptionType::Put, 105.0)
+                .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 7.0846, epsilon = 0.0001);
+
+            let model = BlackScholesModel::new(0.08, 0.3);
+            let option = BarrierOption::up_and_in(instrument(), 110.0, 0.5, OptionType::Put, 105.0)
+                .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 8.3686, epsilon = 0.0001);
+        }
+
+        #[test]
+        fn test_in_out_parity() {
+            let model = BlackScholesModel::new(0.08, 0.25);
+            for (strike, barrier) in [(90.0, 95.0), (110.0, 95.0)] {
+                for option_type in [OptionType::Call, OptionType::Put] {
+                    let vanilla = EuropeanOption::new(instrument(), strike, 0.5, option_type);
+                    let knock_in =
+                        BarrierOption::down_and_in(instrument(), strike, 0.5, option_type, barrier);
+                    let knock_out = BarrierOption::down_and_out(
+                        instrument(),
+                        strike,
+                        0.5,
+                        option_type,
+                        barrier,
+                    );
+                    assert_abs_diff_eq!(
+                        model.price(&knock_in) + model.price(&knock_out),
+                        model.price(&vanilla),
+                        epsilon = 1e-10
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn test_breached_barrier() {
+            let model = BlackScholesModel::new(0.08, 0.25);
+            let vanilla = EuropeanOption::new(instrument(), 100.0, 0.5, OptionType::Call);
+
+            let option =
+                BarrierOption::down_and_out(instrument(), 100.0, 0.5, OptionType::Call, 100.0)
+                    .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 3.0, epsilon = 1e-12);
+            let option =
+                BarrierOption::down_and_in(instrument(), 100.0, 0.5, OptionType::Call, 100.0)
+                    .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), model.price(&vanilla), epsilon = 1e-12);
+            assert_abs_diff_eq!(model.price(&option), 7.8494, epsilon = 0.0001);
+        }
+
+        #[test]
+        fn test_discrete_monitoring() {
+            let model = BlackScholesModel::new(0.08, 0.25);
+            let option =
+                BarrierOption::down_and_out(instrument(), 100.0, 0.5, OptionType::Call, 95.0);
+            let continuous = model.price(&option);
+            let daily = model.price(&option.clone().with_monitoring(Monitoring::Discrete(126)));
+            let weekly = model.price(&option.with_monitoring(Monitoring::Discrete(26)));
+
+            // A discretely monitored barrier is hit less often
+            assert!(continuous < daily && daily < weekly);
+            assert_abs_diff_eq!(weekly, 5.6012, epsilon = 0.0001);
+        }
+
+        #[test]
+        fn test_double_knock_out() {
+            let instrument = Instrument::new().with_spot(100.0);
+
+            let model = BlackScholesModel::new(0.1, 0.15);
+            let option = BarrierOption::double_knock_out(
+                instrument.clone(),
+                100.0,
+                0.25,
+                OptionType::Call,
+                50.0,
+                150.0,
+            );
+            assert_abs_diff_eq!(model.price(&option), 4.3515, epsilon = 0.0001);
+            let option = BarrierOption::double_knock_out(
+                instrument.clone(),
+                100.0,
+                0.25,
+                OptionType::Call,
+                90.0,
+                110.0,
+            );
+            assert_abs_diff_eq!(model.price(&option), 1.2055, epsilon = 0.0001);
+
+            let model = BlackScholesModel::new(0.1, 0.25);
+            let option = BarrierOption::double_knock_out(
+                instrument.clone(),
+                100.0,
+                0.25,
+                OptionType::Call,
+                80.0,
+                120.0,
+            );
+            assert_abs_diff_eq!(model.price(&option), 2.6387, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.price(&option.flip()), 2.6866, epsilon = 0.0001);
+
+            // Wide barriers converge to the vanilla price
+            let option = BarrierOption::double_knock_out(
+                instrument.clone(),
+                100.0,
+                0.25,
+                OptionType::Put,
+                1.0,
+                1000.0,
+            );
+            let vanilla = EuropeanOption::new(instrument, 100.0, 0.25, OptionType::Put);
+            assert_abs_diff_eq!(model.price(&option), model.price(&vanilla), epsilon = 1e-8);
+        }
+
+        #[test]
+        fn test_double_knock_in() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let model = BlackScholesModel::new(0.1, 0.25);
+
+            let vanilla = EuropeanOption::new(instrument.clone(), 100.0, 0.25, OptionType::Call);
+            let knock_out = BarrierOption::double_knock_out(
+                instrument.clone(),
+                100.0,
+                0.25,
+                OptionType::Call,
+                80.0,
+                120.0,
+            );
+            let knock_in = BarrierOption::double_knock_in(
+                instrument,
+                100.0,
+                0.25,
+                OptionType::Call,
+                80.0,
+                120.0,
+            );
+            assert_abs_diff_eq!(
+                model.price(&knock_in) + model.price(&knock_out),
+                model.price(&vanilla),
+                epsilon = 1e-10
+            );
+
+            let with_rebate = model.price(&knock_in.clone().with_rebate(2.0));
+            assert_abs_diff_eq!(with_rebate, 5.1111, epsilon = 0.0001);
+            let with_rebate = model.price(&knock_out.with_rebate(2.0));
+            assert_abs_diff_eq!(with_rebate, 3.0979, epsilon = 0.0001);
+        }
+
+        #[test]
+        fn test_invalid() {
+            let model = BlackScholesModel::new(0.08, 0.25);
+            let normal = statrs::distribution::Normal::new(0.0, 1.0).unwrap();
+            let european = EuropeanOption::new(instrument(), 100.0, 0.5, OptionType::Call);
+
+            assert!(matches!(
+                model.price_barrier(&european, &normal),
+                Err(PricingError::UnsupportedStyle { .. })
+            ));
+            assert!(matches!(
+                model.price_double_barrier(&european, &normal),
+                Err(PricingError::UnsupportedStyle { .. })
+            ));
+
+            let mut option = BarrierOption::double_knock_out(
+                instrument(),
+                100.0,
+                0.5,
+                OptionType::Call,
+                80.0,
+                120.0,
+            );
+            option.barrier.upper_level = None;
+            assert!(matches!(
+                model.price_double_barrier(&option, &normal),
+                Err(PricingError::InvalidInput(_))
+            ));
+            assert!(matches!(
+                model.try_price(&option),
+                Err(PricingError::InvalidInput(_))
+            ));
+        }
+    }
+
+    mod asian_option_tests {
+        use super::*;
+
+        #[test]
+        fn test_kemna_vorst() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let option = AsianOption::fixed(instrument, 100.0, 1.0, OptionType::Call);
+            let model = BlackScholesModel::new(0.05, 0.2);
+
+            // Continuous geometric average: σ_G = σ / √3, b_G = (r - q - σ² / 6) / 2
+            assert_abs_diff_eq!(model.price(&option), 5.5469, epsilon = 0.001);
+            assert_abs_diff_eq!(model.price(&option.flip()), 3.4635, epsilon = 0.001);
+        }
+
+        #[test]
+        fn test_arithmetic() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let schedule = AveragingSchedule::uniform(1.0, 12);
+            let option = AsianOption::fixed(instrument, 100.0, 1.0, OptionType::Call)
+                .with_schedule(schedule);
+            let model = BlackScholesModel::new(0.05, 0.2);
+
+            // Control variate Monte Carlo gives 6.1558 ± 0.0005 for the call and 3.5346 ± 0.0003 for the put
+            let geometric = model.price(&option);
+            let turnbull_wakeman = model
+                .clone()
+                .with_asian_approximation(AsianApproximation::TurnbullWakeman);
+            let curran = model.with_asian_approximation(AsianApproximation::Curran);
+            assert_abs_diff_eq!(geometric, 5.9402, epsilon = 0.0001);
+            assert_abs_diff_eq!(turnbull_wakeman.price(&option), 6.1742, epsilon = 0.0001);
+            assert_abs_diff_eq!(curran.price(&option), 6.1556, epsilon = 0.0001);
+            assert_abs_diff_eq!(curran.price(&option.flip()), 3.5341, epsilon = 0.0001);
+            assert!(geometric < curran.price(&option));
+        }
+
+        #[test]
+        fn test_seasoned() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let schedule = AveragingSchedule::uniform(0.5, 6)
+                .with_past_fixings(vec![95.0, 97.0, 99.0, 101.0, 103.0, 105.0]);
+            let option = AsianOption::fixed(instrument.clone(), 100.0, 0.5, OptionType::Call)
+                .with_schedule(schedule);
+            let model = BlackScholesModel::new(0.05, 0.2)
+                .with_asian_approximation(AsianApproximation::Curran);
+            assert_abs_diff_eq!(model.price(&option), 2.1822, epsilon = 0.001);
+            assert_abs_diff_eq!(model.price(&option.flip()), 1.4645, epsilon = 0.001);
+
+            // The strike is covered by the past fixings
+            let schedule = AveragingSchedule::uniform(0.5, 2).with_past_fixings(vec![100.0; 6]);
+            let option = AsianOption::fixed(instrument.clone(), 50.0, 0.5, OptionType::Call)
+                .with_schedule(schedule.clone());
+            let expected_average = (600.0 + 100.0 * (0.0125f64.exp() + 0.025f64.exp())) / 8.0;
+            assert_abs_diff_eq!(
+                model.price(&option),
+                (-0.025f64).exp() * (expected_average - 50.0),
+                epsilon = 1e-10
+            );
+            assert_eq!(model.price(&option.flip()), 0.0);
+
+            let option = AsianOption::fixed(instrument, 100.0, 0.5, OptionType::Call)
+                .with_schedule(AveragingSchedule::new(vec![0.25, 0.75]));
+            assert!(matches!(
+                model.try_price(&option),
+                Err(PricingError::InvalidInput(_))
+            ));
+        }
+    }
+
+    mod basket_option_tests {
+        use super::*;
+
+        fn two_asset_basket() -> Instrument {
+            Instrument::new()
+                .with_weighted_assets(vec![
+                    (Instrument::new().with_spot(100.0).with_volatility(0.2), 0.5),
+                    (Instrument::new().with_spot(100.0).with_volatility(0.3), 0.5),
+                ])
+                .with_correlation_matrix(vec![vec![1.0, 0.5], vec![0.5, 1.0]])
+        }
+
+        fn three_asset_basket() -> Instrument {
+            Instrument::new()
+                .with_weighted_assets(vec![
+                    (
+                        Instrument::new()
+                            .with_spot(110.0)
+                            .with_volatility(0.25)
+                            .with_continuous_dividend_yield(0.02),
+                        0.5,
+                    ),
+                    (
+                        Instrument::new()
+                            .with_spot(100.0)
+                            .with_volatility(0.2)
+                            .with_continuous_dividend_yield(0.01),
+                        0.3,
+                    ),
+                    (
+                        Instrument::new()
+                            .with_spot(90.0)
+                            .with_volatility(0.35)
+                            .with_continuous_dividend_yield(0.03),
+                        0.2,
+                    ),
+                ])
+                .with_correlation_matrix(vec![
+                    vec![1.0, 0.3, 0.2],
+                    vec![0.3, 1.0, 0.6],
+                    vec![0.2, 0.6, 1.0],
+                ])
+        }
+
+        #[test]
+        fn test_levy() {
+            let model = BlackScholesModel::new(0.05, 0.2);
+            let normal = statrs::distribution::Normal::new(0.0, 1.0).unwrap();
+
+            let call = BasketOption::call(two_asset_basket(), 100.0, 1.0);
+            assert_abs_diff_eq!(model.price(&call), 11.1523, epsilon = 0.0001);
+            assert_abs_diff_eq!(
+                model.price_basket_levy(&call, &normal),
+                model.price(&call),
+                epsilon = 1e-12
+            );
+            let put = BasketOption::put(two_asset_basket(), 100.0, 1.0);
+            assert_abs_diff_eq!(model.price(&put), 6.2752, epsilon = 0.0001);
+
+            let model = BlackScholesModel::new(0.04, 0.2);
+            let call = BasketOption::call(three_asset_basket(), 95.0, 0.75);
+            assert_abs_diff_eq!(model.price(&call), 12.1890, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.price(&call.flip()), 2.8248, epsilon = 0.0001);
+        }
+
+        #[test]
+        fn test_gentle() {
+            let model = BlackScholesModel::new(0.05, 0.2);
+            let normal = statrs::distribution::Normal::new(0.0, 1.0).unwrap();
+
+            let call = BasketOption::call(two_asset_basket(), 100.0, 1.0);
+            let put = BasketOption::put(two_asset_basket(), 100.0, 1.0);
+            assert_abs_diff_eq!(
+                model.price_basket_gentle(&call, &normal),
+                11.0517,
+                epsilon = 0.0001
+            );
+            assert_abs_diff_eq!(
+                model.price_basket_gentle(&put, &normal),
+                6.1747,
+                epsilon = 0.0001
+            );
+
+            let model = BlackScholesModel::new(0.04, 0.2);
+            let call = BasketOption::call(three_asset_basket(), 95.0, 0.75);
+            let put = BasketOption::put(three_asset_basket(), 95.0, 0.75);
+            assert_abs_diff_eq!(
+                model.price_basket_gentle(&call, &normal),
+                12.1096,
+                epsilon = 0.0001
+            );
+            assert_abs_diff_eq!(
+                model.price_basket_gentle(&put, &normal),
+                2.7454,
+                epsilon = 0.0001
+            );
+        }
+
+        #[test]
+        fn test_put_call_parity() {
+            let model = BlackScholesModel::new(0.05, 0.2);
+            let normal = statrs::distribution::Normal::new(0.0, 1.0).unwrap();
+            let call = BasketOption::call(two_asset_basket(), 100.0, 1.0);
+            let put = BasketOption::put(two_asset_basket(), 100.0, 1.0);
+            // Both assets have no dividends, so the basket forward is 100 e^{rT}
+            let parity = 100.0 - 100.0 * (-0.05f64).exp();
+
+            assert_abs_diff_eq!(
+                model.price(&call) - model.price(&put),
+                parity,
+                epsilon = 1e-10
+            );
+            assert_abs_diff_eq!(
+                model.price_basket_gentle(&call, &normal)
+                    - model.price_basket_gentle(&put, &normal),
+                parity,
+                epsilon = 1e-10
+            );
+        }
+
+        #[test]
+        fn test_single_asset() {
+            let model = BlackScholesModel::new(0.05, 0.2);
+            let normal = statrs::distribution::Normal::new(0.0, 1.0).unwrap();
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.02);
+            let european = EuropeanOption::new(instrument.clone(), 105.0, 1.0, OptionType::Call);
+            let basket = BasketOption::call(instrument, 105.0, 1.0);
+
+            assert_abs_diff_eq!(
+                model.price(&basket),
+                model.price(&european),
+                epsilon = 1e-10
+            );
+            assert_abs_diff_eq!(
+                model.price_basket_gentle(&basket, &normal),
+                model.price(&european),
+                epsilon = 1e-10
+            );
+        }
+
+        #[test]
+        fn test_levy_degenerate() {
+            let model = BlackScholesModel::new(0.05, 0.2);
+
+            // At expiry the option is worth its payoff
+            let call = BasketOption::call(two_asset_basket(), 90.0, 0.0);
+            assert_abs_diff_eq!(model.price(&call), call.payoff(None), epsilon = 1e-12);
+            assert!(model.price(&call) > 0.0);
+
+            // Without volatility the basket is worth its discounted intrinsic value on the forward
+            let instrument = Instrument::new().with_weighted_assets(vec![
+                (Instrument::new().with_spot(120.0).with_volatility(0.0), 0.5),
+                (Instrument::new().with_spot(80.0).with_volatility(0.0), 0.5),
+            ]);
+            let call = BasketOption::call(instrument, 95.0, 1.0);
+            assert_abs_diff_eq!(
+                model.price(&call),
+                100.0 - 95.0 * (-0.05_f64).exp(),
+                epsilon = 1e-10
+            );
+        }
+
+        #[test]
+        fn test_perfect_correlation() {
+            // With identical volatilities and perfect correlation the basket is log-normal
+            let model = BlackScholesModel::new(0.05, 0.25);
+            let instrument = Instrument::new()
+                .with_weighted_assets(vec![
+                    (Instrument::new().with_spot(120.0), 0.5),
+                    (Instrument::new().with_spot(80.0), 0.5),
+                ])
+                .with_correlation_matrix(vec![vec![1.0, 1.0], vec![1.0, 1.0]]);
+            let basket = BasketOption::put(instrument, 95.0, 1.0);
+            let european = EuropeanOption::new(
+                Instrument::new().with_spot(100.0),
+                95.0,
+                1.0,
+                OptionType::Put,
+            );
+
+            assert_abs_diff_eq!(
+                model.price(&basket),
+                model.price(&european),
+                epsilon = 1e-10
+            );
+        }
+    }
+}
+
+// Binomial Tree Model Tests
+mod binomial_tree_tests {
+    use super::*;
+    mod european_option_tests {
+        use super::*;
+        #[test]
+        fn test_itm() {
+            let instrument = Instrument::new().with_spot(52.0);
+            let option = EuropeanOption::new(instrument, 50.0, 2.0, OptionType::Call);
+            let model = BinomialTreeModel::new(0.05, 0.182321557, 2);
+
+            assert_abs_diff_eq!(model.price(&option), 8.8258, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.price(&option.flip()), 2.0677, epsilon = 0.0001);
+        }
+
+        #[test]
         fn test_otm() {
             let instrument = Instrument::new().with_spot(50.0);
             let option = EuropeanOption::new(instrument, 60.0, 2.0, OptionType::Call);
@@ -787,6 +1984,30 @@ mod binomial_tree_tests {
             assert_abs_diff_eq!(model.price(&option), 3.8360, epsilon = 0.0001);
             assert_abs_diff_eq!(model.price(&option.flip()), 10.0000, epsilon = 0.0001);
         }
+
+        #[test]
+        fn test_cash_dividends() {
+            let instrument = Instrument::new().with_spot(100.0).with_cash_dividends(vec![
+                CashDividend::new(0.25, 2.0),
+                CashDividend::new(0.5, 3.0),
+                CashDividend::new(1.5, 5.0),
+            ]);
+            let option = AmericanOption::new(instrument.clone(), 100.0, 1.0, OptionType::Put);
+            let european = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+            let model = BinomialTreeModel::new(0.05, 0.2, 200);
+
+            assert_abs_diff_eq!(model.price(&option), 8.2388, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.price(&option.flip()), 7.5873, epsilon = 0.0001);
+
+            // The European option converges to the escrowed Black-Scholes price
+            let black_scholes = BlackScholesModel::new(0.05, 0.2);
+            assert_abs_diff_eq!(
+                model.price(&european),
+                black_scholes.price(&european),
+                epsilon = 0.005
+            );
+            assert!(model.price(&option.flip()) > model.price(&european));
+        }
     }
 
     mod bermudan_option_tests {
@@ -1079,702 +2300,3282 @@ mod binomial_tree_tests {
         });
         assert!(result.is_err(), "Expected panic for delta calculation");
     }
-}
-
-// Monte Carlo Model Tests
-mod monte_carlo_tests {
-    use super::*;
-
-    mod european_option_tests {
-        use rand_distr::num_traits::Zero;
 
+    mod barrier_option_tests {
         use super::*;
 
-        #[test]
-        fn test_itm() {
-            let instrument = Instrument::new().with_spot(110.0);
-            let option = EuropeanOption::new(instrument, 100.0, 0.7, OptionType::Call);
-            let model = MonteCarloModel::brownian(0.03, 0.2, 2_000, 20);
-
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 14.575, epsilon = 1.0);
-
-            let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 2.497, epsilon = 1.0);
+        fn instrument() -> Instrument {
+            Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.04)
         }
 
         #[test]
-        fn test_otm() {
-            let instrument = Instrument::new().with_spot(85.0);
-            let option = EuropeanOption::new(instrument, 70.0, 0.7, OptionType::Call);
-            let model = MonteCarloModel::brownian(0.05, 0.3, 2_000, 20);
-
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 19.264, epsilon = 2.0);
+        fn test_knock_out() {
+            // 961 steps place the down barrier (95) and 1063 steps the up barrier (105) just beyond a layer of nodes
+            let model = BinomialTreeModel::new(0.08, 0.25, 961);
 
-            let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 1.857, epsilon = 1.0);
+            let option =
+                BarrierOption::down_and_out(instrument(), 90.0, 0.5, OptionType::Call, 95.0)
+                    .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 9.0246, epsilon = 0.05);
+            let option =
+                BarrierOption::up_and_out(instrument(), 90.0, 0.5, OptionType::Call, 105.0)
+                    .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 2.6789, epsilon = 0.05);
+            let option =
+                BarrierOption::down_and_out(instrument(), 90.0, 0.5, OptionType::Put, 95.0)
+                    .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 2.2798, epsilon = 0.05);
         }
 
         #[test]
-        fn test_div_itm() {
-            let instrument = Instrument::new()
-                .with_spot(105.0)
-                .with_continuous_dividend_yield(0.05);
-            let option = EuropeanOption::new(instrument, 100.0, 1.2, OptionType::Call);
-            let model = MonteCarloModel::brownian(0.04, 0.1, 2_000, 1);
-
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 6.2640, epsilon = 0.5);
+        fn test_knock_in() {
+            let model = BinomialTreeModel::new(0.08, 0.25, 961);
 
-            let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 2.6921, epsilon = 0.5);
+            let option =
+                BarrierOption::down_and_in(instrument(), 100.0, 0.5, OptionType::Call, 95.0)
+                    .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 4.0109, epsilon = 0.05);
+            let option =
+                BarrierOption::down_and_in(instrument(), 100.0, 0.5, OptionType::Put, 95.0)
+                    .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 6.5677, epsilon = 0.05);
         }
 
         #[test]
-        fn test_div_otm() {
-            let instrument = Instrument::new()
-                .with_spot(70.0)
-                .with_continuous_dividend_yield(0.05);
-            let option = EuropeanOption::new(instrument, 72.0, 0.43, OptionType::Call);
-            let model = MonteCarloModel::brownian(0.02, 0.2, 2_000, 1);
-
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 2.3985, epsilon = 0.5);
+        fn test_in_out_parity() {
+            let model = BinomialTreeModel::new(0.08, 0.25, 200);
+            for option_type in [OptionType::Call, OptionType::Put] {
+                let vanilla = EuropeanOption::new(instrument(), 100.0, 0.5, option_type);
+                let knock_in =
+                    BarrierOption::up_and_in(instrument(), 100.0, 0.5, option_type, 110.0);
+                let knock_out =
+                    BarrierOption::up_and_out(instrument(), 100.0, 0.5, option_type, 110.0);
+                assert_abs_diff_eq!(
+                    model.price(&knock_in) + model.price(&knock_out),
+                    model.price(&vanilla),
+                    epsilon = 1e-10
+                );
+            }
+        }
 
-            let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 5.2709, epsilon = 0.5);
+        #[test]
+        fn test_discrete_monitoring() {
+            let model = BinomialTreeModel::new(0.08, 0.25, 1040);
+            let option =
+                BarrierOption::down_and_out(instrument(), 100.0, 0.5, OptionType::Call, 95.0)
+                    .with_monitoring(Monitoring::Discrete(26));
+            assert_abs_diff_eq!(model.price(&option), 5.6012, epsilon = 0.1);
         }
 
         #[test]
-        fn test_edge() {
+        fn test_double_barrier() {
             let instrument = Instrument::new().with_spot(100.0);
-            let option = EuropeanOption::new(instrument, 100.0, 2.0, OptionType::Call);
-            let model = MonteCarloModel::brownian(0.05, 0.2, 2_000, 1);
-
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 16.127, epsilon = 1.5);
+            let model = BinomialTreeModel::new(0.1, 0.25, 1000);
 
-            let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 6.611, epsilon = 1.5);
-
-            let instrument = Instrument::new().with_spot(0.0);
-            let option = EuropeanOption::new(instrument, 0.0, 0.0, OptionType::Call);
-            let model = MonteCarloModel::brownian(0.0, 0.0, 2_000, 1);
-
-            let price = model.price(&option);
-            assert!(price.is_nan() || price.is_zero());
+            let option = BarrierOption::double_knock_out(
+                instrument.clone(),
+                100.0,
+                0.25,
+                OptionType::Put,
+                80.0,
+                120.0,
+            );
+            assert_abs_diff_eq!(model.price(&option), 2.6866, epsilon = 0.05);
+            let option = BarrierOption::double_knock_in(
+                instrument,
+                100.0,
+                0.25,
+                OptionType::Put,
+                80.0,
+                120.0,
+            )
+            .with_rebate(2.0);
+            assert_abs_diff_eq!(model.price(&option), 2.5942, epsilon = 0.05);
         }
     }
 
-    mod asian_option_tests {
+    mod lattice_scheme_tests {
         use super::*;
 
+        const SCHEMES: [LatticeScheme; 5] = [
+            LatticeScheme::CoxRossRubinstein,
+            LatticeScheme::JarrowRudd,
+            LatticeScheme::Tian,
+            LatticeScheme::LeisenReimer,
+            LatticeScheme::Trinomial,
+        ];
+
         #[test]
-        fn test_avg() {
-            let instrument = Instrument::new()
-                .with_spot(30.0)
-                .with_continuous_dividend_yield(0.02);
-            let option = AsianOption::fixed(instrument, 29.0, 1.0, OptionType::Call);
-            let arithmetic_model = MonteCarloModel::arithmetic(0.08, 0.3, 4_000, 20);
-            let geometric_model = MonteCarloModel::geometric(0.08, 0.3, 4_000, 20);
+        fn test_european() {
+            let option = EuropeanOption::new(
+                Instrument::new().with_spot(100.0),
+                95.0,
+                1.0,
+                OptionType::Call,
+            );
+            let black_scholes = BlackScholesModel::new(0.05, 0.2).price(&option);
+
+            for scheme in SCHEMES {
+                let model = BinomialTreeModel::new(0.05, 0.2, 200).with_scheme(scheme);
+                assert_abs_diff_eq!(model.price(&option), black_scholes, epsilon = 0.02);
+                assert_abs_diff_eq!(
+                    model.price(&option.flip()),
+                    BlackScholesModel::new(0.05, 0.2).price(&option.flip()),
+                    epsilon = 0.02
+                );
+            }
 
-            let sd = (1.0 / 20f64).exp();
+            // Leisen-Reimer trees converge at second order and are extrapolated accordingly
+            let model =
+                BinomialTreeModel::new(0.05, 0.2, 200).with_scheme(LatticeScheme::LeisenReimer);
+            assert_abs_diff_eq!(model.price(&option), black_scholes, epsilon = 1e-5);
+            assert_abs_diff_eq!(
+                model.with_richardson_extrapolation().price(&option),
+                black_scholes,
+                epsilon = 1e-6
+            );
+        }
 
-            let price = arithmetic_model.price(&option);
-            assert_abs_diff_eq!(price, 2.9427, epsilon = sd);
+        #[test]
+        fn test_american_put() {
+            // Reference price of a 20,000-step tree
+            let option = AmericanOption::new(
+                Instrument::new().with_spot(100.0),
+                100.0,
+                1.0,
+                OptionType::Put,
+            );
+            let reference = 6.0904;
+
+            for scheme in SCHEMES {
+                let model = BinomialTreeModel::new(0.05, 0.2, 200).with_scheme(scheme);
+                assert_abs_diff_eq!(model.price(&option), reference, epsilon = 0.02);
+                assert_abs_diff_eq!(
+                    model.with_control_variate().price(&option),
+                    reference,
+                    epsilon = 0.01
+                );
+            }
 
-            let price = arithmetic_model.price(&option.flip());
-            assert_abs_diff_eq!(price, 1.1719, epsilon = sd);
+            let model =
+                BinomialTreeModel::new(0.05, 0.2, 200).with_scheme(LatticeScheme::LeisenReimer);
+            assert_abs_diff_eq!(
+                model.with_richardson_extrapolation().price(&option),
+                reference,
+                epsilon = 0.001
+            );
+        }
 
-            let price = geometric_model.price(&option);
-            assert_abs_diff_eq!(price, 2.777, epsilon = sd);
+        #[test]
+        fn test_richardson_extrapolation() {
+            let option = AmericanOption::new(
+                Instrument::new().with_spot(100.0),
+                100.0,
+                1.0,
+                OptionType::Put,
+            );
+            let reference = 6.0904;
+
+            // Extrapolation reduces the error of Leisen-Reimer trees
+            for steps in [100, 200, 500] {
+                let model = BinomialTreeModel::new(0.05, 0.2, steps)
+                    .with_scheme(LatticeScheme::LeisenReimer);
+                let error = (model.price(&option) - reference).abs();
+                let extrapolated =
+                    (model.with_richardson_extrapolation().price(&option) - reference).abs();
+                assert!(extrapolated < 0.25 * error);
+            }
 
-            let price = geometric_model.price(&option.flip());
-            assert_abs_diff_eq!(price, 1.224, epsilon = sd);
+            // The oscillating error of the other schemes is not extrapolated
+            for scheme in [
+                LatticeScheme::CoxRossRubinstein,
+                LatticeScheme::JarrowRudd,
+                LatticeScheme::Tian,
+                LatticeScheme::Trinomial,
+            ] {
+                let model = BinomialTreeModel::new(0.05, 0.2, 500).with_scheme(scheme);
+                assert_eq!(
+                    model.clone().with_richardson_extrapolation().price(&option),
+                    model.price(&option)
+                );
+            }
         }
 
         #[test]
-        fn test_fixed_itm() {
-            let instrument = Instrument::new().with_spot(110.0);
-            let option = AsianOption::fixed(instrument, 100.0, 0.7, OptionType::Call);
-            let model = MonteCarloModel::arithmetic(0.03, 0.2, 4_000, 20);
+        fn test_expiry() {
+            let option = AmericanOption::new(
+                Instrument::new().with_spot(100.0),
+                110.0,
+                0.0,
+                OptionType::Put,
+            );
+            for scheme in SCHEMES {
+                let model = BinomialTreeModel::new(0.05, 0.2, 200).with_scheme(scheme);
+                assert_eq!(model.price(&option), 10.0);
+                assert_eq!(model.price(&option.flip()), 0.0);
+                assert_abs_diff_eq!(model.try_delta(&option).unwrap(), -1.0, epsilon = 1e-6);
+                assert_eq!(model.try_delta(&option.flip()).unwrap(), 0.0);
+                assert_eq!(model.try_gamma(&option).unwrap(), 0.0);
+                assert_eq!(model.try_theta(&option).unwrap(), 0.0);
+            }
+        }
 
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 12.0, epsilon = 1.0);
+        #[test]
+        fn test_control_variate() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let option = AmericanOption::new(instrument.clone(), 100.0, 1.0, OptionType::Put);
+            let european = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Put);
+            let model = BinomialTreeModel::new(0.05, 0.2, 200);
+            let corrected = model.clone().with_control_variate();
+
+            // The control variate corrects the tree by its error for the European option
+            assert_abs_diff_eq!(
+                corrected.price(&option) - model.price(&option),
+                BlackScholesModel::new(0.05, 0.2).price(&european) - model.price(&european),
+                epsilon = 1e-10
+            );
+            assert_abs_diff_eq!(
+                corrected.price(&european),
+                model.price(&european),
+                epsilon = 1e-12
+            );
+            assert_abs_diff_eq!(corrected.price(&option), 6.0904, epsilon = 0.01);
+        }
 
-            let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 0.595, epsilon = 1.0);
+        #[test]
+        fn test_leisen_reimer_steps() {
+            let option = AmericanOption::new(
+                Instrument::new().with_spot(100.0),
+                110.0,
+                0.5,
+                OptionType::Put,
+            );
+            let model = |steps| {
+                BinomialTreeModel::new(0.05, 0.3, steps).with_scheme(LatticeScheme::LeisenReimer)
+            };
+
+            // Leisen-Reimer trees have an odd number of steps
+            assert_abs_diff_eq!(
+                model(100).price(&option),
+                model(101).price(&option),
+                epsilon = 1e-12
+            );
+            assert!(model(100).price(&option) > option.strike() - option.instrument().spot());
         }
+    }
+
+    mod greeks_tests {
+        use super::*;
 
         #[test]
-        fn test_fixed_otm() {
-            let instrument = Instrument::new().with_spot(85.0);
-            let option = AsianOption::fixed(instrument, 90.0, 0.7, OptionType::Call);
-            let model = MonteCarloModel::geometric(0.05, 0.3, 4_000, 20);
+        fn test_european() {
+            let option = EuropeanOption::new(
+                Instrument::new().with_spot(100.0),
+                95.0,
+                1.0,
+                OptionType::Put,
+            );
+            let black_scholes = BlackScholesModel::new(0.05, 0.2);
+            let model = BinomialTreeModel::new(0.05, 0.2, 200);
 
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 3.629, epsilon = 1.0);
+            assert_abs_diff_eq!(
+                model.delta(&option),
+                black_scholes.delta(&option),
+                epsilon = 0.001
+            );
+            assert_abs_diff_eq!(
+                model.gamma(&option),
+                black_scholes.gamma(&option),
+                epsilon = 1e-4
+            );
+            assert_abs_diff_eq!(
+                model.theta(&option),
+                black_scholes.theta(&option),
+                epsilon = 0.02
+            );
 
-            let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 6.474, epsilon = 1.0);
+            let model = model.with_scheme(LatticeScheme::LeisenReimer);
+            assert_abs_diff_eq!(
+                model.vega(&option),
+                black_scholes.vega(&option),
+                epsilon = 0.05
+            );
+            assert_abs_diff_eq!(
+                model.rho(&option),
+                black_scholes.rho(&option),
+                epsilon = 0.01
+            );
         }
 
         #[test]
-        fn test_floating_itm() {
-            let instrument = Instrument::new().with_spot(110.0);
-            let option = AsianOption::floating(instrument, 1.0, OptionType::Call);
-            let model = MonteCarloModel::geometric(0.03, 0.2, 4_000, 20);
-
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 5.878, epsilon = 1.0);
+        fn test_american() {
+            let option = AmericanOption::new(
+                Instrument::new().with_spot(100.0),
+                100.0,
+                1.0,
+                OptionType::Put,
+            );
+            // Bumped and repriced on a fine tree
+            let reference = NumericalGreeks::new(
+                BinomialTreeModel::new(0.05, 0.2, 2000).with_scheme(LatticeScheme::LeisenReimer),
+            );
 
-            let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 4.222, epsilon = 1.0);
+            for scheme in [
+                LatticeScheme::CoxRossRubinstein,
+                LatticeScheme::LeisenReimer,
+                LatticeScheme::Trinomial,
+            ] {
+                let model = BinomialTreeModel::new(0.05, 0.2, 200).with_scheme(scheme);
+                let greeks = Greeks::calculate(&model, &option);
+                assert_abs_diff_eq!(greeks.delta, reference.delta(&option), epsilon = 0.001);
+                assert_abs_diff_eq!(greeks.gamma, reference.gamma(&option), epsilon = 1e-4);
+                assert_abs_diff_eq!(greeks.theta, reference.theta(&option), epsilon = 0.02);
+                assert_abs_diff_eq!(greeks.vega, reference.vega(&option), epsilon = 0.05);
+                assert_abs_diff_eq!(greeks.rho, reference.rho(&option), epsilon = 0.1);
+
+                // Richardson extrapolation and the control variate also apply to the Greeks
+                let model = model.with_richardson_extrapolation().with_control_variate();
+                assert_abs_diff_eq!(
+                    model.delta(&option),
+                    reference.delta(&option),
+                    epsilon = 5e-4
+                );
+                assert_abs_diff_eq!(
+                    model.theta(&option),
+                    reference.theta(&option),
+                    epsilon = 0.005
+                );
+            }
         }
 
         #[test]
-        fn test_floating_otm() {
-            let instrument = Instrument::new().with_spot(85.0);
-            let option = AsianOption::floating(instrument, 1.0, OptionType::Call);
-            let model = MonteCarloModel::arithmetic(0.05, 0.4, 4_000, 20);
+        fn test_bermudan() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let model = BinomialTreeModel::new(0.05, 0.2, 200);
+            let bermudan = BermudanOption::new(
+                instrument.clone(),
+                100.0,
+                vec![0.25, 0.5, 0.75, 1.0],
+                OptionType::Put,
+            );
+            let american = AmericanOption::new(instrument.clone(), 100.0, 1.0, OptionType::Put);
+            let european = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Put);
 
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 8.378, epsilon = 1.0);
+            // The early exercise rights of the Bermudan option lie between the European and American option
+            let delta = model.delta(&bermudan);
+            assert!(model.delta(&american) < delta && delta < model.delta(&european));
+            assert!(model.gamma(&bermudan) > 0.0);
+        }
 
-            let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 6.685, epsilon = 1.0);
+        #[test]
+        fn test_barrier() {
+            let option = BarrierOption::down_and_out(
+                Instrument::new().with_spot(100.0),
+                100.0,
+                1.0,
+                OptionType::Call,
+                90.0,
+            );
+            let model = BinomialTreeModel::new(0.05, 0.2, 200);
+            assert_abs_diff_eq!(
+                model.delta(&option),
+                NumericalGreeks::new(model.clone()).delta(&option),
+                epsilon = 1e-12
+            );
         }
     }
+}
+
+// Monte Carlo Model Tests
+mod monte_carlo_tests {
+    use super::*;
+
+    mod european_option_tests {
+        use rand_distr::num_traits::Zero;
 
-    mod lookback_option_tests {
         use super::*;
 
         #[test]
-        fn test_avg() {
-            let instrument = Instrument::new()
-                .with_spot(30.0)
-                .with_continuous_dividend_yield(0.02);
-            let option = LookbackOption::floating(instrument, 1.0, OptionType::Call);
-            let arithmetic_model = MonteCarloModel::arithmetic(0.08, 0.3, 4_000, 20);
-            let geometric_model = MonteCarloModel::geometric(0.08, 0.3, 4_000, 20);
+        fn test_seeded() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 10_000, 1).with_seed(7);
 
-            let sd = 1.0;
+            let price = model.price(&option);
+            assert_eq!(price, model.price(&option));
+            assert_ne!(price, model.clone().with_seed(8).price(&option));
+            assert_abs_diff_eq!(price, 10.4506, epsilon = 0.5);
+        }
 
-            let price = arithmetic_model.price(&option);
-            assert_abs_diff_eq!(price, 6.636, epsilon = sd);
+        #[test]
+        fn test_variance_reduction() {
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.02);
+            let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 20_000, 4).with_seed(3);
+            let models = [
+                model.clone().with_antithetic_variates(),
+                model.clone().with_control_variates(),
+                model.clone().with_moment_matching(),
+                model
+                    .with_antithetic_variates()
+                    .with_control_variates()
+                    .with_moment_matching(),
+            ];
+
+            for model in models {
+                let price = model.price(&option);
+                assert_eq!(price, model.price(&option));
+                assert_abs_diff_eq!(price, 9.2270, epsilon = 0.15);
+                assert_abs_diff_eq!(
+                    price - model.price(&option.flip()),
+                    100.0 * (-0.02_f64).exp() - 100.0 * (-0.05_f64).exp(),
+                    epsilon = 0.3
+                );
+            }
+        }
 
-            let price = arithmetic_model.price(&option.flip());
-            assert_abs_diff_eq!(price, 5.304, epsilon = sd);
+        #[test]
+        fn test_statistics() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 10_000, 1).with_seed(11);
+
+            let result = model.price_statistics(&option).unwrap();
+            assert_eq!(result.price, model.price(&option));
+            assert_eq!(result.paths, 10_000);
+            // The standard deviation of the discounted payoff is about 14.7
+            assert_abs_diff_eq!(result.standard_error, 0.147, epsilon = 0.01);
+            let (lower, upper) = result.confidence_interval;
+            assert_abs_diff_eq!(
+                upper - lower,
+                2.0 * 1.96 * result.standard_error,
+                epsilon = 1e-3
+            );
+            assert!(lower < 10.4506 && 10.4506 < upper);
 
-            let price = geometric_model.price(&option);
-            assert_abs_diff_eq!(price, 6.636, epsilon = sd);
+            let reduced = model
+                .with_antithetic_variates()
+                .with_control_variates()
+                .price_statistics(&option)
+                .unwrap();
+            assert!(reduced.standard_error < 0.6 * result.standard_error);
 
-            let price = geometric_model.price(&option.flip());
-            assert_abs_diff_eq!(price, 5.304, epsilon = sd);
+            let option = AsianOption::fixed(
+                Instrument::new().with_spot(100.0),
+                100.0,
+                1.0,
+                OptionType::Put,
+            );
+            assert!(matches!(
+                MonteCarloModel::brownian(0.05, 0.2, 100, 1).price_statistics(&option),
+                Err(PricingError::InvalidInput(_))
+            ));
         }
 
         #[test]
-        fn test_fixed_itm() {
-            let instrument = Instrument::new().with_spot(110.0);
-            let option = LookbackOption::fixed(instrument, 31.0, 1.0, OptionType::Call);
-            let model = MonteCarloModel::geometric(0.03, 0.3, 4_000, 20);
-
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 101.0136, epsilon = 1.5);
+        fn test_adaptive() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 1)
+                .with_seed(5)
+                .with_target_standard_error(0.1, 100_000);
+
+            let result = model.price_statistics(&option).unwrap();
+            assert!(result.standard_error <= 0.1);
+            assert_eq!(result.paths % 1_000, 0);
+            assert!(result.paths > 1_000 && result.paths < 100_000);
+            assert_eq!(
+                result,
+                MonteCarloResult {
+                    elapsed: result.elapsed,
+                    ..model.price_statistics(&option).unwrap()
+                }
+            );
+            assert_abs_diff_eq!(result.price, 10.4506, epsilon = 0.4);
+
+            // Stops at the maximum number of paths
+            let result = model
+                .with_target_standard_error(1e-6, 5_000)
+                .price_statistics(&option)
+                .unwrap();
+            assert_eq!(result.paths, 5_000);
+        }
 
-            let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 0.0, epsilon = 1.5);
+        #[test]
+        fn test_no_simulations() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+
+            let model = MonteCarloModel::brownian(0.05, 0.2, 0, 1);
+            assert!(matches!(
+                model.price_statistics(&option),
+                Err(PricingError::InvalidInput(_))
+            ));
+            assert!(matches!(
+                model.try_price(&option),
+                Err(PricingError::InvalidInput(_))
+            ));
+            let model =
+                MonteCarloModel::brownian(0.05, 0.2, 1_000, 1).with_target_standard_error(0.1, 0);
+            assert!(matches!(
+                model.price_statistics(&option),
+                Err(PricingError::InvalidInput(_))
+            ));
         }
 
         #[test]
-        fn test_fixed_otm() {
-            let instrument = Instrument::new().with_spot(15.0);
-            let option = LookbackOption::fixed(instrument, 20.0, 1.0, OptionType::Call);
-            let model = MonteCarloModel::arithmetic(0.01, 0.1, 4_000, 20);
+        fn test_sobol() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+            let model =
+                MonteCarloModel::brownian(0.05, 0.2, 4_096, 1).with_sampling(Sampling::Sobol);
 
+            // The plain sequence is deterministic without a seed
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 0.0007, epsilon = 0.1);
-
-            let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 5.8520, epsilon = 0.1);
+            assert_eq!(price, model.price(&option));
+            assert_abs_diff_eq!(price, 10.4506, epsilon = 0.05);
+
+            let model = model.with_sampling(Sampling::ScrambledSobol);
+            let scrambled = model.clone().with_seed(1).price(&option);
+            assert_eq!(scrambled, model.clone().with_seed(1).price(&option));
+            assert_ne!(scrambled, model.clone().with_seed(2).price(&option));
+            assert_abs_diff_eq!(scrambled, 10.4506, epsilon = 0.05);
         }
 
         #[test]
-        fn test_floating_itm() {
+        fn test_itm() {
             let instrument = Instrument::new().with_spot(110.0);
-            let option = LookbackOption::floating(instrument, 1.0, OptionType::Call);
-            let model = MonteCarloModel::geometric(0.03, 0.3, 4_000, 20);
+            let option = EuropeanOption::new(instrument, 100.0, 0.7, OptionType::Call);
+            let model = MonteCarloModel::brownian(0.03, 0.2, 2_000, 20);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 22.225, epsilon = 1.5);
+            assert_abs_diff_eq!(price, 14.575, epsilon = 1.0);
 
             let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 21.917, epsilon = 1.5);
+            assert_abs_diff_eq!(price, 2.497, epsilon = 1.0);
         }
 
         #[test]
-        fn test_floating_otm() {
-            let instrument = Instrument::new().with_spot(15.0);
-            let option = LookbackOption::floating(instrument, 1.0, OptionType::Call);
-            let model = MonteCarloModel::arithmetic(0.01, 0.1, 4_000, 20);
+        fn test_otm() {
+            let instrument = Instrument::new().with_spot(85.0);
+            let option = EuropeanOption::new(instrument, 70.0, 0.7, OptionType::Call);
+            let model = MonteCarloModel::brownian(0.05, 0.3, 2_000, 20);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 1.068, epsilon = 0.1);
+            assert_abs_diff_eq!(price, 19.264, epsilon = 2.0);
 
             let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 0.965, epsilon = 0.1);
+            assert_abs_diff_eq!(price, 1.857, epsilon = 1.0);
         }
-    }
-
-    mod binary_option_tests {
-        use super::*;
 
         #[test]
-        fn test_asset_or_nothing_itm() {
+        fn test_div_itm() {
             let instrument = Instrument::new()
-                .with_spot(110.0)
+                .with_spot(105.0)
                 .with_continuous_dividend_yield(0.05);
-            let option = BinaryOption::asset_or_nothing(instrument, 100.0, 0.7, OptionType::Call);
-            let model = MonteCarloModel::brownian(0.03, 0.2, 4_000, 20);
+            let option = EuropeanOption::new(instrument, 100.0, 1.2, OptionType::Call);
+            let model = MonteCarloModel::brownian(0.04, 0.1, 2_000, 1);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 76.0002, epsilon = 2.5);
+            assert_abs_diff_eq!(price, 6.2640, epsilon = 0.5);
 
             let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 30.2164, epsilon = 2.5);
+            assert_abs_diff_eq!(price, 2.6921, epsilon = 0.5);
         }
 
         #[test]
-        fn test_asset_or_nothing_otm() {
+        fn test_div_otm() {
             let instrument = Instrument::new()
-                .with_spot(85.0)
-                .with_continuous_dividend_yield(0.01);
-            let option = BinaryOption::asset_or_nothing(instrument, 90.0, 0.7, OptionType::Call);
-            let model = MonteCarloModel::brownian(0.05, 0.3, 4_000, 20);
+                .with_spot(70.0)
+                .with_continuous_dividend_yield(0.05);
+            let option = EuropeanOption::new(instrument, 72.0, 0.43, OptionType::Call);
+            let model = MonteCarloModel::brownian(0.02, 0.2, 2_000, 1);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 42.5177, epsilon = 2.0);
+            assert_abs_diff_eq!(price, 2.3985, epsilon = 0.5);
 
             let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 41.8894, epsilon = 2.0);
+            assert_abs_diff_eq!(price, 5.2709, epsilon = 0.5);
         }
 
         #[test]
-        fn test_cash_or_nothing_itm() {
-            let instrument = Instrument::new()
-                .with_spot(120.0)
-                .with_continuous_dividend_yield(0.01);
-            let option = BinaryOption::cash_or_nothing(instrument, 115.0, 4.0, OptionType::Call);
-            let model = MonteCarloModel::brownian(0.05, 0.3, 4_000, 20);
-
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 0.4216, epsilon = 0.1);
+        fn test_cash_dividends() {
+            let instrument = Instrument::new().with_spot(100.0).with_cash_dividends(vec![
+                CashDividend::new(0.25, 2.0),
+                CashDividend::new(0.5, 3.0),
+                CashDividend::new(1.5, 5.0),
+            ]);
+            let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 20_000, 52)
+                .with_seed(42)
+                .with_control_variates();
+
+            // The spot drops on the ex-dividend dates, which raises the volatility of the escrowed spot
+            let call = model.price(&option);
+            let put = model.price(&option.flip());
+            assert_abs_diff_eq!(call, 7.7212, epsilon = 0.05);
+            assert_abs_diff_eq!(put, 7.7453, epsilon = 0.05);
+            assert!(call > BlackScholesModel::new(0.05, 0.2).price(&option));
+
+            // Put-call parity with the present value of the dividends
+            let escrowed = 100.0 - 2.0 * (-0.0125f64).exp() - 3.0 * (-0.025f64).exp();
+            assert_abs_diff_eq!(
+                call - put,
+                escrowed - 100.0 * (-0.05f64).exp(),
+                epsilon = 0.05
+            );
 
-            let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 0.3971, epsilon = 0.1);
+            // Dividends going ex after expiry do not change the paths
+            let model = MonteCarloModel::brownian(0.05, 0.2, 2_000, 52).with_seed(42);
+            let late = Instrument::new()
+                .with_spot(100.0)
+                .with_cash_dividends(vec![CashDividend::new(1.5, 5.0)]);
+            assert_eq!(
+                model.price(&EuropeanOption::new(late, 100.0, 1.0, OptionType::Call)),
+                model.price(&EuropeanOption::new(
+                    Instrument::new().with_spot(100.0),
+                    100.0,
+                    1.0,
+                    OptionType::Call
+                ))
+            );
         }
 
         #[test]
-        fn test_cash_or_nothing_otm() {
+        fn test_term_structures() {
+            let discount_curve = YieldCurve::new(vec![0.5, 1.0, 2.0], vec![0.03, 0.035, 0.04]);
+            let volatilities =
+                VolatilityTermStructure::new(vec![0.5, 1.0, 2.0], vec![0.25, 0.22, 0.2]);
             let instrument = Instrument::new()
-                .with_spot(70.0)
-                .with_continuous_dividend_yield(0.02);
-            let option = BinaryOption::cash_or_nothing(instrument, 85.0, 4.0, OptionType::Call);
-            let model = MonteCarloModel::brownian(0.05, 0.3, 4_000, 20);
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.01);
+            let option = EuropeanOption::new(instrument.clone(), 100.0, 1.5, OptionType::Call);
+            let model = MonteCarloModel::brownian(0.0, 0.0, 20_000, 30)
+                .with_seed(42)
+                .with_control_variates()
+                .with_discount_curve(discount_curve.clone())
+                .with_borrow_curve(YieldCurve::flat(0.01))
+                .with_volatility_term_structure(volatilities.clone());
+
+            assert_abs_diff_eq!(model.price(&option), 10.9653, epsilon = 0.15);
+            assert_abs_diff_eq!(model.price(&option.flip()), 8.4510, epsilon = 0.15);
+
+            // Flat term structures reproduce the flat model path by path
+            let flat = MonteCarloModel::arithmetic(0.05, 0.2, 2_000, 20).with_seed(7);
+            let curves = MonteCarloModel::arithmetic(0.05, 0.2, 2_000, 20)
+                .with_seed(7)
+                .with_discount_curve(YieldCurve::flat(0.05))
+                .with_volatility_term_structure(VolatilityTermStructure::flat(0.2));
+            let asian = AsianOption::fixed(instrument, 100.0, 1.0, OptionType::Call);
+            assert_abs_diff_eq!(flat.price(&asian), curves.price(&asian), epsilon = 1e-10);
+            assert_abs_diff_eq!(flat.price(&option), curves.price(&option), epsilon = 1e-10);
+        }
+
+        #[test]
+        fn test_edge() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let option = EuropeanOption::new(instrument, 100.0, 2.0, OptionType::Call);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 2_000, 1);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 0.2750, epsilon = 0.1);
+            assert_abs_diff_eq!(price, 16.127, epsilon = 1.5);
 
             let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 0.5437, epsilon = 0.1);
+            assert_abs_diff_eq!(price, 6.611, epsilon = 1.5);
+
+            let instrument = Instrument::new().with_spot(0.0);
+            let option = EuropeanOption::new(instrument, 0.0, 0.0, OptionType::Call);
+            let model = MonteCarloModel::brownian(0.0, 0.0, 2_000, 1);
+
+            let price = model.price(&option);
+            assert!(price.is_nan() || price.is_zero());
         }
     }
 
-    mod rainbow_option_tests {
+    mod asian_option_tests {
         use super::*;
 
         #[test]
-        fn test_best_of() {
-            let i1 = Instrument::new().with_spot(115.0);
-            let i2 = Instrument::new().with_spot(86.0);
-            let option =
-                RainbowOption::best_of(Instrument::new().with_assets(vec![i1, i2]), 105.0, 1.0);
-            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 20);
+        fn test_avg() {
+            let instrument = Instrument::new()
+                .with_spot(30.0)
+                .with_continuous_dividend_yield(0.02);
+            let option = AsianOption::fixed(instrument, 29.0, 1.0, OptionType::Call);
+            let arithmetic_model = MonteCarloModel::arithmetic(0.08, 0.3, 4_000, 20);
+            let geometric_model = MonteCarloModel::geometric(0.08, 0.3, 4_000, 20);
 
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 118.0372, epsilon = 2.0);
+            let sd = (1.0 / 20f64).exp();
+
+            let price = arithmetic_model.price(&option);
+            assert_abs_diff_eq!(price, 2.9427, epsilon = sd);
+
+            let price = arithmetic_model.price(&option.flip());
+            assert_abs_diff_eq!(price, 1.1719, epsilon = sd);
+
+            let price = geometric_model.price(&option);
+            assert_abs_diff_eq!(price, 2.777, epsilon = sd);
+
+            let price = geometric_model.price(&option.flip());
+            assert_abs_diff_eq!(price, 1.224, epsilon = sd);
         }
 
         #[test]
-        fn test_worst_of() {
-            let i1 = Instrument::new().with_spot(115.0);
-            let i2 = Instrument::new().with_spot(86.0);
-            let option =
-                RainbowOption::worst_of(Instrument::new().with_assets(vec![i1, i2]), 105.0, 1.0);
-            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 20);
+        fn test_brownian_bridge() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let option = AsianOption::fixed(instrument, 100.0, 1.0, OptionType::Call);
+            let model = MonteCarloModel::geometric(0.05, 0.2, 4_096, 64);
+            let exact = model
+                .clone()
+                .with_seed(1)
+                .with_control_variates()
+                .price(&option);
+
+            // The leading Sobol dimensions drive the end point and the midpoints of the path
+            let bridged = model
+                .with_sampling(Sampling::ScrambledSobol)
+                .with_brownian_bridge();
+            for seed in 0..3 {
+                assert_abs_diff_eq!(
+                    bridged.clone().with_seed(seed).price(&option),
+                    exact,
+                    epsilon = 0.02
+                );
+            }
+        }
 
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 83.5883, epsilon = 2.0);
+        #[test]
+        fn test_control_variate() {
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.02);
+            let option = AsianOption::fixed(instrument, 100.0, 1.0, OptionType::Call);
+
+            // The geometric Asian option is its own control, so the estimate is exact
+            let model = MonteCarloModel::geometric(0.05, 0.2, 1_000, 50).with_control_variates();
+            let price = model.clone().with_seed(1).price(&option);
+            assert_abs_diff_eq!(price, model.with_seed(2).price(&option), epsilon = 1e-10);
+            assert_abs_diff_eq!(price, 4.9039, epsilon = 0.0001);
+
+            let model = MonteCarloModel::arithmetic(0.05, 0.2, 5_000, 50).with_control_variates();
+            let price = model.clone().with_seed(1).price(&option);
+            assert_abs_diff_eq!(price, model.with_seed(2).price(&option), epsilon = 0.02);
+            assert_abs_diff_eq!(price, 5.096, epsilon = 0.02);
         }
 
         #[test]
-        fn test_call_on_max() {
-            let i1 = Instrument::new().with_spot(115.0);
-            let i2 = Instrument::new().with_spot(86.0);
-            let option =
-                RainbowOption::call_on_max(Instrument::new().with_assets(vec![i1, i2]), 105.0, 1.0);
-            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 20);
+        fn test_schedule() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let schedule = AveragingSchedule::uniform(1.0, 12);
+            let option = AsianOption::fixed(instrument.clone(), 100.0, 1.0, OptionType::Call)
+                .with_schedule(schedule.clone());
+            let curran = BlackScholesModel::new(0.05, 0.2)
+                .with_asian_approximation(AsianApproximation::Curran);
+
+            // The simulated fixings ignore the number of steps of the model
+            let model = MonteCarloModel::arithmetic(0.05, 0.2, 10_000, 50)
+                .with_seed(1)
+                .with_control_variates();
+            let result = model.price_statistics(&option).unwrap();
+            assert!(result.standard_error < 0.01);
+            assert_abs_diff_eq!(result.price, curran.price(&option), epsilon = 0.01);
+
+            let model = MonteCarloModel::geometric(0.05, 0.2, 1_000, 50).with_control_variates();
+            assert_abs_diff_eq!(
+                model.with_seed(1).price(&option),
+                BlackScholesModel::new(0.05, 0.2).price(&option),
+                epsilon = 1e-10
+            );
 
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 18.1580, epsilon = 2.0);
+            let seasoned = AsianOption::fixed(instrument, 100.0, 0.5, OptionType::Put)
+                .with_schedule(
+                    AveragingSchedule::uniform(0.5, 6)
+                        .with_past_fixings(vec![95.0, 97.0, 99.0, 101.0, 103.0, 105.0]),
+                );
+            let model = MonteCarloModel::arithmetic(0.05, 0.2, 10_000, 50)
+                .with_seed(1)
+                .with_control_variates();
+            assert_abs_diff_eq!(
+                model.price(&seasoned),
+                curran.price(&seasoned),
+                epsilon = 0.01
+            );
+        }
 
-            let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 3.0371, epsilon = 2.0);
+        #[test]
+        fn test_floating_schedule() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let option = AsianOption::floating(instrument, 1.0, OptionType::Call)
+                .with_schedule(AveragingSchedule::uniform(1.0, 12));
+            let model = MonteCarloModel::arithmetic(0.05, 0.2, 10_000, 12)
+                .with_seed(1)
+                .with_control_variates();
+
+            let call = model.price(&option);
+            let put = model.price(&option.flip());
+            assert_abs_diff_eq!(call, 5.46, epsilon = 0.1);
+
+            // Parity: C - P = e^(-rT) (E[S_T] - E[A])
+            let expected_average = (1..=12)
+                .map(|i| 100.0 * (0.05 * i as f64 / 12.0).exp())
+                .sum::<f64>()
+                / 12.0;
+            assert_abs_diff_eq!(
+                call - put,
+                (-0.05f64).exp() * (100.0 * 0.05f64.exp() - expected_average),
+                epsilon = 0.2
+            );
         }
 
         #[test]
-        fn test_put_on_max() {
-            let i1 = Instrument::new().with_spot(115.0);
-            let i2 = Instrument::new().with_spot(86.0);
-            let option =
-                RainbowOption::put_on_max(Instrument::new().with_assets(vec![i1, i2]), 120.0, 1.0);
-            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 20);
+        fn test_fixed_itm() {
+            let instrument = Instrument::new().with_spot(110.0);
+            let option = AsianOption::fixed(instrument, 100.0, 0.7, OptionType::Call);
+            let model = MonteCarloModel::arithmetic(0.03, 0.2, 4_000, 20);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 8.6920, epsilon = 2.0);
+            assert_abs_diff_eq!(price, 12.0, epsilon = 1.0);
 
             let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 9.5445, epsilon = 2.0);
+            assert_abs_diff_eq!(price, 0.595, epsilon = 1.0);
         }
 
         #[test]
-        fn test_call_on_min() {
-            let i1 = Instrument::new().with_spot(115.0);
-            let i2 = Instrument::new().with_spot(86.0);
-            let option =
-                RainbowOption::call_on_min(Instrument::new().with_assets(vec![i1, i2]), 120.0, 1.0);
-            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 20);
+        fn test_fixed_otm() {
+            let instrument = Instrument::new().with_spot(85.0);
+            let option = AsianOption::fixed(instrument, 90.0, 0.7, OptionType::Call);
+            let model = MonteCarloModel::geometric(0.05, 0.3, 4_000, 20);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 0.6952, epsilon = 2.0);
+            assert_abs_diff_eq!(price, 3.629, epsilon = 1.0);
 
             let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 28.8427, epsilon = 2.0);
+            assert_abs_diff_eq!(price, 6.474, epsilon = 1.0);
         }
 
         #[test]
-        fn test_put_on_min() {
-            let i1 = Instrument::new().with_spot(115.0);
-            let i2 = Instrument::new().with_spot(86.0);
-            let option =
-                RainbowOption::put_on_min(Instrument::new().with_assets(vec![i1, i2]), 105.0, 1.0);
-            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 20);
+        fn test_floating_itm() {
+            let instrument = Instrument::new().with_spot(110.0);
+            let option = AsianOption::floating(instrument, 1.0, OptionType::Call);
+            let model = MonteCarloModel::geometric(0.03, 0.2, 4_000, 20);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 16.2908, epsilon = 2.0);
+            assert_abs_diff_eq!(price, 5.878, epsilon = 1.0);
 
             let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 2.4117, epsilon = 2.0);
+            assert_abs_diff_eq!(price, 4.222, epsilon = 1.0);
         }
 
         #[test]
-        fn test_call_on_average() {
-            let i1 = Instrument::new().with_spot(115.0);
-            let i2 = Instrument::new().with_spot(86.0);
-            let option =
-                RainbowOption::call_on_avg(Instrument::new().with_assets(vec![i1, i2]), 100.0, 1.0);
-            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 20);
+        fn test_floating_otm() {
+            let instrument = Instrument::new().with_spot(85.0);
+            let option = AsianOption::floating(instrument, 1.0, OptionType::Call);
+            let model = MonteCarloModel::arithmetic(0.05, 0.4, 4_000, 20);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 10.7675, epsilon = 2.0);
+            assert_abs_diff_eq!(price, 8.378, epsilon = 1.0);
 
             let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 5.3905, epsilon = 2.0);
+            assert_abs_diff_eq!(price, 6.685, epsilon = 1.0);
         }
+    }
+
+    mod lookback_option_tests {
+        use super::*;
 
         #[test]
-        fn test_put_on_average() {
-            let i1 = Instrument::new().with_spot(115.0);
-            let i2 = Instrument::new().with_spot(86.0);
-            let option =
-                RainbowOption::put_on_avg(Instrument::new().with_assets(vec![i1, i2]), 110.0, 1.0);
-            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 20);
+        fn test_avg() {
+            let instrument = Instrument::new()
+                .with_spot(30.0)
+                .with_continuous_dividend_yield(0.02);
+            let option = LookbackOption::floating(instrument, 1.0, OptionType::Call);
+            let arithmetic_model = MonteCarloModel::arithmetic(0.08, 0.3, 4_000, 20);
+            let geometric_model = MonteCarloModel::geometric(0.08, 0.3, 4_000, 20);
 
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 10.4088, epsilon = 2.0);
+            let sd = 1.0;
 
-            let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 6.2736, epsilon = 2.0);
+            let price = arithmetic_model.price(&option);
+            assert_abs_diff_eq!(price, 6.636, epsilon = sd);
+
+            let price = arithmetic_model.price(&option.flip());
+            assert_abs_diff_eq!(price, 5.304, epsilon = sd);
+
+            let price = geometric_model.price(&option);
+            assert_abs_diff_eq!(price, 6.636, epsilon = sd);
+
+            let price = geometric_model.price(&option.flip());
+            assert_abs_diff_eq!(price, 5.304, epsilon = sd);
         }
 
         #[test]
-        fn test_all_itm() {
-            let i1 = Instrument::new().with_spot(115.0);
-            let i2 = Instrument::new().with_spot(104.0);
-            let i3 = Instrument::new().with_spot(86.0);
-            let option =
-                RainbowOption::all_itm(Instrument::new().with_assets(vec![i1, i2, i3]), 105.0, 1.0);
-            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 20);
+        fn test_fixed_itm() {
+            let instrument = Instrument::new().with_spot(110.0);
+            let option = LookbackOption::fixed(instrument, 31.0, 1.0, OptionType::Call);
+            let model = MonteCarloModel::geometric(0.03, 0.3, 4_000, 20);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 0.0, epsilon = 2.0);
+            assert_abs_diff_eq!(price, 101.0136, epsilon = 1.5);
+
+            let price = model.price(&option.flip());
+            assert_abs_diff_eq!(price, 0.0, epsilon = 1.5);
         }
 
         #[test]
-        fn test_all_otm() {
-            let i1 = Instrument::new().with_spot(115.0);
-            let i2 = Instrument::new().with_spot(104.0);
-            let i3 = Instrument::new().with_spot(86.0);
-            let option =
-                RainbowOption::all_otm(Instrument::new().with_assets(vec![i1, i2, i3]), 105.0, 1.0);
-            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 20);
+        fn test_fixed_otm() {
+            let instrument = Instrument::new().with_spot(15.0);
+            let option = LookbackOption::fixed(instrument, 20.0, 1.0, OptionType::Call);
+            let model = MonteCarloModel::arithmetic(0.01, 0.1, 4_000, 20);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 0.0, epsilon = 2.0);
-        }
-    }
-}
-
-// Black-76 Model Tests
-mod black_76_tests {
-    use super::*;
+            assert_abs_diff_eq!(price, 0.0007, epsilon = 0.1);
 
-    mod european_option_tests {
-        use super::*;
+            let price = model.price(&option.flip());
+            assert_abs_diff_eq!(price, 5.8520, epsilon = 0.1);
+        }
 
         #[test]
-        fn test_itm() {
-            let instrument = Instrument::new().with_spot(2006.0);
-            let option = EuropeanOption::new(instrument, 2100.0, 0.08493, OptionType::Call);
-            let model = Black76Model::new(0.050067, 0.35);
+        fn test_floating_itm() {
+            let instrument = Instrument::new().with_spot(110.0);
+            let option = LookbackOption::floating(instrument, 1.0, OptionType::Call);
+            let model = MonteCarloModel::geometric(0.03, 0.3, 4_000, 20);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 44.5829, epsilon = 0.0001);
+            assert_abs_diff_eq!(price, 22.225, epsilon = 1.5);
 
             let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 138.1841, epsilon = 0.0001);
+            assert_abs_diff_eq!(price, 21.917, epsilon = 1.5);
         }
 
         #[test]
-        fn test_otm() {
-            let instrument = Instrument::new().with_spot(2100.0);
-            let option = EuropeanOption::new(instrument, 2006.0, 0.08493, OptionType::Call);
-            let model = Black76Model::new(0.050067, 0.35);
+        fn test_floating_otm() {
+            let instrument = Instrument::new().with_spot(15.0);
+            let option = LookbackOption::floating(instrument, 1.0, OptionType::Call);
+            let model = MonteCarloModel::arithmetic(0.01, 0.1, 4_000, 20);
 
             let price = model.price(&option);
-            assert_abs_diff_eq!(price, 138.1841, epsilon = 0.0001);
+            assert_abs_diff_eq!(price, 1.068, epsilon = 0.1);
 
             let price = model.price(&option.flip());
-            assert_abs_diff_eq!(price, 44.5829, epsilon = 0.0001);
+            assert_abs_diff_eq!(price, 0.965, epsilon = 0.1);
         }
 
-        // #[test]
-        // fn test_div_itm() {
-        //     let instrument = Instrument::new()
-        //         .with_spot(120.0)
-        //         .with_continuous_dividend_yield(0.01);
-        //     let option = EuropeanOption::new(instrument, 100.0, 2.0, OptionType::Call);
-        //     let model = Black76Model::new(0.03, 0.27);
-        //
-        //     let price = model.price(&option);
-        //     assert_abs_diff_eq!(price, 30.3564, epsilon = 0.0001);
-        //
-        //     let price = model.price(&option.flip());
-        //     assert_abs_diff_eq!(price, 6.9091, epsilon = 0.0001);
-        // }
-        //
-        // #[test]
-        // fn test_div_otm() {
-        //     let instrument = Instrument::new()
-        //         .with_spot(50.0)
-        //         .with_continuous_dividend_yield(0.05);
-        //     let option = EuropeanOption::new(instrument, 65.0, 0.43, OptionType::Call);
-        //     let model = Black76Model::new(0.1, 0.31);
-        //
-        //     let price = model.price(&option);
-        //     assert_abs_diff_eq!(price, 0.6470, epsilon = 0.0001);
-        //
-        //     let price = model.price(&option.flip());
-        //     assert_abs_diff_eq!(price, 13.9748, epsilon = 0.0001);
-        // }
+        #[test]
+        fn test_seasoned() {
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.02);
+            let option = LookbackOption::fixed(instrument, 95.0, 1.0, OptionType::Call)
+                .with_running_max(110.0)
+                .with_monitoring(Monitoring::Discrete(52));
+            let model = MonteCarloModel::geometric(0.05, 0.3, 20_000, 52).with_seed(42);
+            let black_scholes = BlackScholesModel::new(0.05, 0.3);
+
+            assert_abs_diff_eq!(
+                model.price(&option),
+                black_scholes.price(&option),
+                epsilon = 0.5
+            );
+        }
 
         #[test]
-        fn test_edge() {
-            let instrument = Instrument::new().with_spot(120.0);
-            let option = EuropeanOption::new(instrument, 100.0, 0.0, OptionType::Call);
-            let model = Black76Model::new(0.03, 0.27);
+        fn test_partial() {
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.02);
+            let model = MonteCarloModel::geometric(0.05, 0.3, 20_000, 52).with_seed(42);
+            let black_scholes = BlackScholesModel::new(0.05, 0.3);
+
+            let option = LookbackOption::floating(instrument.clone(), 1.0, OptionType::Call)
+                .with_partial_time(0.5)
+                .with_monitoring(Monitoring::Discrete(26));
+            assert_abs_diff_eq!(
+                model.price(&option),
+                black_scholes.price(&option),
+                epsilon = 0.5
+            );
 
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 20.0, epsilon = 0.0001);
+            let option = LookbackOption::fixed(instrument, 100.0, 1.0, OptionType::Put)
+                .with_partial_time(0.5)
+                .with_monitoring(Monitoring::Discrete(26));
+            assert_abs_diff_eq!(
+                model.price(&option),
+                black_scholes.price(&option),
+                epsilon = 0.5
+            );
+        }
+    }
+
+    mod american_option_tests {
+        use super::*;
+
+        #[test]
+        fn test_put() {
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.02);
+            let option = AmericanOption::new(instrument.clone(), 110.0, 1.0, OptionType::Put);
+            let model = MonteCarloModel::brownian(0.05, 0.25, 10_000, 50)
+                .with_seed(1)
+                .with_antithetic_variates()
+                .with_control_variates();
+
+            // The finite difference price is 14.4286
+            let result = model.price_statistics(&option).unwrap();
+            assert_abs_diff_eq!(result.price, 14.4286, epsilon = 0.25);
+            assert!(result.standard_error < 0.1);
+
+            let european = EuropeanOption::new(instrument, 110.0, 1.0, OptionType::Put);
+            assert!(result.price > BlackScholesModel::new(0.05, 0.25).price(&european));
+        }
 
+        #[test]
+        fn test_regression_basis() {
             let instrument = Instrument::new().with_spot(100.0);
-            let option = EuropeanOption::new(instrument, 120.0, 0.0, OptionType::Put);
-            let model = Black76Model::new(0.03, 0.27);
+            let option = AmericanOption::new(instrument, 100.0, 1.0, OptionType::Put);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 10_000, 50).with_seed(3);
+
+            let laguerre = model
+                .clone()
+                .with_regression_basis(RegressionBasis::Laguerre(3))
+                .price(&option);
+            let polynomial = model
+                .with_regression_basis(RegressionBasis::Polynomial(2))
+                .price(&option);
+            assert_abs_diff_eq!(laguerre, 6.09, epsilon = 0.2);
+            assert_abs_diff_eq!(polynomial, laguerre, epsilon = 0.1);
+        }
 
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 20.0, epsilon = 0.0001);
+        #[test]
+        fn test_deep_itm_put() {
+            let instrument = Instrument::new().with_spot(50.0);
+            let option = AmericanOption::new(instrument, 100.0, 1.0, OptionType::Put);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 50).with_seed(1);
+
+            // Immediate exercise is optimal
+            assert_abs_diff_eq!(model.price(&option), 50.0, epsilon = 1e-10);
+        }
+    }
+
+    mod bermudan_option_tests {
+        use super::*;
 
+        #[test]
+        fn test_put() {
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.02);
+            let expiration_dates = vec![0.25, 0.5, 0.75, 1.0];
+            let option = BermudanOption::new(instrument, 110.0, expiration_dates, OptionType::Put);
+            let model = MonteCarloModel::brownian(0.05, 0.25, 10_000, 50)
+                .with_seed(1)
+                .with_antithetic_variates()
+                .with_control_variates();
+
+            // The finite difference price is 14.2760
+            assert_abs_diff_eq!(model.price(&option), 14.2760, epsilon = 0.25);
+        }
+
+        #[test]
+        fn test_exercise_dates() {
+            // A single exercise date at maturity is a European option
             let instrument = Instrument::new().with_spot(100.0);
-            let option = EuropeanOption::new(instrument, 100.0, 0.0, OptionType::Call);
-            let model = Black76Model::new(0.03, 0.27);
+            let option = BermudanOption::new(instrument.clone(), 100.0, vec![1.0], OptionType::Put);
+            let european = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Put);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 50).with_seed(1);
+            assert_abs_diff_eq!(
+                model.price(&option),
+                MonteCarloModel::brownian(0.05, 0.2, 1_000, 1)
+                    .with_seed(1)
+                    .price(&european),
+                epsilon = 1e-10
+            );
+        }
+    }
+
+    mod binary_option_tests {
+        use super::*;
+
+        #[test]
+        fn test_asset_or_nothing_itm() {
+            let instrument = Instrument::new()
+                .with_spot(110.0)
+                .with_continuous_dividend_yield(0.05);
+            let option = BinaryOption::asset_or_nothing(instrument, 100.0, 0.7, OptionType::Call);
+            let model = MonteCarloModel::brownian(0.03, 0.2, 4_000, 20);
 
             let price = model.price(&option);
-            assert!(price.is_nan());
+            assert_abs_diff_eq!(price, 76.0002, epsilon = 2.5);
 
             let price = model.price(&option.flip());
-            assert!(price.is_nan());
+            assert_abs_diff_eq!(price, 30.2164, epsilon = 2.5);
+        }
 
-            let instrument = Instrument::new().with_spot(0.0);
-            let option = EuropeanOption::new(instrument, 0.0, 0.0, OptionType::Call);
-            let model = Black76Model::new(0.0, 0.0);
+        #[test]
+        fn test_asset_or_nothing_otm() {
+            let instrument = Instrument::new()
+                .with_spot(85.0)
+                .with_continuous_dividend_yield(0.01);
+            let option = BinaryOption::asset_or_nothing(instrument, 90.0, 0.7, OptionType::Call);
+            let model = MonteCarloModel::brownian(0.05, 0.3, 4_000, 20);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 42.5177, epsilon = 2.0);
+
+            let price = model.price(&option.flip());
+            assert_abs_diff_eq!(price, 41.8894, epsilon = 2.0);
+        }
+
+        #[test]
+        fn test_cash_or_nothing_itm() {
+            let instrument = Instrument::new()
+                .with_spot(120.0)
+                .with_continuous_dividend_yield(0.01);
+            let option = BinaryOption::cash_or_nothing(instrument, 115.0, 4.0, OptionType::Call);
+            let model = MonteCarloModel::brownian(0.05, 0.3, 4_000, 20);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 0.4216, epsilon = 0.1);
+
+            let price = model.price(&option.flip());
+            assert_abs_diff_eq!(price, 0.3971, epsilon = 0.1);
+        }
+
+        #[test]
+        fn test_cash_or_nothing_otm() {
+            let instrument = Instrument::new()
+                .with_spot(70.0)
+                .with_continuous_dividend_yield(0.02);
+            let option = BinaryOption::cash_or_nothing(instrument, 85.0, 4.0, OptionType::Call);
+            let model = MonteCarloModel::brownian(0.05, 0.3, 4_000, 20);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 0.2750, epsilon = 0.1);
+
+            let price = model.price(&option.flip());
+            assert_abs_diff_eq!(price, 0.5437, epsilon = 0.1);
+        }
+    }
+
+    mod rainbow_option_tests {
+        use super::*;
+
+        #[test]
+        fn test_best_of() {
+            let i1 = Instrument::new().with_spot(115.0);
+            let i2 = Instrument::new().with_spot(86.0);
+            let option =
+                RainbowOption::best_of(Instrument::new().with_assets(vec![i1, i2]), 105.0, 1.0);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 20);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 119.1473, epsilon = 2.0);
+        }
+
+        #[test]
+        fn test_worst_of() {
+            let i1 = Instrument::new().with_spot(115.0);
+            let i2 = Instrument::new().with_spot(86.0);
+            let option =
+                RainbowOption::worst_of(Instrument::new().with_assets(vec![i1, i2]), 105.0, 1.0);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 20);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 82.4763, epsilon = 2.0);
+        }
+
+        #[test]
+        fn test_call_on_max() {
+            let i1 = Instrument::new().with_spot(115.0);
+            let i2 = Instrument::new().with_spot(86.0);
+            let option =
+                RainbowOption::call_on_max(Instrument::new().with_assets(vec![i1, i2]), 105.0, 1.0);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 20);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 19.2682, epsilon = 2.0);
+
+            let price = model.price(&option.flip());
+            assert_abs_diff_eq!(price, 1.9376, epsilon = 2.0);
+        }
+
+        #[test]
+        fn test_put_on_max() {
+            let i1 = Instrument::new().with_spot(115.0);
+            let i2 = Instrument::new().with_spot(86.0);
+            let option =
+                RainbowOption::put_on_max(Instrument::new().with_assets(vec![i1, i2]), 120.0, 1.0);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 20);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 6.9656, epsilon = 2.0);
+
+            let price = model.price(&option.flip());
+            assert_abs_diff_eq!(price, 10.0277, epsilon = 2.0);
+        }
+
+        #[test]
+        fn test_call_on_min() {
+            let i1 = Instrument::new().with_spot(115.0);
+            let i2 = Instrument::new().with_spot(86.0);
+            let option =
+                RainbowOption::call_on_min(Instrument::new().with_assets(vec![i1, i2]), 120.0, 1.0);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 20);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 0.2306, epsilon = 2.0);
+
+            let price = model.price(&option.flip());
+            assert_abs_diff_eq!(price, 30.5878, epsilon = 2.0);
+        }
+
+        #[test]
+        fn test_put_on_min() {
+            let i1 = Instrument::new().with_spot(115.0);
+            let i2 = Instrument::new().with_spot(86.0);
+            let option =
+                RainbowOption::put_on_min(Instrument::new().with_assets(vec![i1, i2]), 105.0, 1.0);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 20);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 17.4028, epsilon = 2.0);
+
+            let price = model.price(&option.flip());
+            assert_abs_diff_eq!(price, 1.3140, epsilon = 2.0);
+        }
+
+        #[test]
+        fn test_call_on_average() {
+            let i1 = Instrument::new().with_spot(115.0);
+            let i2 = Instrument::new().with_spot(86.0);
+            let option =
+                RainbowOption::call_on_avg(Instrument::new().with_assets(vec![i1, i2]), 100.0, 1.0);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 20);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 8.6912, epsilon = 2.0);
+
+            let price = model.price(&option.flip());
+            assert_abs_diff_eq!(price, 3.3142, epsilon = 2.0);
+        }
+
+        #[test]
+        fn test_put_on_average() {
+            let i1 = Instrument::new().with_spot(115.0);
+            let i2 = Instrument::new().with_spot(86.0);
+            let option =
+                RainbowOption::put_on_avg(Instrument::new().with_assets(vec![i1, i2]), 110.0, 1.0);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 1_000, 20);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 8.1673, epsilon = 2.0);
+
+            let price = model.price(&option.flip());
+            assert_abs_diff_eq!(price, 4.0321, epsilon = 2.0);
+        }
+
+        #[test]
+        fn test_all_itm() {
+            let i1 = Instrument::new().with_spot(115.0);
+            let i2 = Instrument::new().with_spot(104.0);
+            let i3 = Instrument::new().with_spot(86.0);
+            let option =
+                RainbowOption::all_itm(Instrument::new().with_assets(vec![i1, i2, i3]), 105.0, 1.0);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 50_000, 20).with_seed(42);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 9.24, epsilon = 0.5);
+        }
+
+        #[test]
+        fn test_all_otm() {
+            let i1 = Instrument::new().with_spot(115.0);
+            let i2 = Instrument::new().with_spot(104.0);
+            let i3 = Instrument::new().with_spot(86.0);
+            let option =
+                RainbowOption::all_otm(Instrument::new().with_assets(vec![i1, i2, i3]), 105.0, 1.0);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 50_000, 20);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 8.49, epsilon = 0.5);
+        }
+
+        #[test]
+        fn test_correlated_assets() {
+            let instrument = Instrument::new()
+                .with_assets(vec![
+                    Instrument::new()
+                        .with_spot(100.0)
+                        .with_volatility(0.11)
+                        .with_continuous_dividend_yield(0.06),
+                    Instrument::new()
+                        .with_spot(105.0)
+                        .with_volatility(0.16)
+                        .with_continuous_dividend_yield(0.09),
+                ])
+                .with_correlation_matrix(vec![vec![1.0, 0.63], vec![0.63, 1.0]]);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 100_000, 1);
+
+            let option = RainbowOption::call_on_min(instrument.clone(), 98.0, 0.5);
+            assert_abs_diff_eq!(model.price(&option), 2.9339, epsilon = 0.05);
+            let option = RainbowOption::call_on_max(instrument, 98.0, 0.5);
+            assert_abs_diff_eq!(model.price(&option), 8.0701, epsilon = 0.08);
+        }
+    }
+
+    mod barrier_option_tests {
+        use super::*;
+
+        fn instrument() -> Instrument {
+            Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.04)
+        }
+
+        #[test]
+        fn test_knock_out() {
+            let model = MonteCarloModel::brownian(0.08, 0.25, 20_000, 200);
+
+            let option =
+                BarrierOption::down_and_out(instrument(), 90.0, 0.5, OptionType::Call, 95.0)
+                    .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 9.0246, epsilon = 0.3);
+            let option = BarrierOption::up_and_out(instrument(), 90.0, 0.5, OptionType::Put, 105.0)
+                .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 3.7760, epsilon = 0.2);
+        }
+
+        #[test]
+        fn test_knock_in() {
+            let model = MonteCarloModel::brownian(0.08, 0.25, 20_000, 200);
+
+            let option =
+                BarrierOption::down_and_in(instrument(), 90.0, 0.5, OptionType::Call, 95.0)
+                    .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 7.7627, epsilon = 0.3);
+            let option = BarrierOption::up_and_in(instrument(), 110.0, 0.5, OptionType::Put, 105.0)
+                .with_rebate(3.0);
+            assert_abs_diff_eq!(model.price(&option), 7.0846, epsilon = 0.3);
+        }
+
+        #[test]
+        fn test_discrete_monitoring() {
+            let model = MonteCarloModel::brownian(0.08, 0.25, 20_000, 26);
+            let option =
+                BarrierOption::down_and_out(instrument(), 100.0, 0.5, OptionType::Call, 95.0)
+                    .with_monitoring(Monitoring::Discrete(26));
+            assert_abs_diff_eq!(model.price(&option), 5.6012, epsilon = 0.25);
+        }
+
+        #[test]
+        fn test_double_barrier() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let model = MonteCarloModel::brownian(0.1, 0.25, 20_000, 200);
+
+            let option = BarrierOption::double_knock_out(
+                instrument.clone(),
+                100.0,
+                0.25,
+                OptionType::Call,
+                80.0,
+                120.0,
+            )
+            .with_rebate(2.0);
+            assert_abs_diff_eq!(model.price(&option), 3.0979, epsilon = 0.2);
+            let option = BarrierOption::double_knock_in(
+                instrument,
+                100.0,
+                0.25,
+                OptionType::Put,
+                80.0,
+                120.0,
+            );
+            assert_abs_diff_eq!(model.price(&option), 1.0989, epsilon = 0.15);
+        }
+    }
+
+    mod basket_option_tests {
+        use super::*;
+
+        #[test]
+        fn test_two_assets() {
+            let instrument = Instrument::new()
+                .with_weighted_assets(vec![
+                    (Instrument::new().with_spot(100.0).with_volatility(0.2), 0.5),
+                    (Instrument::new().with_spot(100.0).with_volatility(0.3), 0.5),
+                ])
+                .with_correlation_matrix(vec![vec![1.0, 0.5], vec![0.5, 1.0]]);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 100_000, 1);
+
+            let call = BasketOption::call(instrument.clone(), 100.0, 1.0);
+            assert_abs_diff_eq!(model.price(&call), 11.13, epsilon = 0.15);
+            let put = BasketOption::put(instrument, 100.0, 1.0);
+            assert_abs_diff_eq!(model.price(&put), 6.25, epsilon = 0.1);
+        }
+
+        #[test]
+        fn test_three_assets() {
+            let instrument = Instrument::new()
+                .with_correlation_matrix(vec![
+                    vec![1.0, 0.6, 0.2],
+                    vec![0.6, 1.0, 0.3],
+                    vec![0.2, 0.3, 1.0],
+                ])
+                .with_weighted_assets(vec![
+                    (
+                        Instrument::new()
+                            .with_spot(90.0)
+                            .with_volatility(0.35)
+                            .with_continuous_dividend_yield(0.03),
+                        0.2,
+                    ),
+                    (
+                        Instrument::new()
+                            .with_spot(100.0)
+                            .with_volatility(0.2)
+                            .with_continuous_dividend_yield(0.01),
+                        0.3,
+                    ),
+                    (
+                        Instrument::new()
+                            .with_spot(110.0)
+                            .with_volatility(0.25)
+                            .with_continuous_dividend_yield(0.02),
+                        0.5,
+                    ),
+                ]);
+            let model = MonteCarloModel::brownian(0.04, 0.2, 100_000, 1);
+
+            let call = BasketOption::call(instrument.clone(), 95.0, 0.75);
+            assert_abs_diff_eq!(model.price(&call), 12.17, epsilon = 0.15);
+            let put = BasketOption::put(instrument, 95.0, 0.75);
+            assert_abs_diff_eq!(model.price(&put), 2.81, epsilon = 0.08);
+        }
+
+        #[test]
+        fn test_model_volatility_fallback() {
+            let instrument = Instrument::new().with_weighted_assets(vec![
+                (Instrument::new().with_spot(100.0), 0.5),
+                (Instrument::new().with_spot(100.0), 0.5),
+            ]);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 100_000, 1).with_seed(42);
+            let basket = BasketOption::call(instrument.clone(), 100.0, 1.0);
+
+            // Independent assets diversify the basket volatility down to 0.2 / √2
+            let expected = BlackScholesModel::new(0.05, 0.2).price(&basket);
+            assert_abs_diff_eq!(model.price(&basket), expected, epsilon = 0.1);
+            assert!(model.price(&basket) < 9.0);
+        }
+
+        #[test]
+        fn test_invalid_correlation() {
+            let instrument = Instrument::new()
+                .with_weighted_assets(vec![
+                    (Instrument::new().with_spot(100.0), 0.5),
+                    (Instrument::new().with_spot(90.0), 0.5),
+                ])
+                .with_correlation_matrix(vec![vec![1.0, 1.5], vec![1.5, 1.0]]);
+            let model = MonteCarloModel::brownian(0.05, 0.2, 100, 1);
+            let basket = BasketOption::call(instrument, 100.0, 1.0);
+
+            assert!(std::panic::catch_unwind(|| model.price(&basket)).is_err());
+
+            // Three-asset matrix for a two-asset basket
+            let instrument = Instrument::new()
+                .with_weighted_assets(vec![
+                    (Instrument::new().with_spot(100.0), 0.5),
+                    (Instrument::new().with_spot(90.0), 0.5),
+                ])
+                .with_correlation_matrix(vec![
+                    vec![1.0, 0.5, 0.5],
+                    vec![0.5, 1.0, 0.5],
+                    vec![0.5, 0.5, 1.0],
+                ]);
+            let basket = BasketOption::call(instrument, 100.0, 1.0);
+            for result in [
+                model.try_price(&basket),
+                BlackScholesModel::new(0.05, 0.2).try_price(&basket),
+                BlackScholesModel::new(0.05, 0.2).try_price(&RainbowOption::call_on_max(
+                    basket.instrument.clone(),
+                    100.0,
+                    1.0,
+                )),
+            ] {
+                assert!(matches!(result, Err(PricingError::InvalidInput(_))));
+            }
+
+            // Not positive semi-definite, not symmetric and without a unit diagonal
+            let three_assets = vec![
+                Instrument::new().with_spot(100.0),
+                Instrument::new().with_spot(95.0),
+                Instrument::new().with_spot(90.0),
+            ];
+            for correlation in [
+                vec![
+                    vec![1.0, -0.9, -0.9],
+                    vec![-0.9, 1.0, -0.9],
+                    vec![-0.9, -0.9, 1.0],
+                ],
+                vec![
+                    vec![1.0, 0.2, 0.0],
+                    vec![0.3, 1.0, 0.0],
+                    vec![0.0, 0.0, 1.0],
+                ],
+                vec![
+                    vec![2.0, 0.0, 0.0],
+                    vec![0.0, 1.0, 0.0],
+                    vec![0.0, 0.0, 1.0],
+                ],
+            ] {
+                let instrument = Instrument::new()
+                    .with_assets(three_assets.clone())
+                    .with_correlation_matrix(correlation);
+                let basket = BasketOption::call(instrument, 100.0, 1.0);
+                assert!(matches!(
+                    model.try_price(&basket),
+                    Err(PricingError::InvalidInput(_))
+                ));
+                assert!(matches!(
+                    BlackScholesModel::new(0.05, 0.2).try_price(&basket),
+                    Err(PricingError::InvalidInput(_))
+                ));
+            }
+        }
+    }
+}
+
+// Black-76 Model Tests
+mod black_76_tests {
+    use super::*;
+
+    mod european_option_tests {
+        use super::*;
+
+        #[test]
+        fn test_itm() {
+            let instrument = Instrument::new().with_spot(2006.0);
+            let option = EuropeanOption::new(instrument, 2100.0, 0.08493, OptionType::Call);
+            let model = Black76Model::new(0.050067, 0.35);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 44.5829, epsilon = 0.0001);
+
+            let price = model.price(&option.flip());
+            assert_abs_diff_eq!(price, 138.1841, epsilon = 0.0001);
+        }
+
+        #[test]
+        fn test_otm() {
+            let instrument = Instrument::new().with_spot(2100.0);
+            let option = EuropeanOption::new(instrument, 2006.0, 0.08493, OptionType::Call);
+            let model = Black76Model::new(0.050067, 0.35);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 138.1841, epsilon = 0.0001);
+
+            let price = model.price(&option.flip());
+            assert_abs_diff_eq!(price, 44.5829, epsilon = 0.0001);
+        }
+
+        // #[test]
+        // fn test_div_itm() {
+        //     let instrument = Instrument::new()
+        //         .with_spot(120.0)
+        //         .with_continuous_dividend_yield(0.01);
+        //     let option = EuropeanOption::new(instrument, 100.0, 2.0, OptionType::Call);
+        //     let model = Black76Model::new(0.03, 0.27);
+        //
+        //     let price = model.price(&option);
+        //     assert_abs_diff_eq!(price, 30.3564, epsilon = 0.0001);
+        //
+        //     let price = model.price(&option.flip());
+        //     assert_abs_diff_eq!(price, 6.9091, epsilon = 0.0001);
+        // }
+        //
+        // #[test]
+        // fn test_div_otm() {
+        //     let instrument = Instrument::new()
+        //         .with_spot(50.0)
+        //         .with_continuous_dividend_yield(0.05);
+        //     let option = EuropeanOption::new(instrument, 65.0, 0.43, OptionType::Call);
+        //     let model = Black76Model::new(0.1, 0.31);
+        //
+        //     let price = model.price(&option);
+        //     assert_abs_diff_eq!(price, 0.6470, epsilon = 0.0001);
+        //
+        //     let price = model.price(&option.flip());
+        //     assert_abs_diff_eq!(price, 13.9748, epsilon = 0.0001);
+        // }
+
+        #[test]
+        fn test_edge() {
+            let instrument = Instrument::new().with_spot(120.0);
+            let option = EuropeanOption::new(instrument, 100.0, 0.0, OptionType::Call);
+            let model = Black76Model::new(0.03, 0.27);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 20.0, epsilon = 0.0001);
+
+            let instrument = Instrument::new().with_spot(100.0);
+            let option = EuropeanOption::new(instrument, 120.0, 0.0, OptionType::Put);
+            let model = Black76Model::new(0.03, 0.27);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 20.0, epsilon = 0.0001);
+
+            let instrument = Instrument::new().with_spot(100.0);
+            let option = EuropeanOption::new(instrument, 100.0, 0.0, OptionType::Call);
+            let model = Black76Model::new(0.03, 0.27);
+
+            let price = model.price(&option);
+            assert!(price.is_nan());
+
+            let price = model.price(&option.flip());
+            assert!(price.is_nan());
+
+            let instrument = Instrument::new().with_spot(0.0);
+            let option = EuropeanOption::new(instrument, 0.0, 0.0, OptionType::Call);
+            let model = Black76Model::new(0.0, 0.0);
+
+            let price = model.price(&option);
+            assert!(price.is_nan());
+
+            let price = model.price(&option.flip());
+            assert!(price.is_nan());
+        }
+
+        #[test]
+        fn test_call_greeks() {
+            let option = EuropeanOption::new(
+                Instrument::new().with_spot(2006.0),
+                2100.0,
+                0.08493,
+                OptionType::Call,
+            );
+            let model = Black76Model::new(0.050067, 0.35);
+
+            // Sanity check for input values
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 44.5829, epsilon = 0.0001);
+
+            let delta = model.delta(&option);
+            assert_abs_diff_eq!(delta, 0.3438, epsilon = 0.0001);
+            let gamma = model.gamma(&option);
+            assert_abs_diff_eq!(gamma, 0.0018, epsilon = 0.0001);
+            let vega = model.vega(&option);
+            assert_abs_diff_eq!(vega, 214.5523, epsilon = 0.0001);
+            let rho = model.rho(&option);
+            assert_abs_diff_eq!(rho, -3.7864, epsilon = 0.0001);
+            let theta = model.theta(&option);
+            assert_abs_diff_eq!(theta, -439.8573, epsilon = 0.0001);
+        }
+
+        #[test]
+        fn test_put_greeks() {
+            let option = EuropeanOption::new(
+                Instrument::new().with_spot(110.0),
+                100.0,
+                0.43,
+                OptionType::Put,
+            );
+            let model = Black76Model::new(0.05, 0.2);
+
+            // Sanity check for input values
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 1.8356, epsilon = 0.0001);
+
+            let delta = model.delta(&option);
+            assert_abs_diff_eq!(delta, -0.2095, epsilon = 0.0001);
+            let gamma = model.gamma(&option);
+            assert_abs_diff_eq!(gamma, 0.0197, epsilon = 0.0001);
+            let vega = model.vega(&option);
+            assert_abs_diff_eq!(vega, 20.5771, epsilon = 0.0001);
+            let rho = model.rho(&option);
+            assert_abs_diff_eq!(rho, -0.7893, epsilon = 0.0001);
+            let theta = model.theta(&option);
+            assert_abs_diff_eq!(theta, -4.6936, epsilon = 0.0001);
+        }
+
+        #[test]
+        fn test_higher_order_greeks() {
+            let option = EuropeanOption::new(
+                Instrument::new().with_spot(2006.0),
+                2100.0,
+                0.08493,
+                OptionType::Call,
+            );
+            let model = Black76Model::new(0.050067, 0.35);
+
+            assert_abs_diff_eq!(model.lambda(&option), 15.4719, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.epsilon(&option), 0.0, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.vanna(&option), 0.5243, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.charm(&option), -1.0630, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.vomma(&option), 121.9712, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.veta(&option), -1503.6948, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.vera(&option), -18.2219, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.speed(&option), 0.0000026, epsilon = 0.0000001);
+            assert_abs_diff_eq!(model.zomma(&option), -0.0041051, epsilon = 0.0000001);
+            assert_abs_diff_eq!(model.color(&option), 0.0085484, epsilon = 0.0000001);
+            assert_abs_diff_eq!(model.ultima(&option), -994.3495, epsilon = 0.0001);
+
+            let option = EuropeanOption::new(
+                Instrument::new().with_spot(110.0),
+                100.0,
+                0.43,
+                OptionType::Put,
+            );
+            let model = Black76Model::new(0.05, 0.2);
+
+            assert_abs_diff_eq!(model.lambda(&option), -12.5563, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.vanna(&option), -0.9430, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.charm(&option), 0.2088, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.vomma(&option), 53.8958, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.veta(&option), -35.4320, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.vera(&option), -8.8482, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.speed(&option), -0.0012658, epsilon = 0.0000001);
+            assert_abs_diff_eq!(model.zomma(&option), -0.0470785, epsilon = 0.0000001);
+            assert_abs_diff_eq!(model.color(&option), 0.0119372, epsilon = 0.0000001);
+            assert_abs_diff_eq!(model.ultima(&option), -676.1209, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn test_black_76_iv() {
+        let option = EuropeanOption::new(
+            Instrument::new().with_spot(125.0),
+            130.0,
+            2.5,
+            OptionType::Call,
+        );
+        let model = Black76Model::new(0.02, 0.2);
+
+        let price = model.price(&option);
+        assert_abs_diff_eq!(
+            model.implied_volatility(&option, price),
+            0.2,
+            epsilon = 1e-10
+        );
+        let price = model.price(&option.flip());
+        assert_abs_diff_eq!(
+            model.implied_volatility(&option.flip(), price),
+            0.2,
+            epsilon = 1e-10
+        );
+
+        let iv = model.implied_volatility(&option, 15.0);
+        assert_abs_diff_eq!(
+            Black76Model::new(0.02, iv).price(&option),
+            15.0,
+            epsilon = 1e-10
+        );
+
+        let option = EuropeanOption::new(
+            Instrument::new().with_spot(100.0),
+            100.0,
+            1.0,
+            OptionType::Put,
+        );
+        let model = Black76Model::new(0.05, 0.2);
+        assert!(matches!(
+            model.try_implied_volatility(&option, 1200.0),
+            Err(PricingError::PriceOutOfBounds { .. })
+        ));
+    }
+}
+
+// Bachelier Model Tests
+mod bachelier_tests {
+    use super::*;
+
+    fn forward_option(forward: f64, strike: f64, option_type: OptionType) -> EuropeanOption {
+        EuropeanOption::new(
+            Instrument::new().with_spot(forward),
+            strike,
+            1.0,
+            option_type,
+        )
+    }
+
+    #[test]
+    fn test_price() {
+        let model = BachelierModel::new(0.03, 0.006);
+
+        // At the money the price is e^(-rT) σ √(T / 2π)
+        let atm = (-0.03_f64).exp() * 0.006 / (2.0 * std::f64::consts::PI).sqrt();
+        let call = forward_option(0.0, 0.0, OptionType::Call);
+        assert_abs_diff_eq!(model.price(&call), atm, epsilon = 1e-12);
+        assert_abs_diff_eq!(model.price(&call.flip()), atm, epsilon = 1e-12);
+
+        // Negative forward
+        let call = forward_option(-0.002, 0.0, OptionType::Call);
+        let put = forward_option(-0.002, 0.0, OptionType::Put);
+        assert_abs_diff_eq!(model.price(&call), 0.00148033, epsilon = 1e-8);
+        assert_abs_diff_eq!(model.price(&put), 0.00342122, epsilon = 1e-8);
+        assert_abs_diff_eq!(
+            model.price(&call) - model.price(&put),
+            (-0.03_f64).exp() * -0.002,
+            epsilon = 1e-15
+        );
+
+        // Without volatility the price is the discounted intrinsic value
+        let model = BachelierModel::new(0.03, 0.0);
+        assert_abs_diff_eq!(
+            model.price(&put),
+            (-0.03_f64).exp() * 0.002,
+            epsilon = 1e-15
+        );
+    }
+
+    #[test]
+    fn test_greeks() {
+        let model = BachelierModel::new(0.03, 0.006);
+        let numerical = NumericalGreeks::new(model.clone())
+            .with_spot_bump(1e-3)
+            .with_volatility_bump(1e-5);
+
+        for option in [
+            forward_option(0.01, 0.005, OptionType::Put),
+            forward_option(-0.002, 0.001, OptionType::Call),
+        ] {
+            let greeks = model.try_greeks(&option).unwrap();
+            let expected = numerical.try_greeks(&option).unwrap();
+            assert_abs_diff_eq!(greeks.delta, expected.delta, epsilon = 1e-6);
+            assert_abs_diff_eq!(greeks.gamma, expected.gamma, epsilon = 1e-3);
+            assert_abs_diff_eq!(greeks.vega, expected.vega, epsilon = 1e-6);
+            assert_abs_diff_eq!(greeks.theta, expected.theta, epsilon = 1e-8);
+            assert_abs_diff_eq!(greeks.rho, expected.rho, epsilon = 1e-8);
+            assert_abs_diff_eq!(greeks.vanna, expected.vanna, epsilon = 1e-3);
+            assert_abs_diff_eq!(greeks.charm, expected.charm, epsilon = 1e-5);
+            assert_abs_diff_eq!(greeks.vomma, expected.vomma, epsilon = 1e-3);
+            assert_abs_diff_eq!(greeks.veta, expected.veta, epsilon = 1e-5);
+            assert_eq!(greeks.epsilon, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_implied_volatility() {
+        let model = BachelierModel::new(0.03, 0.006);
+        for option in [
+            forward_option(-0.002, 0.0, OptionType::Call),
+            forward_option(-0.002, 0.0, OptionType::Put),
+            forward_option(0.01, 0.03, OptionType::Put),
+            forward_option(0.0, 0.0, OptionType::Call),
+        ] {
+            let price = model.price(&option);
+            assert_abs_diff_eq!(
+                BachelierModel::new(0.03, 0.02).implied_volatility(&option, price),
+                0.006,
+                epsilon = 1e-10
+            );
+        }
+
+        // Below the discounted intrinsic value
+        let put = forward_option(-0.002, 0.0, OptionType::Put);
+        assert!(matches!(
+            model.try_implied_volatility(&put, 0.001),
+            Err(PricingError::PriceOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn test_black_volatility() {
+        let option = EuropeanOption::new(
+            Instrument::new().with_spot(0.03),
+            0.035,
+            2.0,
+            OptionType::Call,
+        );
+        let model = BachelierModel::from_black_volatility(0.01, 0.2, &option).unwrap();
+        assert_abs_diff_eq!(
+            model.price(&option),
+            Black76Model::new(0.01, 0.2).price(&option),
+            epsilon = 1e-14
+        );
+        assert_abs_diff_eq!(
+            model.black_volatility(&option).unwrap(),
+            0.2,
+            epsilon = 1e-10
+        );
+        // Close to the money σ_N ≈ σ_B √(F K)
+        assert_abs_diff_eq!(
+            model.volatility,
+            0.2 * (0.03_f64 * 0.035).sqrt(),
+            epsilon = 1e-4
+        );
+
+        let negative = forward_option(-0.002, 0.0, OptionType::Put);
+        assert!(matches!(
+            BachelierModel::new(0.01, 0.006).black_volatility(&negative),
+            Err(PricingError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            BachelierModel::from_black_volatility(0.01, 0.2, &negative),
+            Err(PricingError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid() {
+        let model = BachelierModel::new(0.03, 0.006);
+        assert!(matches!(
+            model.try_price(&forward_option(f64::NAN, 0.0, OptionType::Call)),
+            Err(PricingError::InvalidInput(_))
+        ));
+
+        let option = AmericanOption::new(
+            Instrument::new().with_spot(-0.002),
+            0.0,
+            1.0,
+            OptionType::Put,
+        );
+        assert!(matches!(
+            model.try_price(&option),
+            Err(PricingError::UnsupportedStyle { .. })
+        ));
+        assert!(matches!(
+            model.try_delta(&option),
+            Err(PricingError::UnsupportedStyle { .. })
+        ));
+    }
+}
+
+// Heston Model Tests
+mod heston_tests {
+    use super::*;
+
+    mod european_option_tests {
+        use super::*;
+
+        #[test]
+        fn test_cash_dividends() {
+            let instrument = Instrument::new().with_spot(100.0).with_cash_dividends(vec![
+                CashDividend::new(0.25, 2.0),
+                CashDividend::new(0.5, 3.0),
+            ]);
+            let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+
+            // Without vol of vol the Heston price is the escrowed Black-Scholes price
+            let model = HestonModel::new(0.05, 0.04, 2.0, 0.04, 1e-8, 0.0);
+            let black_scholes = BlackScholesModel::new(0.05, 0.2);
+            assert_abs_diff_eq!(
+                model.price(&option),
+                black_scholes.price(&option),
+                epsilon = 1e-4
+            );
+            assert_abs_diff_eq!(
+                model.price(&option.flip()),
+                black_scholes.price(&option.flip()),
+                epsilon = 1e-4
+            );
+        }
+
+        #[test]
+        fn test_atm() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+            let model = HestonModel::new(0.05, 0.04, 2.0, 0.04, 0.3, -0.7);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 10.3942, epsilon = 0.0001);
+
+            // Put-call parity
+            let put = model.price(&option.flip());
+            assert_abs_diff_eq!(
+                price - put,
+                100.0 - 100.0 * (-0.05f64).exp(),
+                epsilon = 0.0001
+            );
+        }
+
+        #[test]
+        fn test_div_otm() {
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.02);
+            let option = EuropeanOption::new(instrument, 110.0, 0.5, OptionType::Call);
+            let model = HestonModel::new(0.03, 0.09, 1.5, 0.06, 0.5, -0.5);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 3.6869, epsilon = 0.0001);
+        }
+
+        #[test]
+        fn test_zero_vol_of_vol() {
+            let instrument = Instrument::new().with_spot(110.0);
+            let option = EuropeanOption::new(instrument, 100.0, 0.43, OptionType::Put);
+            let model = HestonModel::new(0.05, 0.04, 1.0, 0.04, 0.0, 0.0);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 1.3884, epsilon = 0.0001);
+
+            // Tiny vol of vol should converge to the deterministic variance limit
+            let model = HestonModel::new(0.05, 0.04, 1.0, 0.04, 1e-4, 0.0);
+            assert_abs_diff_eq!(model.price(&option), 1.3884, epsilon = 0.001);
+        }
+
+        #[test]
+        fn test_smile() {
+            let model = HestonModel::new(0.0, 0.04, 1.5, 0.04, 0.6, -0.8);
+            let iv = |strike: f64| {
+                let option = EuropeanOption::new(
+                    Instrument::new().with_spot(100.0),
+                    strike,
+                    1.0,
+                    OptionType::Call,
+                );
+                model.implied_volatility(&option, model.price(&option))
+            };
+
+            // Negative correlation produces a downward sloping skew
+            assert!(iv(80.0) > iv(100.0));
+            assert!(iv(100.0) > iv(120.0));
+        }
+
+        #[test]
+        fn test_greeks() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+            let model = HestonModel::new(0.05, 0.04, 2.0, 0.04, 0.3, -0.7);
+
+            let delta = model.delta(&option);
+            assert_abs_diff_eq!(delta, 0.6919, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.delta(&option.flip()), delta - 1.0, epsilon = 0.0001);
+
+            let gamma = model.gamma(&option);
+            assert!(gamma > 0.0);
+            let vega = model.vega(&option);
+            assert!(vega > 0.0);
+            let theta = model.theta(&option);
+            assert!(theta > 0.0);
+            let rho = model.rho(&option);
+            assert!(rho > 0.0);
+
+            let greeks = Greeks::calculate(&model, &option);
+            assert_abs_diff_eq!(greeks.delta, delta, epsilon = 1e-12);
+            assert_abs_diff_eq!(greeks.gamma, gamma, epsilon = 1e-12);
+
+            let result = std::panic::catch_unwind(|| {
+                let option = AmericanOption::new(
+                    Instrument::new().with_spot(100.0),
+                    100.0,
+                    1.0,
+                    OptionType::Put,
+                );
+                model.delta(&option)
+            });
+            assert!(result.is_err(), "Expected panic for unsupported style");
+        }
+    }
+
+    mod calibration_tests {
+        use super::*;
+
+        fn synthetic_quotes(model: &HestonModel, instrument: &Instrument) -> Vec<MarketQuote> {
+            let mut quotes = Vec::new();
+            for &ttm in &[0.25, 0.5, 1.0, 2.0] {
+                for &strike in &[80.0, 90.0, 100.0, 110.0, 120.0] {
+                    let option_type = if strike < 100.0 {
+                        OptionType::Put
+                    } else {
+                        OptionType::Call
+                    };
+                    let option = EuropeanOption::new(instrument.clone(), strike, ttm, option_type);
+                    quotes.push(MarketQuote::price(
+                        strike,
+                        ttm,
+                        option_type,
+                        model.price(&option),
+                    ));
+                }
+            }
+            quotes
+        }
+
+        #[test]
+        fn test_recover_parameters_from_prices() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let target = HestonModel::new(0.03, 0.05, 1.5, 0.06, 0.6, -0.6);
+            let quotes = synthetic_quotes(&target, &instrument);
+
+            let initial = HestonModel::new(0.03, 0.03, 1.0, 0.03, 0.3, -0.2);
+            let calibration = HestonCalibrator::new(CalibrationTarget::Price)
+                .calibrate(&initial, &instrument, &quotes)
+                .unwrap();
+
+            assert!(calibration.converged);
+            assert_eq!(calibration.residuals.len(), quotes.len());
+            assert!(calibration.rmse < 1e-4);
+            assert_abs_diff_eq!(calibration.model.v0, 0.05, epsilon = 1e-3);
+            assert_abs_diff_eq!(calibration.model.kappa, 1.5, epsilon = 0.05);
+            assert_abs_diff_eq!(calibration.model.theta, 0.06, epsilon = 1e-3);
+            assert_abs_diff_eq!(calibration.model.xi, 0.6, epsilon = 0.01);
+            assert_abs_diff_eq!(calibration.model.rho, -0.6, epsilon = 0.01);
+        }
+
+        #[test]
+        fn test_recover_parameters_from_implied_vols() {
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.01);
+            let target = HestonModel::new(0.02, 0.03, 2.0, 0.05, 0.4, -0.7);
+            let quotes = synthetic_quotes(&target, &instrument);
+
+            let calibration = HestonModel::new(0.02, 0.04, 1.0, 0.04, 0.5, -0.3)
+                .calibrate(&instrument, &quotes)
+                .unwrap();
+
+            assert!(calibration.rmse < 1e-5);
+            assert_abs_diff_eq!(calibration.model.v0, 0.03, epsilon = 1e-3);
+            assert_abs_diff_eq!(calibration.model.theta, 0.05, epsilon = 1e-3);
+            assert_abs_diff_eq!(calibration.model.rho, -0.7, epsilon = 0.02);
+            assert!(calibration.residuals.iter().all(|r| r.abs() < 1e-4));
+        }
+
+        #[test]
+        fn test_reject_invalid_quotes() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let initial = HestonModel::new(0.02, 0.04, 1.0, 0.04, 0.5, -0.3);
+
+            // Call price below the discounted intrinsic value
+            let quotes = [
+                MarketQuote::price(80.0, 1.0, OptionType::Call, 5.0),
+                MarketQuote::price(100.0, 1.0, OptionType::Call, 8.0),
+            ];
+            for target in [
+                CalibrationTarget::Price,
+                CalibrationTarget::ImpliedVolatility,
+            ] {
+                assert!(matches!(
+                    HestonCalibrator::new(target).calibrate(&initial, &instrument, &quotes),
+                    Err(PricingError::PriceOutOfBounds { .. })
+                ));
+            }
+
+            let quotes = [MarketQuote::implied_volatility(
+                100.0,
+                1.0,
+                OptionType::Call,
+                -0.2,
+            )];
+            assert!(matches!(
+                initial.calibrate(&instrument, &quotes),
+                Err(PricingError::InvalidInput(_))
+            ));
+        }
+    }
+}
+
+// Finite Difference Model Tests
+mod finite_diff_tests {
+    use super::*;
+
+    mod european_option_tests {
+        use super::*;
+
+        #[test]
+        fn test_schemes() {
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.02);
+            let option = EuropeanOption::new(instrument, 110.0, 1.0, OptionType::Call);
+            let bs = BlackScholesModel::new(0.05, 0.25);
+
+            let model = FiniteDiffModel::crank_nicolson(0.05, 0.25, 200, 200);
+            assert_abs_diff_eq!(model.price(&option), 7.1121, epsilon = 0.001);
+            assert_abs_diff_eq!(model.price(&option.flip()), 13.7275, epsilon = 0.001);
+            assert_abs_diff_eq!(model.price(&option), bs.price(&option), epsilon = 0.001);
+
+            let model = FiniteDiffModel::implicit(0.05, 0.25, 200, 200);
+            assert_abs_diff_eq!(model.price(&option), 7.1121, epsilon = 0.01);
+            assert_abs_diff_eq!(model.price(&option.flip()), 13.7275, epsilon = 0.01);
+
+            let model = FiniteDiffModel::explicit(0.05, 0.25, 10, 200);
+            assert_abs_diff_eq!(model.price(&option), 7.1121, epsilon = 0.005);
+            assert_abs_diff_eq!(model.price(&option.flip()), 13.7275, epsilon = 0.005);
+        }
+
+        #[test]
+        fn test_rannacher() {
+            // A strike on the grid with few time steps makes plain Crank-Nicolson oscillate
+            let instrument = Instrument::new().with_spot(100.0);
+            let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+            let model = FiniteDiffModel::crank_nicolson(0.05, 0.2, 25, 400);
+
+            assert_abs_diff_eq!(model.gamma(&option), 0.018762, epsilon = 0.0002);
+            let model = model.with_rannacher_steps(0);
+            assert!((model.gamma(&option) - 0.018762).abs() > 0.0002);
+        }
+
+        #[test]
+        fn test_greeks() {
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.02);
+            let option = EuropeanOption::new(instrument, 110.0, 1.0, OptionType::Call);
+            let model = FiniteDiffModel::crank_nicolson(0.05, 0.25, 200, 200);
+
+            assert_abs_diff_eq!(model.delta(&option), 0.4370, epsilon = 0.001);
+            assert_abs_diff_eq!(model.gamma(&option), 0.015497, epsilon = 0.0001);
+            assert_abs_diff_eq!(model.theta(&option), 5.7982, epsilon = 0.02);
+            assert_abs_diff_eq!(model.vega(&option), 38.7430, epsilon = 0.2);
+            assert_abs_diff_eq!(model.rho(&option), 36.5867, epsilon = 0.02);
+            assert_abs_diff_eq!(
+                model.delta(&option.flip()),
+                model.delta(&option) - (-0.02_f64).exp(),
+                epsilon = 0.001
+            );
+        }
+    }
+
+    mod american_option_tests {
+        use super::*;
+
+        #[test]
+        fn test_put() {
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.02);
+            let option = AmericanOption::new(instrument.clone(), 110.0, 1.0, OptionType::Put);
+            let european = EuropeanOption::new(instrument, 110.0, 1.0, OptionType::Put);
+            let model = FiniteDiffModel::crank_nicolson(0.05, 0.25, 200, 200);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 14.4286, epsilon = 0.005);
+            assert!(price > model.price(&european));
+
+            let price = FiniteDiffModel::implicit(0.05, 0.25, 200, 200).price(&option);
+            assert_abs_diff_eq!(price, 14.4286, epsilon = 0.02);
+        }
+
+        #[test]
+        fn test_call_no_dividends() {
+            // Early exercise of a call without dividends is never optimal
+            let instrument = Instrument::new().with_spot(100.0);
+            let option = AmericanOption::new(instrument.clone(), 100.0, 1.0, OptionType::Call);
+            let european = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+            let model = FiniteDiffModel::crank_nicolson(0.05, 0.2, 200, 200);
+
+            assert_abs_diff_eq!(model.price(&option), model.price(&european), epsilon = 1e-6);
+        }
+
+        #[test]
+        fn test_deep_itm_put() {
+            let instrument = Instrument::new().with_spot(50.0);
+            let option = AmericanOption::new(instrument, 100.0, 1.0, OptionType::Put);
+            let model = FiniteDiffModel::crank_nicolson(0.05, 0.2, 100, 200);
+
+            assert_abs_diff_eq!(model.price(&option), 50.0, epsilon = 1e-6);
+            assert_abs_diff_eq!(model.delta(&option), -1.0, epsilon = 1e-3);
+        }
+
+        #[test]
+        fn test_greeks() {
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.02);
+            let option = AmericanOption::new(instrument, 110.0, 1.0, OptionType::Put);
+            let model = FiniteDiffModel::crank_nicolson(0.05, 0.25, 200, 200);
+
+            assert_abs_diff_eq!(model.delta(&option), -0.5872, epsilon = 0.001);
+            assert_abs_diff_eq!(model.gamma(&option), 0.01818, epsilon = 0.0002);
+            assert!(model.theta(&option) > 0.0);
+        }
+
+        #[test]
+        fn test_expiry() {
+            let option = AmericanOption::new(
+                Instrument::new().with_spot(100.0),
+                110.0,
+                0.0,
+                OptionType::Put,
+            );
+            let model = FiniteDiffModel::crank_nicolson(0.05, 0.25, 200, 200);
+
+            assert_eq!(model.price(&option), 10.0);
+            assert_abs_diff_eq!(model.delta(&option), -1.0, epsilon = 0.01);
+            assert_eq!(model.try_theta(&option), Ok(0.0));
+            assert!(model.try_theta(&option.flip()).unwrap().is_finite());
+        }
+
+        #[test]
+        fn test_cash_dividends() {
+            let instrument = Instrument::new().with_spot(100.0).with_cash_dividends(vec![
+                CashDividend::new(0.25, 2.0),
+                CashDividend::new(0.5, 3.0),
+                CashDividend::new(1.5, 5.0),
+            ]);
+            let option = AmericanOption::new(instrument.clone(), 100.0, 1.0, OptionType::Put);
+            let european = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Put);
+            let model = FiniteDiffModel::crank_nicolson(0.05, 0.2, 200, 200);
+
+            assert_abs_diff_eq!(
+                model.price(&option),
+                BinomialTreeModel::new(0.05, 0.2, 500).price(&option),
+                epsilon = 0.01
+            );
+            assert_abs_diff_eq!(
+                model.price(&european),
+                BlackScholesModel::new(0.05, 0.2).price(&european),
+                epsilon = 0.005
+            );
+        }
+    }
+
+    mod bermudan_option_tests {
+        use super::*;
+
+        #[test]
+        fn test_put() {
+            let instrument = Instrument::new()
+                .with_spot(100.0)
+                .with_continuous_dividend_yield(0.02);
+            let expiration_dates = vec![0.25, 0.5, 0.75, 1.0];
+            let option =
+                BermudanOption::new(instrument.clone(), 110.0, expiration_dates, OptionType::Put);
+            let model = FiniteDiffModel::crank_nicolson(0.05, 0.25, 200, 200);
+
+            let price = model.price(&option);
+            assert_abs_diff_eq!(price, 14.2760, epsilon = 0.005);
+
+            let american = AmericanOption::new(instrument.clone(), 110.0, 1.0, OptionType::Put);
+            let european = EuropeanOption::new(instrument, 110.0, 1.0, OptionType::Put);
+            assert!(price < model.price(&american));
+            assert!(price > model.price(&european));
+        }
+
+        #[test]
+        fn test_unsupported_style() {
+            let instrument = Instrument::new().with_spot(100.0);
+            let option = AsianOption::fixed(instrument, 100.0, 1.0, OptionType::Call);
+            let model = FiniteDiffModel::crank_nicolson(0.05, 0.2, 10, 20);
+
+            let result = std::panic::catch_unwind(|| model.price(&option));
+            assert!(result.is_err(), "Expected panic for unsupported style");
+        }
+    }
+}
+
+// Jump-Diffusion Model Tests
+mod jump_diffusion_tests {
+    use super::*;
+
+    fn option() -> EuropeanOption {
+        EuropeanOption::new(
+            Instrument::new().with_spot(100.0),
+            100.0,
+            1.0,
+            OptionType::Call,
+        )
+    }
+
+    #[test]
+    fn test_no_jumps() {
+        let bs_price = BlackScholesModel::new(0.05, 0.2).price(&option());
+
+        let model = MertonJumpModel::new(0.05, 0.2, 0.0, -0.1, 0.15);
+        assert_abs_diff_eq!(model.price(&option()), bs_price, epsilon = 1e-10);
+        let model = KouJumpModel::new(0.05, 0.2, 0.0, 0.4, 10.0, 5.0);
+        assert_abs_diff_eq!(model.price(&option()), bs_price, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_merton_price() {
+        let model = MertonJumpModel::new(0.05, 0.2, 1.0, -0.1, 0.15);
+        let call = model.price(&option());
+        let put = model.price(&option().flip());
+
+        assert_abs_diff_eq!(call, 12.7613, epsilon = 0.0001);
+        assert_abs_diff_eq!(put, 7.8842, epsilon = 0.0001);
+        assert_abs_diff_eq!(
+            call - put,
+            100.0 - 100.0 * (-0.05_f64).exp(),
+            epsilon = 1e-8
+        );
+
+        // Jumps fatten the tails of short-dated options
+        let option = EuropeanOption::new(
+            Instrument::new().with_spot(100.0),
+            90.0,
+            0.05,
+            OptionType::Put,
+        );
+        let implied_volatility = model.implied_volatility(&option, model.price(&option));
+        assert!(implied_volatility > 0.3);
+    }
+
+    #[test]
+    fn test_kou_price() {
+        // Kou (2002)
+        let model = KouJumpModel::new(0.05, 0.16, 1.0, 0.4, 10.0, 5.0);
+        let option = EuropeanOption::new(
+            Instrument::new().with_spot(100.0),
+            98.0,
+            0.5,
+            OptionType::Call,
+        );
+        let call = model.price(&option);
+
+        assert_abs_diff_eq!(call, 9.14732, epsilon = 0.00001);
+        assert_abs_diff_eq!(
+            call - model.price(&option.flip()),
+            100.0 - 98.0 * (-0.025_f64).exp(),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn test_greeks() {
+        let merton = MertonJumpModel::new(0.05, 0.2, 1.0, -0.1, 0.15);
+        let numerical = NumericalGreeks::new(merton.clone());
+        assert_abs_diff_eq!(
+            merton.delta(&option()),
+            numerical.delta(&option()),
+            epsilon = 1e-3
+        );
+        assert_abs_diff_eq!(
+            merton.gamma(&option()),
+            numerical.gamma(&option()),
+            epsilon = 1e-4
+        );
+        assert_abs_diff_eq!(
+            merton.vega(&option()),
+            numerical.vega(&option()),
+            epsilon = 0.05
+        );
+        assert_abs_diff_eq!(
+            merton.theta(&option()),
+            numerical.theta(&option()),
+            epsilon = 1e-3
+        );
+        assert_abs_diff_eq!(
+            merton.rho(&option()),
+            numerical.rho(&option()),
+            epsilon = 1e-3
+        );
+
+        let kou = KouJumpModel::new(0.05, 0.2, 1.0, 0.4, 10.0, 5.0);
+        let numerical = NumericalGreeks::new(kou.clone());
+        assert_abs_diff_eq!(
+            kou.delta(&option()),
+            numerical.delta(&option()),
+            epsilon = 1e-3
+        );
+        assert_abs_diff_eq!(
+            kou.gamma(&option()),
+            numerical.gamma(&option()),
+            epsilon = 1e-4
+        );
+        assert_abs_diff_eq!(
+            kou.vega(&option()),
+            numerical.vega(&option()),
+            epsilon = 0.05
+        );
+        assert_abs_diff_eq!(
+            kou.theta(&option()),
+            numerical.theta(&option()),
+            epsilon = 1e-3
+        );
+        assert_abs_diff_eq!(kou.rho(&option()), numerical.rho(&option()), epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_monte_carlo() {
+        let merton = MertonJumpModel::new(0.05, 0.2, 1.0, -0.1, 0.15);
+        let model = MonteCarloModel::brownian(0.05, 0.2, 20_000, 10)
+            .with_seed(42)
+            .with_jumps(merton.jump_process());
+        assert_abs_diff_eq!(
+            model.price(&option()),
+            merton.price(&option()),
+            epsilon = 0.4
+        );
+
+        let kou = KouJumpModel::new(0.05, 0.16, 1.0, 0.4, 10.0, 5.0);
+        let model = MonteCarloModel::brownian(0.05, 0.16, 20_000, 10)
+            .with_seed(42)
+            .with_jumps(kou.jump_process());
+        assert_abs_diff_eq!(model.price(&option()), kou.price(&option()), epsilon = 0.4);
+
+        let model = MonteCarloModel::arithmetic(0.05, 0.2, 10_000, 50)
+            .with_seed(42)
+            .with_jumps(merton.jump_process());
+        let instrument = Instrument::new().with_spot(100.0);
+        let asian = AsianOption::fixed(instrument.clone(), 100.0, 1.0, OptionType::Call);
+        let asian_price = model.price(&asian);
+        assert!(asian_price > 0.0 && asian_price < merton.price(&option()));
+        let lookback = LookbackOption::floating(instrument, 1.0, OptionType::Call);
+        assert!(model.price(&lookback) > merton.price(&option()));
+    }
+
+    #[test]
+    fn test_invalid() {
+        let model = KouJumpModel::new(0.05, 0.2, 1.0, 0.4, 0.5, 5.0);
+        assert!(matches!(
+            model.try_price(&option()),
+            Err(PricingError::InvalidInput(_))
+        ));
+        let model =
+            MonteCarloModel::brownian(0.05, 0.2, 1_000, 10).with_jumps(model.jump_process());
+        assert!(matches!(
+            model.try_price(&option()),
+            Err(PricingError::InvalidInput(_))
+        ));
+
+        let model = MertonJumpModel::new(0.05, 0.2, 1.0, -0.1, 0.15);
+        let option = AmericanOption::new(
+            Instrument::new().with_spot(100.0),
+            100.0,
+            1.0,
+            OptionType::Put,
+        );
+        assert!(matches!(
+            model.try_price(&option),
+            Err(PricingError::UnsupportedStyle { .. })
+        ));
+    }
+}
+
+// Local Volatility Model Tests
+mod local_vol_tests {
+    use super::*;
+
+    const STRIKES: [f64; 7] = [70.0, 80.0, 90.0, 100.0, 110.0, 120.0, 130.0];
+
+    fn model(volatilities: &[Vec<f64>]) -> LocalVolModel {
+        let instrument = Instrument::new().with_spot(100.0);
+        LocalVolModel::from_implied_volatilities(
+            &instrument,
+            0.03,
+            &[0.5, 1.0],
+            &STRIKES,
+            volatilities,
+        )
+        .unwrap()
+    }
+
+    fn smile() -> Vec<Vec<f64>> {
+        vec![
+            vec![0.3, 0.26, 0.23, 0.21, 0.2, 0.205, 0.215],
+            vec![0.28, 0.25, 0.225, 0.21, 0.2, 0.2, 0.205],
+        ]
+    }
+
+    #[test]
+    fn test_flat_surface() {
+        let model = model(&[vec![0.2; 7], vec![0.2; 7]]);
+        let instrument = Instrument::new().with_spot(100.0);
+        let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+        let bs_price = BlackScholesModel::new(0.03, 0.2).price(&option);
+
+        assert_abs_diff_eq!(model.local_volatility(0.5, 100.0), 0.2, epsilon = 1e-4);
+        assert_abs_diff_eq!(model.local_volatility(0.75, 120.0), 0.2, epsilon = 1e-4);
+        assert_abs_diff_eq!(model.price(&option), bs_price, epsilon = 0.01);
+
+        let model =
+            model.with_monte_carlo(MonteCarloModel::brownian(0.0, 0.0, 10_000, 50).with_seed(42));
+        assert_abs_diff_eq!(model.price(&option), bs_price, epsilon = 0.2);
+    }
+
+    #[test]
+    fn test_reprices_smile() {
+        let model = model(&smile());
+        let instrument = Instrument::new().with_spot(100.0);
+        let bs_model = BlackScholesModel::new(0.03, 0.0).with_vol_surface(model.surface.clone());
+
+        for (strike, ttm) in [(85.0, 0.5), (90.0, 1.0), (100.0, 0.75), (110.0, 1.0)] {
+            let option = EuropeanOption::new(instrument.clone(), strike, ttm, OptionType::Put);
+            assert_abs_diff_eq!(
+                model.price(&option),
+                bs_model.price(&option),
+                epsilon = 0.02
+            );
+        }
+
+        let option = EuropeanOption::new(instrument, 90.0, 1.0, OptionType::Put);
+        let implied_volatility = model.implied_volatility(&option, model.price(&option));
+        assert_abs_diff_eq!(
+            implied_volatility,
+            model.surface.volatility(1.0, 90.0),
+            epsilon = 1e-3
+        );
+    }
+
+    #[test]
+    fn test_local_volatility() {
+        let model = model(&smile());
+
+        // The skew of the implied volatility is roughly doubled in the local volatility
+        assert!(model.local_volatility(0.75, 90.0) > model.surface.volatility(0.75, 90.0));
+        assert!(model.local_volatility(0.75, 110.0) < model.surface.volatility(0.75, 110.0));
+        assert!(model.local_volatility(0.75, 100.0) > 0.0);
+    }
+
+    #[test]
+    fn test_exotics() {
+        let model = model(&smile());
+        let instrument = Instrument::new().with_spot(100.0);
+        let european = EuropeanOption::new(instrument.clone(), 100.0, 1.0, OptionType::Call);
+        let european_price = model.price(&european);
+
+        let brownian = model
+            .clone()
+            .with_monte_carlo(MonteCarloModel::brownian(0.0, 0.0, 10_000, 50).with_seed(42));
+        let barrier =
+            BarrierOption::down_and_out(instrument.clone(), 100.0, 1.0, OptionType::Call, 85.0);
+        let barrier_price = brownian.price(&barrier);
+        assert!(barrier_price > 0.0 && barrier_price < european_price);
+
+        let arithmetic =
+            model.with_monte_carlo(MonteCarloModel::arithmetic(0.0, 0.0, 10_000, 50).with_seed(42));
+        let lookback = LookbackOption::floating(instrument, 1.0, OptionType::Call);
+        assert!(arithmetic.price(&lookback) > european_price);
+    }
+
+    #[test]
+    fn test_greeks() {
+        let model = model(&smile());
+        let instrument = Instrument::new().with_spot(100.0);
+        let option = EuropeanOption::new(instrument.clone(), 90.0, 1.0, OptionType::Put);
+
+        assert!(model.delta(&option) < 0.0 && model.delta(&option) > -0.5);
+        assert!(model.gamma(&option) > 0.0);
+        assert!(model.vega(&option) > 0.0);
+
+        let american = AmericanOption::new(instrument, 100.0, 1.0, OptionType::Put);
+        assert!(model.price(&american) >= model.price(&option));
+    }
+
+    #[test]
+    fn test_invalid() {
+        let instrument = Instrument::new().with_spot(100.0);
+        assert!(matches!(
+            LocalVolModel::from_implied_volatilities(
+                &instrument,
+                0.03,
+                &[0.5, 1.0],
+                &STRIKES,
+                &[vec![0.2; 7]],
+            ),
+            Err(PricingError::InvalidInput(_))
+        ));
+
+        let model = model(&smile())
+            .with_monte_carlo(MonteCarloModel::brownian(0.0, 0.0, 1_000, 10).with_seed(42));
+        let assets = Instrument::new()
+            .with_assets(vec![
+                instrument.clone().with_volatility(0.2),
+                instrument.with_volatility(0.2),
+            ])
+            .with_correlation_matrix(vec![vec![1.0, 0.5], vec![0.5, 1.0]]);
+        let basket = BasketOption::new(assets, 100.0, 1.0, OptionType::Call);
+        assert!(matches!(
+            model.try_price(&basket),
+            Err(PricingError::NotImplemented(_))
+        ));
+    }
+}
+
+// SABR Model Tests
+mod sabr_tests {
+    use super::*;
+
+    fn sabr() -> SabrModel {
+        SabrModel::new(0.03, 0.5, -0.3, 0.4)
+    }
+
+    #[test]
+    fn test_lognormal_volatility() {
+        // At the money: α / F^(1-β) (1 + ((1-β)² α² / (24 F^(2-2β)) + ρβνα / (4 F^(1-β)) + (2-3ρ²) ν² / 24) T)
+        assert_abs_diff_eq!(
+            sabr().lognormal_volatility(0.03, 0.03, 2.0),
+            0.176409,
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(
+            sabr().lognormal_volatility(0.03, 0.02, 2.0),
+            0.233022,
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(
+            sabr().lognormal_volatility(0.03, 0.04, 2.0),
+            0.160269,
+            epsilon = 1e-6
+        );
+
+        // Obloj only differs from Hagan away from the money
+        let obloj = sabr().with_expansion(SabrExpansion::Obloj);
+        assert_abs_diff_eq!(
+            obloj.lognormal_volatility(0.03, 0.03, 2.0),
+            0.176409,
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(
+            obloj.lognormal_volatility(0.03, 0.02, 2.0),
+            0.233101,
+            epsilon = 1e-6
+        );
+
+        // Without volatility of volatility and with β = 1 the model is Black-76
+        let black = SabrModel::new(0.2, 1.0, 0.0, 0.0);
+        assert_abs_diff_eq!(
+            black.lognormal_volatility(0.03, 0.04, 1.0),
+            0.2,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            black.shifted(0.01).lognormal_volatility(0.03, 0.04, 1.0),
+            0.21,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_normal_volatility() {
+        // Without volatility of volatility and with β = 0 the model is Bachelier
+        let normal = SabrModel::new(0.2, 0.0, 0.0, 0.0);
+        assert_abs_diff_eq!(
+            normal.normal_volatility(0.03, 0.03, 1.0),
+            0.2,
+            epsilon = 1e-12
+        );
+
+        // The normal and lognormal volatilities give the same prices
+        let forward = 0.03;
+        for strike in [0.02, 0.03, 0.04] {
+            let option = EuropeanOption::new(
+                Instrument::new().with_spot(forward),
+                strike,
+                2.0,
+                OptionType::Call,
+            );
+            let black_price =
+                Black76Model::new(0.0, sabr().lognormal_volatility(forward, strike, 2.0))
+                    .price(&option);
+            let deviation = sabr().normal_volatility(forward, strike, 2.0) * 2.0_f64.sqrt();
+            let d = (forward - strike) / deviation;
+            let normal = statrs::distribution::Normal::new(0.0, 1.0).unwrap();
+            let bachelier_price = (forward - strike)
+                * statrs::distribution::ContinuousCDF::cdf(&normal, d)
+                + deviation * statrs::distribution::Continuous::pdf(&normal, d);
+            assert_abs_diff_eq!(black_price, bachelier_price, epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_volatility_source() {
+        let option = EuropeanOption::new(
+            Instrument::new().with_spot(0.03),
+            0.035,
+            2.0,
+            OptionType::Put,
+        );
+        let volatility = sabr().lognormal_volatility(0.03, 0.035, 2.0);
+
+        let model = Black76Model::new(0.02, 0.1).with_sabr(sabr());
+        let flat = Black76Model::new(0.02, volatility);
+        assert_abs_diff_eq!(model.price(&option), flat.price(&option), epsilon = 1e-12);
+        assert_abs_diff_eq!(model.delta(&option), flat.delta(&option), epsilon = 1e-12);
+        assert_abs_diff_eq!(model.vega(&option), flat.vega(&option), epsilon = 1e-8);
+
+        // The forward of the spot includes the rate and the dividend yield
+        let instrument = Instrument::new()
+            .with_spot(100.0)
+            .with_continuous_dividend_yield(0.01);
+        let option = EuropeanOption::new(instrument, 95.0, 1.0, OptionType::Call);
+        let sabr = SabrModel::new(2.0, 0.5, -0.3, 0.4);
+        let forward = 100.0 * (0.04_f64).exp();
+        let model = BlackScholesModel::new(0.05, 0.1).with_sabr(sabr.clone());
+        let flat = BlackScholesModel::new(0.05, sabr.lognormal_volatility(forward, 95.0, 1.0));
+        assert_abs_diff_eq!(model.price(&option), flat.price(&option), epsilon = 1e-10);
+        assert_abs_diff_eq!(model.rho(&option), flat.rho(&option), epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_calibration() {
+        let quotes: Vec<MarketQuote> = [0.02, 0.025, 0.03, 0.035, 0.04]
+            .iter()
+            .map(|&strike| {
+                MarketQuote::implied_volatility(
+                    strike,
+                    2.0,
+                    OptionType::Call,
+                    sabr().lognormal_volatility(0.03, strike, 2.0),
+                )
+            })
+            .collect();
+        let calibration = SabrModel::new(0.02, 0.5, 0.0, 0.5)
+            .calibrate(0.03, &quotes)
+            .unwrap();
+
+        assert!(calibration.converged);
+        assert!(calibration.rmse < 1e-8);
+        assert_eq!(calibration.residuals.len(), quotes.len());
+        assert_abs_diff_eq!(calibration.model.alpha, 0.03, epsilon = 1e-6);
+        assert_abs_diff_eq!(calibration.model.beta, 0.5, epsilon = 1e-12);
+        assert_abs_diff_eq!(calibration.model.rho, -0.3, epsilon = 1e-4);
+        assert_abs_diff_eq!(calibration.model.nu, 0.4, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_invalid_calibration() {
+        let quote = |strike: f64, expiry: f64| {
+            MarketQuote::implied_volatility(strike, expiry, OptionType::Call, 0.2)
+        };
+        let calibrator = SabrCalibrator::default();
+
+        let quotes = vec![quote(0.02, 1.0), quote(0.03, 1.0)];
+        assert!(matches!(
+            calibrator.calibrate(&sabr(), 0.03, &quotes),
+            Err(PricingError::InvalidInput(_))
+        ));
+        let quotes = vec![quote(0.02, 1.0), quote(0.03, 1.0), quote(0.04, 2.0)];
+        assert!(matches!(
+            calibrator.calibrate(&sabr(), 0.03, &quotes),
+            Err(PricingError::InvalidInput(_))
+        ));
+        let quotes = vec![quote(0.02, 1.0), quote(0.03, 1.0), quote(0.04, 1.0)];
+        assert!(matches!(
+            calibrator.calibrate(&sabr(), -0.03, &quotes),
+            Err(PricingError::InvalidInput(_))
+        ));
+        let quotes = vec![
+            quote(0.02, 1.0),
+            quote(0.03, 1.0),
+            MarketQuote::price(0.04, 1.0, OptionType::Call, 0.001),
+        ];
+        assert!(matches!(
+            calibrator.calibrate(&sabr(), 0.03, &quotes),
+            Err(PricingError::InvalidInput(_))
+        ));
+    }
+}
+
+// Greeks Tests
+mod greeks_tests {
+    use super::*;
+
+    #[test]
+    fn test_greeks() {
+        let instrument = Instrument::new()
+            .with_spot(100.0)
+            .with_continuous_dividend_yield(0.01);
+        let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+        let model = BlackScholesModel::new(0.05, 0.2);
+
+        let greeks = Greeks::calculate(&model, &option);
+
+        assert_abs_diff_eq!(greeks.delta, 0.6118, epsilon = 0.0001);
+        assert_abs_diff_eq!(greeks.vega, 37.7593, epsilon = 0.0001);
+        assert_abs_diff_eq!(greeks.theta, 5.7317, epsilon = 0.0001);
+        assert_abs_diff_eq!(greeks.rho, 51.3500, epsilon = 0.0001);
+
+        assert_abs_diff_eq!(greeks.lambda, 6.2258, epsilon = 0.0001);
+        assert_abs_diff_eq!(greeks.epsilon, -61.1763, epsilon = 0.0001);
+
+        assert_abs_diff_eq!(greeks.vanna, -0.1888, epsilon = 0.0001);
+        assert_abs_diff_eq!(greeks.charm, 0.0505, epsilon = 0.0001);
+        assert_abs_diff_eq!(greeks.vomma, 5.6639, epsilon = 0.0001);
+        assert_abs_diff_eq!(greeks.veta, 16.8029, epsilon = 0.0001);
+        assert_abs_diff_eq!(greeks.vera, -56.6389, epsilon = 0.0001);
+
+        assert_abs_diff_eq!(greeks.speed, -0.000472, epsilon = 0.000001);
+        assert_abs_diff_eq!(greeks.zomma, -0.0916, epsilon = 0.0001);
+        assert_abs_diff_eq!(greeks.color, -0.0105, epsilon = 0.0001);
+        assert_abs_diff_eq!(greeks.ultima, -121.8681, epsilon = 0.0001);
+
+        let _result = std::panic::catch_unwind(|| {
+            _ = greeks.parmicharma;
+        });
+
+        assert_abs_diff_eq!(greeks.gamma, 0.01888, epsilon = 0.00001);
+    }
+
+    #[test]
+    fn test_dividend_yield() {
+        let instrument = Instrument::new()
+            .with_spot(100.0)
+            .with_continuous_dividend_yield(0.04);
+        let option = EuropeanOption::new(instrument, 95.0, 2.0, OptionType::Call);
+        let model = BlackScholesModel::new(0.05, 0.25);
+        let numerical = NumericalGreeks::new(model.clone())
+            .with_spot_bump(0.001)
+            .with_time_bump(1e-4);
+
+        for option in [option.clone(), option.flip()] {
+            assert_abs_diff_eq!(
+                model.gamma(&option),
+                numerical.gamma(&option),
+                epsilon = 1e-6
+            );
+            assert_abs_diff_eq!(
+                model.theta(&option),
+                numerical.theta(&option),
+                epsilon = 1e-5
+            );
+            // Speed and zomma are the derivatives of gamma in the spot and the volatility
+            let h = 1e-4;
+            let bumped = |spot: f64, volatility: f64| {
+                BlackScholesModel::new(0.05, volatility).gamma(&EuropeanOption::new(
+                    Instrument::new()
+                        .with_spot(spot)
+                        .with_continuous_dividend_yield(0.04),
+                    95.0,
+                    2.0,
+                    option.option_type(),
+                ))
+            };
+            assert_abs_diff_eq!(
+                model.speed(&option),
+                (bumped(100.0 + h, 0.25) - bumped(100.0 - h, 0.25)) / (2.0 * h),
+                epsilon = 1e-7
+            );
+            assert_abs_diff_eq!(
+                model.zomma(&option),
+                (bumped(100.0, 0.25 + h) - bumped(100.0, 0.25 - h)) / (2.0 * h),
+                epsilon = 1e-6
+            );
+        }
+    }
+}
+
+mod pricing_error_tests {
+    use super::*;
+
+    fn european(spot: f64, option_type: OptionType) -> EuropeanOption {
+        EuropeanOption::new(Instrument::new().with_spot(spot), 100.0, 1.0, option_type)
+    }
+
+    #[test]
+    fn test_try_price() {
+        let model = BlackScholesModel::new(0.05, 0.2);
+        let option = european(100.0, OptionType::Call);
+        assert_eq!(model.try_price(&option), Ok(model.price(&option)));
+
+        let asian =
+            AsianOption::floating(Instrument::new().with_spot(100.0), 1.0, OptionType::Call);
+        let err = model.try_price(&asian).unwrap_err();
+        assert!(matches!(
+            err,
+            PricingError::UnsupportedStyle {
+                model: "BlackScholesModel",
+                ..
+            }
+        ));
+        assert!(
+            err.to_string()
+                .starts_with("BlackScholesModel does not support")
+        );
+
+        assert!(matches!(
+            model.try_price(&european(-1.0, OptionType::Call)),
+            Err(PricingError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            model.try_price(&EuropeanOption::new(
+                Instrument::new().with_spot(100.0),
+                100.0,
+                f64::NAN,
+                OptionType::Put
+            )),
+            Err(PricingError::InvalidInput(_))
+        ));
+
+        let model = FiniteDiffModel::crank_nicolson(0.05, 0.2, 10, 20);
+        assert!(matches!(
+            model.try_price(&asian),
+            Err(PricingError::UnsupportedStyle {
+                model: "FiniteDiffModel",
+                ..
+            })
+        ));
+
+        let model = MonteCarloModel::brownian(0.05, 0.2, 100, 10);
+        assert!(matches!(
+            model.try_price(&asian),
+            Err(PricingError::InvalidInput(_))
+        ));
+
+        let instrument = Instrument::new()
+            .with_weighted_assets(vec![
+                (Instrument::new().with_spot(100.0), 0.5),
+                (Instrument::new().with_spot(90.0), 0.5),
+            ])
+            .with_correlation_matrix(vec![vec![1.0, 1.5], vec![1.5, 1.0]]);
+        let basket = BasketOption::call(instrument, 100.0, 1.0);
+        assert_eq!(
+            model.try_price(&basket),
+            Err(PricingError::invalid_input(
+                "Correlation matrix must be symmetric with entries in [-1, 1]"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_batch_pricing() {
+        let model = BlackScholesModel::new(0.05, 0.2);
+        let options = [
+            european(100.0, OptionType::Call),
+            european(-100.0, OptionType::Call),
+            european(100.0, OptionType::Put),
+        ];
+
+        let prices: Vec<_> = options
+            .iter()
+            .map(|option| model.try_price(option))
+            .collect();
+        assert_abs_diff_eq!(*prices[0].as_ref().unwrap(), 10.4506, epsilon = 0.0001);
+        assert!(prices[1].is_err());
+        assert_abs_diff_eq!(*prices[2].as_ref().unwrap(), 5.5735, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn test_try_implied_volatility() {
+        let model = BlackScholesModel::new(0.02, 0.2);
+        let option = european(100.0, OptionType::Call);
+        let iv = model.try_implied_volatility(&option, 15.0).unwrap();
+        assert_abs_diff_eq!(iv, model.implied_volatility(&option, 15.0), epsilon = 1e-12);
 
-            let price = model.price(&option);
-            assert!(price.is_nan());
+        let model = BlackScholesModel::new(0.05, 0.2);
+        let option = european(100.0, OptionType::Put);
+        assert!(matches!(
+            model.try_implied_volatility(&option, 1200.0),
+            Err(PricingError::PriceOutOfBounds { .. })
+        ));
+        assert!(matches!(
+            model.try_implied_volatility(&option, -1.0),
+            Err(PricingError::InvalidInput(_))
+        ));
 
-            let price = model.price(&option.flip());
-            assert!(price.is_nan());
-        }
+        let model = BinomialTreeModel::new(0.05, 0.2, 100);
+        let err = model.try_implied_volatility(&option, 10.0).unwrap_err();
+        assert_eq!(err, PricingError::not_implemented("Implied volatility"));
+        assert_eq!(
+            err.to_string(),
+            "Implied volatility not implemented for this model"
+        );
+    }
 
-        #[test]
-        fn test_call_greeks() {
-            let option = EuropeanOption::new(
-                Instrument::new().with_spot(2006.0),
-                2100.0,
-                0.08493,
-                OptionType::Call,
-            );
-            let model = Black76Model::new(0.050067, 0.35);
+    #[test]
+    fn test_try_greeks() {
+        let model = BlackScholesModel::new(0.05, 0.2);
+        let option = european(100.0, OptionType::Call);
+        let greeks = model.try_greeks(&option).unwrap();
+        let expected = Greeks::calculate(&model, &option);
+        assert_eq!(greeks.delta, expected.delta);
+        assert_eq!(greeks.gamma, expected.gamma);
+        assert_eq!(greeks.vanna, expected.vanna);
+        assert_eq!(greeks.parmicharma, 0.0);
+        assert_eq!(
+            model.try_parmicharma(&option),
+            Err(PricingError::not_implemented("Parmicharma"))
+        );
 
-            // Sanity check for input values
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 44.5829, epsilon = 0.0001);
+        let american = AmericanOption::new(
+            Instrument::new().with_spot(100.0),
+            100.0,
+            1.0,
+            OptionType::Put,
+        );
+        assert!(matches!(
+            model.try_greeks(&american),
+            Err(PricingError::UnsupportedStyle { .. })
+        ));
+        assert!(model.try_delta(&american).is_err());
 
-            let delta = model.delta(&option);
-            assert_abs_diff_eq!(delta, 0.3438, epsilon = 0.0001);
-            let gamma = model.gamma(&option);
-            assert_abs_diff_eq!(gamma, 0.0018, epsilon = 0.0001);
-            let vega = model.vega(&option);
-            assert_abs_diff_eq!(vega, 214.5523, epsilon = 0.0001);
-            let rho = model.rho(&option);
-            assert_abs_diff_eq!(rho, -3.7864, epsilon = 0.0001);
-            let theta = model.theta(&option);
-            assert_abs_diff_eq!(theta, -439.8573, epsilon = 0.0001);
-        }
+        // Unsupported styles no longer need to unwind
+        let greeks = Greeks::calculate(&model, &american);
+        assert_eq!(greeks.delta, 0.0);
+        assert_eq!(greeks.vega, 0.0);
 
-        #[test]
-        fn test_put_greeks() {
-            let option = EuropeanOption::new(
-                Instrument::new().with_spot(110.0),
-                100.0,
-                0.43,
-                OptionType::Put,
-            );
-            let model = Black76Model::new(0.05, 0.2);
+        let model = Black76Model::new(0.05, 0.2);
+        assert!(matches!(
+            model.try_vega(&american),
+            Err(PricingError::UnsupportedStyle {
+                model: "Black76Model",
+                ..
+            })
+        ));
 
-            // Sanity check for input values
-            let price = model.price(&option);
-            assert_abs_diff_eq!(price, 1.8356, epsilon = 0.0001);
+        let model = HestonModel::new(0.05, 0.04, 2.0, 0.04, 0.3, -0.7);
+        let greeks = model.try_greeks(&option).unwrap();
+        assert!(greeks.delta > 0.0);
+        assert_eq!(greeks.vanna, 0.0);
+        assert!(model.try_greeks(&american).is_err());
 
-            let delta = model.delta(&option);
-            assert_abs_diff_eq!(delta, -0.2095, epsilon = 0.0001);
-            let gamma = model.gamma(&option);
-            assert_abs_diff_eq!(gamma, 0.0197, epsilon = 0.0001);
-            let vega = model.vega(&option);
-            assert_abs_diff_eq!(vega, 20.5771, epsilon = 0.0001);
-            let rho = model.rho(&option);
-            assert_abs_diff_eq!(rho, -0.7893, epsilon = 0.0001);
-            let theta = model.theta(&option);
-            assert_abs_diff_eq!(theta, -4.6936, epsilon = 0.0001);
-        }
+        let model = NumericalGreeks::new(FiniteDiffModel::crank_nicolson(0.05, 0.2, 100, 200));
+        assert!(model.try_delta(&american).is_ok());
+        let asian = AsianOption::fixed(
+            Instrument::new().with_spot(100.0),
+            100.0,
+            1.0,
+            OptionType::Call,
+        );
+        assert!(matches!(
+            model.try_vanna(&asian),
+            Err(PricingError::UnsupportedStyle { .. })
+        ));
     }
-
-    // #[test]
-    // fn test_black_76_iv() {
-    //     let option = EuropeanOption::new(
-    //         Instrument::new()
-    //             .with_spot(125.0)
-    //             .with_continuous_dividend_yield(0.03),
-    //         130.0,
-    //         2.5,
-    //         OptionType::Call,
-    //     );
-    //     let model = Black76Model::new(0.02, 0.2);
-    //
-    //     // Sanity check for input values
-    //     let price = model.price(&option);
-    //     assert_abs_diff_eq!(price, 11.5133, epsilon = 0.0001);
-    //
-    //     let iv = model.implied_volatility(&option, 15.0);
-    //     assert_abs_diff_eq!(iv, 0.2477, epsilon = 0.0001);
-    //
-    //     let option = EuropeanOption::new(
-    //         Instrument::new().with_spot(100.0),
-    //         100.0,
-    //         1.0,
-    //         OptionType::Put,
-    //     );
-    //     let model = Black76Model::new(0.05, 0.2);
-    //     let iv = model.implied_volatility(&option, 1200.0);
-    //     assert_abs_diff_eq!(iv, 2947.0381, epsilon = 0.0001);
-    // }
 }
 
-// Greeks Tests
-mod greeks_tests {
+mod numerical_greeks_tests {
     use super::*;
 
     #[test]
-    fn test_greeks() {
+    fn test_black_scholes() {
         let instrument = Instrument::new()
             .with_spot(100.0)
             .with_continuous_dividend_yield(0.01);
         let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
-        let model = BlackScholesModel::new(0.05, 0.2);
+        let analytic = BlackScholesModel::new(0.05, 0.2);
+        let model = NumericalGreeks::new(BlackScholesModel::new(0.05, 0.2))
+            .with_spot_bump(0.005)
+            .with_volatility_bump(0.005);
+
+        assert_abs_diff_eq!(
+            model.price(&option),
+            analytic.price(&option),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            model.delta(&option),
+            analytic.delta(&option),
+            epsilon = 1e-4
+        );
+        assert_abs_diff_eq!(model.vega(&option), analytic.vega(&option), epsilon = 1e-2);
+        assert_abs_diff_eq!(
+            model.theta(&option),
+            analytic.theta(&option),
+            epsilon = 0.05
+        );
+        assert_abs_diff_eq!(model.rho(&option), analytic.rho(&option), epsilon = 1e-3);
+        assert_abs_diff_eq!(
+            model.lambda(&option),
+            analytic.lambda(&option),
+            epsilon = 1e-3
+        );
+        assert_abs_diff_eq!(
+            model.epsilon(&option),
+            analytic.epsilon(&option),
+            epsilon = 1e-3
+        );
+        assert_abs_diff_eq!(model.gamma(&option), 0.01888, epsilon = 1e-4);
+        assert_abs_diff_eq!(
+            model.vanna(&option),
+            analytic.vanna(&option),
+            epsilon = 1e-3
+        );
+        assert_abs_diff_eq!(
+            model.charm(&option),
+            analytic.charm(&option),
+            epsilon = 1e-3
+        );
+        assert_abs_diff_eq!(
+            model.vomma(&option),
+            analytic.vomma(&option),
+            epsilon = 1e-2
+        );
+        assert_abs_diff_eq!(model.veta(&option), analytic.veta(&option), epsilon = 1e-2);
+        assert_abs_diff_eq!(model.vera(&option), analytic.vera(&option), epsilon = 0.05);
+        assert_abs_diff_eq!(
+            model.speed(&option),
+            analytic.speed(&option),
+            epsilon = 1e-5
+        );
+        assert_abs_diff_eq!(
+            model.zomma(&option),
+            analytic.zomma(&option),
+            epsilon = 1e-3
+        );
+        assert_abs_diff_eq!(
+            model.color(&option),
+            analytic.color(&option),
+            epsilon = 1e-3
+        );
+        assert_abs_diff_eq!(
+            model.ultima(&option),
+            analytic.ultima(&option),
+            epsilon = 1.0
+        );
+    }
 
-        let greeks = Greeks::calculate(&model, &option);
+    #[test]
+    fn test_binomial_tree() {
+        let instrument = Instrument::new()
+            .with_spot(100.0)
+            .with_continuous_dividend_yield(0.02);
+        let option = AmericanOption::new(instrument, 110.0, 1.0, OptionType::Put);
+        let model =
+            NumericalGreeks::new(BinomialTreeModel::new(0.05, 0.25, 1000)).with_spot_bump(0.05);
+
+        assert_abs_diff_eq!(model.delta(&option), -0.5872, epsilon = 0.002);
+        assert_abs_diff_eq!(model.gamma(&option), 0.01818, epsilon = 0.001);
+        assert!(model.theta(&option) > 0.0);
+        assert!(model.vega(&option) > 0.0);
+        assert!(model.rho(&option) < 0.0);
+    }
 
-        assert_abs_diff_eq!(greeks.delta, 0.6118, epsilon = 0.0001);
-        assert_abs_diff_eq!(greeks.vega, 37.7593, epsilon = 0.0001);
-        assert_abs_diff_eq!(greeks.theta, 5.7696, epsilon = 0.0001);
-        assert_abs_diff_eq!(greeks.rho, 51.3500, epsilon = 0.0001);
+    #[test]
+    fn test_monte_carlo_common_random_numbers() {
+        let instrument = Instrument::new().with_spot(100.0);
+        let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+        let analytic = BlackScholesModel::new(0.05, 0.2);
+        let model =
+            NumericalGreeks::new(MonteCarloModel::brownian(0.05, 0.2, 20_000, 1).with_seed(42));
+
+        assert_abs_diff_eq!(
+            model.delta(&option),
+            analytic.delta(&option),
+            epsilon = 0.01
+        );
+        assert_abs_diff_eq!(model.vega(&option), analytic.vega(&option), epsilon = 1.0);
+        assert_abs_diff_eq!(model.rho(&option), analytic.rho(&option), epsilon = 1.0);
+        assert_abs_diff_eq!(model.delta(&option), model.delta(&option), epsilon = 1e-12);
+    }
 
-        let _result = std::panic::catch_unwind(|| {
-            _ = greeks.epsilon;
-        });
+    #[test]
+    fn test_heston() {
+        let instrument = Instrument::new().with_spot(100.0);
+        let option = EuropeanOption::new(instrument, 100.0, 1.0, OptionType::Call);
+        let heston = HestonModel::new(0.05, 0.04, 1.5, 0.04, 0.3, -0.7);
+        let model = NumericalGreeks::new(heston.clone()).with_spot_bump(0.001);
 
-        let _result = std::panic::catch_unwind(|| {
-            _ = greeks.vanna;
-        });
-        let _result = std::panic::catch_unwind(|| {
-            _ = greeks.charm;
-        });
-        let _result = std::panic::catch_unwind(|| {
-            _ = greeks.vomma;
-        });
-        let _result = std::panic::catch_unwind(|| {
-            _ = greeks.veta;
-        });
-        let _result = std::panic::catch_unwind(|| {
-            _ = greeks.vera;
-        });
-        let _result = std::panic::catch_unwind(|| {
-            _ = greeks.speed;
-        });
-        let _result = std::panic::catch_unwind(|| {
-            _ = greeks.zomma;
-        });
-        let _result = std::panic::catch_unwind(|| {
-            _ = greeks.color;
-        });
-        let _result = std::panic::catch_unwind(|| {
-            _ = greeks.ultima;
-        });
-        let _result = std::panic::catch_unwind(|| {
-            _ = greeks.parmicharma;
-        });
+        assert_abs_diff_eq!(model.delta(&option), heston.delta(&option), epsilon = 1e-4);
+        assert_abs_diff_eq!(model.gamma(&option), heston.gamma(&option), epsilon = 1e-4);
+        assert_abs_diff_eq!(model.rho(&option), heston.rho(&option), epsilon = 1e-2);
+    }
 
-        assert_abs_diff_eq!(greeks.gamma, 0.0191, epsilon = 0.0001);
+    #[test]
+    fn test_expiry() {
+        let instrument = Instrument::new().with_spot(100.0);
+        let option = EuropeanOption::new(instrument, 110.0, 0.0, OptionType::Put);
+        let model = NumericalGreeks::new(BlackScholesModel::new(0.05, 0.2));
+
+        assert_abs_diff_eq!(model.delta(&option), -1.0, epsilon = 1e-6);
+        assert!(matches!(
+            model.try_theta(&option),
+            Err(PricingError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            model.try_charm(&option),
+            Err(PricingError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            model.try_color(&option),
+            Err(PricingError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            NumericalGreeks::new(BlackScholesModel::new(0.05, 0.2))
+                .with_time_bump(0.0)
+                .try_veta(&EuropeanOption::new(
+                    Instrument::new().with_spot(100.0),
+                    110.0,
+                    1.0,
+                    OptionType::Put
+                )),
+            Err(PricingError::InvalidInput(_))
+        ));
     }
 }
 
@@ -1814,6 +5615,38 @@ mod instrument_tests {
         );
     }
 
+    #[test]
+    fn test_cash_dividends() {
+        let instrument = Instrument::new().with_spot(100.0).with_cash_dividends(vec![
+            CashDividend::new(0.5, 3.0),
+            CashDividend::new(0.25, 2.0),
+            CashDividend::new(1.5, 5.0),
+        ]);
+
+        let ex_dates: Vec<f64> = instrument
+            .cash_dividends
+            .iter()
+            .map(|dividend| dividend.ex_date)
+            .collect();
+        assert_eq!(ex_dates, vec![0.25, 0.5, 1.5]);
+        assert_abs_diff_eq!(
+            instrument.dividends_present_value(0.05, 0.0, 1.0),
+            4.9011,
+            epsilon = 0.0001
+        );
+        assert_abs_diff_eq!(
+            instrument.dividends_present_value(0.05, 0.4, 2.0),
+            7.7175,
+            epsilon = 0.0001
+        );
+        assert_abs_diff_eq!(
+            instrument.escrowed_spot(0.05, 1.0),
+            95.0989,
+            epsilon = 0.0001
+        );
+        assert_eq!(instrument.escrowed_spot(0.05, 0.1), 100.0);
+    }
+
     #[test]
     fn test_log_simulation() {
         let instrument = Instrument::new().with_spot(100.0).with_cont_yield(0.01);
@@ -1858,6 +5691,26 @@ mod instrument_tests {
         );
 
         assert_abs_diff_eq!(price, 100.0, epsilon = 100.0 * 0.01 * 4.0);
+
+        // The geometric average of a simulated path lies below its arithmetic average
+        let average = |geometric: bool| {
+            let mut rng = <rand::rngs::StdRng as rand::SeedableRng>::seed_from_u64(7);
+            let simulate = if geometric {
+                Instrument::simulate_geometric_average
+            } else {
+                Instrument::simulate_arithmetic_average
+            };
+            simulate(
+                &instrument,
+                &mut rng,
+                quantrs::options::SimMethod::Log,
+                0.3,
+                1.0,
+                0.05,
+                252,
+            )
+        };
+        assert!(average(true) < average(false));
     }
 
     #[test]
@@ -1903,6 +5756,387 @@ mod instrument_tests {
         assert_eq!(instrument.assets[1].0.spot, asset2.spot);
         assert_eq!(instrument.assets[2].0.spot, asset3.spot);
     }
+
+    #[test]
+    fn test_correlation_matrix() {
+        let asset1 = Instrument::new().with_spot(86.0);
+        let asset2 = Instrument::new().with_spot(104.0);
+        let asset3 = Instrument::new().with_spot(115.0);
+
+        let instrument = Instrument::new().with_assets(vec![asset1.clone(), asset2.clone()]);
+        assert_eq!(
+            instrument.correlation_matrix(),
+            vec![vec![1.0, 0.0], vec![0.0, 1.0]]
+        );
+        assert_eq!(Instrument::new().correlation_matrix(), vec![vec![1.0]]);
+
+        // The matrix is reordered together with the assets
+        let instrument = Instrument::new()
+            .with_correlation_matrix(vec![
+                vec![1.0, 0.1, 0.2],
+                vec![0.1, 1.0, 0.3],
+                vec![0.2, 0.3, 1.0],
+            ])
+            .with_assets(vec![asset1, asset2, asset3]);
+        assert_eq!(instrument.assets[0].0.spot(), 115.0);
+        assert_eq!(
+            instrument.correlation_matrix(),
+            vec![
+                vec![1.0, 0.3, 0.2],
+                vec![0.3, 1.0, 0.1],
+                vec![0.2, 0.1, 1.0],
+            ]
+        );
+
+        // The matrix follows the order in which the assets are passed, before or after setting them
+        let correlation = vec![
+            vec![1.0, 0.1, 0.2],
+            vec![0.1, 1.0, 0.3],
+            vec![0.2, 0.3, 1.0],
+        ];
+        let assets = vec![
+            Instrument::new().with_spot(86.0),
+            Instrument::new().with_spot(104.0),
+            Instrument::new().with_spot(115.0),
+        ];
+        let before = Instrument::new()
+            .with_correlation_matrix(correlation.clone())
+            .with_assets(assets.clone());
+        let after = Instrument::new()
+            .with_assets(assets)
+            .with_correlation_matrix(correlation);
+        assert_eq!(before.correlation_matrix(), after.correlation_matrix());
+
+        // Invalid matrices are reported when pricing
+        let instrument = Instrument::new()
+            .with_assets(vec![
+                Instrument::new().with_spot(86.0),
+                Instrument::new().with_spot(104.0),
+            ])
+            .with_correlation_matrix(vec![vec![1.0, 0.5]]);
+        let basket = BasketOption::call(instrument, 100.0, 1.0);
+        assert!(matches!(
+            BlackScholesModel::new(0.05, 0.2).try_price(&basket),
+            Err(PricingError::InvalidInput(_))
+        ));
+    }
+}
+
+// Term Structure Tests
+mod term_structure_tests {
+    use super::*;
+
+    #[test]
+    fn test_yield_curve() {
+        let curve = YieldCurve::new(vec![0.5, 1.0, 2.0], vec![0.03, 0.035, 0.04]);
+
+        assert_abs_diff_eq!(curve.zero_rate(0.25), 0.03, epsilon = 1e-12);
+        assert_abs_diff_eq!(curve.zero_rate(1.5), 0.0375, epsilon = 1e-12);
+        assert_abs_diff_eq!(curve.zero_rate(3.0), 0.04, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            curve.discount_factor(1.5),
+            (-0.0375 * 1.5f64).exp(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(curve.forward_rate(1.0, 2.0), 0.045, epsilon = 1e-12);
+        assert_abs_diff_eq!(curve.forward_rate(1.0, 1.0), 0.035, epsilon = 1e-12);
+        assert_abs_diff_eq!(curve.shifted(0.01).zero_rate(1.5), 0.0475, epsilon = 1e-12);
+        assert_abs_diff_eq!(YieldCurve::flat(0.05).zero_rate(10.0), 0.05);
+
+        assert!(
+            std::panic::catch_unwind(|| YieldCurve::new(vec![1.0, 0.5], vec![0.03, 0.04])).is_err()
+        );
+        assert!(std::panic::catch_unwind(|| YieldCurve::new(vec![], vec![])).is_err());
+        for (times, rates) in [
+            (vec![], vec![]),
+            (vec![1.0, 0.5], vec![0.03, 0.04]),
+            (vec![0.5, 1.0], vec![0.03]),
+            (vec![0.0, 1.0], vec![0.03, 0.04]),
+            (vec![0.5, 1.0], vec![0.03, f64::NAN]),
+        ] {
+            assert!(matches!(
+                YieldCurve::try_new(times, rates),
+                Err(PricingError::InvalidInput(_))
+            ));
+        }
+        assert_eq!(
+            YieldCurve::try_new(vec![0.5, 1.0, 2.0], vec![0.03, 0.035, 0.04]),
+            Ok(curve)
+        );
+    }
+
+    #[test]
+    fn test_volatility_term_structure() {
+        let volatilities = VolatilityTermStructure::new(vec![0.5, 1.0, 2.0], vec![0.25, 0.22, 0.2]);
+
+        assert_abs_diff_eq!(volatilities.variance(0.0), 0.0);
+        assert_abs_diff_eq!(volatilities.volatility(0.25), 0.25, epsilon = 1e-12);
+        assert_abs_diff_eq!(volatilities.volatility(1.0), 0.22, epsilon = 1e-12);
+        assert_abs_diff_eq!(volatilities.variance(1.5), 0.0642, epsilon = 1e-12);
+        assert_abs_diff_eq!(volatilities.volatility(4.0), 0.2, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            volatilities.forward_volatility(1.0, 2.0),
+            0.0316f64.sqrt(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            volatilities.shifted(0.01).volatility(1.0),
+            0.23,
+            epsilon = 1e-12
+        );
+
+        // A decreasing total variance implies a negative forward variance
+        assert!(
+            std::panic::catch_unwind(|| {
+                VolatilityTermStructure::new(vec![1.0, 2.0], vec![0.3, 0.2])
+            })
+            .is_err()
+        );
+        for (times, volatilities) in [
+            (vec![1.0, 2.0], vec![0.3, 0.2]),
+            (vec![2.0, 1.0], vec![0.2, 0.2]),
+            (vec![1.0], vec![-0.2]),
+            (vec![1.0, 2.0], vec![0.2]),
+        ] {
+            assert!(matches!(
+                VolatilityTermStructure::try_new(times, volatilities),
+                Err(PricingError::InvalidInput(_))
+            ));
+        }
+        assert!(VolatilityTermStructure::try_new(vec![0.5, 1.0], vec![0.25, 0.22]).is_ok());
+    }
+}
+
+// Volatility Surface Tests
+mod vol_surface_tests {
+    use super::*;
+    use quantrs::options::{ArbitrageViolation, QuoteValue};
+
+    fn quotes(instrument: &Instrument, smiles: &[(f64, SviParameters)]) -> Vec<MarketQuote> {
+        smiles
+            .iter()
+            .flat_map(|&(ttm, parameters)| {
+                let forward = instrument.spot() * (0.02 * ttm).exp();
+                [70.0, 80.0, 90.0, 95.0, 100.0, 105.0, 110.0, 120.0, 130.0].map(|strike| {
+                    let variance = parameters.total_variance((strike / forward).ln());
+                    MarketQuote::implied_volatility(
+                        strike,
+                        ttm,
+                        OptionType::Call,
+                        (variance / ttm).sqrt(),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_svi_fit() {
+        let instrument = Instrument::new()
+            .with_spot(100.0)
+            .with_continuous_dividend_yield(0.01);
+        let smiles = [
+            (0.5, SviParameters::new(0.01, 0.1, -0.4, 0.05, 0.2)),
+            (1.0, SviParameters::new(0.02, 0.12, -0.35, 0.05, 0.25)),
+        ];
+        let surface = VolSurface::fit(
+            &instrument,
+            0.03,
+            &quotes(&instrument, &smiles),
+            SmileModel::Svi,
+        )
+        .unwrap();
+
+        assert_eq!(surface.slices.len(), 2);
+        for (slice, (ttm, parameters)) in surface.slices.iter().zip(smiles) {
+            assert_eq!(slice.time_to_maturity, ttm);
+            assert_abs_diff_eq!(slice.forward, 100.0 * (0.02 * ttm).exp(), epsilon = 1e-10);
+            assert_abs_diff_eq!(slice.parameters.a, parameters.a, epsilon = 1e-6);
+            assert_abs_diff_eq!(slice.parameters.b, parameters.b, epsilon = 1e-6);
+            assert_abs_diff_eq!(slice.parameters.rho, parameters.rho, epsilon = 1e-6);
+            assert_abs_diff_eq!(slice.parameters.m, parameters.m, epsilon = 1e-6);
+            assert_abs_diff_eq!(slice.parameters.sigma, parameters.sigma, epsilon = 1e-6);
+            assert!(slice.rmse < 1e-8);
+        }
+        assert!(surface.arbitrage_violations().is_empty());
+
+        // Quoted prices are converted to implied volatilities
+        let prices: Vec<MarketQuote> = quotes(&instrument, &smiles)
+            .iter()
+            .map(|quote| {
+                let QuoteValue::ImpliedVolatility(volatility) = quote.value else {
+                    unreachable!()
+                };
+                let option = EuropeanOption::new(
+                    instrument.clone(),
+                    quote.strike,
+                    quote.time_to_maturity,
+                    OptionType::Put,
+                );
+                MarketQuote::price(
+                    quote.strike,
+                    quote.time_to_maturity,
+                    OptionType::Put,
+                    BlackScholesModel::new(0.03, volatility).price(&option),
+                )
+            })
+            .collect();
+        let fitted = VolSurface::fit(&instrument, 0.03, &prices, SmileModel::Svi).unwrap();
+        assert_abs_diff_eq!(
+            fitted.volatility(0.75, 85.0),
+            surface.volatility(0.75, 85.0),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn test_ssvi_fit() {
+        let instrument = Instrument::new()
+            .with_spot(100.0)
+            .with_continuous_dividend_yield(0.01);
+        let smiles = [
+            (0.5, SviParameters::from_ssvi(0.02, -0.5, 2.0)),
+            (1.0, SviParameters::from_ssvi(0.04, -0.4, 1.5)),
+        ];
+        let surface = VolSurface::fit(
+            &instrument,
+            0.03,
+            &quotes(&instrument, &smiles),
+            SmileModel::Ssvi,
+        )
+        .unwrap();
+
+        for (slice, (_, parameters)) in surface.slices.iter().zip(smiles) {
+            assert!(slice.rmse < 1e-8);
+            for k in [-0.3, 0.0, 0.2] {
+                assert_abs_diff_eq!(
+                    slice.parameters.total_variance(k),
+                    parameters.total_variance(k),
+                    epsilon = 1e-8
+                );
+            }
+        }
+
+        // The ATM total variance of an SSVI slice is θ
+        assert_abs_diff_eq!(
+            SviParameters::from_ssvi(0.04, -0.4, 1.5).total_variance(0.0),
+            0.04,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_interpolation() {
+        let surface = VolSurface::new(
+            100.0,
+            vec![
+                SmileSlice {
+                    time_to_maturity: 1.0,
+                    forward: 103.0,
+                    parameters: SviParameters::new(0.04, 0.0, 0.0, 0.0, 0.1),
+                    rmse: 0.0,
+                },
+                SmileSlice {
+                    time_to_maturity: 0.5,
+                    forward: 101.0,
+                    parameters: SviParameters::new(0.01, 0.0, 0.0, 0.0, 0.1),
+                    rmse: 0.0,
+                },
+            ],
+        );
+
+        // The smiles are sorted by expiry and the forwards are interpolated log-linearly
+        assert_eq!(surface.slices[0].time_to_maturity, 0.5);
+        assert_abs_diff_eq!(surface.forward(0.25), 101f64.sqrt() * 10.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(surface.forward(1.5), 103.0 * 103.0 / 101.0, epsilon = 1e-10);
+
+        // Total variance is linear in time between the smiles and implied volatilities are flat outside
+        assert_abs_diff_eq!(surface.total_variance(0.75, 100.0), 0.025, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            surface.volatility(0.25, 100.0),
+            0.01f64.sqrt() / 0.5f64.sqrt(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(surface.volatility(3.0, 100.0), 0.2, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            surface.shifted(0.01).volatility(3.0, 100.0),
+            0.21,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_arbitrage() {
+        let slice = |ttm, parameters| SmileSlice {
+            time_to_maturity: ttm,
+            forward: 100.0,
+            parameters,
+            rmse: 0.0,
+        };
+        let surface = VolSurface::new(
+            100.0,
+            vec![
+                slice(0.5, SviParameters::new(-0.05, 0.8, -0.9, 0.0, 0.01)),
+                slice(1.0, SviParameters::new(0.001, 0.01, 0.0, 0.0, 0.1)),
+            ],
+        );
+        let violations = surface.arbitrage_violations();
+
+        assert_eq!(violations.len(), 2);
+        assert!(
+            matches!(violations[0], ArbitrageViolation::Butterfly { time_to_maturity, .. } if time_to_maturity == 0.5)
+        );
+        assert!(
+            matches!(violations[1], ArbitrageViolation::Calendar { time_to_maturity, .. } if time_to_maturity == 1.0)
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        let instrument = Instrument::new().with_spot(100.0);
+        let quotes: Vec<MarketQuote> = [90.0, 100.0, 110.0]
+            .map(|strike| MarketQuote::implied_volatility(strike, 1.0, OptionType::Call, 0.2))
+            .to_vec();
+
+        assert!(matches!(
+            VolSurface::fit(&instrument, 0.03, &quotes, SmileModel::Svi),
+            Err(PricingError::InvalidInput(_))
+        ));
+        assert!(VolSurface::fit(&instrument, 0.03, &quotes, SmileModel::Ssvi).is_ok());
+        assert!(matches!(
+            VolSurface::fit(&instrument, 0.03, &[], SmileModel::Ssvi),
+            Err(PricingError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            VolSurface::fit(
+                &instrument,
+                0.03,
+                &[MarketQuote::implied_volatility(100.0, 1.0, OptionType::Call, -0.2); 3],
+                SmileModel::Ssvi
+            ),
+            Err(PricingError::InvalidInput(_))
+        ));
+
+        let slice = |ttm, forward| SmileSlice {
+            time_to_maturity: ttm,
+            forward,
+            parameters: SviParameters::new(0.04, 0.0, 0.0, 0.0, 0.1),
+            rmse: 0.0,
+        };
+        for (spot, slices) in [
+            (100.0, vec![]),
+            (0.0, vec![slice(1.0, 100.0)]),
+            (100.0, vec![slice(0.0, 100.0)]),
+            (100.0, vec![slice(1.0, -100.0)]),
+            (100.0, vec![slice(1.0, 100.0), slice(1.0, 101.0)]),
+        ] {
+            assert!(matches!(
+                VolSurface::try_new(spot, slices),
+                Err(PricingError::InvalidInput(_))
+            ));
+        }
+        assert!(VolSurface::try_new(100.0, vec![slice(1.0, 100.0)]).is_ok());
+    }
 }
 
 // Option Trait Tests
@@ -1989,6 +6223,32 @@ mod option_trait_tests {
         let opt =
             LookbackOption::floating(Instrument::new().with_spot(100.0), 1.0, OptionType::Put);
         assert_implements_option_trait(&opt);
+        let opt = BarrierOption::down_and_out(
+            Instrument::new().with_spot(100.0),
+            100.0,
+            1.0,
+            OptionType::Call,
+            90.0,
+        );
+        assert_implements_option_trait(&opt);
+        let opt = BarrierOption::double_knock_in(
+            Instrument::new().with_spot(100.0),
+            100.0,
+            1.0,
+            OptionType::Put,
+            90.0,
+            110.0,
+        );
+        assert_implements_option_trait(&opt);
+        let opt = BasketOption::call(
+            Instrument::new().with_assets(vec![
+                Instrument::new().with_spot(100.0),
+                Instrument::new().with_spot(90.0),
+            ]),
+            95.0,
+            1.0,
+        );
+        assert_implements_option_trait(&opt);
         let opt = BinaryOption::cash_or_nothing(
             Instrument::new().with_spot(100.0),
             100.0,
@@ -2050,6 +6310,10 @@ mod option_trait_tests {
         assert_implements_model_trait(&model);
         let model = Black76Model::new(0.05, 0.2);
         assert_implements_model_trait(&model);
+        let model = HestonModel::new(0.05, 0.04, 2.0, 0.04, 0.3, -0.7);
+        assert_implements_model_trait(&model);
+        let model = FiniteDiffModel::crank_nicolson(0.05, 0.2, 10, 20);
+        assert_implements_model_trait(&model);
     }
 }
 